@@ -0,0 +1,49 @@
+#!/usr/bin/env tsx
+/**
+ * CLI script to export tile images as SVG
+ *
+ * Usage: tsx scripts/export-tiles.ts <output-dir> [--export-tiles]
+ *
+ * Generates:
+ * - <output-dir>/T<type>_<orientation>.svg - One SVG per tile type in each of the
+ *   six rotations, e.g. T2_SE.svg
+ * - <output-dir>/tile-atlas.svg - With --export-tiles, every tile type in every
+ *   unique rotation laid out in a labelled grid
+ */
+
+import * as fs from 'fs';
+import * as path from 'path';
+import { TileType, Rotation } from '../src/game/types';
+import { generateTileAtlasSvg, generateTileSvg, getTileSvgName } from '../src/rendering/tileAtlas';
+
+function main() {
+  const args = process.argv.slice(2);
+  const exportAtlas = args.includes('--export-tiles');
+  const positional = args.filter(arg => !arg.startsWith('--'));
+
+  if (positional.length < 1) {
+    console.error('Usage: tsx scripts/export-tiles.ts <output-dir> [--export-tiles]');
+    console.error('');
+    console.error('Example: tsx scripts/export-tiles.ts docs/tiles --export-tiles');
+    process.exit(1);
+  }
+
+  const outputDir = positional[0];
+  fs.mkdirSync(outputDir, { recursive: true });
+
+  for (const type of [TileType.NoSharps, TileType.OneSharp, TileType.TwoSharps, TileType.ThreeSharps]) {
+    for (const rotation of [0, 1, 2, 3, 4, 5] as Rotation[]) {
+      const file = path.join(outputDir, `${getTileSvgName(type, rotation)}.svg`);
+      fs.writeFileSync(file, generateTileSvg(type, rotation));
+      console.log(`  ✓ Wrote ${file}`);
+    }
+  }
+
+  if (exportAtlas) {
+    const file = path.join(outputDir, 'tile-atlas.svg');
+    fs.writeFileSync(file, generateTileAtlasSvg());
+    console.log(`  ✓ Wrote ${file}`);
+  }
+}
+
+main();
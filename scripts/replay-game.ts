@@ -0,0 +1,103 @@
+#!/usr/bin/env tsx
+/**
+ * CLI script to replay a game headlessly
+ *
+ * Usage: tsx scripts/replay-game.ts < <game-file>
+ *
+ * Reads a save file, a JSON array of actions, or a JSONL action log (as written by
+ * generate-game-test.ts) from stdin, replays it through the game reducer, and prints
+ * the final board and outcome. Exits with status 1 if the input cannot be loaded,
+ * contains moves the reducer rejects, or contains a move that breaks the legality
+ * rules (such as closing a player's last path), so illegal-move reports can be
+ * reproduced without opening the game.
+ */
+
+import * as fs from 'fs';
+import { pathToFileURL } from 'url';
+import { GameAction } from '../src/redux/actions';
+import { fromSaveString, toSaveString } from '../src/redux/saveFile';
+import { boardToText } from '../src/game/boardText';
+import { validateMoveHistory } from '../src/game/legality';
+
+// Turn stdin into save text; bare action logs are wrapped in a save header
+function toSaveText(input: string): string {
+  try {
+    const parsed = JSON.parse(input);
+    if (Array.isArray(parsed)) {
+      return toSaveString(parsed as GameAction[]);
+    }
+    // A one-line action log parses as a single action rather than a save
+    return parsed && typeof parsed === 'object' && 'type' in parsed ? toSaveString([parsed]) : input;
+  } catch {
+    const lines = input.split('\n').filter(line => line.trim().length > 0);
+    try {
+      return toSaveString(lines.map(line => JSON.parse(line) as GameAction));
+    } catch {
+      return input;
+    }
+  }
+}
+
+// Replay a game log, returning the report to print and any errors
+// Besides moves the reducer rejects, the move history is checked against the full
+// legality rules, since the reducer does not stop a placement closing a player's last path
+export function replayGame(input: string): { report: string[]; errors: string[] } {
+  const result = fromSaveString(toSaveText(input));
+  if (!result.valid) {
+    return { report: [], errors: [`Error: ${result.reason}`] };
+  }
+
+  const { state, actions, rejectedMoves } = result;
+  const report = [
+    boardToText(state.board, state.players, state.flowEdges, state.boardRadius),
+    '',
+    `Actions: ${actions.length}, moves: ${state.moveHistory.length}`,
+  ];
+
+  if (state.phase === 'finished') {
+    const winners = state.winners.length > 0 ? `, winners ${state.winners.join(', ')}` : '';
+    report.push(`Outcome: ${state.winType}${winners}`);
+  } else {
+    const currentPlayer = state.players[state.currentPlayerIndex];
+    report.push(`Outcome: in progress (phase ${state.phase}, ${currentPlayer ? currentPlayer.id : 'no player'} to move)`);
+  }
+
+  const errors = rejectedMoves.map(
+    (index) => `Illegal move: action ${index + 1} ${JSON.stringify(actions[index])}`,
+  );
+
+  const history = validateMoveHistory(
+    state.moveHistory,
+    state.players,
+    state.teams,
+    state.boardRadius,
+    state.supermove,
+    state.supermoveAnyPlayer,
+    state.oppositeSidesVictory,
+  );
+  if (!history.valid) {
+    const move = state.moveHistory[history.moveIndex];
+    errors.push(`Illegal move: move ${history.moveIndex + 1} by ${move.playerId}: ${history.reason}`);
+  }
+
+  return { report, errors };
+}
+
+function main() {
+  const input = fs.readFileSync(0, 'utf-8');
+
+  // The reducer logs each rejected move; the summary below covers them
+  console.warn = () => {};
+
+  const { report, errors } = replayGame(input);
+  report.forEach((line) => console.log(line));
+  errors.forEach((line) => console.error(line));
+  if (errors.length > 0) {
+    process.exit(1);
+  }
+}
+
+// Only run when invoked directly, so tests can import replayGame
+if (process.argv[1] && import.meta.url === pathToFileURL(process.argv[1]).href) {
+  main();
+}
@@ -226,6 +226,60 @@ describe('GameStorage', () => {
       
       await newStorage.shutdown();
     });
+
+    it('should pick up a game in progress after a restart', async () => {
+      const gameId = 'test-game-restart';
+      await storage.createGame(gameId, 'Test Game', 'host-1', 2);
+
+      for (const id of ['player-1', 'player-2']) {
+        await storage.appendAction(gameId, {
+          type: 'JOIN_GAME',
+          payload: { player: { id, username: id, socketId: `socket-${id}`, connected: true } },
+          playerId: id,
+          timestamp: Date.now(),
+          sequence: 0
+        });
+      }
+      await storage.appendAction(gameId, {
+        type: 'START_GAME',
+        payload: { seed: 1234, turnTimeLimit: 60 },
+        playerId: 'player-1',
+        timestamp: Date.now(),
+        sequence: 0
+      });
+      await storage.appendAction(gameId, {
+        type: 'PLACE_TILE',
+        payload: { position: { row: 0, col: 0 }, rotation: 2 },
+        playerId: 'player-1',
+        timestamp: Date.now(),
+        sequence: 0
+      });
+      await storage.flushAll();
+      const actionsBefore = await storage.readActions(gameId);
+      await storage.shutdown();
+
+      // A fresh instance stands in for the restarted server
+      const restarted = new GameStorage(testDataDir);
+      await restarted.initialize();
+
+      expect(await restarted.readActions(gameId)).toEqual(actionsBefore);
+      const state = await restarted.getGameState(gameId);
+      expect(state?.status).toBe('playing');
+      expect(state?.players.map(p => p.id)).toEqual(['player-1', 'player-2']);
+
+      // New actions continue the persisted sequence
+      restarted.clearCache(gameId);
+      const next = await restarted.appendAction(gameId, {
+        type: 'PLACE_TILE',
+        payload: { position: { row: 1, col: 0 }, rotation: 0 },
+        playerId: 'player-2',
+        timestamp: Date.now(),
+        sequence: 0
+      });
+      expect(next.sequence).toBe(actionsBefore[actionsBefore.length - 1].sequence + 1);
+
+      await restarted.shutdown();
+    });
   });
 
   describe('clearCache', () => {
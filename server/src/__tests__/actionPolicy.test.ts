@@ -0,0 +1,107 @@
+import { describe, it, expect } from 'vitest';
+import {
+  isServerOnlyAction,
+  sanitizeClientAction,
+  validateClientAction,
+  describeInvalidAction,
+  generateGameSeed,
+} from '../actionPolicy';
+
+describe('Action Policy', () => {
+  const SERVER_SEED = 4242;
+
+  describe('isServerOnlyAction', () => {
+    it('should classify seating randomization as server-only', () => {
+      expect(isServerOnlyAction('START_SEATING_PHASE')).toBe(true);
+    });
+
+    it('should reserve AI seat handovers for the server', () => {
+      expect(isServerOnlyAction('HAND_OVER_TO_AI')).toBe(true);
+      expect(isServerOnlyAction('RECLAIM_FROM_AI')).toBe(true);
+    });
+
+    it('should reserve turn timeouts for the server', () => {
+      expect(isServerOnlyAction('TURN_TIMEOUT')).toBe(true);
+    });
+
+    it('should allow ordinary player actions', () => {
+      expect(isServerOnlyAction('PLACE_TILE')).toBe(false);
+      expect(isServerOnlyAction('DRAW_TILE')).toBe(false);
+    });
+  });
+
+  describe('sanitizeClientAction', () => {
+    it('should discard a client-sent seating order', () => {
+      const action = { type: 'START_SEATING_PHASE', payload: { seatingOrder: ['P2', 'P1'] } };
+      expect(sanitizeClientAction(action, SERVER_SEED)).toBeNull();
+    });
+
+    it('should replace a client-chosen seed with the server seed', () => {
+      const action = { type: 'SHUFFLE_TILES', payload: { seed: 1, tileDistribution: [1, 1, 1, 1] } };
+      expect(sanitizeClientAction(action, SERVER_SEED)).toEqual({
+        type: 'SHUFFLE_TILES',
+        payload: { seed: SERVER_SEED, tileDistribution: [1, 1, 1, 1] },
+      });
+    });
+
+    it('should apply the server seed to START_GAME even without a client seed', () => {
+      const action = { type: 'START_GAME', payload: { boardRadius: 3 } };
+      expect(sanitizeClientAction(action, SERVER_SEED)?.payload).toEqual({
+        boardRadius: 3,
+        seed: SERVER_SEED,
+      });
+    });
+
+    it('should drop any tile value attached to a client draw', () => {
+      const action = { type: 'DRAW_TILE', payload: { tile: 3 } };
+      expect(sanitizeClientAction(action, SERVER_SEED)).toEqual({ type: 'DRAW_TILE', payload: {} });
+    });
+
+    it('should pass other actions through unchanged', () => {
+      const action = { type: 'PLACE_TILE', payload: { position: { row: 0, col: 0 }, rotation: 2 } };
+      expect(sanitizeClientAction(action, SERVER_SEED)).toEqual(action);
+    });
+  });
+
+  describe('validateClientAction', () => {
+    it('should accept an ordinary move from a seated player', () => {
+      const action = { type: 'PLACE_TILE', payload: { position: { row: 0, col: 0 }, rotation: 2 } };
+      expect(validateClientAction(action, true, true)).toBeNull();
+      expect(validateClientAction({ type: 'DRAW_TILE' }, true, true)).toBeNull();
+    });
+
+    it('should reject malformed actions', () => {
+      expect(validateClientAction(null, true, true)).toBe('malformed');
+      expect(validateClientAction('PLACE_TILE', true, true)).toBe('malformed');
+      expect(validateClientAction({ payload: {} }, true, true)).toBe('malformed');
+      expect(validateClientAction({ type: 'PLACE_TILE', payload: 7 }, true, true)).toBe('malformed');
+    });
+
+    it('should reject actions for a game that does not exist', () => {
+      expect(validateClientAction({ type: 'PLACE_TILE', payload: {} }, false, false)).toBe('unknown-game');
+    });
+
+    it('should reject server-only actions', () => {
+      expect(validateClientAction({ type: 'HAND_OVER_TO_AI', payload: {} }, true, true)).toBe('server-only');
+    });
+
+    it('should reject actions from anyone not seated in the game', () => {
+      expect(validateClientAction({ type: 'PLACE_TILE', payload: {} }, true, false)).toBe('not-a-player');
+    });
+  });
+
+  describe('describeInvalidAction', () => {
+    it('should name the action in a server-only rejection', () => {
+      expect(describeInvalidAction('server-only', 'TURN_TIMEOUT')).toBe('TURN_TIMEOUT can only be issued by the server');
+    });
+  });
+
+  describe('generateGameSeed', () => {
+    it('should generate an integer seed in the client range', () => {
+      const seed = generateGameSeed();
+      expect(Number.isInteger(seed)).toBe(true);
+      expect(seed).toBeGreaterThanOrEqual(0);
+      expect(seed).toBeLessThan(1000000);
+    });
+  });
+});
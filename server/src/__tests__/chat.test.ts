@@ -0,0 +1,25 @@
+import { describe, it, expect } from 'vitest';
+import { sanitizeChatMessage, MAX_CHAT_MESSAGE_LENGTH } from '../chat';
+
+describe('Chat', () => {
+  it('should trim surrounding whitespace', () => {
+    expect(sanitizeChatMessage('  good game!  ')).toBe('good game!');
+  });
+
+  it('should replace control characters', () => {
+    expect(sanitizeChatMessage('line one\nline\ttwo\u0007')).toBe('line one line two');
+  });
+
+  it('should cap the message length', () => {
+    const long = 'a'.repeat(MAX_CHAT_MESSAGE_LENGTH + 50);
+
+    expect(sanitizeChatMessage(long)).toHaveLength(MAX_CHAT_MESSAGE_LENGTH);
+  });
+
+  it('should reject empty and non-text messages', () => {
+    expect(sanitizeChatMessage('')).toBeNull();
+    expect(sanitizeChatMessage('   \n ')).toBeNull();
+    expect(sanitizeChatMessage(undefined)).toBeNull();
+    expect(sanitizeChatMessage({ text: 'hi' })).toBeNull();
+  });
+});
@@ -0,0 +1,65 @@
+import { describe, it, expect } from 'vitest';
+import { findQuickPlayRoom, isRoomCompatible } from '../matchmaking';
+import type { GameState } from '../storage/GameStorage';
+
+describe('Quick Play Matchmaking', () => {
+  const createRoom = (
+    gameId: string,
+    playerCount: number,
+    maxPlayers = 2,
+    status: GameState['status'] = 'waiting'
+  ): GameState => ({
+    gameId,
+    status,
+    players: Array.from({ length: playerCount }, (_, i) => ({
+      id: `${gameId}-player${i}`,
+      username: `Player ${i}`,
+      socketId: `socket-${gameId}-${i}`,
+      connected: true,
+    })),
+    hostId: `${gameId}-player0`,
+    name: `Room ${gameId}`,
+    maxPlayers,
+    lastActionSequence: 0,
+  });
+
+  describe('isRoomCompatible', () => {
+    it('should accept a waiting room with matching settings and a free seat', () => {
+      expect(isRoomCompatible(createRoom('a', 1), { maxPlayers: 2 })).toBe(true);
+    });
+
+    it('should reject full, started, or mismatched rooms', () => {
+      expect(isRoomCompatible(createRoom('a', 2), { maxPlayers: 2 })).toBe(false);
+      expect(isRoomCompatible(createRoom('a', 1, 2, 'playing'), { maxPlayers: 2 })).toBe(false);
+      expect(isRoomCompatible(createRoom('a', 1, 4), { maxPlayers: 2 })).toBe(false);
+    });
+  });
+
+  describe('findQuickPlayRoom', () => {
+    it('should join an existing compatible room before creating a new one', () => {
+      const rooms = [createRoom('full', 2), createRoom('open', 1), createRoom('big', 1, 4)];
+
+      const room = findQuickPlayRoom(rooms, { maxPlayers: 2 }, 'newcomer');
+
+      expect(room?.gameId).toBe('open');
+    });
+
+    it('should return null when a new room must be created', () => {
+      const rooms = [createRoom('full', 2), createRoom('started', 1, 2, 'playing'), null];
+
+      expect(findQuickPlayRoom(rooms, { maxPlayers: 2 }, 'newcomer')).toBeNull();
+    });
+
+    it('should prefer the fullest compatible room', () => {
+      const rooms = [createRoom('one', 1, 4), createRoom('three', 3, 4), createRoom('two', 2, 4)];
+
+      expect(findQuickPlayRoom(rooms, { maxPlayers: 4 }, 'newcomer')?.gameId).toBe('three');
+    });
+
+    it('should return a waiting room the player is already in', () => {
+      const rooms = [createRoom('open', 1), createRoom('mine', 1)];
+
+      expect(findQuickPlayRoom(rooms, { maxPlayers: 2 }, 'mine-player0')?.gameId).toBe('mine');
+    });
+  });
+});
@@ -0,0 +1,56 @@
+import { describe, it, expect } from 'vitest';
+import {
+  createServerMetrics,
+  recordAction,
+  countRoomsByStatus,
+  formatMetrics,
+} from '../metrics';
+
+describe('Server Metrics', () => {
+  describe('formatMetrics', () => {
+    it('should format known counter values as Prometheus text', () => {
+      const text = formatMetrics({
+        gamesStarted: 3,
+        movesApplied: 42,
+        activeConnections: 5,
+        rooms: { waiting: 2, playing: 1, finished: 4 },
+      });
+
+      expect(text).toBe([
+        '# HELP quortex_games_started_total Games started since the server started.',
+        '# TYPE quortex_games_started_total counter',
+        'quortex_games_started_total 3',
+        '# HELP quortex_moves_applied_total Tile placements and replacements since the server started.',
+        '# TYPE quortex_moves_applied_total counter',
+        'quortex_moves_applied_total 42',
+        '# HELP quortex_active_connections Currently connected sockets.',
+        '# TYPE quortex_active_connections gauge',
+        'quortex_active_connections 5',
+        '# HELP quortex_rooms Rooms by status.',
+        '# TYPE quortex_rooms gauge',
+        'quortex_rooms{status="finished"} 4',
+        'quortex_rooms{status="playing"} 1',
+        'quortex_rooms{status="waiting"} 2',
+        '',
+      ].join('\n'));
+    });
+  });
+
+  describe('recordAction', () => {
+    it('should count started games and applied moves only', () => {
+      const metrics = createServerMetrics();
+      for (const type of ['START_GAME', 'DRAW_TILE', 'PLACE_TILE', 'REPLACE_TILE', 'NEXT_PLAYER']) {
+        recordAction(metrics, type);
+      }
+      expect(metrics.gamesStarted).toBe(1);
+      expect(metrics.movesApplied).toBe(2);
+    });
+  });
+
+  describe('countRoomsByStatus', () => {
+    it('should count rooms by status and skip missing rooms', () => {
+      const rooms = [{ status: 'waiting' }, null, { status: 'playing' }, { status: 'waiting' }];
+      expect(countRoomsByStatus(rooms)).toEqual({ waiting: 2, playing: 1, finished: 0 });
+    });
+  });
+});
@@ -0,0 +1,55 @@
+import { describe, it, expect } from 'vitest';
+import { PLAYER_COLORS, isPlayerColor, resolvePlayerColors } from '../playerColors';
+
+describe('Player Colors', () => {
+  const [BLUE, ORANGE, GREEN] = PLAYER_COLORS;
+
+  describe('resolvePlayerColors', () => {
+    it('should give each player their preferred color when there is no conflict', () => {
+      const colors = resolvePlayerColors([
+        { id: 'p1', color: GREEN },
+        { id: 'p2', color: BLUE },
+      ]);
+      expect(colors.get('p1')).toBe(GREEN);
+      expect(colors.get('p2')).toBe(BLUE);
+    });
+
+    it('should assign distinct colors when two players pick the same color', () => {
+      const colors = resolvePlayerColors([
+        { id: 'p1', color: GREEN },
+        { id: 'p2', color: GREEN },
+      ]);
+      // The earlier player keeps the color; the later one gets the first free color
+      expect(colors.get('p1')).toBe(GREEN);
+      expect(colors.get('p2')).toBe(BLUE);
+      expect(new Set(colors.values()).size).toBe(2);
+    });
+
+    it('should not let an unassigned player take a color someone later picked', () => {
+      const colors = resolvePlayerColors([
+        { id: 'p1' },
+        { id: 'p2', color: BLUE },
+      ]);
+      expect(colors.get('p1')).toBe(ORANGE);
+      expect(colors.get('p2')).toBe(BLUE);
+    });
+
+    it('should ignore colors outside the palette', () => {
+      const colors = resolvePlayerColors([{ id: 'p1', color: '#000000' }]);
+      expect(colors.get('p1')).toBe(BLUE);
+    });
+
+    it('should assign distinct colors to a full room of players', () => {
+      const players = PLAYER_COLORS.map((_, i) => ({ id: `p${i}`, color: GREEN }));
+      const colors = resolvePlayerColors(players);
+      expect(new Set(colors.values()).size).toBe(PLAYER_COLORS.length);
+    });
+  });
+
+  describe('isPlayerColor', () => {
+    it('should accept only palette colors', () => {
+      expect(isPlayerColor(BLUE)).toBe(true);
+      expect(isPlayerColor('#000000')).toBe(false);
+    });
+  });
+});
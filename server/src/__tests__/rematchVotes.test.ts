@@ -0,0 +1,33 @@
+import { describe, it, expect } from 'vitest';
+import {
+  recordRematchVote,
+  getRequiredRematchVoters,
+  getMissingRematchVoters,
+} from '../rematchVotes';
+
+describe('Rematch Votes', () => {
+  it('should collect votes per game', () => {
+    const votes = new Map<string, Set<string>>();
+
+    recordRematchVote(votes, 'game-1', 'alice');
+    recordRematchVote(votes, 'game-1', 'alice');
+    const voters = recordRematchVote(votes, 'game-1', 'bob');
+    recordRematchVote(votes, 'game-2', 'carol');
+
+    expect([...voters]).toEqual(['alice', 'bob']);
+    expect([...votes.get('game-2')!]).toEqual(['carol']);
+  });
+
+  it('should only require seated players still in the room', () => {
+    const required = getRequiredRematchVoters(['alice', 'bob', 'carol'], new Set(['alice', 'carol', 'spectator']));
+
+    expect(required).toEqual(['alice', 'carol']);
+  });
+
+  it('should report who has yet to agree', () => {
+    const required = ['alice', 'bob'];
+
+    expect(getMissingRematchVoters(new Set(['alice']), required)).toEqual(['bob']);
+    expect(getMissingRematchVoters(new Set(['bob', 'alice']), required)).toEqual([]);
+  });
+});
@@ -0,0 +1,31 @@
+import { describe, it, expect } from 'vitest';
+import { restartsTurnClock, getTurnTimeLimitMs, canIssueTurnTimeout } from '../turnTimer';
+
+describe('Turn Timer', () => {
+  it('should only run the clock once play is under way', () => {
+    expect(restartsTurnClock('DRAW_TILE')).toBe(true);
+    expect(restartsTurnClock('PLACE_TILE')).toBe(true);
+    expect(restartsTurnClock('TURN_TIMEOUT')).toBe(true);
+    expect(restartsTurnClock('START_GAME')).toBe(false);
+    expect(restartsTurnClock('SELECT_EDGE')).toBe(false);
+  });
+
+  it('should read the time limit from the START_GAME payload', () => {
+    expect(getTurnTimeLimitMs({ seed: 1, turnTimeLimit: 60 })).toBe(60000);
+  });
+
+  it('should leave the timer off when no positive limit is given', () => {
+    expect(getTurnTimeLimitMs(undefined)).toBeNull();
+    expect(getTurnTimeLimitMs({ seed: 1 })).toBeNull();
+    expect(getTurnTimeLimitMs({ turnTimeLimit: 0 })).toBeNull();
+    expect(getTurnTimeLimitMs({ turnTimeLimit: -30 })).toBeNull();
+    expect(getTurnTimeLimitMs({ turnTimeLimit: 'soon' })).toBeNull();
+  });
+
+  it('should stop timing out once only one player could be left', () => {
+    expect(canIssueTurnTimeout(0, 3)).toBe(true);
+    expect(canIssueTurnTimeout(1, 3)).toBe(true);
+    expect(canIssueTurnTimeout(2, 3)).toBe(false);
+    expect(canIssueTurnTimeout(1, 2)).toBe(false);
+  });
+});
@@ -0,0 +1,97 @@
+// Server-side policy for game actions posted by clients
+//
+// Every random outcome in a game (tile bag order, seating order) is derived from
+// the game seed, so the server owns the seed and never trusts one sent by a client.
+
+export interface ClientAction {
+  type: string;
+  payload?: any;
+}
+
+// Actions that only the server may originate; copies posted by clients are discarded
+export const SERVER_ONLY_ACTIONS = [
+  'START_SEATING_PHASE', // Carries a randomized seating order
+  'HAND_OVER_TO_AI', // Issued when a player leaves or times out mid-game
+  'RECLAIM_FROM_AI', // Issued when a player whose seat was handed over reconnects
+  'TURN_TIMEOUT', // Issued when the player on turn runs out the room's turn clock
+];
+
+// Actions whose seed is always replaced with the server's seed for the game
+export const SERVER_SEEDED_ACTIONS = [
+  'START_GAME',
+  'SHUFFLE_TILES',
+];
+
+export function isServerOnlyAction(type: string): boolean {
+  return SERVER_ONLY_ACTIONS.includes(type);
+}
+
+// Why the server refused an action; sent back to the client in invalid_request
+export type InvalidActionReason = 'malformed' | 'unknown-game' | 'server-only' | 'not-a-player';
+
+// Check an action posted by a client before it is sanitized and logged.
+// The server doesn't run the game rules, so turn order and placement legality are left to the clients.
+// Returns null if the action can be accepted.
+export function validateClientAction(action: unknown, gameExists: boolean, isSeatedPlayer: boolean): InvalidActionReason | null {
+  if (!action || typeof action !== 'object') {
+    return 'malformed';
+  }
+  const { type, payload } = action as ClientAction;
+  if (typeof type !== 'string' || type.length === 0) {
+    return 'malformed';
+  }
+  if (payload !== undefined && (payload === null || typeof payload !== 'object')) {
+    return 'malformed';
+  }
+  if (!gameExists) {
+    return 'unknown-game';
+  }
+  if (isServerOnlyAction(type)) {
+    return 'server-only';
+  }
+  if (!isSeatedPlayer) {
+    return 'not-a-player';
+  }
+  return null;
+}
+
+// Human-readable explanation of a rejected action
+export function describeInvalidAction(reason: InvalidActionReason, actionType?: string): string {
+  switch (reason) {
+    case 'malformed':
+      return 'The server could not read that action';
+    case 'unknown-game':
+      return 'That game no longer exists';
+    case 'server-only':
+      return `${actionType} can only be issued by the server`;
+    case 'not-a-player':
+      return 'Only seated players can make moves in this game';
+  }
+}
+
+// Generate a new game seed (same range the client uses for local games)
+export function generateGameSeed(): number {
+  return Math.floor(Math.random() * 1000000);
+}
+
+// Sanitize an action posted by a client before it is appended to the action log
+// Returns null if the action must be discarded
+export function sanitizeClientAction(action: ClientAction, serverSeed: number): ClientAction | null {
+  if (isServerOnlyAction(action.type)) {
+    return null;
+  }
+
+  if (SERVER_SEEDED_ACTIONS.includes(action.type)) {
+    return {
+      type: action.type,
+      payload: { ...(action.payload || {}), seed: serverSeed },
+    };
+  }
+
+  // Tiles are drawn from the seeded bag, so any tile value a client attaches is dropped
+  if (action.type === 'DRAW_TILE') {
+    return { type: action.type, payload: {} };
+  }
+
+  return { type: action.type, payload: action.payload || {} };
+}
@@ -0,0 +1,21 @@
+// Room chat
+//
+// Chat is relayed to everyone in a game room, players and spectators alike, and never
+// touches game state. Messages are cleaned up here before they are broadcast.
+
+export const MAX_CHAT_MESSAGE_LENGTH = 200;
+
+// Trim a chat message, blank out control characters and cap its length.
+// Returns null when there is nothing worth sending.
+export function sanitizeChatMessage(text: unknown): string | null {
+  if (typeof text !== 'string') {
+    return null;
+  }
+
+  const cleaned = text.replace(/[\u0000-\u001f\u007f]/g, ' ').trim();
+  if (cleaned.length === 0) {
+    return null;
+  }
+
+  return cleaned.slice(0, MAX_CHAT_MESSAGE_LENGTH).trimEnd();
+}
@@ -11,6 +11,13 @@ import profileRoutes from './routes/profile.js';
 import jwt from 'jsonwebtoken';
 import { GameStorage, DataStorage } from './storage/index.js';
 import { UserStore } from './models/User.js';
+import { generateGameSeed, sanitizeClientAction, validateClientAction, describeInvalidAction } from './actionPolicy.js';
+import { findQuickPlayRoom } from './matchmaking.js';
+import { isPlayerColor, resolvePlayerColors } from './playerColors.js';
+import { restartsTurnClock, getTurnTimeLimitMs, canIssueTurnTimeout } from './turnTimer.js';
+import { sanitizeChatMessage } from './chat.js';
+import { recordRematchVote, getRequiredRematchVoters, getMissingRematchVoters } from './rematchVotes.js';
+import { createServerMetrics, recordAction, countRoomsByStatus, formatMetrics } from './metrics.js';
 
 // Parse command-line arguments for fixed seed (for testing)
 let FIXED_SEED: number | null = null;
@@ -103,6 +110,137 @@ const players = new Map<string, Player>();
 // spectators are keyed by socket.id for quick lookup
 const gameSpectators = new Map<string, Map<string, Spectator>>();
 
+// Server-owned seed for each game; all randomness in a game derives from it
+const gameSeeds = new Map<string, number>();
+
+function getGameSeed(gameId: string): number {
+  let seed = gameSeeds.get(gameId);
+  if (seed === undefined) {
+    seed = FIXED_SEED !== null ? FIXED_SEED : generateGameSeed();
+    gameSeeds.set(gameId, seed);
+  }
+  return seed;
+}
+
+// Operational counters exposed at /metrics
+const metrics = createServerMetrics();
+
+// How long a disconnected player has to reconnect before the AI takes over their seat
+const AI_HANDOVER_TIMEOUT_MS = Number(process.env.AI_HANDOVER_TIMEOUT_MS) || 2 * 60 * 1000;
+
+// Pending AI handovers for disconnected players - maps "gameId:playerId" -> timer
+const aiHandoverTimers = new Map<string, ReturnType<typeof setTimeout>>();
+
+// Seats handed to the AI because their player disconnected, keyed "gameId:playerId".
+// The player gets the seat back on rejoining; seats given up with leave_room stay with the AI.
+const reclaimableAISeats = new Set<string>();
+
+function cancelAIHandover(gameId: string, playerId: string): void {
+  const key = `${gameId}:${playerId}`;
+  const timer = aiHandoverTimers.get(key);
+  if (timer) {
+    clearTimeout(timer);
+    aiHandoverTimers.delete(key);
+  }
+}
+
+// Convert a departing player's seat to AI control so an in-progress game can continue.
+// Every client applies the action and runs the AI for that seat deterministically:
+// the reducer fixes the seat's difficulty, so clients' own AI settings don't matter.
+// Returns whether the seat was handed over.
+async function handOverSeatToAI(gameId: string, playerId: string): Promise<boolean> {
+  cancelAIHandover(gameId, playerId);
+
+  const state = await gameStorage.getGameState(gameId);
+  if (!state || state.status !== 'playing') return false;
+
+  const handoverAction: GameAction = {
+    type: 'HAND_OVER_TO_AI',
+    payload: { playerId },
+    playerId,
+    timestamp: Date.now(),
+    sequence: 0 // Will be overwritten by storage
+  };
+  const finalAction = await gameStorage.appendAction(gameId, handoverAction);
+  recordAction(metrics, finalAction.type);
+  io.to(gameId).emit('action_posted', finalAction);
+  console.log(`Handed seat of ${playerId} in game ${gameId} over to AI`);
+  return true;
+}
+
+// Give a seat handed over on disconnect back to its returning player
+async function reclaimSeatFromAI(gameId: string, playerId: string): Promise<void> {
+  const key = `${gameId}:${playerId}`;
+  if (!reclaimableAISeats.delete(key)) return;
+
+  const state = await gameStorage.getGameState(gameId);
+  if (!state || state.status !== 'playing') return;
+
+  const reclaimAction: GameAction = {
+    type: 'RECLAIM_FROM_AI',
+    payload: { playerId },
+    playerId,
+    timestamp: Date.now(),
+    sequence: 0 // Will be overwritten by storage
+  };
+  const finalAction = await gameStorage.appendAction(gameId, reclaimAction);
+  recordAction(metrics, finalAction.type);
+  io.to(gameId).emit('action_posted', finalAction);
+  console.log(`Gave seat of ${playerId} in game ${gameId} back from AI`);
+}
+
+// Per-room turn time limits in ms, set by START_GAME for rooms that opt in
+const turnTimeLimits = new Map<string, number>();
+
+// Running turn clocks and how many turns in a row have timed out - keyed by gameId
+const turnTimers = new Map<string, ReturnType<typeof setTimeout>>();
+const consecutiveTurnTimeouts = new Map<string, number>();
+
+// Restart a room's turn clock after an action that hands play to the next turn
+function restartTurnClock(gameId: string, actionType: string): void {
+  const limitMs = turnTimeLimits.get(gameId);
+  if (limitMs === undefined || !restartsTurnClock(actionType)) return;
+
+  clearTimeout(turnTimers.get(gameId));
+  turnTimers.set(gameId, setTimeout(() => {
+    issueTurnTimeout(gameId).catch(error => {
+      console.error('Error issuing turn timeout:', error);
+    });
+  }, limitMs));
+}
+
+// The player on turn ran out the clock; every client resigns them on TURN_TIMEOUT
+async function issueTurnTimeout(gameId: string): Promise<void> {
+  turnTimers.delete(gameId);
+
+  const state = await gameStorage.getGameState(gameId);
+  if (!state || state.status !== 'playing') return;
+
+  const timeouts = consecutiveTurnTimeouts.get(gameId) ?? 0;
+  if (!canIssueTurnTimeout(timeouts, state.players.length)) return;
+  consecutiveTurnTimeouts.set(gameId, timeouts + 1);
+
+  const timeoutAction: GameAction = {
+    type: 'TURN_TIMEOUT',
+    payload: {},
+    playerId: 'server',
+    timestamp: Date.now(),
+    sequence: 0 // Will be overwritten by storage
+  };
+  const finalAction = await gameStorage.appendAction(gameId, timeoutAction);
+  recordAction(metrics, finalAction.type);
+  io.to(gameId).emit('action_posted', finalAction);
+  console.log(`Turn timed out in game ${gameId}`);
+
+  restartTurnClock(gameId, finalAction.type);
+}
+
+// Players in a room as sent to clients, with each player's resolved flow color
+function getRoomPlayers(state: { players: Array<{ id: string; username: string; color?: string }> }) {
+  const colors = resolvePlayerColors(state.players);
+  return state.players.map(p => ({ id: p.id, username: p.username, color: colors.get(p.id) }));
+}
+
 // Track rematch games - maps new game ID to original game's player list and spectators
 // Used to emit game_ready when players rejoin after rematch and to re-add spectators after seating
 const rematchGames = new Map<string, { 
@@ -112,11 +250,41 @@ const rematchGames = new Map<string, {
   oldGameId?: string // Track old game ID to notify spectators
 }>();
 
+// Pending rematch requests - maps finished game ID -> IDs of players who asked for a rematch
+const rematchVotes = new Map<string, Set<string>>();
+
+// Games are rebuilt from their action logs on demand, but each room's seed and turn time
+// limit live in memory; recover them from START_GAME so games in progress carry on after a restart.
+// A game whose turns were already under way gets a fresh clock, since its timer died with the process
+async function restoreInProgressGames(): Promise<void> {
+  for (const gameId of await gameStorage.listGames()) {
+    const state = await gameStorage.getGameState(gameId);
+    if (!state || state.status !== 'playing') continue;
+
+    const actions = await gameStorage.readActions(gameId);
+    const start = [...actions].reverse().find(a => a.type === 'START_GAME');
+    if (!start) continue;
+
+    if (typeof start.payload?.seed === 'number') {
+      gameSeeds.set(gameId, start.payload.seed);
+    }
+    const limitMs = getTurnTimeLimitMs(start.payload);
+    if (limitMs !== null) {
+      turnTimeLimits.set(gameId, limitMs);
+      const lastTurnAction = actions.slice(actions.lastIndexOf(start) + 1).reverse().find(a => restartsTurnClock(a.type));
+      if (lastTurnAction) {
+        restartTurnClock(gameId, lastTurnAction.type);
+      }
+    }
+  }
+}
+
 // Initialize storage on startup
 async function initializeStorage() {
   await gameStorage.initialize();
   await sessionStorage.initialize();
   await UserStore.init(); // Load users from persistent storage
+  await restoreInProgressGames();
   
   // Check if rating migration is needed
   const { checkMigrationNeeded, migrateUsersToRatings } = await import('./rating/migration.js');
@@ -292,6 +460,18 @@ app.get('/health', async (req, res) => {
   }
 });
 
+// Prometheus-style metrics
+app.get('/metrics', async (req, res) => {
+  try {
+    const gameIds = await gameStorage.listGames();
+    const rooms = await Promise.all(gameIds.map(gameId => gameStorage.getGameState(gameId)));
+    metrics.rooms = countRoomsByStatus(rooms);
+    res.type('text/plain; version=0.0.4').send(formatMetrics(metrics));
+  } catch (error) {
+    res.status(500).send('Storage unavailable\n');
+  }
+});
+
 // Get all available rooms
 app.get('/api/rooms', async (req, res) => {
   try {
@@ -381,6 +561,38 @@ app.post('/api/rooms', async (req, res) => {
   }
 });
 
+// Quick play: join an open room with matching settings, or create one if none exist
+app.post('/api/rooms/quick-play', async (req, res) => {
+  const { playerId, username, maxPlayers } = req.body;
+  
+  if (!playerId || !maxPlayers) {
+    return res.status(400).json({ error: 'Missing required fields' });
+  }
+
+  if (maxPlayers < 2 || maxPlayers > 6) {
+    return res.status(400).json({ error: 'maxPlayers must be between 2 and 6' });
+  }
+
+  try {
+    const gameIds = await gameStorage.listGames();
+    const rooms = await Promise.all(gameIds.map(gameId => gameStorage.getGameState(gameId)));
+    
+    const room = findQuickPlayRoom(rooms, { maxPlayers }, playerId);
+    if (room) {
+      return res.json({ room: { id: room.gameId, name: room.name, maxPlayers: room.maxPlayers }, created: false });
+    }
+    
+    // No compatible room - create a new one hosted by this player
+    const newRoomId = uuidv4();
+    const name = `${username || 'Quick Play'}'s game`;
+    await gameStorage.createGame(newRoomId, name, playerId, maxPlayers);
+    res.json({ room: { id: newRoomId, name, maxPlayers }, created: true });
+  } catch (error) {
+    console.error('Error finding quick play room:', error);
+    res.status(500).json({ error: 'Failed to find a room' });
+  }
+});
+
 // Create a rematch game (new game with same players and settings)
 app.post('/api/rooms/:roomId/rematch', async (req, res) => {
   const { roomId } = req.params;
@@ -477,6 +689,7 @@ io.use((socket, next) => {
 });
 
 io.on('connection', (socket) => {
+  metrics.activeConnections++;
   console.log('Client connected:', socket.id, socket.data.authenticated ? '(authenticated)' : '(anonymous)');
 
   // Player identification
@@ -604,6 +817,8 @@ io.on('connection', (socket) => {
       
       // If rejoining, notify other players
       if (isRejoining) {
+        cancelAIHandover(roomId, player.id);
+        await reclaimSeatFromAI(roomId, player.id);
         io.to(roomId).emit('player_reconnected', {
           playerId: player.id,
           username: player.username
@@ -617,7 +832,7 @@ io.on('connection', (socket) => {
           room: {
             id: updatedState!.gameId,
             name: updatedState!.name,
-            players: updatedState!.players.map(p => ({ id: p.id, username: p.username })),
+            players: getRoomPlayers(updatedState!),
             hostId: updatedState!.hostId
           }
         });
@@ -627,9 +842,8 @@ io.on('connection', (socket) => {
       if (isRejoining && (updatedState!.status === 'playing' || updatedState!.status === 'finished')) {
         socket.emit('game_ready', {
           gameId: updatedState!.gameId,
-          players: updatedState!.players.map((p, index) => ({ 
-            id: p.id, 
-            username: p.username,
+          players: getRoomPlayers(updatedState!).map((p, index) => ({ 
+            ...p,
             playerIndex: index
           }))
         });
@@ -670,6 +884,12 @@ io.on('connection', (socket) => {
       const state = await gameStorage.getGameState(roomId);
       if (!state) return;
 
+      // Leaving an in-progress game hands the seat to the AI instead of stalling the game
+      if (state.status === 'playing' && state.players.some(p => p.id === player.id)) {
+        reclaimableAISeats.delete(`${roomId}:${player.id}`);
+        await handOverSeatToAI(roomId, player.id);
+      }
+
       // Record leave action (sequence will be auto-assigned)
       const leaveAction: GameAction = {
         type: 'LEAVE_GAME',
@@ -696,7 +916,7 @@ io.on('connection', (socket) => {
         room: {
           id: updatedState!.gameId,
           name: updatedState!.name,
-          players: updatedState!.players.map(p => ({ id: p.id, username: p.username })),
+          players: getRoomPlayers(updatedState!),
           hostId: updatedState!.hostId
         }
       });
@@ -707,6 +927,42 @@ io.on('connection', (socket) => {
     }
   });
 
+  // Choose a preferred flow color while waiting in a room
+  socket.on('set_player_color', async (data: { roomId: string; color: string }) => {
+    const { roomId, color } = data;
+    const player = players.get(socket.id);
+
+    if (!player || !isPlayerColor(color)) return;
+
+    try {
+      const state = await gameStorage.getGameState(roomId);
+      if (!state || state.status !== 'waiting' || !state.players.some(p => p.id === player.id)) {
+        return;
+      }
+
+      const colorAction: GameAction = {
+        type: 'SET_PLAYER_COLOR',
+        payload: { playerId: player.id, color },
+        playerId: player.id,
+        timestamp: Date.now(),
+        sequence: 0 // Will be overwritten by storage
+      };
+      await gameStorage.appendAction(roomId, colorAction);
+
+      const updatedState = await gameStorage.getGameState(roomId);
+      io.to(roomId).emit('room_updated', {
+        room: {
+          id: updatedState!.gameId,
+          name: updatedState!.name,
+          players: getRoomPlayers(updatedState!),
+          hostId: updatedState!.hostId
+        }
+      });
+    } catch (error) {
+      console.error('Error setting player color:', error);
+    }
+  });
+
   // Start game (host posts START_GAME action with seed)
   socket.on('start_game', async (data: { roomId: string }) => {
     const { roomId } = data;
@@ -755,9 +1011,8 @@ io.on('connection', (socket) => {
       io.to(roomId).emit('game_ready', {
         gameId: updatedState!.gameId,
         seed: FIXED_SEED !== null ? FIXED_SEED : undefined, // Include fixed seed if set
-        players: updatedState!.players.map((p, index) => ({ 
-          id: p.id, 
-          username: p.username,
+        players: getRoomPlayers(updatedState!).map((p, index) => ({ 
+          ...p,
           playerIndex: index // Assign player indices for the game
         }))
       });
@@ -778,12 +1033,31 @@ io.on('connection', (socket) => {
 
     try {
       const state = await gameStorage.getGameState(gameId);
-      if (!state) return;
+
+      // Tell the client why an action was refused rather than dropping it silently
+      const reason = validateClientAction(action, state !== null, !!state?.players.some(p => p.id === player.id));
+      if (reason) {
+        console.warn(`Rejecting ${action?.type} posted by ${player.username} to game ${gameId}: ${reason}`);
+        socket.emit('invalid_request', {
+          gameId,
+          actionType: action?.type,
+          reason,
+          message: describeInvalidAction(reason, action?.type)
+        });
+        return;
+      }
+      
+      // Never trust client-provided randomness
+      const sanitized = sanitizeClientAction(action, getGameSeed(gameId));
+      if (!sanitized) {
+        console.warn(`Discarding server-only action ${action.type} posted by ${player.username}`);
+        return;
+      }
       
       // Create the action with metadata (sequence will be auto-assigned by storage)
       const gameAction: GameAction = {
-        type: action.type,
-        payload: action.payload || {},
+        type: sanitized.type,
+        payload: sanitized.payload,
         playerId: player.id,
         timestamp: Date.now(),
         sequence: 0 // Will be overwritten by storage
@@ -791,10 +1065,23 @@ io.on('connection', (socket) => {
 
       // Append to action log (storage assigns correct sequence)
       const finalAction = await gameStorage.appendAction(gameId, gameAction);
+      recordAction(metrics, finalAction.type);
 
       // Broadcast action to all players in the game
       io.to(gameId).emit('action_posted', finalAction);
 
+      // A room's turn time limit is fixed when its game starts
+      if (finalAction.type === 'START_GAME') {
+        const limitMs = getTurnTimeLimitMs(finalAction.payload);
+        if (limitMs !== null) {
+          turnTimeLimits.set(gameId, limitMs);
+        } else {
+          turnTimeLimits.delete(gameId);
+        }
+      }
+      consecutiveTurnTimeouts.delete(gameId);
+      restartTurnClock(gameId, finalAction.type);
+
       // Check if this is a SELECT_EDGE in a rematch game
       // We trigger on every SELECT_EDGE to ensure spectators rejoin even if they miss one
       if (finalAction.type === 'SELECT_EDGE' && rematchGames.has(gameId)) {
@@ -901,6 +1188,33 @@ io.on('connection', (socket) => {
         return;
       }
 
+      // Only seated players get a say in whether there's a rematch
+      if (!state.players.some(p => p.id === player.id)) {
+        socket.emit('error', { message: 'Only players can request a rematch' });
+        return;
+      }
+
+      // Wait until every seated player still in the room has asked for a rematch
+      const voters = recordRematchVote(rematchVotes, gameId, player.id);
+      const presentPlayerIds = new Set<string>();
+      for (const socketId of io.sockets.adapter.rooms.get(gameId) ?? []) {
+        const present = players.get(socketId);
+        if (present) presentPlayerIds.add(present.id);
+      }
+      const required = getRequiredRematchVoters(state.players.map(p => p.id), presentPlayerIds);
+      const waitingFor = getMissingRematchVoters(voters, required);
+      if (waitingFor.length > 0) {
+        io.to(gameId).emit('rematch_requested', {
+          gameId,
+          requestedBy: player.id,
+          ready: required.length - waitingFor.length,
+          total: required.length,
+          waitingFor
+        });
+        return;
+      }
+      rematchVotes.delete(gameId);
+
       // Create a new game with the same settings
       const newGameId = uuidv4();
       const newGameName = `${state.name} (Rematch)`;
@@ -945,6 +1259,7 @@ io.on('connection', (socket) => {
         name: newGameName,
         maxPlayers: state.maxPlayers,
         hostId: state.hostId,
+        initiatorId: player.id,
         players: state.players.map(p => ({ id: p.id, username: p.username }))
       });
 
@@ -955,6 +1270,31 @@ io.on('connection', (socket) => {
     }
   });
 
+  // Room chat - relayed to players and spectators in the room without touching game state
+  socket.on('chat_message', (data: { gameId: string; text: string }) => {
+    const { gameId } = data;
+    const player = players.get(socket.id);
+
+    if (!player) {
+      socket.emit('error', { message: 'Player not identified' });
+      return;
+    }
+
+    // Only sockets watching or playing in the room may talk in it
+    if (!socket.rooms.has(gameId)) return;
+
+    const text = sanitizeChatMessage(data.text);
+    if (!text) return;
+
+    io.to(gameId).emit('chat_broadcast', {
+      gameId,
+      playerId: player.id,
+      username: player.username,
+      text,
+      timestamp: Date.now()
+    });
+  });
+
   // Join as spectator
   socket.on('join_as_spectator', async (data: { gameId: string }) => {
     const { gameId } = data;
@@ -1049,8 +1389,14 @@ io.on('connection', (socket) => {
     console.log(`Spectator ${spectator.username} left game ${gameId}`);
   });
 
+  // Stop receiving a room's updates without giving up a seat (e.g. after navigating away)
+  socket.on('unsubscribe_room', (data: { roomId: string }) => {
+    socket.leave(data.roomId);
+  });
+
   // Disconnect
   socket.on('disconnect', async () => {
+    metrics.activeConnections--;
     console.log('Client disconnected:', socket.id);
     const player = players.get(socket.id);
 
@@ -1093,6 +1439,20 @@ io.on('connection', (socket) => {
                 playerId: player.id,
                 username: player.username
               });
+
+              // Give the player time to reconnect before the AI takes over their seat
+              if (state.status === 'playing') {
+                cancelAIHandover(gameId, player.id);
+                const playerId = player.id;
+                const key = `${gameId}:${playerId}`;
+                aiHandoverTimers.set(key, setTimeout(() => {
+                  handOverSeatToAI(gameId, playerId).then(handedOver => {
+                    if (handedOver) reclaimableAISeats.add(key);
+                  }).catch(error => {
+                    console.error('Error handing seat over to AI:', error);
+                  });
+                }, AI_HANDOVER_TIMEOUT_MS));
+              }
             }
           }
         }
@@ -1101,7 +1461,7 @@ io.on('connection', (socket) => {
       }
 
       // Remove from in-memory map only (keep in session for reconnection)
-      // Games wait indefinitely for player to reconnect - no timeout removal
+      // In-progress games hand the seat to the AI if the player does not return in time
       players.delete(socket.id);
       console.log(`Player ${player.username} disconnected. Games will wait for reconnection.`);
     }
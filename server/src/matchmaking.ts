@@ -0,0 +1,39 @@
+// Quick play matchmaking: place a player into an open room with the desired settings
+import type { GameState } from './storage/index.js';
+
+// Settings a quick play request must match exactly
+export interface QuickPlaySettings {
+  maxPlayers: number;
+}
+
+// Check if a room is open for a new player with the given settings
+export function isRoomCompatible(room: GameState, settings: QuickPlaySettings): boolean {
+  return room.status === 'waiting' &&
+         room.maxPlayers === settings.maxPlayers &&
+         room.players.length < room.maxPlayers;
+}
+
+// Find the best open room for a quick play request, or null if a new room is needed
+// A waiting room the player is already in is returned first; otherwise the fullest
+// compatible room is chosen so games start as soon as possible
+export function findQuickPlayRoom(
+  rooms: Array<GameState | null>,
+  settings: QuickPlaySettings,
+  playerId: string
+): GameState | null {
+  let best: GameState | null = null;
+
+  for (const room of rooms) {
+    if (!room || room.status !== 'waiting' || room.maxPlayers !== settings.maxPlayers) continue;
+
+    if (room.players.some(p => p.id === playerId)) {
+      return room;
+    }
+
+    if (isRoomCompatible(room, settings) && (!best || room.players.length > best.players.length)) {
+      best = room;
+    }
+  }
+
+  return best;
+}
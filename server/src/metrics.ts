@@ -0,0 +1,62 @@
+// Prometheus-style metrics for operating a public server
+//
+// Counters are kept in memory and reset when the server restarts; room counts are
+// gauges read from storage when the metrics endpoint is scraped.
+
+export interface ServerMetrics {
+  gamesStarted: number;
+  movesApplied: number;
+  activeConnections: number;
+  rooms: Record<string, number>; // Room count by status
+}
+
+// Actions that count as a move applied to the board
+const MOVE_ACTIONS = ['PLACE_TILE', 'REPLACE_TILE'];
+
+export function createServerMetrics(): ServerMetrics {
+  return {
+    gamesStarted: 0,
+    movesApplied: 0,
+    activeConnections: 0,
+    rooms: {},
+  };
+}
+
+// Update the counters for an action appended to a game's action log
+export function recordAction(metrics: ServerMetrics, actionType: string): void {
+  if (actionType === 'START_GAME') {
+    metrics.gamesStarted++;
+  } else if (MOVE_ACTIONS.includes(actionType)) {
+    metrics.movesApplied++;
+  }
+}
+
+// Count rooms by status
+export function countRoomsByStatus(rooms: Array<{ status: string } | null>): Record<string, number> {
+  const counts: Record<string, number> = { waiting: 0, playing: 0, finished: 0 };
+  for (const room of rooms) {
+    if (room) {
+      counts[room.status] = (counts[room.status] || 0) + 1;
+    }
+  }
+  return counts;
+}
+
+function formatMetric(name: string, type: 'counter' | 'gauge', help: string, lines: string[]): string[] {
+  return [`# HELP ${name} ${help}`, `# TYPE ${name} ${type}`, ...lines];
+}
+
+// Format metrics in the Prometheus text exposition format
+export function formatMetrics(metrics: ServerMetrics): string {
+  const lines = [
+    ...formatMetric('quortex_games_started_total', 'counter', 'Games started since the server started.',
+      [`quortex_games_started_total ${metrics.gamesStarted}`]),
+    ...formatMetric('quortex_moves_applied_total', 'counter', 'Tile placements and replacements since the server started.',
+      [`quortex_moves_applied_total ${metrics.movesApplied}`]),
+    ...formatMetric('quortex_active_connections', 'gauge', 'Currently connected sockets.',
+      [`quortex_active_connections ${metrics.activeConnections}`]),
+    ...formatMetric('quortex_rooms', 'gauge', 'Rooms by status.',
+      Object.keys(metrics.rooms).sort().map(status => `quortex_rooms{status="${status}"} ${metrics.rooms[status]}`)),
+  ];
+  return lines.join('\n') + '\n';
+}
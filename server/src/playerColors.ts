@@ -0,0 +1,46 @@
+// Flow color choices for players in a room
+//
+// Players may pick a preferred color while waiting in a room. Preferences are resolved
+// in join order so no two players end up with the same color.
+
+// Same color-blind friendly palette as the client (src/redux/types.ts)
+export const PLAYER_COLORS = [
+  '#0173B2', // Blue
+  '#DE8F05', // Orange
+  '#029E73', // Green
+  '#ECE133', // Yellow
+  '#CC78BC', // Purple
+  '#CA5127', // Red
+];
+
+export function isPlayerColor(color: string): boolean {
+  return PLAYER_COLORS.includes(color);
+}
+
+// Assign every player a distinct color
+// Players keep their preferred color unless an earlier player already has it; anyone
+// left without a color gets the first palette color not yet taken
+export function resolvePlayerColors(
+  players: Array<{ id: string; color?: string }>
+): Map<string, string> {
+  const assigned = new Map<string, string>();
+  const taken = new Set<string>();
+
+  for (const player of players) {
+    if (player.color && isPlayerColor(player.color) && !taken.has(player.color)) {
+      assigned.set(player.id, player.color);
+      taken.add(player.color);
+    }
+  }
+
+  for (const player of players) {
+    if (assigned.has(player.id)) continue;
+    const color = PLAYER_COLORS.find(c => !taken.has(c));
+    if (color) {
+      assigned.set(player.id, color);
+      taken.add(color);
+    }
+  }
+
+  return assigned;
+}
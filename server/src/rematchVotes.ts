@@ -0,0 +1,29 @@
+// Rematch voting
+//
+// A rematch is only created once every seated player still in the game room has
+// asked for one. Players who have left the room don't hold the others up.
+
+// Record a player's rematch request for a game; returns everyone who has asked so far
+export function recordRematchVote(
+  votes: Map<string, Set<string>>,
+  gameId: string,
+  playerId: string
+): Set<string> {
+  let voters = votes.get(gameId);
+  if (!voters) {
+    voters = new Set();
+    votes.set(gameId, voters);
+  }
+  voters.add(playerId);
+  return voters;
+}
+
+// Seated players still present in the game room; all of them must agree to a rematch
+export function getRequiredRematchVoters(seatedPlayerIds: string[], presentPlayerIds: Set<string>): string[] {
+  return seatedPlayerIds.filter(id => presentPlayerIds.has(id));
+}
+
+// Required players who haven't asked for a rematch yet
+export function getMissingRematchVoters(voters: Set<string>, requiredPlayerIds: string[]): string[] {
+  return requiredPlayerIds.filter(id => !voters.has(id));
+}
@@ -26,6 +26,7 @@ export interface GameState {
     username: string;
     socketId: string;
     connected: boolean;
+    color?: string; // Preferred flow color chosen in the room
   }>;
   hostId: string;
   name: string;
@@ -106,6 +107,12 @@ export class GameStorage {
    * @returns The action with the assigned sequence number
    */
   async appendAction(gameId: string, action: GameAction, immediate = false): Promise<GameAction> {
+    // After a restart the game may not be cached yet; rebuild it from disk so the
+    // sequence carries on from the persisted log
+    if (!this.cache.has(gameId) && action.type !== 'CREATE_GAME') {
+      await this.getGameState(gameId);
+    }
+
     // Ensure sequence is set correctly
     const state = this.cache.get(gameId);
     const finalAction: GameAction = {
@@ -296,6 +303,16 @@ export class GameStorage {
           players: newState.players.filter(p => p.id !== action.payload.playerId)
         };
       
+      case 'SET_PLAYER_COLOR':
+        return {
+          ...newState,
+          players: newState.players.map(p =>
+            p.id === action.payload.playerId
+              ? { ...p, color: action.payload.color }
+              : p
+          )
+        };
+      
       case 'START_GAME':
         return {
           ...newState,
@@ -0,0 +1,36 @@
+// Turn timer policy
+//
+// A room can opt into a per-turn time limit when its game starts. The server doesn't
+// run the game rules, so it watches the action log instead: the clock restarts whenever
+// play moves on, and when it runs out the server posts TURN_TIMEOUT, which every client
+// resolves by resigning whoever is on turn.
+
+// Actions that mean a turn is under way; setup and seating don't start the clock
+export const TURN_CLOCK_ACTIONS = [
+  'DRAW_TILE',
+  'PLACE_TILE',
+  'REPLACE_TILE',
+  'SWAP_SIDES',
+  'UNDO_MOVE',
+  'RESIGN',
+  'TURN_TIMEOUT',
+];
+
+export function restartsTurnClock(actionType: string): boolean {
+  return TURN_CLOCK_ACTIONS.includes(actionType);
+}
+
+// Turn time limit in milliseconds from a START_GAME payload, or null when the room has none
+export function getTurnTimeLimitMs(startGamePayload: any): number | null {
+  const seconds = Number(startGamePayload?.turnTimeLimit);
+  if (!Number.isFinite(seconds) || seconds <= 0) {
+    return null;
+  }
+  return seconds * 1000;
+}
+
+// Each timeout resigns a player, so after all but one have timed out in a row the game
+// is over and the clock stops rather than ticking on a finished game
+export function canIssueTurnTimeout(consecutiveTimeouts: number, playerCount: number): boolean {
+  return consecutiveTimeouts < playerCount - 1;
+}
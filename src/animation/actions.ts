@@ -57,6 +57,16 @@ export type AnimationAction =
   | StepFrameAction
   | CancelAnimationsByNameAction;
 
+// Animation speed setting: steps of the settings control and its upper bound
+export const ANIMATION_SPEED_STEP = 0.5;
+export const MAX_ANIMATION_SPEED = 3;
+
+// Scale a frame count by the animation speed setting
+// A speed of 0 (or less) skips the animation, so it lasts no frames at all
+export function scaleAnimationFrames(frames: number, speed: number): number {
+  return speed > 0 ? Math.round(frames / speed) : 0;
+}
+
 // Action creators
 export const incrementFrame = (): IncrementFrameAction => ({
   type: INCREMENT_FRAME,
@@ -74,8 +84,12 @@ export const registerAnimation = (
   
   // Apply debug slowdown if set
   const slowdown = (window as any).ANIMATIONS_DEBUG_SLOWDOWN || 1;
-  const adjustedDuration = duration * slowdown;
-  const adjustedDelay = delay * slowdown;
+
+  // One-shot animations follow the animation speed setting; looping ones are ambient
+  // effects and keep their pace
+  const speed = loop ? 1 : state.ui?.settings.animationSpeed ?? 1;
+  const adjustedDuration = scaleAnimationFrames(duration, speed) * slowdown;
+  const adjustedDelay = scaleAnimationFrames(delay, speed) * slowdown;
   
   return {
     type: REGISTER_ANIMATION,
@@ -7,6 +7,7 @@ import { getFlowExit } from '../game/tiles';
 import { defineAnimation, undefineAnimation } from './registry';
 import { registerAnimation } from './actions';
 import { store } from '../redux/store';
+import { GameState } from '../redux/types';
 
 // Track active flow preview animations
 interface FlowSegment {
@@ -17,6 +18,12 @@ interface FlowSegment {
   orderInPath: number; // Order of this segment in its flow path
 }
 
+// Segment data read by the renderer while a segment animates
+interface FlowSegmentAnimation extends FlowSegment {
+  animationProgress: number; // 0-1
+  opponent: boolean; // Whether the flow was extended by another player's move
+}
+
 // Represents an ordered flow path
 interface OrderedFlowPath {
   playerId: string;
@@ -26,6 +33,70 @@ interface OrderedFlowPath {
 // Store current preview segments to track changes
 let currentPreviewSegments: FlowSegment[] = [];
 
+// Segments animating the flows of the latest tile placed by another player
+let currentOpponentSegments: FlowSegment[] = [];
+
+// Animation duration per segment (in frames)
+const SEGMENT_DURATION = 12; // ~200ms per segment at 60fps
+
+function getSegmentAnimationName(segment: FlowSegment): string {
+  return `flow-preview-${segment.position}-${segment.direction1}-${segment.direction2}`;
+}
+
+/**
+ * Remove the animations and rendering data for the given segments
+ */
+function removeSegmentAnimations(segments: FlowSegment[]): void {
+  const previewData = getFlowPreviewData();
+  segments.forEach((segment) => {
+    const animName = getSegmentAnimationName(segment);
+    undefineAnimation(animName);
+    delete previewData[animName];
+  });
+}
+
+/**
+ * Register animations that draw each path's new segments in order
+ * Each path's segments animate sequentially, but different paths can animate concurrently
+ */
+function animateFlowPaths(paths: OrderedFlowPath[], opponent: boolean, startDelay: number = 0): void {
+  paths.forEach((path) => {
+    path.segments.forEach((segment) => {
+      const animName = getSegmentAnimationName(segment);
+
+      // Store segment data for rendering
+      const segmentData: FlowSegmentAnimation = {
+        ...segment,
+        animationProgress: 0,
+        opponent,
+      };
+
+      // An opponent's flows are already in the game state, so hide them until their
+      // segment starts drawing rather than showing them filled during the delay
+      if (opponent) {
+        const previewData = getFlowPreviewData();
+        previewData[animName] = segmentData;
+        (window as any).__FLOW_PREVIEW_DATA__ = previewData;
+      }
+
+      // Define animation that updates progress
+      defineAnimation(animName, (t: number) => {
+        segmentData.animationProgress = t;
+        // Store in global state for renderer to access
+        const previewData = (window as any).__FLOW_PREVIEW_DATA__ || {};
+        previewData[animName] = segmentData;
+        (window as any).__FLOW_PREVIEW_DATA__ = previewData;
+      });
+
+      // Calculate delay: each segment starts after the previous one in its path completes
+      const delay = startDelay + segment.orderInPath * SEGMENT_DURATION;
+
+      // Register animation with delay
+      store.dispatch(registerAnimation(animName, SEGMENT_DURATION, delay));
+    });
+  });
+}
+
 /**
  * Trace a flow path from a starting position and return ordered segments
  */
@@ -158,15 +229,17 @@ export function updateFlowPreview(
   previewRotation: Rotation,
   currentTile: number | null
 ): void {
-  // Clear old animations by undefining them
-  currentPreviewSegments.forEach((segment) => {
-    const animName = `flow-preview-${segment.position}-${segment.direction1}-${segment.direction2}`;
-    undefineAnimation(animName);
-  });
+  // Clear old animations, leaving any opponent move still animating
+  removeSegmentAnimations(currentPreviewSegments);
   currentPreviewSegments = [];
   
   // Clear old flow preview data to prevent old animations from persisting
-  clearFlowPreviewData();
+  const previewData = getFlowPreviewData();
+  for (const animName in previewData) {
+    if (!previewData[animName].opponent) {
+      delete previewData[animName];
+    }
+  }
 
   // If no preview position, nothing to animate
   if (!previewPosition || currentTile === null) {
@@ -195,37 +268,56 @@ export function updateFlowPreview(
   });
   currentPreviewSegments = allSegments;
 
-  // Animation duration per segment (in frames)
-  const segmentDuration = 12; // ~200ms per segment at 60fps
+  animateFlowPaths(newPaths, false);
+}
 
-  // Register animations for each path
-  // Each path's segments animate sequentially, but different paths can animate concurrently
-  newPaths.forEach((path) => {
-    path.segments.forEach((segment) => {
-      const animName = `flow-preview-${segment.position}-${segment.direction1}-${segment.direction2}`;
-      
-      // Store segment data for rendering
-      const segmentData = {
-        ...segment,
-        animationProgress: 0,
-      };
+/**
+ * Animate the flows extended by a tile another player placed (over the network or by the AI)
+ * The paths are traced from the player edges exactly as the hover preview traces them
+ * startDelay (in frames) holds the flows back, e.g. until the tile has faded in
+ */
+export function triggerOpponentFlowAnimation(
+  game: Pick<GameState, 'board' | 'moveHistory' | 'players' | 'boardRadius'>,
+  position: HexPosition,
+  startDelay: number = 0
+): void {
+  removeSegmentAnimations(currentOpponentSegments);
+  currentOpponentSegments = [];
 
-      // Define animation that updates progress
-      defineAnimation(animName, (t: number) => {
-        segmentData.animationProgress = t;
-        // Store in global state for renderer to access
-        const previewData = (window as any).__FLOW_PREVIEW_DATA__ || {};
-        previewData[animName] = segmentData;
-        (window as any).__FLOW_PREVIEW_DATA__ = previewData;
-      });
+  const posKey = positionToKey(position);
+  const placements = game.moveHistory.filter((move) => positionToKey(move.tile.position) === posKey);
+  if (placements.length === 0) {
+    return;
+  }
 
-      // Calculate delay: each segment starts after the previous one in its path completes
-      const delay = segment.orderInPath * segmentDuration;
-      
-      // Register animation with delay
-      store.dispatch(registerAnimation(animName, segmentDuration, delay));
-    });
-  });
+  // Rebuild the board as it was before the tile arrived, restoring any tile it replaced
+  const previousBoard = new Map(game.board);
+  const replaced = placements[placements.length - 2];
+  if (replaced) {
+    previousBoard.set(posKey, replaced.tile);
+  } else {
+    previousBoard.delete(posKey);
+  }
+
+  const newPaths = calculateNewFlowPaths(game.board, previousBoard, game.players, position, game.boardRadius);
+  newPaths.forEach((path) => currentOpponentSegments.push(...path.segments));
+  animateFlowPaths(newPaths, true, startDelay);
+}
+
+/**
+ * Whether any flow segment is still animating
+ */
+export function hasFlowAnimation(): boolean {
+  const previewData = getFlowPreviewData();
+  return Object.values(previewData).some((segment) => segment.animationProgress < 1);
+}
+
+/**
+ * Whether a flow segment extended by another player's move is still animating
+ */
+export function hasOpponentFlowAnimation(): boolean {
+  const previewData = getFlowPreviewData();
+  return Object.values(previewData).some((segment) => segment.opponent && segment.animationProgress < 1);
 }
 
 /**
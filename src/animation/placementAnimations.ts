@@ -0,0 +1,110 @@
+// Placement animations for tiles arriving from other players
+
+import { HexPosition } from '../game/types';
+import { GameState, Move } from '../redux/types';
+import { defineAnimation } from './registry';
+import { registerAnimation, cancelAnimationsByName } from './actions';
+import { triggerOpponentFlowAnimation } from './flowPreview';
+
+// Duration of the fade-in and pulse for a newly arrived tile (~1 second)
+const PLACED_TILE_FADE_FRAMES = 60;
+
+// Fraction of the animation spent fading in; the remainder pulses the border
+const FADE_IN_FRACTION = 0.4;
+
+// Frames spent fading in; an arrived tile's flows start drawing after this
+export const PLACED_TILE_FADE_IN_FRAMES = Math.round(PLACED_TILE_FADE_FRAMES * FADE_IN_FRACTION);
+
+// State for placed tile animation rendering (not in Redux)
+export const placedTileAnimationState = {
+  position: null as HexPosition | null,
+  opacity: 1.0,      // Tile opacity while fading in
+  pulseIntensity: 0, // Border glow intensity while pulsing (0-1)
+};
+
+/**
+ * Detect a tile placed by another player since the previous frame
+ * Returns the new move, or null if nothing new arrived or the local player made it
+ * In tabletop mode (no local player) every move is local, so nothing is detected
+ */
+export function detectNewPlacedTile(
+  previousMoveCount: number,
+  moveHistory: Move[],
+  localPlayerId: string | null
+): Move | null {
+  if (localPlayerId === null || moveHistory.length <= previousMoveCount) {
+    return null;
+  }
+
+  const lastMove = moveHistory[moveHistory.length - 1];
+  if (lastMove.playerId === localPlayerId) {
+    return null;
+  }
+
+  return lastMove;
+}
+
+/**
+ * Start the fade-in and pulse animation for a newly arrived tile
+ */
+export function initPlacedTileAnimation(position: HexPosition): void {
+  placedTileAnimationState.position = position;
+  placedTileAnimationState.opacity = 0;
+  placedTileAnimationState.pulseIntensity = 0;
+
+  defineAnimation('placed-tile-fade', (t: number) => {
+    if (t < FADE_IN_FRACTION) {
+      // Fade the tile in
+      placedTileAnimationState.opacity = t / FADE_IN_FRACTION;
+      placedTileAnimationState.pulseIntensity = 0;
+    } else {
+      // Pulse the border once the tile is fully visible
+      const pulseT = (t - FADE_IN_FRACTION) / (1 - FADE_IN_FRACTION);
+      placedTileAnimationState.opacity = 1.0;
+      placedTileAnimationState.pulseIntensity = Math.sin(pulseT * Math.PI);
+    }
+
+    if (t >= 1) {
+      placedTileAnimationState.position = null;
+      placedTileAnimationState.pulseIntensity = 0;
+    }
+  });
+
+  const store = (window as any).__REDUX_STORE__;
+  if (!store) {
+    console.warn('Redux store not available for placed tile animations');
+    return;
+  }
+
+  // Restart if a previous tile is still animating
+  store.dispatch(cancelAnimationsByName('placed-tile-fade'));
+  store.dispatch(registerAnimation('placed-tile-fade', PLACED_TILE_FADE_FRAMES));
+}
+
+/**
+ * Animate a move that arrived from another player or the AI
+ * The tile fades in at its destination, then the flows it extends draw in
+ */
+export function animateArrivedMove(
+  game: Pick<GameState, 'board' | 'moveHistory' | 'players' | 'boardRadius'>,
+  move: Move
+): void {
+  initPlacedTileAnimation(move.tile.position);
+  triggerOpponentFlowAnimation(game, move.tile.position, PLACED_TILE_FADE_IN_FRAMES);
+}
+
+/**
+ * Cancel the placed tile animation and show the tile normally
+ */
+export function cancelPlacedTileAnimation(): void {
+  placedTileAnimationState.position = null;
+  placedTileAnimationState.opacity = 1.0;
+  placedTileAnimationState.pulseIntensity = 0;
+
+  const store = (window as any).__REDUX_STORE__;
+  if (!store) {
+    return;
+  }
+
+  store.dispatch(cancelAnimationsByName('placed-tile-fade'));
+}
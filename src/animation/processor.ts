@@ -35,7 +35,8 @@ export function processAnimations(
     // Calculate progress
     const totalFrames = animation.endFrame - animation.startFrame;
     const elapsedFrames = currentFrame - animation.startFrame;
-    const t = Math.min(1, elapsedFrames / totalFrames);
+    // Zero-length animations (animation speed 0) complete immediately
+    const t = totalFrames > 0 ? Math.min(1, elapsedFrames / totalFrames) : 1;
 
     // Get and call animation function
     const animFn = getAnimationFunction(animation.animationName);
@@ -0,0 +1,67 @@
+// Replay playback: steps through a game's move history at a fixed pace
+// The clock is kept free of rendering and Redux so the timing can be tested on its own
+
+import { RootState } from '../redux/types';
+import { stepReplay, StepReplayAction } from '../redux/actions';
+import { initPlacedTileAnimation } from './placementAnimations';
+
+// Time between replayed moves at 1x speed
+export const REPLAY_STEP_MS = 1000;
+
+// Playback speeds offered by the speed button, cycled in order
+export const REPLAY_SPEEDS = [0.5, 1, 2, 4];
+
+/**
+ * Advance the replay clock by one frame
+ * Returns how many moves to step and the time carried over towards the next step
+ */
+export function advanceReplayClock(
+  elapsedMs: number,
+  deltaMs: number,
+  speed: number
+): { steps: number; elapsedMs: number } {
+  const interval = REPLAY_STEP_MS / speed;
+  const total = elapsedMs + Math.max(0, deltaMs);
+  const steps = Math.floor(total / interval);
+  return { steps, elapsedMs: total - steps * interval };
+}
+
+/**
+ * Get the speed after the given one, wrapping back to the slowest
+ */
+export function nextReplaySpeed(speed: number): number {
+  const index = REPLAY_SPEEDS.indexOf(speed);
+  return REPLAY_SPEEDS[(index + 1) % REPLAY_SPEEDS.length];
+}
+
+/**
+ * Create the per-frame replay driver for an animation loop
+ * Each replayed tile gets the same fade-in and pulse as a tile arriving from another player
+ */
+export function createReplayDriver(store: {
+  getState: () => RootState;
+  dispatch: (action: StepReplayAction) => unknown;
+}): (deltaMs: number) => void {
+  let elapsedMs = 0;
+
+  return (deltaMs: number) => {
+    const state = store.getState();
+    if (!state.ui.replayPlaying) {
+      elapsedMs = 0;
+      return;
+    }
+
+    const clock = advanceReplayClock(elapsedMs, deltaMs, state.ui.replaySpeed);
+    elapsedMs = clock.elapsedMs;
+
+    const moves = state.game.moveHistory;
+    for (let i = 0; i < clock.steps && store.getState().ui.replayPlaying; i++) {
+      store.dispatch(stepReplay(moves.length));
+      const index = store.getState().ui.moveListIndex;
+      const move = moves[(index === -1 ? moves.length : index) - 1];
+      if (move) {
+        initPlacedTileAnimation(move.tile.position);
+      }
+    }
+  };
+}
@@ -0,0 +1,108 @@
+// Rotation animations: turning the held tile and the board smoothly to their new angles
+
+import { Rotation } from '../game/types';
+import { defineAnimation } from './registry';
+import { registerAnimation, cancelAnimationsByName } from './actions';
+
+// Duration of the held tile's turn to a new rotation (~150ms)
+const HELD_TILE_ROTATION_FRAMES = 9;
+
+// Duration of the board's turn when it is reoriented to another player's edge (~0.5 seconds)
+const BOARD_ROTATION_FRAMES = 30;
+
+// State for held tile rotation rendering (not in Redux)
+export const heldTileRotationState = {
+  offset: 0, // Radians the tile is still drawn turned away from its held rotation
+};
+
+// State for board rotation rendering (not in Redux)
+// Only the drawing is turned; hit-testing always uses the final board angle
+export const boardRotationState = {
+  offset: 0, // Radians the board is still drawn turned away from its orientation
+};
+
+// Board angle (degrees) seen by the last update, or null outside gameplay
+let lastBoardAngle: number | null = null;
+
+/**
+ * Screen angle of a rotation in radians (each step turns 60° clockwise)
+ */
+export function rotationToRadians(rotation: Rotation): number {
+  return (rotation * Math.PI) / 3;
+}
+
+/**
+ * Interpolate between two angles along the shorter way round the circle
+ * The result is not normalised, so it may lie outside [0, 2π) near the wraparound
+ */
+export function lerpRadians(start: number, end: number, t: number): number {
+  const fullTurn = 2 * Math.PI;
+  // Wrap the difference into [-π, π) so e.g. 300° → 0° turns 60° forward, not 300° back
+  const diff = ((((end - start) % fullTurn) + fullTurn + Math.PI) % fullTurn) - Math.PI;
+  return start + diff * t;
+}
+
+/**
+ * Start turning the held tile from its previous rotation to the new one
+ */
+export function animateHeldTileRotation(from: Rotation, to: Rotation): void {
+  const start = rotationToRadians(from);
+  // Reached by turning the short way, so it may be a full turn away from the new rotation's angle
+  const end = lerpRadians(start, rotationToRadians(to), 1);
+  heldTileRotationState.offset = start - end;
+
+  defineAnimation('held-tile-rotation', (t: number) => {
+    // Ease out so the tile settles into place
+    const eased = 1 - (1 - t) * (1 - t);
+    heldTileRotationState.offset = t >= 1 ? 0 : lerpRadians(start, end, eased) - end;
+  });
+
+  const store = (window as any).__REDUX_STORE__;
+  if (!store) {
+    heldTileRotationState.offset = 0;
+    return;
+  }
+
+  // Replace any turn still in progress
+  store.dispatch(cancelAnimationsByName('held-tile-rotation'));
+  store.dispatch(registerAnimation('held-tile-rotation', HELD_TILE_ROTATION_FRAMES));
+}
+
+/**
+ * Start turning the board from its previous angle to the new one (both in degrees)
+ */
+export function animateBoardRotation(fromDegrees: number, toDegrees: number): void {
+  const start = (fromDegrees * Math.PI) / 180;
+  const end = lerpRadians(start, (toDegrees * Math.PI) / 180, 1);
+  boardRotationState.offset = start - end;
+
+  defineAnimation('board-rotation', (t: number) => {
+    // Ease in and out so the turn reads as a deliberate swing rather than a jump
+    const eased = t < 0.5 ? 2 * t * t : 1 - 2 * (1 - t) * (1 - t);
+    boardRotationState.offset = t >= 1 ? 0 : lerpRadians(start, end, eased) - end;
+  });
+
+  const store = (window as any).__REDUX_STORE__;
+  if (!store) {
+    boardRotationState.offset = 0;
+    return;
+  }
+
+  // Replace any turn still in progress
+  store.dispatch(cancelAnimationsByName('board-rotation'));
+  store.dispatch(registerAnimation('board-rotation', BOARD_ROTATION_FRAMES));
+}
+
+/**
+ * Record the board angle after a state change, animating the turn if it changed during play
+ * Pass null outside gameplay so entering a game shows its orientation without a swing
+ */
+export function updateBoardRotation(angle: number | null): void {
+  // Record the angle first: starting the animation dispatches and re-enters the store listener
+  const previous = lastBoardAngle;
+  lastBoardAngle = angle;
+
+  if (previous !== null && angle !== null && previous !== angle) {
+    animateBoardRotation(previous, angle);
+  }
+}
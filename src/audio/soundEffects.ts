@@ -0,0 +1,73 @@
+// Sound effects for key game events
+//
+// Effects are synthesized with the Web Audio API rather than loaded from files,
+// and the audio context is only created the first time a sound plays, so sound
+// costs nothing at startup. Without Web Audio (e.g. in tests) playback is a no-op.
+
+export type SoundEffect = 'place' | 'rotate' | 'snap' | 'victory';
+
+// One tone within an effect
+interface Tone {
+  frequency: number; // Hz
+  start: number;     // Seconds after the effect starts
+  duration: number;  // Seconds
+  volume: number;    // Peak gain (0-1)
+  wave: OscillatorType;
+}
+
+const SOUND_EFFECT_TONES: Record<SoundEffect, Tone[]> = {
+  // A short click when a tile is placed
+  place: [{ frequency: 220, start: 0, duration: 0.08, volume: 0.4, wave: 'square' }],
+  // A quiet tick per rotation step
+  rotate: [{ frequency: 1200, start: 0, duration: 0.03, volume: 0.15, wave: 'triangle' }],
+  // A soft low thud when the tile snaps onto a hex
+  snap: [{ frequency: 140, start: 0, duration: 0.1, volume: 0.25, wave: 'sine' }],
+  // A rising major arpeggio when the game is won
+  victory: [
+    { frequency: 523.25, start: 0, duration: 0.15, volume: 0.3, wave: 'triangle' },
+    { frequency: 659.25, start: 0.15, duration: 0.15, volume: 0.3, wave: 'triangle' },
+    { frequency: 783.99, start: 0.3, duration: 0.15, volume: 0.3, wave: 'triangle' },
+    { frequency: 1046.5, start: 0.45, duration: 0.45, volume: 0.3, wave: 'triangle' },
+  ],
+};
+
+let audioContext: AudioContext | null = null;
+
+// Create the audio context on first use
+function getAudioContext(): AudioContext | null {
+  if (audioContext === null && typeof AudioContext !== 'undefined') {
+    audioContext = new AudioContext();
+  }
+  return audioContext;
+}
+
+// Play a sound effect
+export function playSoundEffect(effect: SoundEffect): void {
+  const context = getAudioContext();
+  if (context === null) {
+    return;
+  }
+
+  // Browsers suspend audio until the page has been interacted with
+  if (context.state === 'suspended') {
+    void context.resume();
+  }
+
+  for (const tone of SOUND_EFFECT_TONES[effect]) {
+    const startTime = context.currentTime + tone.start;
+    const oscillator = context.createOscillator();
+    const gain = context.createGain();
+
+    oscillator.type = tone.wave;
+    oscillator.frequency.value = tone.frequency;
+
+    // Decay quickly so tones don't click when they stop
+    gain.gain.setValueAtTime(tone.volume, startTime);
+    gain.gain.exponentialRampToValueAtTime(0.001, startTime + tone.duration);
+
+    oscillator.connect(gain);
+    gain.connect(context.destination);
+    oscillator.start(startTime);
+    oscillator.stop(startTime + tone.duration);
+  }
+}
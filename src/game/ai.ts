@@ -7,22 +7,25 @@ import {
   HexPosition,
   TileType,
   Rotation,
-  Direction,
 } from "./types";
 import {
   findLegalMoves,
+  findAllLegalMoves,
+  hasContinuation,
   hasViablePath,
   isValidReplacementMove,
 } from "./legality";
 import {
   getAllBoardPositions,
   positionToKey,
+  keyToPosition,
   getOppositeEdge,
-  getNeighborInDirection,
-  isValidPosition,
+  getNeighborsWithDirections,
   getEdgePositionsWithDirections,
 } from "./board";
-import { checkVictory } from "./victory";
+import { checkVictory, getGoalEdge, findPlayerTeam } from "./victory";
+import { getFlowConnections } from "./tiles";
+import { calculateFlows } from "./flows";
 
 // Evaluation constants
 const WIN_SCORE = 100000;
@@ -32,6 +35,19 @@ const OWN_PATH_WEIGHT = -2;
 const ENEMY_PATH_WEIGHT = 1;
 const BLOCK_THREAT_PENALTY = -50000; // Large penalty when enemy is 1 move from victory (urgent to block)
 const BLOCKING_PENALTY = -75000; // Heavy penalty for blocking the opponent completely
+const DRAW_SCORE = -50; // Filling the board without a winner is slightly worse than an ongoing game
+const CENTRALITY_WEIGHT = 0.5; // Per own-flow hex, per ring closer to the centre (control evaluator)
+const CORNER_PENALTY = -2; // Per own-flow hex in a board corner (control evaluator)
+
+// Difficulty levels: weaker AIs choose among more of their ranked candidates
+// Every level searches one move ahead; difficulty is not a search depth
+export const MIN_AI_DIFFICULTY = 1;
+export const MAX_AI_DIFFICULTY = 3;
+const DIFFICULTY_POOL_FRACTION: Record<number, number> = {
+  1: 0.5,  // Any move from the better half
+  2: 0.2,  // Any move from the top fifth
+  3: 0,    // Always the best move
+};
 
 // Move candidate with evaluation score
 export interface MoveCandidate {
@@ -42,6 +58,33 @@ export interface MoveCandidate {
   isWinningMove: boolean;
 }
 
+// Evaluation counts from the most recent generateMoveCandidates call
+export interface SearchStats {
+  evaluations: number; // Positions scored by evaluatePosition
+  cacheHits: number;   // Positions answered from the evaluation memo
+}
+
+let lastSearchStats: SearchStats = { evaluations: 0, cacheHits: 0 };
+
+export function getLastSearchStats(): SearchStats {
+  return { ...lastSearchStats };
+}
+
+// Key identifying a board position in the AI's evaluation memo
+// Each tile is described by its location and the ports it connects, so rotations of
+// a symmetric tile that connect the same ports (e.g. ThreeSharps 0, 2, and 4) share a key
+export function getEvaluationKey(board: Map<string, PlacedTile>): string {
+  const tileKeys: string[] = [];
+  board.forEach((tile, key) => {
+    const connections = getFlowConnections(tile.type, tile.rotation)
+      .map(([dir1, dir2]) => (dir1 < dir2 ? `${dir1}${dir2}` : `${dir2}${dir1}`))
+      .sort()
+      .join("-");
+    tileKeys.push(`${key}:${connections}`);
+  });
+  return tileKeys.sort().join("|");
+}
+
 // Get shortest path length for a player to their target edge
 // Uses BFS on the edge graph similar to hasViablePath but returns path length
 function getShortestPathLength(
@@ -84,6 +127,67 @@ function getShortestPathLength(
   return emptyTileCount;
 }
 
+// Get the shortest path length among a player's opponents (teammates excluded)
+// This is how many tiles the closest opponent still needs to win
+export function getOpponentPathLength(
+  board: Map<string, PlacedTile>,
+  player: Player,
+  players: Player[],
+  teams: Team[],
+  boardRadius = 3,
+  oppositeSidesVictory = false,
+): number {
+  const team = findPlayerTeam(player.id, teams);
+
+  let minPathLength = Infinity;
+  for (const opponent of players) {
+    if (opponent.id === player.id) continue;
+    if (team && (opponent.id === team.player1Id || opponent.id === team.player2Id)) continue;
+
+    const pathLength = getShortestPathLength(
+      board,
+      opponent,
+      getGoalEdge(opponent, players, teams, oppositeSidesVictory),
+      boardRadius,
+    );
+    minPathLength = Math.min(minPathLength, pathLength);
+  }
+
+  return minPathLength;
+}
+
+// Compare the closest opponent's path length before and after a move
+// Positive values mean the move sets the opponent back by that many tiles,
+// Infinity means the move blocks them, and 0 if they were already blocked
+export function computeOpponentSetback(
+  boardBefore: Map<string, PlacedTile>,
+  boardAfter: Map<string, PlacedTile>,
+  player: Player,
+  players: Player[],
+  teams: Team[],
+  boardRadius = 3,
+  oppositeSidesVictory = false,
+): number {
+  const before = getOpponentPathLength(boardBefore, player, players, teams, boardRadius, oppositeSidesVictory);
+  if (before === Infinity) {
+    return 0;
+  }
+
+  const after = getOpponentPathLength(boardAfter, player, players, teams, boardRadius, oppositeSidesVictory);
+  return after - before;
+}
+
+// Scores a board position for the AI player; higher is better for the AI
+export type EvaluationStrategy = (
+  board: Map<string, PlacedTile>,
+  aiPlayer: Player,
+  players: Player[],
+  teams: Team[],
+  boardRadius: number,
+  supermoveEnabled: boolean,
+  oppositeSidesVictory?: boolean,
+) => number;
+
 // Evaluate a board position for the AI player
 // Returns a score where higher is better for the AI
 function evaluatePosition(
@@ -93,6 +197,7 @@ function evaluatePosition(
   teams: Team[],
   boardRadius = 3,
   supermoveEnabled = false,
+  oppositeSidesVictory = false,
 ): number {
   // Check if this is a winning position for the AI
   const victoryResult = checkVictory(
@@ -101,6 +206,8 @@ function evaluatePosition(
     teams,
     undefined,
     boardRadius,
+    supermoveEnabled,
+    oppositeSidesVictory,
   );
 
   if (victoryResult.winners.includes(aiPlayer.id)) {
@@ -115,8 +222,13 @@ function evaluatePosition(
     return LOSS_SCORE;
   }
 
-  // Calculate shortest path for AI
-  const aiTargetEdge = getOppositeEdge(aiPlayer.edgePosition);
+  // A full board with no winner ends the game in a draw
+  if (victoryResult.winType === 'draw') {
+    return DRAW_SCORE;
+  }
+
+  // Calculate shortest path for AI (to its partner's edge in team games)
+  const aiTargetEdge = getGoalEdge(aiPlayer, players, teams, oppositeSidesVictory);
   const aiPathLength = getShortestPathLength(
     board,
     aiPlayer,
@@ -125,23 +237,14 @@ function evaluatePosition(
   );
 
   // Calculate shortest path for enemies (take the minimum among all enemies)
-  let enemyMinPathLength = Infinity;
-
-  for (const player of players) {
-    if (player.id === aiPlayer.id) continue;
-
-    const targetEdge = getOppositeEdge(player.edgePosition);
-    const pathLength = getShortestPathLength(
-      board,
-      player,
-      targetEdge,
-      boardRadius,
-    );
-
-    if (pathLength < enemyMinPathLength) {
-      enemyMinPathLength = pathLength;
-    }
-  }
+  const enemyMinPathLength = getOpponentPathLength(
+    board,
+    aiPlayer,
+    players,
+    teams,
+    boardRadius,
+    oppositeSidesVictory,
+  );
 
   // Evaluation: -A * (AI path length)^2 + B * (enemy min path length)^2
   // We want to minimize our path and maximize enemy's path
@@ -187,6 +290,49 @@ function evaluatePosition(
   return aiScore + enemyScore + blockThreatPenalty;
 }
 
+// The default evaluation: shortest paths only
+export const pathLengthEvaluator: EvaluationStrategy = evaluatePosition;
+
+// Score how much of the board a player's flow controls
+// Each own-flow hex earns more the closer it is to the centre; hexes in a corner are penalized
+function getControlScore(
+  board: Map<string, PlacedTile>,
+  player: Player,
+  boardRadius: number,
+): number {
+  const { flows } = calculateFlows(board, [player], boardRadius);
+  let score = 0;
+
+  for (const key of flows.get(player.id) ?? []) {
+    const { row, col } = keyToPosition(key);
+    const distances = [Math.abs(row), Math.abs(col), Math.abs(row + col)];
+    score += CENTRALITY_WEIGHT * (boardRadius - Math.max(...distances));
+    if (distances.filter((d) => d === boardRadius).length >= 2) {
+      score += CORNER_PENALTY;
+    }
+  }
+
+  return score;
+}
+
+// Path lengths blended with board control
+// Decided positions (wins and losses) keep their path-length score
+export const controlEvaluator: EvaluationStrategy = (
+  board,
+  aiPlayer,
+  players,
+  teams,
+  boardRadius,
+  supermoveEnabled,
+  oppositeSidesVictory,
+) => {
+  const score = evaluatePosition(board, aiPlayer, players, teams, boardRadius, supermoveEnabled, oppositeSidesVictory);
+  if (score >= WIN_SCORE || score <= LOSS_SCORE) {
+    return score;
+  }
+  return score + getControlScore(board, aiPlayer, boardRadius);
+};
+
 // Check if a position is adjacent to any flow or starting edge for any player
 // OR if the position itself is a starting edge position
 function isAdjacentToFlowOrEdge(
@@ -209,19 +355,10 @@ function isAdjacentToFlowOrEdge(
     }
   }
 
-  // Check all 6 neighbors
-  for (let dir = 0; dir < 6; dir++) {
-    const neighbor = getNeighborInDirection(position, dir as Direction);
-
-    // Check if neighbor is a valid position
-    if (!isValidPosition(neighbor, boardRadius)) {
-      continue;
-    }
-
-    const neighborKey = positionToKey(neighbor);
-
+  // Check all on-board neighbors
+  for (const { position: neighbor } of getNeighborsWithDirections(position, boardRadius)) {
     // Check if neighbor has a tile (adjacent to existing tile/flow)
-    if (board.has(neighborKey)) {
+    if (board.has(positionToKey(neighbor))) {
       return true;
     }
   }
@@ -229,6 +366,46 @@ function isAdjacentToFlowOrEdge(
   return false;
 }
 
+// Order moves for searching: most promising first
+// Priorities: next to the last move, next to the move before it, next to the AI's own flow,
+// next to another player's flow, then everything else. Moves keep their order within a priority.
+export function orderMoves<T extends { position: HexPosition }>(
+  moves: T[],
+  board: Map<string, PlacedTile>,
+  aiPlayer: Player,
+  players: Player[],
+  recentMoves: HexPosition[], // Most recent first
+  boardRadius = 3,
+): T[] {
+  const { flows } = calculateFlows(board, players, boardRadius);
+  const ownFlow = flows.get(aiPlayer.id) ?? new Set<string>();
+  const otherFlows = new Set<string>();
+  for (const player of players) {
+    if (player.id === aiPlayer.id) continue;
+    flows.get(player.id)?.forEach((key) => otherFlows.add(key));
+  }
+
+  const isNextTo = (position: HexPosition, keys: Set<string>) =>
+    getNeighborsWithDirections(position, boardRadius).some(({ position: neighbor }) =>
+      keys.has(positionToKey(neighbor)),
+    );
+  const lastMove = new Set(recentMoves.slice(0, 1).map(positionToKey));
+  const secondLastMove = new Set(recentMoves.slice(1, 2).map(positionToKey));
+
+  const priority = (position: HexPosition): number => {
+    if (isNextTo(position, lastMove)) return 0;
+    if (isNextTo(position, secondLastMove)) return 1;
+    if (isNextTo(position, ownFlow)) return 2;
+    if (isNextTo(position, otherFlows)) return 3;
+    return 4;
+  };
+
+  return moves
+    .map((move, index) => ({ move, index, priority: priority(move.position) }))
+    .sort((a, b) => a.priority - b.priority || a.index - b.index)
+    .map(({ move }) => move);
+}
+
 // Generate all move candidates for the AI
 export function generateMoveCandidates(
   board: Map<string, PlacedTile>,
@@ -238,11 +415,45 @@ export function generateMoveCandidates(
   teams: Team[],
   supermoveEnabled: boolean,
   boardRadius = 3,
+  memoizeEvaluations = true,
+  evaluator: EvaluationStrategy = pathLengthEvaluator,
+  oppositeSidesVictory = false,
 ): MoveCandidate[] {
   const startTime = performance.now();
   let evaluationCount = 0;
+  let cacheHits = 0;
   const candidates: MoveCandidate[] = [];
 
+  // Memo of scores for the positions this call has evaluated
+  // Different moves can reach the same position: symmetric rotations of the tile,
+  // or a supermove and its follow-up landing where another sequence already did
+  const evaluationMemo = new Map<string, number>();
+  const evaluate = (testBoard: Map<string, PlacedTile>): number => {
+    const key = memoizeEvaluations ? getEvaluationKey(testBoard) : "";
+    if (memoizeEvaluations) {
+      const cached = evaluationMemo.get(key);
+      if (cached !== undefined) {
+        cacheHits++;
+        return cached;
+      }
+    }
+
+    evaluationCount++;
+    const score = evaluator(
+      testBoard,
+      aiPlayer,
+      players,
+      teams,
+      boardRadius,
+      supermoveEnabled,
+      oppositeSidesVictory,
+    );
+    if (memoizeEvaluations) {
+      evaluationMemo.set(key, score);
+    }
+    return score;
+  };
+
   // Try all rotations
   for (let rotation = 0; rotation < 6; rotation++) {
     const rot = rotation as Rotation;
@@ -256,6 +467,8 @@ export function generateMoveCandidates(
       teams,
       boardRadius,
       supermoveEnabled,
+      undefined,
+      oppositeSidesVictory,
     );
 
     // Filter to only positions adjacent to flows or starting edges
@@ -274,15 +487,7 @@ export function generateMoveCandidates(
       testBoard.set(positionToKey(position), tile);
 
       // Evaluate this position
-      evaluationCount++;
-      const score = evaluatePosition(
-        testBoard,
-        aiPlayer,
-        players,
-        teams,
-        boardRadius,
-        supermoveEnabled,
-      );
+      const score = evaluate(testBoard);
       const isWinning = score >= WIN_SCORE;
 
       candidates.push({
@@ -316,6 +521,8 @@ export function generateMoveCandidates(
             players,
             teams,
             boardRadius,
+            false,
+            oppositeSidesVictory,
           )
         ) {
           continue;
@@ -331,15 +538,7 @@ export function generateMoveCandidates(
         testBoard.set(posKey, newTile);
 
         // Check if the replacement itself causes victory
-        evaluationCount++;
-        let replacementScore = evaluatePosition(
-          testBoard,
-          aiPlayer,
-          players,
-          teams,
-          boardRadius,
-          supermoveEnabled,
-        );
+        let replacementScore = evaluate(testBoard);
         const replacementWins = replacementScore >= WIN_SCORE;
 
         // If replacement wins, that's the score
@@ -359,9 +558,8 @@ export function generateMoveCandidates(
         const replacedTileType = existingTile.type;
 
         // Find best follow-up move with the replaced tile
-        let bestFollowupScore = -Infinity;
-        let foundWinningFollowup = false;
-
+        // Moves near the replacement are tried first, so a winning follow-up ends the search sooner
+        const followupMoves: Array<{ position: HexPosition; rotation: Rotation }> = [];
         for (
           let followupRotation = 0;
           followupRotation < 6;
@@ -376,42 +574,34 @@ export function generateMoveCandidates(
             teams,
             boardRadius,
             supermoveEnabled,
+            undefined,
+            oppositeSidesVictory,
           );
-
           for (const followupPosition of followupPositions) {
-            // Create test board with follow-up move
-            const followupBoard = new Map(testBoard);
-            const followupTile: PlacedTile = {
-              type: replacedTileType,
-              rotation: followupRot,
-              position: followupPosition,
-            };
-            followupBoard.set(positionToKey(followupPosition), followupTile);
-
-            // Evaluate the final position after both moves
-            evaluationCount++;
-            const followupScore = evaluatePosition(
-              followupBoard,
-              aiPlayer,
-              players,
-              teams,
-              boardRadius,
-              supermoveEnabled,
-            );
-
-            if (followupScore > bestFollowupScore) {
-              bestFollowupScore = followupScore;
-            }
-
-            // Stop early if we found a winning move
-            if (followupScore >= WIN_SCORE) {
-              foundWinningFollowup = true;
-              break;
-            }
+            followupMoves.push({ position: followupPosition, rotation: followupRot });
+          }
+        }
+
+        let bestFollowupScore = -Infinity;
+        for (const followup of orderMoves(followupMoves, testBoard, aiPlayer, players, [position], boardRadius)) {
+          // Create test board with follow-up move
+          const followupBoard = new Map(testBoard);
+          const followupTile: PlacedTile = {
+            type: replacedTileType,
+            rotation: followup.rotation,
+            position: followup.position,
+          };
+          followupBoard.set(positionToKey(followup.position), followupTile);
+
+          // Evaluate the final position after both moves
+          const followupScore = evaluate(followupBoard);
+
+          if (followupScore > bestFollowupScore) {
+            bestFollowupScore = followupScore;
           }
 
-          // Break outer loop if we found a winning move
-          if (foundWinningFollowup) {
+          // Stop early if we found a winning move
+          if (followupScore >= WIN_SCORE) {
             break;
           }
         }
@@ -428,13 +618,54 @@ export function generateMoveCandidates(
     }
   }
 
+  lastSearchStats = { evaluations: evaluationCount, cacheHits };
+
   const endTime = performance.now();
   const elapsedMs = endTime - startTime;
-  console.log(`[AI] Generated ${candidates.length} candidates with ${evaluationCount} evaluations in ${elapsedMs.toFixed(2)}ms (board size: ${board.size})`);
+  console.log(`[AI] Generated ${candidates.length} candidates with ${evaluationCount} evaluations (${cacheHits} cache hits) in ${elapsedMs.toFixed(2)}ms (board size: ${board.size})`);
 
   return candidates;
 }
 
+// One legal placement with its evaluation, for studying a position
+export interface MoveAnalysis {
+  position: HexPosition;
+  rotation: Rotation;
+  score: number;
+}
+
+// Evaluate every legal placement of the tile in hand, best first
+// Unlike the AI's own search this covers the whole board, not just hexes next to play.
+// Ties are broken by position and rotation so the list is the same on every call.
+export function analyzePosition(
+  board: Map<string, PlacedTile>,
+  tileType: TileType,
+  aiPlayer: Player,
+  players: Player[],
+  teams: Team[],
+  supermoveEnabled: boolean,
+  boardRadius = 3,
+  evaluator: EvaluationStrategy = pathLengthEvaluator,
+  oppositeSidesVictory = false,
+): MoveAnalysis[] {
+  const moves = findAllLegalMoves(board, tileType, players, teams, boardRadius, supermoveEnabled, undefined, oppositeSidesVictory);
+
+  return moves
+    .map(({ position, rotation }) => {
+      const testBoard = new Map(board);
+      testBoard.set(positionToKey(position), { type: tileType, rotation, position });
+      const score = evaluator(testBoard, aiPlayer, players, teams, boardRadius, supermoveEnabled, oppositeSidesVictory);
+      return { position, rotation, score };
+    })
+    .sort(
+      (a, b) =>
+        b.score - a.score ||
+        a.position.row - b.position.row ||
+        a.position.col - b.position.col ||
+        a.rotation - b.rotation,
+    );
+}
+
 // Select the best move for the AI
 export function selectAIMove(
   board: Map<string, PlacedTile>,
@@ -444,10 +675,15 @@ export function selectAIMove(
   teams: Team[],
   supermoveEnabled: boolean,
   boardRadius = 3,
+  difficulty = MAX_AI_DIFFICULTY,
+  seed = 0,
+  continuationTileTypes?: TileType[],
+  evaluator: EvaluationStrategy = pathLengthEvaluator,
+  oppositeSidesVictory = false,
 ): MoveCandidate | null {
   const startTime = performance.now();
   
-  const candidates = generateMoveCandidates(
+  let candidates = generateMoveCandidates(
     board,
     tileType,
     aiPlayer,
@@ -455,8 +691,23 @@ export function selectAIMove(
     teams,
     supermoveEnabled,
     boardRadius,
+    true,
+    evaluator,
+    oppositeSidesVictory,
   );
 
+  // With the no-self-stalemate rule, drop placements that leave no legal move for the bag
+  if (continuationTileTypes) {
+    candidates = candidates.filter((c) => {
+      if (c.isReplacement || c.isWinningMove) {
+        return true;
+      }
+      const testBoard = new Map(board);
+      testBoard.set(positionToKey(c.position), { type: tileType, rotation: c.rotation, position: c.position });
+      return hasContinuation(testBoard, continuationTileTypes, players, teams, boardRadius, supermoveEnabled, oppositeSidesVictory);
+    });
+  }
+
   if (candidates.length === 0) {
     console.log('[AI] No valid moves available');
     return null;
@@ -474,10 +725,22 @@ export function selectAIMove(
   // Otherwise, select the move with the highest score
   candidates.sort((a, b) => b.score - a.score);
 
+  // Below full strength, pick among the top-ranked candidates
+  // The choice comes from the seed so every client picks the same move
+  const fraction = DIFFICULTY_POOL_FRACTION[difficulty] ?? 0;
+  const poolSize = Math.max(1, Math.ceil(candidates.length * fraction));
+  const choice = poolSize > 1 ? seededIndex(seed, poolSize) : 0;
+
   const endTime = performance.now();
-  console.log(`[AI] selectAIMove took ${(endTime - startTime).toFixed(2)}ms total - selected best of ${candidates.length} candidates (score: ${candidates[0].score.toFixed(2)})`);
+  console.log(`[AI] selectAIMove took ${(endTime - startTime).toFixed(2)}ms total - selected #${choice + 1} of ${candidates.length} candidates at difficulty ${difficulty} (score: ${candidates[choice].score.toFixed(2)})`);
+
+  return candidates[choice];
+}
 
-  return candidates[0];
+// Deterministic index in [0, count) from a seed
+function seededIndex(seed: number, count: number): number {
+  const value = Math.sin(seed * 12.9898 + 78.233) * 43758.5453;
+  return Math.floor((value - Math.floor(value)) * count);
 }
 
 // Select an edge for the AI during the seating phase
@@ -67,38 +67,52 @@ export function getNeighborInDirection(
   };
 }
 
-// Get all neighboring positions for a hex
-export function getNeighbors(pos: HexPosition, radius: number = 3): HexPosition[] {
-  const neighbors: HexPosition[] = [];
+// Get all on-board neighbors of a hex together with the direction to reach them
+// Off-board directions are skipped, so edge hexes yield fewer than six entries
+export function getNeighborsWithDirections(
+  pos: HexPosition,
+  radius: number = 3,
+): Array<{ direction: Direction; position: HexPosition }> {
+  const neighbors: Array<{ direction: Direction; position: HexPosition }> = [];
 
   for (let dir = 0; dir < 6; dir++) {
-    const neighbor = getNeighborInDirection(pos, dir as Direction);
-    if (isValidPosition(neighbor, radius)) {
-      neighbors.push(neighbor);
+    const direction = dir as Direction;
+    const position = getNeighborInDirection(pos, direction);
+    if (isValidPosition(position, radius)) {
+      neighbors.push({ direction, position });
     }
   }
 
   return neighbors;
 }
 
+// Get all neighboring positions for a hex
+export function getNeighbors(pos: HexPosition, radius: number = 3): HexPosition[] {
+  return getNeighborsWithDirections(pos, radius).map(({ position }) => position);
+}
+
+// Get the direction whose offset (see DIRECTION_VECTORS) is the given vector
+// Returns null for any vector that is not one of the six neighbor offsets
+export function directionFromOffset(offset: HexPosition): Direction | null {
+  switch (offset.row) {
+    case -1:
+      return offset.col === 0 ? Direction.SouthWest : offset.col === 1 ? Direction.SouthEast : null;
+    case 0:
+      return offset.col === -1 ? Direction.West : offset.col === 1 ? Direction.East : null;
+    case 1:
+      return offset.col === -1 ? Direction.NorthWest : offset.col === 0 ? Direction.NorthEast : null;
+    default:
+      return null;
+  }
+}
+
 // Get the direction from one position to a neighboring position
 // Returns null if positions are not adjacent
 export function getDirection(
   from: HexPosition,
   to: HexPosition,
 ): Direction | null {
-  const deltaRow = to.row - from.row;
-  const deltaCol = to.col - from.col;
-
-  // Check each direction
-  for (let dir = 0; dir < 6; dir++) {
-    const offset = DIRECTION_VECTORS[dir as Direction];
-    if (offset.row === deltaRow && offset.col === deltaCol) {
-      return dir as Direction;
-    }
-  }
-
-  return null;
+  return directionFromOffset({ row: to.row - from.row, col: to.col - from.col });
 }
 
 // Get the opposite direction (180 degrees)
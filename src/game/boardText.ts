@@ -0,0 +1,89 @@
+// Plain-text board rendering for Quortex/Flows
+// Produces a monospace picture of the board suitable for posting in chat (e.g. by a Discord bot)
+
+import { PlacedTile, Player, Direction, TileType } from './types';
+import { positionToKey } from './board';
+import { getFlowConnections } from './tiles';
+
+// Width of one hex cell in characters; rows are offset by half a cell
+const CELL_WIDTH = 6;
+
+// One glyph per tile type, by number of sharp corners
+const TILE_GLYPHS: Record<TileType, string> = {
+  [TileType.NoSharps]: '○',
+  [TileType.OneSharp]: '◔',
+  [TileType.TwoSharps]: '◑',
+  [TileType.ThreeSharps]: '●',
+};
+
+const EMPTY_GLYPH = '·';
+const EMPTY_CELL = EMPTY_GLYPH + '  ';
+const NO_FLOW_GLYPH = '-';
+const SHARED_FLOW_GLYPH = '*';
+
+// Get the flow glyph for a tile: the owning player's number (1-based, in player order),
+// '*' if several players' flows pass through it, or '-' if no flow reaches it
+function flowGlyph(
+  tile: PlacedTile,
+  players: Player[],
+  flowEdges: Map<string, Map<Direction, string>>,
+): string {
+  const tileFlowEdges = flowEdges.get(positionToKey(tile.position));
+  const owners = new Set<string>();
+  for (const [dir1, dir2] of getFlowConnections(tile.type, tile.rotation)) {
+    for (const dir of [dir1, dir2]) {
+      const playerId = tileFlowEdges?.get(dir);
+      if (playerId !== undefined) {
+        owners.add(playerId);
+      }
+    }
+  }
+
+  if (owners.size === 0) {
+    return NO_FLOW_GLYPH;
+  }
+  if (owners.size > 1) {
+    return SHARED_FLOW_GLYPH;
+  }
+  const [owner] = owners;
+  const index = players.findIndex((p) => p.id === owner);
+  return index >= 0 ? String(index + 1) : SHARED_FLOW_GLYPH;
+}
+
+// Explains the three characters of each placed tile's cell
+const CELL_KEY = 'Cells: tile (○ 0, ◔ 1, ◑ 2, ● 3 sharps), rotation 0-5, flow (player number, - none, * shared)';
+
+// Render the board as text
+// Each hex is a tile glyph, its rotation and a flow glyph, enough to rebuild the position
+// from a bug report; every board row has the same width so the picture stays aligned in
+// monospace chat. A key explains the cells and a legend maps flow glyphs to players.
+export function boardToText(
+  board: Map<string, PlacedTile>,
+  players: Player[],
+  flowEdges: Map<string, Map<Direction, string>>,
+  boardRadius: number = 3,
+): string {
+  const width = (2 * boardRadius + 1) * CELL_WIDTH;
+  const lines: string[] = [];
+
+  for (let row = -boardRadius; row <= boardRadius; row++) {
+    const minCol = Math.max(-boardRadius, -boardRadius - row);
+    const maxCol = Math.min(boardRadius, boardRadius - row);
+
+    let line = ' '.repeat(Math.abs(row) * (CELL_WIDTH / 2));
+    for (let col = minCol; col <= maxCol; col++) {
+      const tile = board.get(positionToKey({ row, col }));
+      const cell = tile
+        ? TILE_GLYPHS[tile.type] + tile.rotation + flowGlyph(tile, players, flowEdges)
+        : EMPTY_CELL;
+      line += '  ' + cell + ' ';
+    }
+    lines.push(line.padEnd(width));
+  }
+
+  const legend = players.map(
+    (player, index) => `${index + 1} ${player.id} ${player.color} (edge ${player.edgePosition})`,
+  );
+
+  return [...lines, '', CELL_KEY, ...legend].join('\n');
+}
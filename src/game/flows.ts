@@ -1,6 +1,6 @@
 // Flow propagation logic for Quortex/Flows
 
-import { HexPosition, Direction, PlacedTile, Player } from "./types";
+import { HexPosition, Direction, PlacedTile, Player, FlowConnection } from "./types";
 import {
   positionToKey,
   getNeighborInDirection,
@@ -8,7 +8,7 @@ import {
   getEdgePositionsWithDirections,
   isValidPosition,
 } from "./board";
-import { getFlowExit } from "./tiles";
+import { getFlowExit, getFlowConnections } from "./tiles";
 
 // Flow edge data: tracks which player's flow enters/exits each hex edge
 export interface FlowEdgeData {
@@ -126,6 +126,81 @@ export function calculateFlows(
   return { flows, flowEdges };
 }
 
+// Flow ownership of a single tile segment (one flow connection)
+export interface SegmentOwners {
+  connection: FlowConnection;
+  owners: string[]; // Player IDs filling this segment, in connection order (empty if unfilled)
+}
+
+// Get the players filling each segment of a tile, for rendering
+// Only entry directions are recorded in flowEdges, so a segment is owned by whoever
+// entered from either end. Different segments of the same tile can carry different
+// players, and one segment can carry two players entering from opposite ends.
+export function getTileSegmentOwners(
+  tile: PlacedTile,
+  flowEdges: Map<string, Map<Direction, string>>,
+): SegmentOwners[] {
+  const tileFlowEdges = flowEdges.get(positionToKey(tile.position));
+
+  return getFlowConnections(tile.type, tile.rotation).map((connection) => {
+    const owners: string[] = [];
+    for (const dir of connection) {
+      const playerId = tileFlowEdges?.get(dir);
+      if (playerId !== undefined && !owners.includes(playerId)) {
+        owners.push(playerId);
+      }
+    }
+    return { connection, owners };
+  });
+}
+
+// Get the distinct players whose flow passes through a tile, in segment order
+export function getTileFlowPlayers(
+  tile: PlacedTile,
+  flowEdges: Map<string, Map<Direction, string>>,
+): string[] {
+  const players: string[] = [];
+  for (const { owners } of getTileSegmentOwners(tile, flowEdges)) {
+    for (const playerId of owners) {
+      if (!players.includes(playerId)) {
+        players.push(playerId);
+      }
+    }
+  }
+  return players;
+}
+
+// Check if a hex edge carries a player's flow back to that player's board edge
+// Follows every flow starting on the player's edge; the hex edge is connected if one of
+// them enters or leaves the tile at that position through it
+export function isEdgeConnectedToStart(
+  board: Map<string, PlacedTile>,
+  position: HexPosition,
+  direction: Direction,
+  player: Player,
+  boardRadius: number,
+): boolean {
+  const posKey = positionToKey(position);
+  const tile = board.get(posKey);
+  if (!tile) {
+    return false;
+  }
+
+  for (const { pos, dir } of getEdgePositionsWithDirections(player.edgePosition, boardRadius)) {
+    const { edges } = traceFlow(board, pos, dir, player.id, boardRadius);
+    for (const edge of edges) {
+      if (
+        edge.position === posKey &&
+        (edge.direction === direction || getFlowExit(tile, edge.direction) === direction)
+      ) {
+        return true;
+      }
+    }
+  }
+
+  return false;
+}
+
 // Check if two positions are flow-connected for a specific player
 export function areConnected(
   pos1: HexPosition,
@@ -6,3 +6,6 @@ export * from './tiles';
 export * from './flows';
 export * from './legality';
 export * from './victory';
+export * from './turns';
+export * from './boardText';
+export * from './tutorial';
@@ -4,14 +4,14 @@ import { PlacedTile, Player, Team, HexPosition, TileType, Rotation, Direction }
 import {
   getAllBoardPositions,
   positionToKey,
-  getOppositeEdge,
   getNeighborInDirection,
   getOppositeDirection,
   isValidPosition,
   getEdgePositionsWithDirections,
 } from './board';
-import { checkFlowVictory } from './victory';
-import { getFlowConnections } from './tiles';
+import { checkFlowVictory, isBoardFull, getGoalEdge } from './victory';
+import { getFlowConnections, getUniqueRotations, getEquivalentRotation } from './tiles';
+import { Move } from '../redux/types';
 
 // Check if placing a tile would result in a victory
 function wouldCauseVictory(
@@ -19,14 +19,15 @@ function wouldCauseVictory(
   tile: PlacedTile,
   players: Player[],
   teams: Team[],
-  boardRadius: number
+  boardRadius: number,
+  oppositeSidesVictory: boolean = false
 ): boolean {
   // Create a new board with the proposed tile
   const testBoard = new Map(board);
   testBoard.set(positionToKey(tile.position), tile);
   
   // Check if this causes a victory
-  const victory = checkFlowVictory(testBoard, players, teams, boardRadius);
+  const victory = checkFlowVictory(testBoard, players, teams, boardRadius, oppositeSidesVictory);
   
   return victory.winners.length > 0;
 }
@@ -289,50 +290,91 @@ export function hasViablePath(
   };
 }
 
+// One way for a side to stay in the game: a player's path from their edge to a target edge
+export interface PlayerRoute {
+  player: Player;
+  targetEdge: number;
+}
+
+// Get the routes each side needs; a side is blocked only when all of its routes are
+// Individual games: each player towards the opposite edge
+// Team games: each partner towards the other's edge (teams missing a player are skipped),
+// or towards their own opposite edge when opposite-sides victory is enabled
+export function getSideRoutes(
+  players: Player[],
+  teams: Team[],
+  oppositeSidesVictory: boolean = false
+): PlayerRoute[][] {
+  const route = (player: Player): PlayerRoute => ({
+    player,
+    targetEdge: getGoalEdge(player, players, teams, oppositeSidesVictory),
+  });
+  
+  if (teams.length === 0) {
+    return players.map((player) => [route(player)]);
+  }
+  
+  const sides: PlayerRoute[][] = [];
+  for (const team of teams) {
+    const player1 = players.find((p) => p.id === team.player1Id);
+    const player2 = players.find((p) => p.id === team.player2Id);
+    
+    if (!player1 || !player2) continue;
+    
+    sides.push([route(player1), route(player2)]);
+  }
+  return sides;
+}
+
 // Check if all players/teams have a viable path after placing a tile
 function allPlayersHaveViablePath(
   board: Map<string, PlacedTile>,
   players: Player[],
   teams: Team[],
-  boardRadius: number
+  boardRadius: number,
+  oppositeSidesVictory: boolean = false
 ): boolean {
-  // For team games
-  if (teams.length > 0) {
-    for (const team of teams) {
-      const player1 = players.find((p) => p.id === team.player1Id);
-      const player2 = players.find((p) => p.id === team.player2Id);
-      
-      if (!player1 || !player2) continue;
-      
-      // Check if team has a viable path (either player can complete it)
-      const path1 = hasViablePath(board, player1, player2.edgePosition, false, true, boardRadius);
-      const path2 = hasViablePath(board, player2, player1.edgePosition, false, true, boardRadius);
-      
-      if (!path1 && !path2) {
-        return false;
-      }
-    }
-  } else {
-    // For individual games
-    for (const player of players) {
-      const targetEdge = getOppositeEdge(player.edgePosition);
-      if (!hasViablePath(board, player, targetEdge, false, true, boardRadius)) {
-        return false;
-      }
-    }
+  return getSideRoutes(players, teams, oppositeSidesVictory).every((routes) =>
+    routes.some(({ player, targetEdge }) =>
+      hasViablePath(board, player, targetEdge, false, true, boardRadius)
+    )
+  );
+}
+
+// Check if a board still offers a legal placement for at least one of the given tile types
+// A full board or an empty list counts as a continuation: the game ends instead of locking up
+export function hasContinuation(
+  board: Map<string, PlacedTile>,
+  tileTypes: TileType[],
+  players: Player[],
+  teams: Team[],
+  boardRadius: number,
+  supermoveEnabled: boolean,
+  oppositeSidesVictory: boolean = false
+): boolean {
+  if (tileTypes.length === 0 || isBoardFull(board, boardRadius)) {
+    return true;
   }
   
-  return true;
+  return Array.from(new Set(tileTypes)).some((tileType) =>
+    canTileBePlacedAnywhere(board, tileType, players, teams, boardRadius, supermoveEnabled, oppositeSidesVictory)
+  );
 }
 
 // Check if placing a tile at a position with a rotation is legal
+// continuationTileTypes enables the no-self-stalemate rule: the placement must leave a legal
+// placement for at least one of those tile types (the ones still in the bag)
+// oppositeSidesVictory must match the game's win condition, which decides both the forced
+// winning moves and the routes a placement may not block
 export function isLegalMove(
   board: Map<string, PlacedTile>,
   tile: PlacedTile,
   players: Player[],
   teams: Team[],
   boardRadius: number,
-  supermoveEnabled: boolean
+  supermoveEnabled: boolean,
+  continuationTileTypes?: TileType[],
+  oppositeSidesVictory: boolean = false
 ): boolean {
   // A move is illegal if:
   // 1. The position is already occupied
@@ -343,23 +385,31 @@ export function isLegalMove(
   
   // 2. It would cause a victory - this is actually LEGAL and must be played
   // So we check this first
-  if (wouldCauseVictory(board, tile, players, teams, boardRadius)) {
+  if (wouldCauseVictory(board, tile, players, teams, boardRadius, oppositeSidesVictory)) {
     return true; // Victory moves are always legal
   }
   
   // 3. With supermove enabled, all non-blocking moves are legal
   // (blocking is allowed, player can use supermove to unblock)
-  if (supermoveEnabled) {
+  if (supermoveEnabled && !continuationTileTypes) {
     return true;
   }
   
-  // 4. It would block all paths for any player/team (standard rules)
   // Create temporary board with the new tile
   const testBoard = new Map(board);
   testBoard.set(posKey, tile);
   
-  // Check if all players still have viable paths
-  return allPlayersHaveViablePath(testBoard, players, teams, boardRadius);
+  // 4. It would block all paths for any player/team (standard rules)
+  if (!supermoveEnabled && !allPlayersHaveViablePath(testBoard, players, teams, boardRadius, oppositeSidesVictory)) {
+    return false;
+  }
+  
+  // 5. With the no-self-stalemate rule, it would leave the next player without a legal move
+  if (continuationTileTypes) {
+    return hasContinuation(testBoard, continuationTileTypes, players, teams, boardRadius, supermoveEnabled, oppositeSidesVictory);
+  }
+  
+  return true;
 }
 
 // Get list of players/teams that would be blocked by placing a tile
@@ -369,7 +419,8 @@ export function getBlockedPlayers(
   tile: PlacedTile,
   players: Player[],
   teams: Team[],
-  boardRadius: number
+  boardRadius: number,
+  oppositeSidesVictory: boolean = false
 ): string[] {
   const blockedPlayerIds: string[] = [];
   
@@ -380,7 +431,7 @@ export function getBlockedPlayers(
   }
   
   // If this causes a victory, no one is blocked (move is forced)
-  if (wouldCauseVictory(board, tile, players, teams, boardRadius)) {
+  if (wouldCauseVictory(board, tile, players, teams, boardRadius, oppositeSidesVictory)) {
     return blockedPlayerIds;
   }
   
@@ -388,36 +439,109 @@ export function getBlockedPlayers(
   const testBoard = new Map(board);
   testBoard.set(posKey, tile);
   
-  // Check which players/teams would be blocked
-  if (teams.length > 0) {
-    // Team games - check each team
-    for (const team of teams) {
-      const player1 = players.find((p) => p.id === team.player1Id);
-      const player2 = players.find((p) => p.id === team.player2Id);
-      
-      if (!player1 || !player2) continue;
+  // A side is blocked when none of its routes survive; a blocked team lists both players
+  for (const routes of getSideRoutes(players, teams, oppositeSidesVictory)) {
+    const open = routes.some(({ player, targetEdge }) =>
+      hasViablePath(testBoard, player, targetEdge, false, true, boardRadius)
+    );
+    if (!open) {
+      blockedPlayerIds.push(...routes.map(({ player }) => player.id));
+    }
+  }
+  
+  return blockedPlayerIds;
+}
+
+// Key for a (position, rotation) entry in a legality map
+export function legalityKey(position: HexPosition, rotation: Rotation): string {
+  return `${positionToKey(position)}:${rotation}`;
+}
+
+// Evaluate legality of every empty position and rotation for a tile in one pass
+// Returns a map from legalityKey(position, rotation) to whether the move is legal
+// Agrees with isLegalMove for every entry, but computes each player's unconstrained
+// path once up front: a placement off that path cannot block the player, so the
+// per-candidate BFS is only re-run for players whose path crosses the candidate hex
+export function computeLegalityMap(
+  board: Map<string, PlacedTile>,
+  tileType: TileType,
+  players: Player[],
+  teams: Team[],
+  boardRadius: number,
+  supermoveEnabled: boolean,
+  oppositeSidesVictory: boolean = false
+): Map<string, boolean> {
+  const legality = new Map<string, boolean>();
+  const emptyPositions = getAllBoardPositions(boardRadius).filter(
+    (pos) => !board.has(positionToKey(pos))
+  );
+  
+  // With supermove enabled every empty position is legal (see isLegalMove)
+  if (supermoveEnabled) {
+    for (const position of emptyPositions) {
+      for (let rotation = 0; rotation < 6; rotation++) {
+        legality.set(legalityKey(position, rotation as Rotation), true);
+      }
+    }
+    return legality;
+  }
+  
+  // Each side must keep at least one of its routes open (see getSideRoutes)
+  // Each route remembers the hexes on its current path, or null if already blocked
+  const requirements = getSideRoutes(players, teams, oppositeSidesVictory).map((routes) =>
+    routes.map(({ player, targetEdge }) => {
+      const result = hasViablePath(board, player, targetEdge, true, true, boardRadius) as PathFindingResult;
+      return {
+        player,
+        targetEdge,
+        pathKeys: result.hasPath
+          ? new Set((result.pathToTarget || []).map(positionToKey))
+          : null,
+      };
+    })
+  );
+  
+  const uniqueRotations = getUniqueRotations(tileType);
+  
+  for (const position of emptyPositions) {
+    const posKey = positionToKey(position);
+    
+    for (const rot of uniqueRotations) {
+      const tile: PlacedTile = { type: tileType, rotation: rot, position };
+      let testBoard: Map<string, PlacedTile> | null = null;
       
-      // Check if team has a viable path (either player can complete it)
-      const path1 = hasViablePath(testBoard, player1, player2.edgePosition, false, true, boardRadius);
-      const path2 = hasViablePath(testBoard, player2, player1.edgePosition, false, true, boardRadius);
+      const allSatisfied = requirements.every((routes) =>
+        routes.some((route) => {
+          if (route.pathKeys === null) {
+            return false;
+          }
+          if (!route.pathKeys.has(posKey)) {
+            return true; // Baseline path avoids this hex, so it survives the placement
+          }
+          if (testBoard === null) {
+            testBoard = new Map(board);
+            testBoard.set(posKey, tile);
+          }
+          return hasViablePath(testBoard, route.player, route.targetEdge, false, true, boardRadius) as boolean;
+        })
+      );
       
-      if (!path1 && !path2) {
-        // Team is blocked - add both players
-        blockedPlayerIds.push(player1.id);
-        blockedPlayerIds.push(player2.id);
-      }
+      // Victory moves are always legal, so only check for victory when blocking
+      const isLegal = allSatisfied || wouldCauseVictory(board, tile, players, teams, boardRadius, oppositeSidesVictory);
+      legality.set(legalityKey(position, rot), isLegal);
     }
-  } else {
-    // Individual games - check each player
-    for (const player of players) {
-      const targetEdge = getOppositeEdge(player.edgePosition);
-      if (!hasViablePath(testBoard, player, targetEdge, false, true, boardRadius)) {
-        blockedPlayerIds.push(player.id);
+    
+    // Rotations that repeat an earlier flow pattern share its verdict
+    for (let rotation = 0; rotation < 6; rotation++) {
+      const rot = rotation as Rotation;
+      const equivalent = getEquivalentRotation(tileType, rot);
+      if (equivalent !== rot) {
+        legality.set(legalityKey(position, rot), legality.get(legalityKey(position, equivalent))!);
       }
     }
   }
   
-  return blockedPlayerIds;
+  return legality;
 }
 
 // Find all legal positions for a given tile type and rotation
@@ -428,7 +552,9 @@ export function findLegalMoves(
   players: Player[],
   teams: Team[],
   boardRadius: number,
-  supermoveEnabled: boolean
+  supermoveEnabled: boolean,
+  continuationTileTypes?: TileType[],
+  oppositeSidesVictory: boolean = false
 ): HexPosition[] {
   const legalPositions: HexPosition[] = [];
   
@@ -442,7 +568,7 @@ export function findLegalMoves(
       position,
     };
     
-    if (isLegalMove(board, tile, players, teams, boardRadius, supermoveEnabled)) {
+    if (isLegalMove(board, tile, players, teams, boardRadius, supermoveEnabled, continuationTileTypes, oppositeSidesVictory)) {
       legalPositions.push(position);
     }
   }
@@ -450,6 +576,88 @@ export function findLegalMoves(
   return legalPositions;
 }
 
+// Find every legal placement of a tile type, over all positions and distinct rotations
+// Rotations that repeat an earlier rotation's flow pattern are skipped as redundant
+export function findAllLegalMoves(
+  board: Map<string, PlacedTile>,
+  tileType: TileType,
+  players: Player[],
+  teams: Team[],
+  boardRadius: number,
+  supermoveEnabled: boolean,
+  continuationTileTypes?: TileType[],
+  oppositeSidesVictory: boolean = false
+): Array<{ position: HexPosition; rotation: Rotation }> {
+  const moves: Array<{ position: HexPosition; rotation: Rotation }> = [];
+  
+  for (const rotation of getUniqueRotations(tileType)) {
+    const positions = findLegalMoves(board, tileType, rotation, players, teams, boardRadius, supermoveEnabled, continuationTileTypes, oppositeSidesVictory);
+    for (const position of positions) {
+      moves.push({ position, rotation });
+    }
+  }
+  
+  return moves;
+}
+
+// Find all rotations of a tile that can be legally placed at a given position
+export function findLegalRotations(
+  board: Map<string, PlacedTile>,
+  tileType: TileType,
+  position: HexPosition,
+  players: Player[],
+  teams: Team[],
+  boardRadius: number,
+  supermoveEnabled: boolean,
+  continuationTileTypes?: TileType[],
+  oppositeSidesVictory: boolean = false
+): Rotation[] {
+  const legalRotations: Rotation[] = [];
+  
+  for (let rotation = 0; rotation < 6; rotation++) {
+    const tile: PlacedTile = {
+      type: tileType,
+      rotation: rotation as Rotation,
+      position,
+    };
+    
+    if (isLegalMove(board, tile, players, teams, boardRadius, supermoveEnabled, continuationTileTypes, oppositeSidesVictory)) {
+      legalRotations.push(rotation as Rotation);
+    }
+  }
+  
+  return legalRotations;
+}
+
+// Pick the rotation to show when a tile is moved onto a position
+// Keeps the current rotation if it is legal there, otherwise snaps to the first legal rotation
+// Returns the current rotation unchanged if no rotation is legal at this position
+export function findFirstLegalRotation(
+  board: Map<string, PlacedTile>,
+  tileType: TileType,
+  position: HexPosition,
+  currentRotation: Rotation,
+  players: Player[],
+  teams: Team[],
+  boardRadius: number,
+  supermoveEnabled: boolean,
+  continuationTileTypes?: TileType[],
+  oppositeSidesVictory: boolean = false
+): Rotation {
+  const currentTile: PlacedTile = {
+    type: tileType,
+    rotation: currentRotation,
+    position,
+  };
+  
+  if (isLegalMove(board, currentTile, players, teams, boardRadius, supermoveEnabled, continuationTileTypes, oppositeSidesVictory)) {
+    return currentRotation;
+  }
+  
+  const legalRotations = findLegalRotations(board, tileType, position, players, teams, boardRadius, supermoveEnabled, continuationTileTypes, oppositeSidesVictory);
+  return legalRotations.length > 0 ? legalRotations[0] : currentRotation;
+}
+
 // Check if a tile can be placed legally anywhere on the board
 // (used for constraint victory detection)
 export function canTileBePlacedAnywhere(
@@ -458,11 +666,12 @@ export function canTileBePlacedAnywhere(
   players: Player[],
   teams: Team[],
   boardRadius: number,
-  supermoveEnabled: boolean
+  supermoveEnabled: boolean,
+  oppositeSidesVictory: boolean = false
 ): boolean {
-  // Try all rotations
-  for (let rotation = 0; rotation < 6; rotation++) {
-    const legalMoves = findLegalMoves(board, tileType, rotation as Rotation, players, teams, boardRadius, supermoveEnabled);
+  // Try each distinct rotation
+  for (const rotation of getUniqueRotations(tileType)) {
+    const legalMoves = findLegalMoves(board, tileType, rotation, players, teams, boardRadius, supermoveEnabled, undefined, oppositeSidesVictory);
     if (legalMoves.length > 0) {
       return true;
     }
@@ -495,67 +704,169 @@ export function getDebugPathInfo(
   board: Map<string, PlacedTile>,
   players: Player[],
   teams: Team[],
-  boardRadius: number
+  boardRadius: number,
+  oppositeSidesVictory: boolean = false
 ): PlayerPathDebugInfo[] {
-  const debugInfo: PlayerPathDebugInfo[] = [];
+  // One entry per route the blocking check considers (see getSideRoutes)
+  return getSideRoutes(players, teams, oppositeSidesVictory).flat().map(({ player, targetEdge }) => {
+    const result = hasViablePath(board, player, targetEdge, true, true, boardRadius) as PathFindingResult;
+    return {
+      playerId: player.id,
+      playerColor: player.color,
+      hasPath: result.hasPath,
+      visitedPositions: Array.from(result.visitedPositions || []),
+      pathToTarget: result.pathToTarget || [],
+      visitedEdges: result.visitedEdges || [],
+      pathEdges: result.pathEdges || [],
+      startEdge: player.edgePosition,
+      targetEdge,
+    };
+  });
+}
+
+// A player's potential path to their goal edge, as found by the blocking check
+export interface PlayerPathAssignment {
+  playerId: string;
+  path: HexPosition[]; // Hexes from the player's edge to the goal edge; empty hexes are wildcards
+}
+
+// Why a placement is legal or illegal, for debugging reported scenarios
+// Paths are found per player on the board after the placement; blockedPlayerIds lists
+// every player left without a path (both members of a blocked team)
+export interface LegalityExplanation {
+  legal: boolean;
+  reason: 'occupied' | 'victory' | 'supermove' | 'paths-open' | 'blocked' | 'no-continuation';
+  paths: PlayerPathAssignment[];
+  blockedPlayerIds: string[];
+}
+
+// Explain the isLegalMove verdict for a placement, keeping the paths the check found
+export function explainLegality(
+  board: Map<string, PlacedTile>,
+  tile: PlacedTile,
+  players: Player[],
+  teams: Team[],
+  boardRadius: number,
+  supermoveEnabled: boolean,
+  continuationTileTypes?: TileType[],
+  oppositeSidesVictory: boolean = false
+): LegalityExplanation {
+  const posKey = positionToKey(tile.position);
+  if (board.has(posKey)) {
+    return { legal: false, reason: 'occupied', paths: [], blockedPlayerIds: [] };
+  }
   
-  if (teams.length > 0) {
-    // Team games - check paths for each player in teams
-    for (const team of teams) {
-      const player1 = players.find((p) => p.id === team.player1Id);
-      const player2 = players.find((p) => p.id === team.player2Id);
-      
-      if (player1 && player2) {
-        // Player 1 trying to reach player 2's edge
-        const result1 = hasViablePath(board, player1, player2.edgePosition, true, true, boardRadius) as PathFindingResult;
-        debugInfo.push({
-          playerId: player1.id,
-          playerColor: player1.color,
-          hasPath: result1.hasPath,
-          visitedPositions: Array.from(result1.visitedPositions || []),
-          pathToTarget: result1.pathToTarget || [],
-          visitedEdges: result1.visitedEdges || [],
-          pathEdges: result1.pathEdges || [],
-          startEdge: player1.edgePosition,
-          targetEdge: player2.edgePosition,
-        });
-        
-        // Player 2 trying to reach player 1's edge
-        const result2 = hasViablePath(board, player2, player1.edgePosition, true, true, boardRadius) as PathFindingResult;
-        debugInfo.push({
-          playerId: player2.id,
-          playerColor: player2.color,
-          hasPath: result2.hasPath,
-          visitedPositions: Array.from(result2.visitedPositions || []),
-          pathToTarget: result2.pathToTarget || [],
-          visitedEdges: result2.visitedEdges || [],
-          pathEdges: result2.pathEdges || [],
-          startEdge: player2.edgePosition,
-          targetEdge: player1.edgePosition,
-        });
-      }
+  const testBoard = new Map(board);
+  testBoard.set(posKey, tile);
+  
+  const debugInfo = getDebugPathInfo(testBoard, players, teams, boardRadius, oppositeSidesVictory);
+  const paths = debugInfo
+    .filter((info) => info.hasPath)
+    .map((info) => ({ playerId: info.playerId, path: info.pathToTarget }));
+  const withoutPath = new Set(debugInfo.filter((info) => !info.hasPath).map((info) => info.playerId));
+  
+  // A team is only blocked when neither member can reach the other's edge
+  const blockedPlayerIds = teams.length > 0
+    ? teams
+        .filter((team) => withoutPath.has(team.player1Id) && withoutPath.has(team.player2Id))
+        .flatMap((team) => [team.player1Id, team.player2Id])
+    : players.filter((p) => withoutPath.has(p.id)).map((p) => p.id);
+  
+  if (wouldCauseVictory(board, tile, players, teams, boardRadius, oppositeSidesVictory)) {
+    return { legal: true, reason: 'victory', paths, blockedPlayerIds };
+  }
+  
+  if (!supermoveEnabled && blockedPlayerIds.length > 0) {
+    return { legal: false, reason: 'blocked', paths, blockedPlayerIds };
+  }
+  
+  if (
+    continuationTileTypes &&
+    !hasContinuation(testBoard, continuationTileTypes, players, teams, boardRadius, supermoveEnabled, oppositeSidesVictory)
+  ) {
+    return { legal: false, reason: 'no-continuation', paths, blockedPlayerIds };
+  }
+  
+  return { legal: true, reason: supermoveEnabled ? 'supermove' : 'paths-open', paths, blockedPlayerIds };
+}
+
+// The part of the board a blocked player can still reach from their edge
+export interface BlockedPlayerRegion {
+  playerId: string;
+  region: HexPosition[]; // Hexes reachable from the player's edge, none of which touch their goal edge
+}
+
+// Explain why a placement is illegal: for each blocked player, the region their edge is sealed into
+// Returns null when the placement is legal or illegal only because the hex is occupied
+export function explainIllegal(
+  board: Map<string, PlacedTile>,
+  tile: PlacedTile,
+  players: Player[],
+  teams: Team[],
+  boardRadius: number,
+  supermoveEnabled: boolean,
+  oppositeSidesVictory: boolean = false
+): BlockedPlayerRegion[] | null {
+  const explanation = explainLegality(board, tile, players, teams, boardRadius, supermoveEnabled, undefined, oppositeSidesVictory);
+  if (explanation.reason !== 'blocked') {
+    return null;
+  }
+  
+  const testBoard = new Map(board);
+  testBoard.set(positionToKey(tile.position), tile);
+  const debugInfo = getDebugPathInfo(testBoard, players, teams, boardRadius, oppositeSidesVictory);
+  
+  return explanation.blockedPlayerIds.map((playerId) => {
+    const info = debugInfo.find((i) => i.playerId === playerId)!;
+    const region = new Map<string, HexPosition>();
+    for (const position of info.visitedPositions) {
+      region.set(positionToKey(position), position);
     }
-  } else {
-    // Individual games - each player to opposite edge
-    for (const player of players) {
-      const targetEdge = getOppositeEdge(player.edgePosition);
-      const result = hasViablePath(board, player, targetEdge, true, true, boardRadius) as PathFindingResult;
-      
-      debugInfo.push({
-        playerId: player.id,
-        playerColor: player.color,
-        hasPath: result.hasPath,
-        visitedPositions: Array.from(result.visitedPositions || []),
-        pathToTarget: result.pathToTarget || [],
-        visitedEdges: result.visitedEdges || [],
-        pathEdges: result.pathEdges || [],
-        startEdge: player.edgePosition,
-        targetEdge,
-      });
+    return { playerId, region: Array.from(region.values()) };
+  });
+}
+
+// Key for a single flow segment (one tile connection), independent of direction order
+export function segmentKey(position: HexPosition, dir1: Direction, dir2: Direction): string {
+  return `${position.row},${position.col}:${Math.min(dir1, dir2)}-${Math.max(dir1, dir2)}`;
+}
+
+// Get the placed-tile flow segments that lie on each player's shortest potential winning path
+// Empty hexes on the path are skipped since there is no flow to draw there yet
+export function getCriticalPathSegments(
+  board: Map<string, PlacedTile>,
+  players: Player[],
+  teams: Team[],
+  boardRadius: number,
+  oppositeSidesVictory: boolean = false
+): Set<string> {
+  const segments = new Set<string>();
+  
+  for (const info of getDebugPathInfo(board, players, teams, boardRadius, oppositeSidesVictory)) {
+    // Consecutive path edges on the same occupied hex are a traversal of that tile's flow
+    for (let i = 1; i < info.pathEdges.length; i++) {
+      const from = info.pathEdges[i - 1];
+      const to = info.pathEdges[i];
+      if (from.position.row !== to.position.row || from.position.col !== to.position.col) {
+        continue;
+      }
+      if (board.has(positionToKey(to.position))) {
+        segments.add(segmentKey(to.position, from.direction, to.direction));
+      }
     }
   }
   
-  return debugInfo;
+  return segments;
+}
+
+// Check if a tile's flow segment is on a critical (shortest winning) path
+export function isSegmentOnCriticalPath(
+  criticalSegments: Set<string>,
+  position: HexPosition,
+  dir1: Direction,
+  dir2: Direction
+): boolean {
+  return criticalSegments.has(segmentKey(position, dir1, dir2));
 }
 
 // Check if a specific player is currently blocked (has no viable path)
@@ -564,29 +875,18 @@ export function isPlayerBlocked(
   player: Player,
   players: Player[],
   teams: Team[],
-  boardRadius: number
+  boardRadius: number,
+  oppositeSidesVictory: boolean = false
 ): boolean {
-  // For team games, check if the player's team is blocked
-  if (teams.length > 0) {
-    // Find the team this player is on
-    const team = teams.find(t => t.player1Id === player.id || t.player2Id === player.id);
-    if (!team) return false;
-    
-    const player1 = players.find(p => p.id === team.player1Id);
-    const player2 = players.find(p => p.id === team.player2Id);
-    
-    if (!player1 || !player2) return false;
-    
-    // Team is blocked if neither player can reach the other
-    const path1 = hasViablePath(board, player1, player2.edgePosition, false, true, boardRadius);
-    const path2 = hasViablePath(board, player2, player1.edgePosition, false, true, boardRadius);
-    
-    return !path1 && !path2;
-  } else {
-    // Individual game - check if player can reach opposite edge
-    const targetEdge = getOppositeEdge(player.edgePosition);
-    return !hasViablePath(board, player, targetEdge, false, true, boardRadius);
-  }
+  // In team games the player is blocked only if their partner cannot connect either
+  const routes = getSideRoutes(players, teams, oppositeSidesVictory).find((side) =>
+    side.some((route) => route.player.id === player.id)
+  );
+  if (!routes) return false;
+  
+  return !routes.some(({ player: routePlayer, targetEdge }) =>
+    hasViablePath(board, routePlayer, targetEdge, false, true, boardRadius)
+  );
 }
 
 // Check if replacing a tile at a position would unblock a specific player
@@ -597,10 +897,11 @@ export function wouldReplacementUnblock(
   player: Player,
   players: Player[],
   teams: Team[],
-  boardRadius: number
+  boardRadius: number,
+  oppositeSidesVictory: boolean = false
 ): boolean {
   // First, check if player is currently blocked
-  if (!isPlayerBlocked(board, player, players, teams, boardRadius)) {
+  if (!isPlayerBlocked(board, player, players, teams, boardRadius, oppositeSidesVictory)) {
     return false; // Player is not blocked, replacement not needed
   }
   
@@ -612,7 +913,7 @@ export function wouldReplacementUnblock(
   testBoard.set(posKey, newTile);
   
   // Check if the player is no longer blocked after replacement
-  return !isPlayerBlocked(testBoard, player, players, teams, boardRadius);
+  return !isPlayerBlocked(testBoard, player, players, teams, boardRadius, oppositeSidesVictory);
 }
 
 // Check if replacing a tile would unblock ANY player
@@ -623,11 +924,12 @@ export function wouldReplacementUnblockAnyPlayer(
   newTile: PlacedTile,
   players: Player[],
   teams: Team[],
-  boardRadius: number
+  boardRadius: number,
+  oppositeSidesVictory: boolean = false
 ): boolean {
   // Check if this replacement would unblock any player
   for (const player of players) {
-    if (wouldReplacementUnblock(board, replacementPosition, newTile, player, players, teams, boardRadius)) {
+    if (wouldReplacementUnblock(board, replacementPosition, newTile, player, players, teams, boardRadius, oppositeSidesVictory)) {
       return true;
     }
   }
@@ -648,7 +950,8 @@ export function isValidReplacementMove(
   players: Player[],
   teams: Team[],
   boardRadius: number,
-  supermoveAnyPlayer: boolean = false
+  supermoveAnyPlayer: boolean = false,
+  oppositeSidesVictory: boolean = false
 ): boolean {
   const posKey = positionToKey(replacementPosition);
   
@@ -667,14 +970,63 @@ export function isValidReplacementMove(
   if (supermoveAnyPlayer) {
     // With supermoveAnyPlayer enabled, check if replacement unblocks ANY player
     // This allows any player to make a supermove as long as they unblock someone
-    return wouldReplacementUnblockAnyPlayer(board, replacementPosition, newTile, players, teams, boardRadius);
+    return wouldReplacementUnblockAnyPlayer(board, replacementPosition, newTile, players, teams, boardRadius, oppositeSidesVictory);
   } else {
     // Original behavior: current player must be blocked and replacement must unblock them
-    if (!isPlayerBlocked(board, currentPlayer, players, teams, boardRadius)) {
+    if (!isPlayerBlocked(board, currentPlayer, players, teams, boardRadius, oppositeSidesVictory)) {
       return false;
     }
     
     // Check if this replacement would unblock the player
-    return wouldReplacementUnblock(board, replacementPosition, newTile, currentPlayer, players, teams, boardRadius);
+    return wouldReplacementUnblock(board, replacementPosition, newTile, currentPlayer, players, teams, boardRadius, oppositeSidesVictory);
   }
 }
+
+// Result of auditing a move history
+export type HistoryValidationResult =
+  | { valid: true }
+  | { valid: false; moveIndex: number; reason: string };
+
+// Replay a move history from an empty board and check that every move was legal when it was made
+// Moves onto an occupied position are treated as supermove replacements
+// Returns the index of the first illegal move, e.g. one accepted due to a legality bug
+// or introduced by an imported transcript
+export function validateMoveHistory(
+  moves: Move[],
+  players: Player[],
+  teams: Team[],
+  boardRadius: number,
+  supermoveEnabled: boolean,
+  supermoveAnyPlayer: boolean = false,
+  oppositeSidesVictory: boolean = false
+): HistoryValidationResult {
+  const board = new Map<string, PlacedTile>();
+
+  for (let i = 0; i < moves.length; i++) {
+    const { playerId, tile } = moves[i];
+    const player = players.find(p => p.id === playerId);
+    if (!player) {
+      return { valid: false, moveIndex: i, reason: `Unknown player ${playerId}` };
+    }
+
+    if (!isValidPosition(tile.position, boardRadius)) {
+      return { valid: false, moveIndex: i, reason: `Position ${positionToKey(tile.position)} is off the board` };
+    }
+
+    const posKey = positionToKey(tile.position);
+    if (board.has(posKey)) {
+      if (!supermoveEnabled) {
+        return { valid: false, moveIndex: i, reason: `Position ${posKey} is already occupied` };
+      }
+      if (!isValidReplacementMove(board, tile.position, tile.type, tile.rotation, player, players, teams, boardRadius, supermoveAnyPlayer, oppositeSidesVictory)) {
+        return { valid: false, moveIndex: i, reason: `Replacement at ${posKey} does not unblock a blocked player` };
+      }
+    } else if (!isLegalMove(board, tile, players, teams, boardRadius, supermoveEnabled, undefined, oppositeSidesVictory)) {
+      return { valid: false, moveIndex: i, reason: `Placement at ${posKey} blocks a player's last path` };
+    }
+
+    board.set(posKey, tile);
+  }
+
+  return { valid: true };
+}
@@ -1,11 +1,11 @@
 // Game notation for recording and displaying Flows/Quortex games
 // Based on the notation system defined in NOTATION.md
 
-import { HexPosition, TileType, Rotation } from './types';
-import { Move } from '../redux/types';
+import { HexPosition, TileType, Rotation, PlacedTile } from './types';
+import { Move, WinType } from '../redux/types';
 
 // Orientation names for notation
-const ORIENTATION_NAMES = ['N', 'NE', 'SE', 'S', 'SW', 'NW'] as const;
+export const ORIENTATION_NAMES = ['N', 'NE', 'SE', 'S', 'SW', 'NW'] as const;
 type OrientationName = typeof ORIENTATION_NAMES[number];
 
 // Tile type names for notation
@@ -98,7 +98,7 @@ export function tileTypeToNotation(type: TileType): TileTypeName {
  * Example: P1A2T0N
  */
 export function formatMoveNotation(
-  move: Move,
+  move: Pick<Move, 'tile'>,
   playerNumber: number,
   playerEdge: number,
   boardRadius: number = 3
@@ -110,6 +110,165 @@ export function formatMoveNotation(
   return `P${playerNumber}${position}${tileType}${orientation}`;
 }
 
+/**
+ * Convert player-relative notation (e.g. "D4") back to internal coordinates
+ * Reverses positionToNotation; returns null if the square is off the board
+ */
+export function notationToPosition(
+  notation: string,
+  playerEdge: number,
+  boardRadius: number = 3
+): HexPosition | null {
+  const match = /^([A-Z])(\d+)$/.exec(notation);
+  if (!match) {
+    return null;
+  }
+  
+  // Undo the row letter and right-to-left column numbering
+  const row = match[1].charCodeAt(0) - 'A'.charCodeAt(0) - boardRadius;
+  if (row > boardRadius) {
+    return null;
+  }
+  const colStart = Math.max(-boardRadius, -boardRadius - row);
+  const colEnd = Math.min(boardRadius, boardRadius - row);
+  const col = colEnd - parseInt(match[2], 10) + 1;
+  if (col < colStart || col > colEnd) {
+    return null;
+  }
+  
+  return playerViewToPosition({ row, col }, playerEdge);
+}
+
+/**
+ * Convert coordinates in a player's view of the board back to internal coordinates
+ * Reverses the rotation in positionToNotation; positions off the board are converted too
+ */
+export function playerViewToPosition(viewPosition: HexPosition, playerEdge: number): HexPosition {
+  let { row, col } = viewPosition;
+  
+  // Rotate 60 degrees counter-clockwise once per edge to undo the player's view
+  for (let i = 0; i < playerEdge; i++) {
+    const newRow = row + col;
+    const newCol = -row;
+    row = newRow;
+    col = newCol;
+  }
+  
+  return { row, col };
+}
+
+/**
+ * A row letter or column number drawn beside the board
+ * The label belongs between an edge hex (inside) and the off-board hex beyond it (outside)
+ */
+export interface CoordinateLabel {
+  text: string;
+  inside: HexPosition;
+  outside: HexPosition;
+}
+
+/**
+ * Get the coordinate labels for a player's view of the board
+ * Row letters go at both ends of each row. Columns run along one diagonal below
+ * the middle row and the other above it, so numbers go along the bottom edges
+ * for the lower half and the top edges for the upper half.
+ */
+export function getCoordinateLabels(playerEdge: number, boardRadius: number = 3): CoordinateLabel[] {
+  const labels: CoordinateLabel[] = [];
+  const toBoard = (row: number, col: number) => playerViewToPosition({ row, col }, playerEdge);
+  
+  for (let row = -boardRadius; row <= boardRadius; row++) {
+    const text = String.fromCharCode('A'.charCodeAt(0) + row + boardRadius);
+    const colStart = Math.max(-boardRadius, -boardRadius - row);
+    const colEnd = Math.min(boardRadius, boardRadius - row);
+    labels.push({ text, inside: toBoard(row, colStart), outside: toBoard(row, colStart - 1) });
+    labels.push({ text, inside: toBoard(row, colEnd), outside: toBoard(row, colEnd + 1) });
+  }
+  
+  for (let line = -boardRadius; line <= boardRadius; line++) {
+    const text = String(boardRadius - line + 1);
+    
+    // Lower half: column number is constant along col, entering from the nearest row
+    const bottomRow = Math.max(-boardRadius, -boardRadius - line);
+    labels.push({ text, inside: toBoard(bottomRow, line), outside: toBoard(bottomRow - 1, line) });
+    
+    // Upper half: column number is constant along row + col, entering from the farthest row
+    const topRow = Math.min(boardRadius, line + boardRadius);
+    labels.push({ text, inside: toBoard(topRow, line - topRow), outside: toBoard(topRow + 1, line - topRow - 1) });
+  }
+  
+  return labels;
+}
+
+/**
+ * Convert a player-relative orientation name back to a rotation
+ * Reverses rotationToOrientation; returns null for an unknown orientation
+ */
+export function orientationToRotation(orientation: string, playerEdge: number): Rotation | null {
+  const adjustedRotation = ORIENTATION_NAMES.indexOf(orientation as OrientationName);
+  if (adjustedRotation === -1) {
+    return null;
+  }
+  
+  return ((adjustedRotation + playerEdge + 3) % 6) as Rotation;
+}
+
+/**
+ * Result of parsing a move written in standard notation
+ */
+export type ParsedMoveNotation =
+  | { valid: true; playerId: string; tile: PlacedTile }
+  | { valid: false; reason: string };
+
+/**
+ * Parse a move in standard notation (e.g. "P1A2T0N") back into a player and placed tile
+ * Reverses formatMoveNotation: player numbers index into the same players list
+ * passed to formatMoveHistory, and the square and orientation are read from that
+ * player's view of the board.
+ */
+export function parseMoveNotation(
+  notation: string,
+  players: Array<{ id: string; edgePosition: number }>,
+  boardRadius: number = 3
+): ParsedMoveNotation {
+  const match = /^P(\d+)([A-Z]\d+)(T\d+)([A-Z]*)$/.exec(notation.trim());
+  if (!match) {
+    return {
+      valid: false,
+      reason: `Malformed move "${notation}", expected P<player><row><column>T<type><orientation>`,
+    };
+  }
+  
+  const [, playerText, square, tileText, orientation] = match;
+  
+  const playerNumber = parseInt(playerText, 10);
+  if (playerNumber < 1 || playerNumber > players.length) {
+    return { valid: false, reason: `Player ${playerNumber} is out of range 1-${players.length}` };
+  }
+  const player = players[playerNumber - 1];
+  
+  const position = notationToPosition(square, player.edgePosition, boardRadius);
+  if (!position) {
+    return { valid: false, reason: `Square ${square} is off the board` };
+  }
+  
+  const tileType = TILE_TYPE_NAMES.indexOf(tileText as TileTypeName);
+  if (tileType === -1) {
+    return { valid: false, reason: `Unknown tile type ${tileText}` };
+  }
+  
+  const rotation = orientationToRotation(orientation, player.edgePosition);
+  if (rotation === null) {
+    return { valid: false, reason: `Unknown orientation "${orientation}"` };
+  }
+  
+  return {
+    valid: true,
+    playerId: player.id,
+    tile: { type: tileType as TileType, rotation, position },
+  };
+}
+
 /**
  * Get the player number (1-based) from player ID
  */
@@ -122,7 +281,7 @@ export function getPlayerNumber(playerId: string, playerIds: string[]): number {
  * Format move history as a list of notation strings
  */
 export function formatMoveHistory(
-  moves: Move[],
+  moves: Array<Pick<Move, 'playerId' | 'tile'>>,
   players: Array<{ id: string; edgePosition: number }>,
   boardRadius: number = 3
 ): string[] {
@@ -160,3 +319,173 @@ export function formatGameRecord(
   
   return record;
 }
+
+/**
+ * Everything a transcript records about a game
+ * GameState satisfies this, so a live or finished game can be passed directly
+ */
+export interface GameTranscript {
+  players: Array<{ id: string; edgePosition: number }>;
+  boardRadius: number;
+  supermove: boolean;
+  singleSupermove: boolean;
+  supermoveAnyPlayer: boolean;
+  oppositeSidesVictory: boolean;
+  winners: string[];
+  winType: WinType | null;
+  moveHistory: Array<Pick<Move, 'playerId' | 'tile'>>;
+}
+
+const WIN_TYPES: WinType[] = ['flow', 'constraint', 'tie', 'draw', 'resignation'];
+
+// Placeholder for a player who did not move in a round (e.g. after resigning)
+const EMPTY_TRANSCRIPT_CELL = '--';
+
+/**
+ * Format a game as a shareable transcript
+ * A header of [Tag "value"] lines records the seating, rules and result, followed
+ * by one numbered line per round with a column for each player. An unfinished
+ * game has the result "*".
+ */
+export function formatTranscript(game: GameTranscript): string {
+  const { players, boardRadius } = game;
+  const playerIds = players.map(p => p.id);
+  const playerLabel = (id: string) => `P${getPlayerNumber(id, playerIds)}`;
+  
+  const result = game.winType === null
+    ? '*'
+    : [...game.winners.map(playerLabel), game.winType].join(' ');
+  
+  const header = [
+    `[Edges "${players.map(p => p.edgePosition).join(' ')}"]`,
+    `[BoardRadius "${boardRadius}"]`,
+    `[Supermove "${game.supermove}"]`,
+    `[SingleSupermove "${game.singleSupermove}"]`,
+    `[SupermoveAnyPlayer "${game.supermoveAnyPlayer}"]`,
+    `[OppositeSidesVictory "${game.oppositeSidesVictory}"]`,
+    `[Result "${result}"]`,
+  ];
+  
+  // Start a new round whenever a player moves at or before the last filled column,
+  // which keeps supermove follow-ups and skipped turns in the right column
+  const notations = formatMoveHistory(game.moveHistory, players, boardRadius);
+  const rounds: string[][] = [];
+  let lastColumn = players.length;
+  game.moveHistory.forEach((move, index) => {
+    const column = playerIds.indexOf(move.playerId);
+    if (column <= lastColumn) {
+      rounds.push([]);
+    }
+    const round = rounds[rounds.length - 1];
+    while (round.length < column) {
+      round.push(EMPTY_TRANSCRIPT_CELL);
+    }
+    round.push(notations[index]);
+    lastColumn = column;
+  });
+  
+  const cellWidth = Math.max(EMPTY_TRANSCRIPT_CELL.length, ...notations.map(n => n.length));
+  const numberWidth = `${rounds.length}.`.length;
+  const lines = rounds.map((cells, index) => {
+    const roundNumber = `${index + 1}.`.padStart(numberWidth);
+    return `${roundNumber} ${cells.map(cell => cell.padEnd(cellWidth)).join('  ')}`.trimEnd();
+  });
+  
+  return [...header, '', ...lines].join('\n') + '\n';
+}
+
+/**
+ * Result of parsing a transcript; players are named P1, P2, ... in seating order
+ */
+export type ParsedTranscript =
+  | { valid: true; transcript: GameTranscript }
+  | { valid: false; reason: string };
+
+/**
+ * Parse a transcript written by formatTranscript
+ * Moves are read left to right, so column alignment is only for readability.
+ */
+export function parseTranscript(text: string): ParsedTranscript {
+  const tags = new Map<string, string>();
+  const moveLines: Array<{ lineNumber: number; text: string }> = [];
+  
+  const lines = text.split(/\r?\n/);
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i].trim();
+    if (line === '') {
+      continue;
+    }
+    
+    const tag = /^\[(\w+) "([^"]*)"\]$/.exec(line);
+    if (tag) {
+      tags.set(tag[1], tag[2]);
+      continue;
+    }
+    
+    const round = /^\d+\.\s*(.*)$/.exec(line);
+    if (!round) {
+      return { valid: false, reason: `Line ${i + 1}: expected a [Tag "value"] header or a numbered round` };
+    }
+    moveLines.push({ lineNumber: i + 1, text: round[1] });
+  }
+  
+  const edges = tags.get('Edges');
+  if (edges === undefined || !/^\d+( \d+)*$/.test(edges)) {
+    return { valid: false, reason: 'Missing or malformed [Edges] header' };
+  }
+  const players = edges.split(' ').map((edge, index) => ({
+    id: `P${index + 1}`,
+    edgePosition: parseInt(edge, 10),
+  }));
+  
+  const boardRadius = parseInt(tags.get('BoardRadius') ?? '3', 10);
+  if (isNaN(boardRadius) || boardRadius < 1) {
+    return { valid: false, reason: `Malformed board radius "${tags.get('BoardRadius')}"` };
+  }
+  
+  // Read the result: "*" while unfinished, otherwise the winners followed by how the game ended
+  let winners: string[] = [];
+  let winType: WinType | null = null;
+  const result = tags.get('Result') ?? '*';
+  if (result !== '*') {
+    const words = result.split(' ');
+    winType = words.pop() as WinType;
+    if (!WIN_TYPES.includes(winType)) {
+      return { valid: false, reason: `Unknown result "${result}"` };
+    }
+    const unknown = words.find(word => !players.some(p => p.id === word));
+    if (unknown !== undefined) {
+      return { valid: false, reason: `Unknown winner ${unknown} in result "${result}"` };
+    }
+    winners = words;
+  }
+  
+  const moveHistory: GameTranscript['moveHistory'] = [];
+  for (const { lineNumber, text: roundText } of moveLines) {
+    for (const word of roundText.split(/\s+/)) {
+      if (word === '' || word === EMPTY_TRANSCRIPT_CELL) {
+        continue;
+      }
+      const move = parseMoveNotation(word, players, boardRadius);
+      if (!move.valid) {
+        return { valid: false, reason: `Line ${lineNumber}: ${move.reason}` };
+      }
+      moveHistory.push({ playerId: move.playerId, tile: move.tile });
+    }
+  }
+  
+  return {
+    valid: true,
+    transcript: {
+      players,
+      boardRadius,
+      supermove: tags.get('Supermove') === 'true',
+      singleSupermove: tags.get('SingleSupermove') === 'true',
+      supermoveAnyPlayer: tags.get('SupermoveAnyPlayer') === 'true',
+      oppositeSidesVictory: tags.get('OppositeSidesVictory') === 'true',
+      winners,
+      winType,
+      moveHistory,
+    },
+  };
+}
@@ -35,22 +35,116 @@ export const TILE_FLOWS: Record<TileType, readonly FlowConnection[]> = {
   ],
 };
 
+// Flow patterns for variant tiles, keyed by tile type id (rotation 0)
+// A variant may pair fewer than all six ports; a flow entering an unpaired port
+// stops there (e.g. a dead-end tile)
+const variantTileFlows = new Map<number, readonly FlowConnection[]>();
+
+// Flow connections of each tile type in all six rotations, built on first use
+// Legality searches and flow tracing ask for the same few patterns over and over
+const rotatedFlowCache = new Map<number, FlowConnection[][]>();
+
+// For each tile type, the first rotation with the same flow pattern as each rotation
+const equivalentRotationCache = new Map<number, Rotation[]>();
+
+// Forget cached patterns, which depend on the variant definitions
+function clearFlowCaches(): void {
+  rotatedFlowCache.clear();
+  equivalentRotationCache.clear();
+}
+
+// Check that a port pairing is valid: each connection joins two different
+// directions and no direction is used by more than one connection
+export function isValidPortPairing(flows: readonly FlowConnection[]): boolean {
+  const used = new Set<Direction>();
+  for (const [dir1, dir2] of flows) {
+    if (dir1 === dir2 || used.has(dir1) || used.has(dir2)) {
+      return false;
+    }
+    if (dir1 < 0 || dir1 > 5 || dir2 < 0 || dir2 > 5) {
+      return false;
+    }
+    used.add(dir1);
+    used.add(dir2);
+  }
+  return true;
+}
+
+// Define the flow pattern of a variant tile type
+// Built-in tile types cannot be redefined
+export function defineTileFlows(type: number, flows: readonly FlowConnection[]): void {
+  if (type in TILE_FLOWS) {
+    throw new Error(`Cannot redefine built-in tile type ${type}`);
+  }
+  if (!isValidPortPairing(flows)) {
+    throw new Error(`Invalid port pairing for tile type ${type}`);
+  }
+  variantTileFlows.set(type, [...flows]);
+  clearFlowCaches();
+}
+
+// Remove all variant tile definitions
+export function clearTileFlowDefinitions(): void {
+  variantTileFlows.clear();
+  clearFlowCaches();
+}
+
+// Get the flow pattern of a tile type in canonical orientation (rotation 0)
+export function getTileFlows(type: TileType): readonly FlowConnection[] {
+  const flows = TILE_FLOWS[type] ?? variantTileFlows.get(type);
+  if (!flows) {
+    throw new Error(`Unknown tile type ${type}`);
+  }
+  return flows;
+}
+
 // Get flow connections for a tile with a specific rotation
+// The result is shared between callers and must not be modified
 export function getFlowConnections(
   type: TileType,
   rotation: Rotation
 ): FlowConnection[] {
-  const baseFlows = TILE_FLOWS[type];
-  
-  if (rotation === 0) {
-    return baseFlows as FlowConnection[];
+  let rotated = rotatedFlowCache.get(type);
+  if (!rotated) {
+    const baseFlows = getTileFlows(type);
+    rotated = [0, 1, 2, 3, 4, 5].map((r) =>
+      baseFlows.map(([dir1, dir2]) => [
+        rotateDirection(dir1, r),
+        rotateDirection(dir2, r),
+      ] as const)
+    );
+    rotatedFlowCache.set(type, rotated);
   }
-  
-  // Apply rotation to each flow connection
-  return baseFlows.map(([dir1, dir2]) => [
-    rotateDirection(dir1, rotation),
-    rotateDirection(dir2, rotation),
-  ] as const);
+  // Rotations outside 0-5 wrap around, as rotateDirection does
+  return rotated[((rotation % 6) + 6) % 6];
+}
+
+// Get the first rotation of a tile type with the same flow pattern as the given rotation
+// Symmetric tiles repeat themselves (ThreeSharps every 2 steps; NoSharps and TwoSharps every 3)
+export function getEquivalentRotation(type: TileType, rotation: Rotation): Rotation {
+  let equivalents = equivalentRotationCache.get(type);
+  if (!equivalents) {
+    const pattern = (r: Rotation) =>
+      getFlowConnections(type, r)
+        .map(([dir1, dir2]) => `${Math.min(dir1, dir2)}-${Math.max(dir1, dir2)}`)
+        .sort()
+        .join(',');
+    const patterns = ([0, 1, 2, 3, 4, 5] as Rotation[]).map(pattern);
+    equivalents = patterns.map((p) => patterns.indexOf(p) as Rotation);
+    equivalentRotationCache.set(type, equivalents);
+  }
+  return equivalents[rotation];
+}
+
+// Get the rotations of a tile type that give distinct flow patterns
+export function getUniqueRotations(type: TileType): Rotation[] {
+  const rotations: Rotation[] = [];
+  for (let r = 0; r < 6; r++) {
+    if (getEquivalentRotation(type, r as Rotation) === r) {
+      rotations.push(r as Rotation);
+    }
+  }
+  return rotations;
 }
 
 // Get the exit direction for a flow entering from a specific direction
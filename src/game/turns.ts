@@ -0,0 +1,42 @@
+// Turn grouping for Quortex/Flows move history
+
+import { Move } from '../redux/types';
+
+// A single player's turn: every tile they put on the board before play passed on
+// Normally one placement; a supermove adds the replacement before the final placement
+export interface Turn {
+  readonly turnNumber: number; // 1-based
+  readonly playerId: string;
+  readonly moves: Move[];      // All moves made during the turn, in order
+  readonly placement: Move;    // The move that ended the turn
+}
+
+// Group a flat move history into turns
+// Consecutive moves by the same player belong to the same turn
+export function groupMovesIntoTurns(moves: Move[]): Turn[] {
+  const turns: Turn[] = [];
+  let currentMoves: Move[] = [];
+
+  const finishTurn = () => {
+    if (currentMoves.length === 0) {
+      return;
+    }
+    turns.push({
+      turnNumber: turns.length + 1,
+      playerId: currentMoves[0].playerId,
+      moves: currentMoves,
+      placement: currentMoves[currentMoves.length - 1],
+    });
+    currentMoves = [];
+  };
+
+  for (const move of moves) {
+    if (currentMoves.length > 0 && currentMoves[0].playerId !== move.playerId) {
+      finishTurn();
+    }
+    currentMoves.push(move);
+  }
+  finishTurn();
+
+  return turns;
+}
@@ -0,0 +1,65 @@
+// First-run tutorial for Quortex/Flows
+// Explains the goal and the blocking rule using the live game state
+
+import { Player, Team, TileType } from './types';
+import { getGoalEdge } from './victory';
+
+// Friendly names for tile types, by number of sharp turns
+const TILE_NAMES: Record<TileType, string> = {
+  [TileType.NoSharps]: 'no sharp turns',
+  [TileType.OneSharp]: 'one sharp turn',
+  [TileType.TwoSharps]: 'two sharp turns',
+  [TileType.ThreeSharps]: 'three sharp turns',
+};
+
+export interface TutorialInfo {
+  readonly playerId: string;
+  readonly startEdge: number;       // Edge the player's flow starts from
+  readonly goalEdge: number;        // Edge the player's flow must reach
+  readonly heldTile: TileType | null;
+  readonly lines: string[];         // Explanation text, one line per entry
+}
+
+// Build the tutorial for the current player, or null if there is no current player
+export function getTutorialInfo(game: {
+  players: Player[];
+  teams: Team[];
+  currentPlayerIndex: number;
+  currentTile: TileType | null;
+  supermove: boolean;
+  oppositeSidesVictory: boolean;
+}): TutorialInfo | null {
+  const player = game.players[game.currentPlayerIndex];
+  if (!player) {
+    return null;
+  }
+
+  const goalEdge = getGoalEdge(player, game.players, game.teams, game.oppositeSidesVictory);
+  const partnerGoal = game.teams.length > 0 && !game.oppositeSidesVictory;
+
+  const lines = [
+    'Your flow starts on the highlighted edge.',
+    partnerGoal
+      ? "Connect it to your partner's edge (outlined) to win."
+      : 'Connect it to the opposite edge (outlined) to win.',
+  ];
+
+  if (game.currentTile !== null) {
+    lines.push(`You are holding a tile with ${TILE_NAMES[game.currentTile]}.`);
+    lines.push('Tap a hex to place it, rotate it, then tap ✓.');
+  }
+
+  lines.push('A move may never completely block any player');
+  lines.push('from reaching their goal.');
+  if (game.supermove) {
+    lines.push('If you are blocked anyway, you may replace a tile.');
+  }
+
+  return {
+    playerId: player.id,
+    startEdge: player.edgePosition,
+    goalEdge,
+    heldTile: game.currentTile,
+    lines,
+  };
+}
@@ -47,6 +47,7 @@ export interface Player {
   readonly edgePosition: number; // 0-5, which edge of hexagon
   readonly isAI: boolean;
   readonly userId?: string; // Optional: User ID for multiplayer (e.g., 'google:123') - associates user with claimed color
+  readonly aiDifficulty?: number; // Optional: fixed AI strength for a seat handed over to the AI mid-game
 }
 
 // Team pairing (for 4-6 player games)
@@ -1,21 +1,51 @@
 // Victory condition checking for Quortex/Flows
 
-import { PlacedTile, Player, Team, TileType, Direction } from './types';
+import { PlacedTile, Player, Team, TileType, Direction, HexPosition } from './types';
 import {
+  getAllBoardPositions,
   getOppositeEdge,
   positionToKey,
   getNeighborInDirection,
+  getOppositeDirection,
+  getEdgePositionsWithDirections,
   isValidPosition,
 } from './board';
 import { canTileBePlacedAnywhere, hasViablePath } from './legality';
+import { getFlowExit } from './tiles';
 
-export type WinType = 'flow' | 'constraint' | 'tie';
+export type WinType = 'flow' | 'constraint' | 'tie' | 'draw';
 
 export interface VictoryResult {
   readonly winners: string[]; // Array of player IDs who won
   readonly winType: WinType | null;
 }
 
+// Find the team a player belongs to, if any
+export function findPlayerTeam(playerId: string, teams: Team[]): Team | undefined {
+  return teams.find(t => t.player1Id === playerId || t.player2Id === playerId);
+}
+
+// Get the edge a player's flow must reach
+// Teammates connect their two edges unless opposite-sides victory is enabled
+export function getGoalEdge(
+  player: Player,
+  players: Player[],
+  teams: Team[],
+  oppositeSidesVictory: boolean = false,
+): number {
+  if (!oppositeSidesVictory) {
+    const team = findPlayerTeam(player.id, teams);
+    if (team) {
+      const partnerId = team.player1Id === player.id ? team.player2Id : team.player1Id;
+      const partner = players.find(p => p.id === partnerId);
+      if (partner) {
+        return partner.edgePosition;
+      }
+    }
+  }
+  return getOppositeEdge(player.edgePosition);
+}
+
 // Check if a player's flow connects their edges (for 2-3 player games)
 // Uses hasViablePath with allowEmptyHexes=false to check if there's a path using only placed tiles
 export function checkPlayerFlowVictory(
@@ -30,11 +60,14 @@ export function checkPlayerFlowVictory(
 
 // Check if a team's flows connect their two edges (for 4-6 player games)
 // Uses hasViablePath with allowEmptyHexes=false to check if there's a path using only placed tiles
+// oppositeSidesVictory: if true, either player connecting their edge to the opposite side wins for the team
+// (instead of the specific goal of reaching their partner's edge)
 export function checkTeamFlowVictory(
   board: Map<string, PlacedTile>,
   team: Team,
   players: Player[],
-  boardRadius: number
+  boardRadius: number,
+  oppositeSidesVictory: boolean = false
 ): boolean {
   const player1 = players.find((p) => p.id === team.player1Id);
   const player2 = players.find((p) => p.id === team.player2Id);
@@ -43,15 +76,18 @@ export function checkTeamFlowVictory(
     return false;
   }
   
-  // Check if player1's flow connects from edge1 to edge2
+  const target1 = oppositeSidesVictory ? getOppositeEdge(player1.edgePosition) : player2.edgePosition;
+  const target2 = oppositeSidesVictory ? getOppositeEdge(player2.edgePosition) : player1.edgePosition;
+  
+  // Check if player1's flow connects from edge1 to its target
   // Use hasViablePath with allowEmptyHexes=false to check victory with only placed tiles
-  const path1 = hasViablePath(board, player1, player2.edgePosition, false, false, boardRadius) as boolean;
+  const path1 = hasViablePath(board, player1, target1, false, false, boardRadius) as boolean;
   if (path1) {
     return true;
   }
   
-  // Check if player2's flow connects from edge2 to edge1
-  const path2 = hasViablePath(board, player2, player1.edgePosition, false, false, boardRadius) as boolean;
+  // Check if player2's flow connects from edge2 to its target
+  const path2 = hasViablePath(board, player2, target2, false, false, boardRadius) as boolean;
   return path2;
 }
 
@@ -60,14 +96,15 @@ export function checkFlowVictory(
   board: Map<string, PlacedTile>,
   players: Player[],
   teams: Team[],
-  boardRadius: number
+  boardRadius: number,
+  oppositeSidesVictory: boolean = false
 ): VictoryResult {
   const winners: string[] = [];
   
   // For team games (4-6 players)
   if (teams.length > 0) {
     for (const team of teams) {
-      if (checkTeamFlowVictory(board, team, players, boardRadius)) {
+      if (checkTeamFlowVictory(board, team, players, boardRadius, oppositeSidesVictory)) {
         // Credit both players individually instead of the team
         winners.push(team.player1Id);
         winners.push(team.player2Id);
@@ -96,6 +133,51 @@ export function checkFlowVictory(
   return { winners, winType: isTie ? 'tie' : 'flow' };
 }
 
+// Check if every hex on the board holds a tile
+export function isBoardFull(board: Map<string, PlacedTile>, boardRadius: number): boolean {
+  return board.size >= getAllBoardPositions(boardRadius).length;
+}
+
+// Length in tiles of the longest line of flow running onto the board from a player's edge
+export function getLongestFlowLength(
+  board: Map<string, PlacedTile>,
+  player: Player,
+  boardRadius: number
+): number {
+  let longest = 0;
+  for (const { pos, dir } of getEdgePositionsWithDirections(player.edgePosition, boardRadius)) {
+    longest = Math.max(longest, followEdgeFlow(board, pos, dir, boardRadius).segments.length);
+  }
+  return longest;
+}
+
+// Award a full board to the player (or team) with the longest connected flow
+// A team is measured by the longer of its two players' flows; equal longest flows tie
+export function checkLongestFlowVictory(
+  board: Map<string, PlacedTile>,
+  players: Player[],
+  teams: Team[],
+  boardRadius: number
+): VictoryResult {
+  const lengths = new Map(
+    players.map((player) => [player.id, getLongestFlowLength(board, player, boardRadius)])
+  );
+  
+  // Each side is a lone player or a team whose players share its length
+  const sides = teams.length > 0
+    ? teams.map((team) => [team.player1Id, team.player2Id])
+    : players.map((player) => [player.id]);
+  const sideLength = (side: string[]) => Math.max(...side.map((id) => lengths.get(id) ?? 0));
+  
+  const longest = Math.max(...sides.map(sideLength));
+  const winningSides = sides.filter((side) => sideLength(side) === longest);
+  
+  return {
+    winners: winningSides.flat(),
+    winType: winningSides.length > 1 ? 'tie' : 'flow',
+  };
+}
+
 // Check if current tile cannot be placed legally anywhere
 export function checkConstraintVictory(
   board: Map<string, PlacedTile>,
@@ -103,9 +185,10 @@ export function checkConstraintVictory(
   players: Player[],
   teams: Team[],
   boardRadius: number,
-  supermoveEnabled: boolean
+  supermoveEnabled: boolean,
+  oppositeSidesVictory: boolean = false
 ): boolean {
-  return !canTileBePlacedAnywhere(board, currentTile, players, teams, boardRadius, supermoveEnabled);
+  return !canTileBePlacedAnywhere(board, currentTile, players, teams, boardRadius, supermoveEnabled, oppositeSidesVictory);
 }
 
 // Check if any player/team has won
@@ -115,17 +198,19 @@ export function checkVictory(
   teams: Team[],
   currentTile: TileType | undefined,
   boardRadius: number,
-  supermoveEnabled: boolean = false
+  supermoveEnabled: boolean = false,
+  oppositeSidesVictory: boolean = false,
+  longestFlowVictory: boolean = false
 ): VictoryResult {
   // First check for flow victory
-  const flowVictory = checkFlowVictory(board, players, teams, boardRadius);
+  const flowVictory = checkFlowVictory(board, players, teams, boardRadius, oppositeSidesVictory);
   if (flowVictory.winners.length > 0) {
     return flowVictory;
   }
   
   // Check for constraint victory if a current tile is provided
   if (currentTile !== undefined) {
-    const constraintWin = checkConstraintVictory(board, currentTile, players, teams, boardRadius, supermoveEnabled);
+    const constraintWin = checkConstraintVictory(board, currentTile, players, teams, boardRadius, supermoveEnabled, oppositeSidesVictory);
     if (constraintWin) {
       // In constraint victory, the current player wins
       // We'll need to pass current player info for this to work properly
@@ -134,6 +219,14 @@ export function checkVictory(
     }
   }
   
+  // A placement that fills the board without a flow victory ends the game in a draw,
+  // unless the longest flow decides it
+  if (isBoardFull(board, boardRadius)) {
+    return longestFlowVictory
+      ? checkLongestFlowVictory(board, players, teams, boardRadius)
+      : { winners: [], winType: 'draw' };
+  }
+  
   return { winners: [], winType: null };
 }
 
@@ -184,3 +277,84 @@ export function isConnectionInWinningPath(
   // The connection is in the winning path if both directions connect to the flow
   return dir1ConnectsToFlow && dir2ConnectsToFlow;
 }
+
+// One tile's part of a winning flow: the flow enters through one side and leaves through another
+export interface WinningPathSegment {
+  position: HexPosition;
+  entry: Direction;
+  exit: Direction;
+}
+
+// The flow that won the game for a player, from their edge to their goal edge
+export interface WinningPath {
+  playerId: string;
+  segments: WinningPathSegment[];
+}
+
+// Trace each winner's flow from their edge to their goal edge
+// A flow from an edge port is a single line, so it is followed tile by tile until it leaves
+// the board; the first one that leaves through the goal edge is the winning path.
+// Winners without a connecting flow of their own (a teammate who won through their
+// partner's flow) are left out.
+export function getWinningPaths(
+  board: Map<string, PlacedTile>,
+  players: Player[],
+  teams: Team[],
+  winners: string[],
+  boardRadius: number,
+  oppositeSidesVictory: boolean = false
+): WinningPath[] {
+  const paths: WinningPath[] = [];
+  
+  for (const playerId of winners) {
+    const player = players.find((p) => p.id === playerId);
+    if (!player) continue;
+    
+    const goalEdge = getGoalEdge(player, players, teams, oppositeSidesVictory);
+    const goalPorts = new Set(
+      getEdgePositionsWithDirections(goalEdge, boardRadius).map(
+        ({ pos, dir }) => `${positionToKey(pos)}:${dir}`
+      )
+    );
+    
+    for (const { pos, dir } of getEdgePositionsWithDirections(player.edgePosition, boardRadius)) {
+      const { segments, exitPort } = followEdgeFlow(board, pos, dir, boardRadius);
+      if (exitPort !== null && goalPorts.has(exitPort)) {
+        paths.push({ playerId, segments });
+        break;
+      }
+    }
+  }
+  
+  return paths;
+}
+
+// Follow the single line of flow entering the board at an edge port, tile by tile
+// Returns the tiles it crosses and, if it runs off the board, the port ("row,col:dir") it leaves by
+function followEdgeFlow(
+  board: Map<string, PlacedTile>,
+  start: HexPosition,
+  startDirection: Direction,
+  boardRadius: number
+): { segments: WinningPathSegment[]; exitPort: string | null } {
+  const segments: WinningPathSegment[] = [];
+  let position = start;
+  let entry = startDirection;
+  
+  for (;;) {
+    const tile = board.get(positionToKey(position));
+    const exit = tile ? getFlowExit(tile, entry) : null;
+    if (!tile || exit === null) {
+      return { segments, exitPort: null };
+    }
+    
+    segments.push({ position, entry, exit });
+    
+    const next = getNeighborInDirection(position, exit);
+    if (!isValidPosition(next, boardRadius)) {
+      return { segments, exitPort: `${positionToKey(position)}:${exit}` };
+    }
+    position = next;
+    entry = getOppositeDirection(exit);
+  }
+}
@@ -1,16 +1,27 @@
 // Gameplay input handling for Phase 4
 
 import { store } from '../redux/store';
-import { setRotation, setSelectedPosition, setHoveredElement, placeTile, replaceTile, nextPlayer, drawTile, resetGame, rematchGame, showHelp, hideHelp, showMoveList, hideMoveList, navigateMoveList } from '../redux/actions';
+import { setRotation, setSelectedPosition, setHoveredElement, placeTile, replaceTile, nextPlayer, drawTile, resetGame, rematchGame, showHelp, hideHelp, hideTutorial, showMoveList, hideMoveList, navigateMoveList, jumpToMove, toggleReplay, setReplaySpeed, resign, showHint, hideHint, undoMove, swapSides, toggleLegalMoves, toggleLegalMovesRotation, setOrientationOverride } from '../redux/actions';
+import { selectResigningPlayer, selectHintMove, selectCanUndo, selectSwappingPlayer, selectContinuationTileTypes, selectIsReviewingHistory } from '../redux/selectors';
 import { GameplayRenderer } from '../rendering/gameplayRenderer';
 import { pixelToHex, isPointInHex, hexToPixel, getPlayerEdgePosition } from '../rendering/hexLayout';
-import { Rotation } from '../game/types';
-import { isValidPosition, positionToKey } from '../game/board';
-import { isLegalMove, isValidReplacementMove } from '../game/legality';
+import { Rotation, Direction, HexPosition } from '../game/types';
+import { isValidPosition, positionToKey, getAllBoardPositions, getNeighborInDirection } from '../game/board';
+import { isLegalMove, isValidReplacementMove, findFirstLegalRotation } from '../game/legality';
 import { HoveredElementType } from '../redux/types';
+import { formatTranscript } from '../game/notation';
+import { generateBoardSvg } from '../rendering/boardSvg';
+import { getBoardRotationAngle } from '../rendering/boardOrientation';
+import { CORNER_BUTTON_RADIUS, getCornerButtonPositions } from '../rendering/cornerButtons';
+import { nextReplaySpeed } from '../animation/replay';
+import { animateHeldTileRotation } from '../animation/rotationAnimations';
+
+// Minimum time between keyboard rotations, so holding a key doesn't spin the tile wildly
+const KEYBOARD_ROTATE_INTERVAL_MS = 150;
 
 export class GameplayInputHandler {
   private renderer: GameplayRenderer;
+  private lastRotateTime = 0;
 
   constructor(renderer: GameplayRenderer) {
     this.renderer = renderer;
@@ -19,6 +30,11 @@ export class GameplayInputHandler {
   handleClick(canvasX: number, canvasY: number): void {
     const state = store.getState();
     
+    // The tutorial doesn't block play: the first tap dismisses it and is still handled
+    if (state.ui.showTutorial) {
+      store.dispatch(hideTutorial());
+    }
+    
     // If help dialog is open, close it on any click (use original coordinates)
     if (state.ui.showHelp) {
       store.dispatch(hideHelp());
@@ -35,6 +51,12 @@ export class GameplayInputHandler {
       return;
     }
 
+    // Never play from an earlier position; a click while reviewing returns to live
+    if (selectIsReviewingHistory(state)) {
+      store.dispatch(navigateMoveList('last'));
+      return;
+    }
+
     // Check if we're in gameplay or game-over mode
     if (state.game.screen !== 'gameplay' && state.game.screen !== 'game-over') return;
     
@@ -54,11 +76,33 @@ export class GameplayInputHandler {
       return;
     }
 
+    // Check for resign buttons while it's the viewing player's turn
+    if (state.game.screen === 'gameplay') {
+      if (this.checkResignButtons(canvasX, canvasY, layout)) {
+        return;
+      }
+      if (this.checkHintButtons(canvasX, canvasY, layout)) {
+        return;
+      }
+      if (this.checkUndoButtons(canvasX, canvasY, layout)) {
+        return;
+      }
+      if (this.checkSwapButtons(canvasX, canvasY, layout)) {
+        return;
+      }
+    }
+
     // Check for rematch buttons if game over
     if (state.game.screen === 'game-over') {
       if (this.checkRematchButtons(canvasX, canvasY, layout)) {
         return;
       }
+      if (this.checkTranscriptButtons(canvasX, canvasY, layout)) {
+        return;
+      }
+      if (this.checkExportButtons(canvasX, canvasY, layout)) {
+        return;
+      }
     }
 
     // Check for exit button clicks in corners with UNTRANSFORMED coordinates
@@ -99,76 +143,8 @@ export class GameplayInputHandler {
         Math.pow(y - buttonPositions.checkmark.y, 2)
       );
       if (distToCheck < buttonSize / 2) {
-      // Ensure currentTile is set (should be if we're in this block, but TS doesn't know)
-      if (state.game.currentTile == null) return;
-      
         // Checkmark clicked - place or replace the tile
-        const posKey = positionToKey(state.ui.selectedPosition);
-        const isOccupied = state.game.board.has(posKey);
-        
-        // Check if this is a replacement move (for supermove)
-        if (isOccupied && state.game.supermove && currentPlayer) {
-          // Validate replacement move
-          if (!isValidReplacementMove(
-            state.game.board,
-            state.ui.selectedPosition,
-            state.game.currentTile,
-            state.ui.currentRotation,
-            currentPlayer,
-            state.game.players,
-            state.game.teams,
-            state.game.boardRadius,
-            state.game.supermoveAnyPlayer
-          )) {
-            // Replacement is not valid
-            return;
-          }
-          
-          // Check if this is a single supermove
-          const isSingleSupermove = state.game.singleSupermove;
-          
-          // Perform replacement
-          store.dispatch(replaceTile(
-            state.ui.selectedPosition,
-            state.ui.currentRotation,
-            isSingleSupermove
-          ));
-          store.dispatch(setSelectedPosition(null));
-          store.dispatch(setRotation(0));
-          
-          // If single supermove, advance to next player and draw a tile
-          if (isSingleSupermove) {
-            store.dispatch(nextPlayer());
-            store.dispatch(drawTile());
-          }
-          // Otherwise, don't advance to next player - they get to place the replaced tile
-          return;
-        }
-        
-        // Normal placement (not a replacement)
-        const placedTile = {
-          type: state.game.currentTile,
-          rotation: state.ui.currentRotation,
-          position: state.ui.selectedPosition,
-        };
-        
-        if (!isLegalMove(state.game.board, placedTile, state.game.players, state.game.teams, state.game.boardRadius, state.game.supermove)) {
-          // Move is illegal - don't allow placement
-          // The UI should already show the button as disabled
-          return;
-        }
-        
-        store.dispatch(placeTile(
-          state.ui.selectedPosition,
-          state.ui.currentRotation
-        ));
-        store.dispatch(setSelectedPosition(null));
-        store.dispatch(setRotation(0));
-        
-        // Always advance to next player after placing a tile
-        // (even when completing supermove)
-        store.dispatch(nextPlayer());
-        store.dispatch(drawTile());
+        this.confirmPlacement();
         return;
       }
       
@@ -198,7 +174,7 @@ export class GameplayInputHandler {
         // NE button: Rotate clockwise
         const currentRotation = state.ui.currentRotation;
         const newRotation = ((currentRotation + 1) % 6) as Rotation;
-        store.dispatch(setRotation(newRotation));
+        this.rotateHeldTile(newRotation);
         return;
       }
       
@@ -206,7 +182,7 @@ export class GameplayInputHandler {
         // NW button: Rotate counter-clockwise
         const currentRotation = state.ui.currentRotation;
         const newRotation = ((currentRotation + 5) % 6) as Rotation;
-        store.dispatch(setRotation(newRotation));
+        this.rotateHeldTile(newRotation);
         return;
       }
       
@@ -242,12 +218,225 @@ export class GameplayInputHandler {
         return;
       }
       
-      // Set the selected position
-      store.dispatch(setSelectedPosition(hexPos));
+      this.selectPosition(hexPos);
       return;
     }
   }
 
+  // Handle a key press during gameplay; returns true if the key was used
+  // Q/E (or ,/.) rotate the tile, arrow keys move the selection between empty hexes,
+  // Space/Enter place the tile at the selection, and O turns the board to another player's view
+  handleKeyDown(key: string): boolean {
+    const state = store.getState();
+    if (state.game.screen !== 'gameplay' || state.ui.showHelp) return false;
+
+    // Turning the board only changes the view, so it also works while reviewing the moves
+    if (key === 'o' || key === 'O') {
+      this.cycleOrientation();
+      return true;
+    }
+
+    if (state.game.currentTile == null) return false;
+    if (state.ui.showMoveList || selectIsReviewingHistory(state)) return false;
+
+    switch (key) {
+      case 'q':
+      case 'Q':
+      case ',':
+        this.rotateFromKeyboard(5);
+        return true;
+      case 'e':
+      case 'E':
+      case '.':
+        this.rotateFromKeyboard(1);
+        return true;
+      case 'ArrowLeft':
+        this.moveSelection(Direction.West);
+        return true;
+      case 'ArrowRight':
+        this.moveSelection(Direction.East);
+        return true;
+      case 'ArrowUp':
+        this.moveSelection(Direction.NorthWest);
+        return true;
+      case 'ArrowDown':
+        this.moveSelection(Direction.SouthEast);
+        return true;
+      case 'l':
+        // Show or hide the legal drop targets for the held tile
+        store.dispatch(toggleLegalMoves());
+        return true;
+      case 'L':
+        // Switch the drop targets between any rotation and the held rotation
+        store.dispatch(toggleLegalMovesRotation());
+        return true;
+      case ' ':
+      case 'Enter':
+        if (state.ui.selectedPosition) {
+          this.confirmPlacement();
+        }
+        return true;
+      default:
+        return false;
+    }
+  }
+
+  // View the board from each player's edge in turn, then return to the usual orientation
+  private cycleOrientation(): void {
+    const { game, ui } = store.getState();
+    const index = game.players.findIndex((player) => player.id === ui.orientationPlayerId);
+    const next = game.players[index + 1];
+    store.dispatch(setOrientationOverride(next ? next.id : null));
+  }
+
+  // Rotate the held tile by the given number of steps clockwise
+  // Throttled so that holding a key down doesn't spin the tile wildly
+  private rotateFromKeyboard(steps: number): void {
+    const now = performance.now();
+    if (now - this.lastRotateTime < KEYBOARD_ROTATE_INTERVAL_MS) return;
+    this.lastRotateTime = now;
+
+    const currentRotation = store.getState().ui.currentRotation;
+    this.rotateHeldTile(((currentRotation + steps) % 6) as Rotation);
+  }
+
+  // Turn the held tile to a new rotation, animating the turn the short way round
+  private rotateHeldTile(rotation: Rotation): void {
+    animateHeldTileRotation(store.getState().ui.currentRotation, rotation);
+    store.dispatch(setRotation(rotation));
+  }
+
+  // Move the selection to the next empty hex in a direction, skipping over placed tiles
+  // With nothing selected, the center (or the first empty hex) is selected instead
+  private moveSelection(direction: Direction): void {
+    const state = store.getState();
+    const { board, boardRadius } = state.game;
+    const isEmpty = (pos: HexPosition) =>
+      isValidPosition(pos, boardRadius) && !board.has(positionToKey(pos));
+
+    if (!state.ui.selectedPosition) {
+      const center = { row: 0, col: 0 };
+      const first = isEmpty(center) ? center : getAllBoardPositions(boardRadius).find(isEmpty);
+      if (first) {
+        this.selectPosition(first);
+      }
+      return;
+    }
+
+    let next = getNeighborInDirection(state.ui.selectedPosition, direction);
+    while (isValidPosition(next, boardRadius)) {
+      if (isEmpty(next)) {
+        this.selectPosition(next);
+        return;
+      }
+      next = getNeighborInDirection(next, direction);
+    }
+  }
+
+  // Select a hex for the held tile, optionally snapping it to a legal orientation
+  private selectPosition(hexPos: HexPosition): void {
+    const state = store.getState();
+    const isOccupied = state.game.board.has(positionToKey(hexPos));
+
+    store.dispatch(setSelectedPosition(hexPos));
+
+    // Optionally snap the tile to a legal orientation for this hex
+    if (state.ui.settings.autoRotateToLegal && !isOccupied && state.game.currentTile != null) {
+      const rotation = findFirstLegalRotation(
+        state.game.board,
+        state.game.currentTile,
+        hexPos,
+        state.ui.currentRotation,
+        state.game.players,
+        state.game.teams,
+        state.game.boardRadius,
+        state.game.supermove,
+        selectContinuationTileTypes(state),
+        state.game.oppositeSidesVictory
+      );
+      if (rotation !== state.ui.currentRotation) {
+        store.dispatch(setRotation(rotation));
+      }
+    }
+  }
+
+  // Place (or, with supermove, replace) the held tile at the selected position if the move is legal
+  private confirmPlacement(): void {
+    const state = store.getState();
+    const currentPlayer = state.game.players[state.game.currentPlayerIndex];
+    const selectedPosition = state.ui.selectedPosition;
+    const currentTile = state.game.currentTile;
+    if (!selectedPosition || currentTile == null) return;
+
+    const posKey = positionToKey(selectedPosition);
+    const isOccupied = state.game.board.has(posKey);
+    
+    // Check if this is a replacement move (for supermove)
+    if (isOccupied && state.game.supermove && currentPlayer) {
+      // Validate replacement move
+      if (!isValidReplacementMove(
+        state.game.board,
+        selectedPosition,
+        currentTile,
+        state.ui.currentRotation,
+        currentPlayer,
+        state.game.players,
+        state.game.teams,
+        state.game.boardRadius,
+        state.game.supermoveAnyPlayer,
+        state.game.oppositeSidesVictory
+      )) {
+        // Replacement is not valid
+        return;
+      }
+      
+      // Check if this is a single supermove
+      const isSingleSupermove = state.game.singleSupermove;
+      
+      // Perform replacement
+      store.dispatch(replaceTile(
+        selectedPosition,
+        state.ui.currentRotation,
+        isSingleSupermove
+      ));
+      store.dispatch(setSelectedPosition(null));
+      store.dispatch(setRotation(0));
+      
+      // If single supermove, advance to next player and draw a tile
+      if (isSingleSupermove) {
+        store.dispatch(nextPlayer());
+        store.dispatch(drawTile());
+      }
+      // Otherwise, don't advance to next player - they get to place the replaced tile
+      return;
+    }
+    
+    // Normal placement (not a replacement)
+    const placedTile = {
+      type: currentTile,
+      rotation: state.ui.currentRotation,
+      position: selectedPosition,
+    };
+    
+    if (!isLegalMove(state.game.board, placedTile, state.game.players, state.game.teams, state.game.boardRadius, state.game.supermove, selectContinuationTileTypes(state), state.game.oppositeSidesVictory)) {
+      // Move is illegal - don't allow placement
+      // The UI should already show the button as disabled
+      return;
+    }
+    
+    store.dispatch(placeTile(
+      selectedPosition,
+      state.ui.currentRotation
+    ));
+    store.dispatch(setSelectedPosition(null));
+    store.dispatch(setRotation(0));
+    
+    // Always advance to next player after placing a tile
+    // (even when completing supermove)
+    store.dispatch(nextPlayer());
+    store.dispatch(drawTile());
+  }
+
   // Calculate button positions oriented toward the player's edge
   // This matches the same calculation in gameplayRenderer.ts
   private getOrientedButtonPositions(
@@ -342,7 +531,7 @@ export class GameplayInputHandler {
       newRotation = ((currentRotation + 1) % 6) as Rotation;
     }
 
-    store.dispatch(setRotation(newRotation));
+    this.rotateHeldTile(newRotation);
   }
 
   // Helper to get hex vertex position
@@ -404,12 +593,11 @@ export class GameplayInputHandler {
       ) {
         // Exit button clicked
         
-        // Check if in multiplayer mode and spectating
-        if (state.ui.gameMode === 'multiplayer' && state.ui.isSpectator) {
-          // Spectator: dispatch custom event for multiplayer layer to handle
-          window.dispatchEvent(new CustomEvent('multiplayer:spectator-exit'));
+        if (state.ui.gameMode === 'multiplayer') {
+          // Player or spectator: the multiplayer layer leaves the room and returns to the lobby
+          window.dispatchEvent(new CustomEvent('multiplayer:leave-game'));
         } else {
-          // Player or tabletop: reset game and return to setup
+          // Tabletop: reset game and return to setup
           store.dispatch(resetGame());
         }
         return;
@@ -711,6 +899,238 @@ export class GameplayInputHandler {
     return false;
   }
 
+  private checkResignButtons(
+    x: number,
+    y: number,
+    layout: { canvasWidth: number; canvasHeight: number }
+  ): boolean {
+    const state = store.getState();
+    const resigningPlayer = selectResigningPlayer(state);
+    if (!resigningPlayer) return false;
+
+    const cornerSize = 50;
+    const margin = 10;
+    const spacing = cornerSize * 0.15;
+    const tripleSpacing = 3 * (cornerSize + spacing);
+
+    const resignButtons = [
+      {
+        // Edge 0 (bottom): after exit, help, and move list buttons
+        centerX: margin + cornerSize / 2 + tripleSpacing,
+        centerY: layout.canvasHeight - margin - cornerSize / 2,
+        corner: 0,
+      },
+      {
+        // Edge 1 (right): after exit, help, and move list buttons
+        centerX: layout.canvasWidth - margin - cornerSize / 2,
+        centerY: layout.canvasHeight - margin - cornerSize / 2 - tripleSpacing,
+        corner: 1,
+      },
+      {
+        // Edge 2 (top): after exit, help, and move list buttons
+        centerX: layout.canvasWidth - margin - cornerSize / 2 - tripleSpacing,
+        centerY: margin + cornerSize / 2,
+        corner: 2,
+      },
+      {
+        // Edge 3 (left): after exit, help, and move list buttons
+        centerX: margin + cornerSize / 2,
+        centerY: margin + cornerSize / 2 + tripleSpacing,
+        corner: 3,
+      },
+    ];
+
+    const radius = cornerSize / 2;
+
+    for (const button of resignButtons) {
+      // In multiplayer mode, only allow clicks on bottom edge (edge 0)
+      if (state.ui.gameMode === 'multiplayer' && button.corner !== 0) {
+        continue;
+      }
+
+      const dist = Math.sqrt(
+        Math.pow(x - button.centerX, 2) + Math.pow(y - button.centerY, 2)
+      );
+      if (dist <= radius) {
+        // Resign button clicked - concede for the player on turn
+        store.dispatch(setSelectedPosition(null));
+        store.dispatch(hideHint());
+        store.dispatch(resign(resigningPlayer.id));
+        return true;
+      }
+    }
+
+    return false;
+  }
+
+  private checkHintButtons(
+    x: number,
+    y: number,
+    layout: { canvasWidth: number; canvasHeight: number }
+  ): boolean {
+    const state = store.getState();
+    if (!selectResigningPlayer(state)) return false;
+
+    const hintButtons = getCornerButtonPositions(4, layout);
+
+    for (const button of hintButtons) {
+      // In multiplayer mode, only allow clicks on bottom edge (edge 0)
+      if (state.ui.gameMode === 'multiplayer' && button.edge !== 0) {
+        continue;
+      }
+
+      const dist = Math.sqrt(
+        Math.pow(x - button.x, 2) + Math.pow(y - button.y, 2)
+      );
+      if (dist <= CORNER_BUTTON_RADIUS) {
+        // Hint button clicked - toggle the suggested placement for the tile in hand
+        if (state.ui.hint) {
+          store.dispatch(hideHint());
+          return true;
+        }
+
+        const hint = selectHintMove(state);
+        if (hint) {
+          store.dispatch(setSelectedPosition(null));
+          store.dispatch(showHint(hint.position, hint.rotation));
+        }
+        return true;
+      }
+    }
+
+    return false;
+  }
+
+  private checkTranscriptButtons(
+    x: number,
+    y: number,
+    layout: { canvasWidth: number; canvasHeight: number }
+  ): boolean {
+    const state = store.getState();
+
+    const transcriptButtons = getCornerButtonPositions(4, layout);
+
+    for (const button of transcriptButtons) {
+      // In multiplayer mode, only allow clicks on bottom edge (edge 0)
+      if (state.ui.gameMode === 'multiplayer' && button.edge !== 0) {
+        continue;
+      }
+
+      const dist = Math.sqrt(
+        Math.pow(x - button.x, 2) + Math.pow(y - button.y, 2)
+      );
+      if (dist <= CORNER_BUTTON_RADIUS) {
+        // Copy transcript button clicked - put the game record on the clipboard
+        navigator.clipboard.writeText(formatTranscript(state.game)).catch((error) => {
+          console.error('Failed to copy transcript:', error);
+        });
+        return true;
+      }
+    }
+
+    return false;
+  }
+
+  private checkExportButtons(
+    x: number,
+    y: number,
+    layout: { canvasWidth: number; canvasHeight: number }
+  ): boolean {
+    const state = store.getState();
+
+    const exportButtons = getCornerButtonPositions(5, layout);
+
+    for (const button of exportButtons) {
+      // In multiplayer mode, only allow clicks on bottom edge (edge 0)
+      if (state.ui.gameMode === 'multiplayer' && button.edge !== 0) {
+        continue;
+      }
+
+      const dist = Math.sqrt(
+        Math.pow(x - button.x, 2) + Math.pow(y - button.y, 2)
+      );
+      if (dist <= CORNER_BUTTON_RADIUS) {
+        // Export image button clicked - download the board as drawn on screen
+        const svg = generateBoardSvg(state.game, {
+          rotation: getBoardRotationAngle(state),
+          palette: state.ui.settings.palette,
+        });
+        this.downloadFile('quortex-board.svg', svg, 'image/svg+xml');
+        return true;
+      }
+    }
+
+    return false;
+  }
+
+  // Offer text as a file download
+  private downloadFile(filename: string, contents: string, type: string): void {
+    const url = URL.createObjectURL(new Blob([contents], { type }));
+    const link = document.createElement('a');
+    link.href = url;
+    link.download = filename;
+    link.click();
+    URL.revokeObjectURL(url);
+  }
+
+  private checkUndoButtons(
+    x: number,
+    y: number,
+    layout: { canvasWidth: number; canvasHeight: number }
+  ): boolean {
+    if (!selectCanUndo(store.getState())) return false;
+
+    const undoButtons = getCornerButtonPositions(5, layout);
+
+    for (const button of undoButtons) {
+      const dist = Math.sqrt(
+        Math.pow(x - button.x, 2) + Math.pow(y - button.y, 2)
+      );
+      if (dist <= CORNER_BUTTON_RADIUS) {
+        // Undo button clicked - take back the last move and any AI replies to it
+        store.dispatch(setSelectedPosition(null));
+        store.dispatch(setRotation(0));
+        store.dispatch(hideHint());
+        store.dispatch(undoMove());
+        return true;
+      }
+    }
+
+    return false;
+  }
+
+  private checkSwapButtons(
+    x: number,
+    y: number,
+    layout: { canvasWidth: number; canvasHeight: number }
+  ): boolean {
+    const state = store.getState();
+    const swappingPlayer = selectSwappingPlayer(state);
+    if (!swappingPlayer) return false;
+
+    const swapButtons = getCornerButtonPositions(6, layout);
+
+    for (const button of swapButtons) {
+      // In multiplayer mode, only allow clicks on bottom edge (edge 0)
+      if (state.ui.gameMode === 'multiplayer' && button.edge !== 0) {
+        continue;
+      }
+
+      const dist = Math.sqrt(
+        Math.pow(x - button.x, 2) + Math.pow(y - button.y, 2)
+      );
+      if (dist <= CORNER_BUTTON_RADIUS) {
+        // Swap button clicked - take over the opening player's side
+        store.dispatch(setSelectedPosition(null));
+        store.dispatch(hideHint());
+        store.dispatch(swapSides(swappingPlayer.id));
+        return true;
+      }
+    }
+
+    return false;
+  }
+
   private checkRematchButtons(
     x: number,
     y: number,
@@ -818,6 +1238,43 @@ export class GameplayInputHandler {
       return false; // Click outside dialog
     }
     
+    const moves = state.game.moveHistory;
+    const currentMoveIndex = state.ui.moveListIndex === -1 ? moves.length : state.ui.moveListIndex;
+
+    // Check for the return to live button (match rendering)
+    const liveX = dialogX + dialogWidth - 80;
+    const liveY = dialogY + 60 + 8;
+    if (selectIsReviewingHistory(state) &&
+        rotatedX >= liveX && rotatedX <= liveX + 60 && rotatedY >= liveY && rotatedY <= liveY + 26) {
+      store.dispatch(navigateMoveList('last'));
+      return true;
+    }
+
+    // Check for the scrubber (match rendering)
+    const sliderY = dialogY + dialogHeight - 92;
+    const sliderX = dialogX + 20;
+    const sliderWidth = dialogWidth - 40 - 84;
+
+    // Check for the play/pause and speed buttons beside the scrubber (match rendering)
+    if (moves.length > 0 && Math.abs(rotatedY - sliderY) <= 11) {
+      const playX = dialogX + dialogWidth - 96;
+      const speedX = dialogX + dialogWidth - 56;
+      if (rotatedX >= playX && rotatedX <= playX + 36) {
+        store.dispatch(toggleReplay(moves.length));
+        return true;
+      }
+      if (rotatedX >= speedX && rotatedX <= speedX + 36) {
+        store.dispatch(setReplaySpeed(nextReplaySpeed(state.ui.replaySpeed)));
+        return true;
+      }
+    }
+    if (moves.length > 0 && Math.abs(rotatedY - sliderY) <= 10 &&
+        rotatedX >= sliderX - 10 && rotatedX <= sliderX + sliderWidth + 10) {
+      const fraction = Math.max(0, Math.min(1, (rotatedX - sliderX) / sliderWidth));
+      store.dispatch(jumpToMove(Math.round(fraction * moves.length), moves.length));
+      return true;
+    }
+
     // Check for navigation buttons at bottom
     const buttonY = dialogY + dialogHeight - 55;
     const buttonHeight = 30;
@@ -832,10 +1289,10 @@ export class GameplayInputHandler {
         store.dispatch(navigateMoveList('first'));
         return true;
       } else if (rotatedX >= buttonsX + buttonWidth + buttonSpacing && rotatedX < buttonsX + 2 * buttonWidth + buttonSpacing) {
-        store.dispatch(navigateMoveList('prev'));
+        store.dispatch(jumpToMove(currentMoveIndex - 1, moves.length));
         return true;
       } else if (rotatedX >= buttonsX + 2 * (buttonWidth + buttonSpacing) && rotatedX < buttonsX + 3 * buttonWidth + 2 * buttonSpacing) {
-        store.dispatch(navigateMoveList('next'));
+        store.dispatch(jumpToMove(currentMoveIndex + 1, moves.length));
         return true;
       } else if (rotatedX >= buttonsX + 3 * (buttonWidth + buttonSpacing) && rotatedX < buttonsX + 4 * buttonWidth + 3 * buttonSpacing) {
         store.dispatch(navigateMoveList('last'));
@@ -848,12 +1305,9 @@ export class GameplayInputHandler {
     const controlsHeight = 40;
     const contentY = controlsY + controlsHeight + 10;
     const lineHeight = 44; // Match rendering: increased to prevent overlap
-    const bottomMargin = 85; // Increased to accommodate navigation buttons
+    const bottomMargin = 105; // Space for the scrubber and navigation buttons
     const maxLines = Math.floor((dialogHeight - (contentY - dialogY) - bottomMargin) / lineHeight);
     
-    const moves = state.game.moveHistory;
-    const currentMoveIndex = state.ui.moveListIndex === -1 ? moves.length : state.ui.moveListIndex;
-    
     // Center the current move in the view when possible (match rendering logic)
     let startIndex = currentMoveIndex - Math.floor(maxLines / 2);
     startIndex = Math.max(0, Math.min(startIndex, moves.length - maxLines));
@@ -866,16 +1320,8 @@ export class GameplayInputHandler {
       const moveNumber = startIndex + clickedIndex + 1;
       
       if (moveNumber >= 1 && moveNumber <= moves.length) {
-        // Navigate to this move
-        if (moveNumber === moves.length) {
-          store.dispatch(navigateMoveList('last'));
-        } else {
-          // Set to specific move by dispatching a single action with the target index
-          store.dispatch(navigateMoveList('first'));
-          for (let i = 0; i < moveNumber - 1; i++) {
-            store.dispatch(navigateMoveList('next'));
-          }
-        }
+        // Jump straight to this move; the latest move is the current state
+        store.dispatch(jumpToMove(moveNumber, moves.length));
         return true;
       }
     }
@@ -934,7 +1380,7 @@ export class GameplayInputHandler {
     const controlsHeight = 40;
     const contentY = controlsY + controlsHeight + 10;
     const lineHeight = 44; // Match click detection and rendering
-    const bottomMargin = 85; // Match click detection
+    const bottomMargin = 105; // Match click detection
     const maxLines = Math.floor((dialogHeight - (contentY - dialogY) - bottomMargin) / lineHeight);
     
     const moves = state.game.moveHistory;
@@ -53,6 +53,27 @@ export class InputHandler {
     canvas.addEventListener('mousemove', (event) => {
       this.handleMouseMove(event.clientX, event.clientY);
     });
+
+    window.addEventListener('keydown', (event) => {
+      this.handleKeyDown(event);
+    });
+  }
+
+  private handleKeyDown(event: KeyboardEvent): void {
+    // Leave shortcuts with modifiers to the browser
+    if (event.ctrlKey || event.metaKey || event.altKey) return;
+
+    // Typing in a text field (e.g. multiplayer chat) is not a game control
+    const target = event.target as HTMLElement | null;
+    if (target && (target.tagName === 'INPUT' || target.tagName === 'TEXTAREA')) return;
+
+    const state = store.getState();
+    if (state.game.screen === 'gameplay' && this.gameplayInputHandler) {
+      if (this.gameplayInputHandler.handleKeyDown(event.key)) {
+        // Stop arrow keys and Space from scrolling the page
+        event.preventDefault();
+      }
+    }
   }
 
   private handleClick(clientX: number, clientY: number): void {
@@ -1,8 +1,13 @@
 // Input handler for the redesigned lobby
 
 import { store } from '../redux/store';
-import { addPlayer, removePlayer, startGame, toggleSettings, updateSettings, showHelp, hideHelp, restoreGame } from '../redux/actions';
+import { addPlayer, removePlayer, setPlayerAI, startGame, toggleSettings, updateSettings, showHelp, hideHelp, restoreGame } from '../redux/actions';
 import { LobbyLayout, isPointInButton, isPointInCircle } from '../rendering/lobbyLayout';
+import { MIN_AI_DIFFICULTY, MAX_AI_DIFFICULTY } from '../game/ai';
+import { getNextPalette } from '../rendering/palette';
+import { ANIMATION_SPEED_STEP, MAX_ANIMATION_SPEED } from '../animation/actions';
+import { TILE_DISTRIBUTION_CLASSIC, TILE_DISTRIBUTION_SKEWED, TURN_TIME_LIMIT_STEP, MAX_TURN_TIME_LIMIT } from '../redux/types';
+import { generateGameSeed } from '../redux/gameReducer';
 import { multiplayerStore } from '../multiplayer/stores/multiplayerStore';
 
 export class LobbyInputHandler {
@@ -31,6 +36,13 @@ export class LobbyInputHandler {
             return;
           }
           
+          if (control.type === 'palette') {
+            store.dispatch(updateSettings({
+              palette: getNextPalette(currentSettings.palette)
+            }));
+            return;
+          }
+          
           if (control.type === 'checkbox' && control.settingKey) {
             const key = control.settingKey;
             store.dispatch(updateSettings({
@@ -63,12 +75,24 @@ export class LobbyInputHandler {
                 newValue = Math.min(6, currentValue + 1);
               } else if (key === 'debugAnimationSlowdown') {
                 newValue = Math.min(10, currentValue + 1);
+              } else if (key === 'aiDifficulty') {
+                newValue = Math.min(MAX_AI_DIFFICULTY, currentValue + 1);
+              } else if (key === 'animationSpeed') {
+                newValue = Math.min(MAX_ANIMATION_SPEED, currentValue + ANIMATION_SPEED_STEP);
+              } else if (key === 'turnTimeLimit') {
+                newValue = Math.min(MAX_TURN_TIME_LIMIT, currentValue + TURN_TIME_LIMIT_STEP);
               }
             } else if (control.label === '-') {
               if (key === 'boardRadius') {
                 newValue = Math.max(2, currentValue - 1);
               } else if (key === 'debugAnimationSlowdown') {
                 newValue = Math.max(1, currentValue - 1);
+              } else if (key === 'aiDifficulty') {
+                newValue = Math.max(MIN_AI_DIFFICULTY, currentValue - 1);
+              } else if (key === 'animationSpeed') {
+                newValue = Math.max(0, currentValue - ANIMATION_SPEED_STEP);
+              } else if (key === 'turnTimeLimit') {
+                newValue = Math.max(0, currentValue - TURN_TIME_LIMIT_STEP);
               }
             }
             
@@ -80,7 +104,14 @@ export class LobbyInputHandler {
 
           if (control.type === 'reset-distribution') {
             store.dispatch(updateSettings({
-              tileDistribution: [1, 1, 1, 1]
+              tileDistribution: TILE_DISTRIBUTION_CLASSIC
+            }));
+            return;
+          }
+
+          if (control.type === 'skewed-distribution') {
+            store.dispatch(updateSettings({
+              tileDistribution: TILE_DISTRIBUTION_SKEWED
             }));
             return;
           }
@@ -118,10 +149,16 @@ export class LobbyInputHandler {
       if (isPointInCircle(x, y, centerX, centerY, radius)) {
         const state = store.getState();
         store.dispatch(startGame({
+          // Seed local games so the whole game can be reproduced; multiplayer seeds come from the server
+          seed: state.ui.gameMode === 'multiplayer' ? undefined : generateGameSeed(),
           boardRadius: state.ui.settings.boardRadius,
           supermove: state.ui.settings.supermove,
           singleSupermove: state.ui.settings.singleSupermove,
           supermoveAnyPlayer: state.ui.settings.supermoveAnyPlayer,
+          oppositeSidesVictory: state.ui.settings.oppositeSidesVictory,
+          turnTimeLimit: state.ui.settings.turnTimeLimit,
+          noSelfStalemate: state.ui.settings.noSelfStalemate,
+          longestFlowVictory: state.ui.settings.longestFlowVictory,
         }));
         return;
       }
@@ -171,8 +208,12 @@ export class LobbyInputHandler {
           // Get the user ID from multiplayerStore (it's stored as 'playerId' but contains the user ID like 'google:...')
           const mpState = multiplayerStore.get();
           const userId = mpState.playerId || undefined;
+          // Keep the flow color chosen in the room, unless someone has already taken it
+          const roomColor = mpState.currentRoom?.players?.find((p) => p.id === userId)?.color;
+          const colorTaken = state.game.configPlayers.some((p) => p.color === roomColor);
+          const color = roomColor && !colorTaken ? roomColor : edgeBtn.color;
           // Pass userId in the userId field for proper ID mapping across clients
-          store.dispatch(addPlayer(edgeBtn.color, edgeBtn.edge, undefined, userId));
+          store.dispatch(addPlayer(color, edgeBtn.edge, undefined, userId));
         } else {
           // Tabletop mode: auto-generate player IDs
           store.dispatch(addPlayer(edgeBtn.color, edgeBtn.edge));
@@ -188,6 +229,10 @@ export class LobbyInputHandler {
           store.dispatch(removePlayer(entry.player.id));
           return;
         }
+        if (entry.aiButton && isPointInButton(x, y, entry.aiButton)) {
+          store.dispatch(setPlayerAI(entry.player.id, !entry.player.isAI));
+          return;
+        }
       }
     }
   }
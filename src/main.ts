@@ -7,9 +7,36 @@ import { GameplayInputHandler } from './input/gameplayInputHandler';
 import { incrementFrame } from './animation/actions';
 import { processAnimations } from './animation/processor';
 import { updateFlowPreview } from './animation/flowPreview';
+import { updateBoardRotation } from './animation/rotationAnimations';
+import { getBoardRotationAngle } from './rendering/boardOrientation';
+import { animateArrivedMove } from './animation/placementAnimations';
+import { createReplayDriver } from './animation/replay';
 import { HexPosition, Rotation } from './game/types';
 import { positionToKey } from './game/board';
 import { isPlayerBlocked } from './game/legality';
+import { showTutorial, updateSettings } from './redux/actions';
+import { MIN_AI_DIFFICULTY, MAX_AI_DIFFICULTY } from './game/ai';
+import { PALETTES } from './rendering/palette';
+import { MAX_ANIMATION_SPEED } from './animation/actions';
+import { Palette, MAX_TURN_TIME_LIMIT } from './redux/types';
+
+// localStorage key recording that the first-run tutorial has been shown
+const TUTORIAL_SEEN_KEY = 'quortex_tutorial_seen';
+
+// localStorage key remembering the chosen AI difficulty across restarts
+const AI_DIFFICULTY_KEY = 'quortex_ai_difficulty';
+
+// localStorage key remembering the chosen player colour palette across restarts
+const PALETTE_KEY = 'quortex_palette';
+
+// localStorage key remembering the chosen animation speed across restarts
+const ANIMATION_SPEED_KEY = 'quortex_animation_speed';
+
+// localStorage key remembering the chosen turn time limit across restarts
+const TURN_TIME_LIMIT_KEY = 'quortex_turn_time_limit';
+
+// localStorage key remembering whether sound effects are on across restarts
+const SOUND_EFFECTS_KEY = 'quortex_sound_effects';
 
 // Expose store to window for testing
 declare global {
@@ -61,15 +88,88 @@ function init() {
     render();
   });
 
+  // Restore the AI difficulty chosen in an earlier session
+  const savedDifficulty = Number(localStorage.getItem(AI_DIFFICULTY_KEY));
+  if (savedDifficulty >= MIN_AI_DIFFICULTY && savedDifficulty <= MAX_AI_DIFFICULTY) {
+    store.dispatch(updateSettings({ aiDifficulty: savedDifficulty }));
+  }
+  let prevAIDifficulty = store.getState().ui.settings.aiDifficulty;
+
+  // Restore the player colour palette chosen in an earlier session
+  const savedPalette = localStorage.getItem(PALETTE_KEY) as Palette | null;
+  if (savedPalette && PALETTES.includes(savedPalette)) {
+    store.dispatch(updateSettings({ palette: savedPalette }));
+  }
+  let prevPalette = store.getState().ui.settings.palette;
+
+  // Restore the animation speed chosen in an earlier session
+  const savedSpeed = localStorage.getItem(ANIMATION_SPEED_KEY);
+  if (savedSpeed !== null && Number(savedSpeed) >= 0 && Number(savedSpeed) <= MAX_ANIMATION_SPEED) {
+    store.dispatch(updateSettings({ animationSpeed: Number(savedSpeed) }));
+  }
+  let prevAnimationSpeed = store.getState().ui.settings.animationSpeed;
+
+  // Restore the turn time limit chosen in an earlier session
+  const savedTurnLimit = localStorage.getItem(TURN_TIME_LIMIT_KEY);
+  if (savedTurnLimit !== null && Number(savedTurnLimit) >= 0 && Number(savedTurnLimit) <= MAX_TURN_TIME_LIMIT) {
+    store.dispatch(updateSettings({ turnTimeLimit: Number(savedTurnLimit) }));
+  }
+  let prevTurnTimeLimit = store.getState().ui.settings.turnTimeLimit;
+
+  // Restore the sound effects choice from an earlier session
+  const savedSoundEffects = localStorage.getItem(SOUND_EFFECTS_KEY);
+  if (savedSoundEffects !== null) {
+    store.dispatch(updateSettings({ soundEffects: savedSoundEffects === 'true' }));
+  }
+  let prevSoundEffects = store.getState().ui.settings.soundEffects;
+
   // Track previous state for flow preview updates and screen transitions
   let prevSelectedPosition: HexPosition | null = null;
   let prevRotation: Rotation = 0;
   let prevScreen: string | null = null;
+  let prevMoveCount = 0;
   let supermoveAnimationActive = false;
 
   // Subscribe to store changes
   store.subscribe(() => {
     const state = store.getState();
+
+    // Remember the AI difficulty whenever it changes
+    if (state.ui.settings.aiDifficulty !== prevAIDifficulty) {
+      prevAIDifficulty = state.ui.settings.aiDifficulty;
+      localStorage.setItem(AI_DIFFICULTY_KEY, String(prevAIDifficulty));
+    }
+
+    // Remember the palette whenever it changes
+    if (state.ui.settings.palette !== prevPalette) {
+      prevPalette = state.ui.settings.palette;
+      localStorage.setItem(PALETTE_KEY, prevPalette);
+    }
+
+    // Remember the animation speed whenever it changes
+    if (state.ui.settings.animationSpeed !== prevAnimationSpeed) {
+      prevAnimationSpeed = state.ui.settings.animationSpeed;
+      localStorage.setItem(ANIMATION_SPEED_KEY, String(prevAnimationSpeed));
+    }
+
+    // Remember the turn time limit whenever it changes
+    if (state.ui.settings.turnTimeLimit !== prevTurnTimeLimit) {
+      prevTurnTimeLimit = state.ui.settings.turnTimeLimit;
+      localStorage.setItem(TURN_TIME_LIMIT_KEY, String(prevTurnTimeLimit));
+    }
+
+    // Remember the sound effects choice whenever it changes
+    if (state.ui.settings.soundEffects !== prevSoundEffects) {
+      prevSoundEffects = state.ui.settings.soundEffects;
+      localStorage.setItem(SOUND_EFFECTS_KEY, String(prevSoundEffects));
+    }
+    
+    // Show the tutorial the first time a game starts on this device
+    if (state.game.screen === 'gameplay' && prevScreen !== 'gameplay' && prevScreen !== 'game-over' &&
+        !localStorage.getItem(TUTORIAL_SEEN_KEY)) {
+      localStorage.setItem(TUTORIAL_SEEN_KEY, 'true');
+      store.dispatch(showTutorial());
+    }
     
     // Check if we transitioned to game-over screen
     if (state.game.screen === 'game-over' && prevScreen !== 'game-over') {
@@ -102,7 +202,8 @@ function init() {
           currentPlayer,
           state.game.players,
           state.game.teams,
-          state.game.boardRadius
+          state.game.boardRadius,
+          state.game.oppositeSidesVictory
         );
       }
       
@@ -128,6 +229,18 @@ function init() {
     
     prevScreen = state.game.screen;
     
+    // Fade in tiles placed by the AI, then animate their flows, so its moves are easy to follow
+    // (update the count first: starting the animation dispatches and re-enters this listener)
+    const moves = state.game.moveHistory;
+    const lastMove = moves.length > prevMoveCount ? moves[moves.length - 1] : null;
+    prevMoveCount = moves.length;
+    if (lastMove && state.game.players.find((p) => p.id === lastMove.playerId)?.isAI) {
+      animateArrivedMove(state.game, lastMove);
+    }
+    
+    // Swing the board round when it is reoriented to another player's edge during play
+    updateBoardRotation(state.game.screen === 'gameplay' ? getBoardRotationAngle(state) : null);
+    
     // Check if we should update flow preview
     if (state.game.screen === 'gameplay') {
       const selectedPos = state.ui.selectedPosition;
@@ -149,10 +262,15 @@ function init() {
 
   // Animation loop for smooth rendering
   let frameSkipCounter = 0;
+  const stepReplayPlayback = createReplayDriver(store);
+  let lastFrameTime = performance.now();
   
-  function animate() {
+  function animate(now: number = performance.now()) {
     requestAnimationFrame(animate);
     
+    const deltaMs = now - lastFrameTime;
+    lastFrameTime = now;
+    
     const state = store.getState();
     
     // Skip if paused (for debugging)
@@ -160,6 +278,9 @@ function init() {
       return;
     }
     
+    // Step a move list replay forward
+    stepReplayPlayback(deltaMs);
+    
     // Apply debug slowdown from settings or window override
     const slowdown = window.ANIMATIONS_DEBUG_SLOWDOWN || state.ui.settings.debugAnimationSlowdown;
     frameSkipCounter++;
@@ -1,6 +1,8 @@
 // Multiplayer game coordinator - handles event sourcing and Redux integration
 import { socket } from './socket';
 import { setLocalPlayerId, selectEdge, setUserIdMapping, addPlayer, startGame } from '../redux/actions';
+import type { PlaceTileAction, ReplaceTileAction } from '../redux/actions';
+import { validateTileAction, generateGameSeed } from '../redux/gameReducer';
 import { multiplayerStore } from './stores/multiplayerStore';
 
 // Interface for rematch information
@@ -12,6 +14,10 @@ interface RematchInfo {
     supermove: boolean;
     singleSupermove: boolean;
     supermoveAnyPlayer: boolean;
+    oppositeSidesVictory: boolean;
+    turnTimeLimit: number;
+    noSelfStalemate: boolean;
+    longestFlowVictory: boolean;
   };
 }
 
@@ -92,6 +98,10 @@ export class GameCoordinator {
       supermove: state.game?.supermove ?? true,
       singleSupermove: state.game?.singleSupermove ?? false,
       supermoveAnyPlayer: state.game?.supermoveAnyPlayer ?? false,
+      oppositeSidesVictory: state.game?.oppositeSidesVictory ?? false,
+      turnTimeLimit: state.game?.turnTimeLimit ?? 0,
+      noSelfStalemate: state.game?.noSelfStalemate ?? false,
+      longestFlowVictory: state.game?.longestFlowVictory ?? false,
     };
     
     // Get local player ID from UI state or coordinator's stored value
@@ -144,10 +154,16 @@ export class GameCoordinator {
 
   private handleRematchCreated(event: Event) {
     const customEvent = event as CustomEvent;
-    const { newGameId, oldGameId } = customEvent.detail;
+    const { newGameId, oldGameId, initiatorId } = customEvent.detail;
     
     console.log('[GameCoordinator] Rematch created, transitioning from', oldGameId, 'to', newGameId);
     
+    // Every player asked for this rematch, but only the one whose request completed
+    // the vote sets up the new game - the rest just rejoin it
+    if (this.rematchInfo && initiatorId !== multiplayerStore.get().playerId) {
+      this.rematchInfo.isInitiator = false;
+    }
+    
     // Spectators should NOT join immediately - they will be added after seating phase
     if (this.isSpectator) {
       console.log('[GameCoordinator] Spectator mode - will wait for rematch_spectator_rejoin event');
@@ -218,7 +234,7 @@ export class GameCoordinator {
       // Check if this is START_GAME from lobby Play button
       if (action.type === 'START_GAME' && !action.payload?.seed) {
         // Use server-provided seed if available, otherwise generate random seed
-        const seed = this.serverSeed !== undefined ? this.serverSeed : Math.floor(Math.random() * 1000000);
+        const seed = this.serverSeed !== undefined ? this.serverSeed : generateGameSeed();
         console.log(`Intercepted START_GAME, adding seed: ${seed}`);
         
         const actionWithSeed = {
@@ -244,6 +260,17 @@ export class GameCoordinator {
         return;
       }
       
+      // Check tile moves locally first so obviously illegal ones don't round-trip to the
+      // server only to be rejected; the server and every client's reducer still have the final say
+      if (action.type === 'PLACE_TILE' || action.type === 'REPLACE_TILE') {
+        const rejection = this.checkLocalTileAction(action);
+        if (rejection) {
+          console.warn(`[GameCoordinator] Not sending ${action.type}: ${rejection}`);
+          socket.showNotice(rejection);
+          return;
+        }
+      }
+      
       // Check if this is a player action that should be broadcast
       if (this.shouldBroadcastAction(action.type)) {
         console.log(`Broadcasting action: ${action.type}`);
@@ -269,6 +296,24 @@ export class GameCoordinator {
     };
   }
 
+  // Why a tile move made on this client would be refused, or null if it can be sent.
+  // The server stamps posted actions with our player ID, so validate as that player.
+  private checkLocalTileAction(action: PlaceTileAction | ReplaceTileAction): string | null {
+    const game = this.store.getState().game;
+    if (game.phase !== 'playing') {
+      return 'The game is not in progress';
+    }
+
+    const playerId = multiplayerStore.get().playerId ?? undefined;
+    const currentPlayer = game.players[game.currentPlayerIndex];
+    if (playerId !== undefined && currentPlayer && currentPlayer.userId !== playerId) {
+      return "It's not your turn";
+    }
+
+    const result = validateTileAction(game, { ...action, playerId });
+    return result.valid ? null : result.reason;
+  }
+
   private shouldBroadcastAction(actionType: string): boolean {
     // Actions that affect game state and should be broadcast
     const broadcastActions = [
@@ -286,6 +331,8 @@ export class GameCoordinator {
       'REPLACE_TILE',
       'DRAW_TILE',
       'NEXT_PLAYER',
+      'RESIGN',
+      'SWAP_SIDES',
       'END_GAME',
       // Other game flow actions
       'SETUP_GAME',
@@ -359,14 +406,18 @@ export class GameCoordinator {
         // Step 2: Send START_GAME with game settings (wait a bit for ADD_PLAYER actions to be broadcast)
         setTimeout(() => {
           // Use server-provided seed if available, otherwise generate random seed
-          const seed = this.serverSeed !== undefined ? this.serverSeed : Math.floor(Math.random() * 1000000);
+          const seed = this.serverSeed !== undefined ? this.serverSeed : generateGameSeed();
           console.log('[GameCoordinator] Posting START_GAME with seed:', seed, 'and settings:', gameSettings);
           socket.postAction(gameId, startGame({
             seed,
             boardRadius: gameSettings.boardRadius,
             supermove: gameSettings.supermove,
             singleSupermove: gameSettings.singleSupermove,
-            supermoveAnyPlayer: gameSettings.supermoveAnyPlayer
+            supermoveAnyPlayer: gameSettings.supermoveAnyPlayer,
+            oppositeSidesVictory: gameSettings.oppositeSidesVictory,
+            turnTimeLimit: gameSettings.turnTimeLimit,
+            noSelfStalemate: gameSettings.noSelfStalemate,
+            longestFlowVictory: gameSettings.longestFlowVictory
           }));
           // SELECT_EDGE will be posted when START_GAME is received and seating order is set
         }, 200);
@@ -414,9 +465,11 @@ export class GameCoordinator {
         this.realOriginalDispatch.call(this.store, setUserIdMapping(mapping));
       }
       
+      // Keep the server's stamp of who posted the action so the reducer can check it
       this.realOriginalDispatch.call(this.store, {
         type: action.type,
-        payload: action.payload
+        payload: action.payload,
+        playerId: action.playerId
       });
       
       this.localActionsProcessed = action.sequence + 1;
@@ -441,7 +494,8 @@ export class GameCoordinator {
       if (action.sequence >= this.localActionsProcessed && this.store && this.realOriginalDispatch) {
         this.realOriginalDispatch.call(this.store, {
           type: action.type,
-          payload: action.payload
+          payload: action.payload,
+          playerId: action.playerId
         });
         this.localActionsProcessed = action.sequence + 1;
         
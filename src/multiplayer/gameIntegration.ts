@@ -7,19 +7,21 @@ import { GameplayInputHandler } from '../input/gameplayInputHandler';
 import { incrementFrame } from '../animation/actions';
 import { processAnimations } from '../animation/processor';
 import { updateFlowPreview } from '../animation/flowPreview';
+import { updateBoardRotation } from '../animation/rotationAnimations';
+import { getBoardRotationAngle } from '../rendering/boardOrientation';
+import { detectNewPlacedTile, animateArrivedMove } from '../animation/placementAnimations';
+import { createReplayDriver } from '../animation/replay';
 import { HexPosition, Rotation } from '../game/types';
 import { positionToKey } from '../game/board';
 import { isPlayerBlocked } from '../game/legality';
 import { GameCoordinator } from './gameCoordinator';
-import { socket } from './socket';
-import { multiplayerStore } from './stores/multiplayerStore';
+import { leaveGame, LEAVE_GAME_EVENT } from './leaveGame';
 
 let renderer: Renderer | null = null;
 let inputHandler: InputHandler | null = null;
 let gameCoordinator: GameCoordinator | null = null;
 let animationId: number | null = null;
 let unsubscribe: (() => void) | null = null;
-let spectatorExitHandler: (() => void) | null = null;
 
 export function initGame(gameId: string) {
   const canvas = document.getElementById('game-canvas') as HTMLCanvasElement;
@@ -46,24 +48,15 @@ export function initGame(gameId: string) {
   gameCoordinator = new GameCoordinator(store, gameId);
   gameCoordinator.start();
 
-  // Handle spectator exit event
-  spectatorExitHandler = () => {
-    const mpState = multiplayerStore.get();
-    if (mpState.isSpectator && mpState.gameId) {
-      // Spectator: leave spectator mode and return to lobby
-      socket.leaveSpectator(mpState.gameId);
-      multiplayerStore.setIsSpectator(false);
-      multiplayerStore.setScreen('lobby');
-    }
-  };
-  
-  window.addEventListener('multiplayer:spectator-exit', spectatorExitHandler);
+  // Handle the exit button: leave the room or stop spectating and return to the lobby
+  window.addEventListener(LEAVE_GAME_EVENT, leaveGame);
 
   // Track previous state for flow preview updates and screen transitions
   let prevSelectedPosition: HexPosition | null = null;
   let prevRotation: Rotation = 0;
   let prevScreen: string | null = null;
   let supermoveAnimationActive = false;
+  let prevMoveCount = 0;
 
   // Subscribe to store changes
   if (unsubscribe) {
@@ -104,7 +97,8 @@ export function initGame(gameId: string) {
           currentPlayer,
           state.game.players,
           state.game.teams,
-          state.game.boardRadius
+          state.game.boardRadius,
+          state.game.oppositeSidesVictory
         );
       }
       
@@ -130,6 +124,17 @@ export function initGame(gameId: string) {
     
     prevScreen = state.game.screen;
     
+    // Fade in tiles placed by other players, then animate their flows, so the change is noticeable
+    // (update the count first: starting the animation dispatches and re-enters this listener)
+    const newMove = detectNewPlacedTile(prevMoveCount, state.game.moveHistory, state.ui.localPlayerId);
+    prevMoveCount = state.game.moveHistory.length;
+    if (newMove) {
+      animateArrivedMove(state.game, newMove);
+    }
+    
+    // Swing the board round when it is reoriented to another player's edge during play
+    updateBoardRotation(state.game.screen === 'gameplay' ? getBoardRotationAngle(state) : null);
+    
     // Check if we should update flow preview
     if (state.game.screen === 'gameplay') {
       const selectedPos = state.ui.selectedPosition;
@@ -176,10 +181,15 @@ function render() {
 
 function startAnimationLoop() {
   let frameSkipCounter = 0;
+  const stepReplayPlayback = createReplayDriver(store);
+  let lastFrameTime = performance.now();
   
-  function animate() {
+  function animate(now: number = performance.now()) {
     animationId = requestAnimationFrame(animate);
     
+    const deltaMs = now - lastFrameTime;
+    lastFrameTime = now;
+    
     const state = store.getState();
     
     // Skip if paused (for debugging)
@@ -187,6 +197,9 @@ function startAnimationLoop() {
       return;
     }
     
+    // Step a move list replay forward
+    stepReplayPlayback(deltaMs);
+    
     // Apply debug slowdown from settings or window override
     const slowdown = (window as any).ANIMATIONS_DEBUG_SLOWDOWN || state.ui.settings.debugAnimationSlowdown;
     frameSkipCounter++;
@@ -231,10 +244,7 @@ export function cleanup() {
     unsubscribe = null;
   }
   
-  if (spectatorExitHandler) {
-    window.removeEventListener('multiplayer:spectator-exit', spectatorExitHandler);
-    spectatorExitHandler = null;
-  }
+  window.removeEventListener(LEAVE_GAME_EVENT, leaveGame);
   
   if (gameCoordinator) {
     gameCoordinator.stop();
@@ -0,0 +1,32 @@
+// Leaving a multiplayer game from the canvas exit button
+
+import { store } from '../redux/store';
+import { resetGame } from '../redux/actions';
+import { socket } from './socket';
+import { multiplayerStore } from './stores/multiplayerStore';
+
+// Window event raised by the gameplay input handler when the exit button is clicked
+export const LEAVE_GAME_EVENT = 'multiplayer:leave-game';
+
+/**
+ * Leave the current game and return to the lobby
+ * Players leave the room, so the server hands an in-progress seat to the AI, and
+ * spectators stop spectating; either way the server stops sending the game's updates
+ */
+export function leaveGame(): void {
+  const { gameId, isSpectator } = multiplayerStore.get();
+
+  if (gameId) {
+    if (isSpectator) {
+      socket.leaveSpectator(gameId);
+    } else {
+      socket.leaveRoom(gameId);
+    }
+  }
+
+  // Leaving the game screen stops the game coordinator
+  multiplayerStore.setScreen('lobby');
+  multiplayerStore.setIsSpectator(false);
+  multiplayerStore.setCurrentRoom(null);
+  store.dispatch(resetGame());
+}
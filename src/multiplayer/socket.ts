@@ -1,19 +1,25 @@
 // Socket.IO client for multiplayer
 import { io, Socket } from "socket.io-client";
 import { multiplayerStore } from "./stores/multiplayerStore";
-import type { Room, Player } from "./stores/multiplayerStore";
+import type { Room, Player, ChatMessage } from "./stores/multiplayerStore";
 import { store } from "../redux/store";
 import {
   setPlayerConnected,
   setPlayerDisconnected,
   setSpectatorCount,
+  setRematchVotes,
+  setServerNotice,
 } from "../redux/actions";
 
+// How long a notice from the server stays on screen
+const SERVER_NOTICE_DURATION_MS = 4000;
+
 class MultiplayerSocket {
   private socket: Socket | null = null;
   private serverUrl: string;
   private useDiscordProxy: boolean = false;
   private heartbeatInterval: NodeJS.Timeout | null = null;
+  private serverNoticeTimer: ReturnType<typeof setTimeout> | null = null;
 
   constructor() {
     // Use environment variable or auto-detect based on current protocol
@@ -246,6 +252,10 @@ class MultiplayerSocket {
       multiplayerStore.setCurrentRoom(data.room);
     });
 
+    this.socket.on("room_updated", (data: { room: Room }) => {
+      multiplayerStore.setCurrentRoom(data.room);
+    });
+
     this.socket.on(
       "player_disconnected",
       (data: { playerId: string; username: string }) => {
@@ -307,9 +317,11 @@ class MultiplayerSocket {
         name: string;
         maxPlayers: number;
         hostId: string;
+        initiatorId: string;
         players: Array<{ id: string; username: string }>;
       }) => {
         console.log("Rematch created:", data.newGameId);
+        store.dispatch(setRematchVotes(null));
 
         // Broadcast rematch event to the game coordinator
         window.dispatchEvent(
@@ -320,6 +332,30 @@ class MultiplayerSocket {
       },
     );
 
+    // Rematch requested - server is waiting for the remaining players to agree
+    this.socket.on(
+      "rematch_requested",
+      (data: {
+        gameId: string;
+        requestedBy: string;
+        ready: number;
+        total: number;
+        waitingFor: string[];
+      }) => {
+        console.log(
+          "Rematch requested by",
+          data.requestedBy,
+          `(${data.ready}/${data.total} ready)`,
+        );
+        store.dispatch(setRematchVotes({ ready: data.ready, total: data.total }));
+      },
+    );
+
+    // Room chat from players and spectators
+    this.socket.on("chat_broadcast", (data: ChatMessage) => {
+      multiplayerStore.addChatMessage(data);
+    });
+
     // Spectator events
     this.socket.on(
       "spectator_joined",
@@ -410,6 +446,23 @@ class MultiplayerSocket {
       alert(data.message);
     });
 
+    // The server refused a posted action - show why so desyncs are visible
+    this.socket.on(
+      "invalid_request",
+      (data: {
+        gameId: string;
+        actionType?: string;
+        reason: string;
+        message: string;
+      }) => {
+        console.warn(
+          `Server rejected ${data.actionType} (${data.reason}):`,
+          data.message,
+        );
+        this.showNotice(data.message);
+      },
+    );
+
     // Error handling
     this.socket.on("error", (data: { message: string }) => {
       console.error("Server error:", data.message);
@@ -417,6 +470,16 @@ class MultiplayerSocket {
     });
   }
 
+  // Briefly show a notice over the game, replacing any notice already showing
+  showNotice(message: string) {
+    store.dispatch(setServerNotice(message));
+    if (this.serverNoticeTimer) clearTimeout(this.serverNoticeTimer);
+    this.serverNoticeTimer = setTimeout(() => {
+      this.serverNoticeTimer = null;
+      store.dispatch(setServerNotice(null));
+    }, SERVER_NOTICE_DURATION_MS);
+  }
+
   identify(username: string) {
     if (!this.socket) return;
     this.socket.emit("identify", { username });
@@ -464,6 +527,29 @@ class MultiplayerSocket {
     }
   }
 
+  // Ask the server for an open room with matching settings (created if none exist)
+  async quickPlay(
+    maxPlayers: number,
+    playerId: string,
+    username: string | null,
+  ): Promise<string | null> {
+    try {
+      // Use Discord proxy if enabled, otherwise use server URL
+      const baseUrl = this.useDiscordProxy ? "/.proxy" : this.serverUrl;
+
+      const response = await fetch(`${baseUrl}/api/rooms/quick-play`, {
+        method: "POST",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify({ maxPlayers, playerId, username }),
+      });
+      const data = await response.json();
+      return data.room?.id || null;
+    } catch (error) {
+      console.error("Failed to quick play:", error);
+      return null;
+    }
+  }
+
   joinRoom(roomId: string) {
     if (!this.socket) return;
     this.socket.emit("join_room", { roomId });
@@ -474,6 +560,17 @@ class MultiplayerSocket {
     this.socket.emit("leave_room", { roomId });
   }
 
+  // Stop receiving a room's updates while keeping any seat in it
+  unsubscribeRoom(roomId: string) {
+    if (!this.socket) return;
+    this.socket.emit("unsubscribe_room", { roomId });
+  }
+
+  setPlayerColor(roomId: string, color: string) {
+    if (!this.socket) return;
+    this.socket.emit("set_player_color", { roomId, color });
+  }
+
   startGame(roomId: string) {
     if (!this.socket) return;
     this.socket.emit("start_game", { roomId });
@@ -495,6 +592,11 @@ class MultiplayerSocket {
     this.socket.emit("request_rematch", { gameId });
   }
 
+  sendChatMessage(gameId: string, text: string) {
+    if (!this.socket) return;
+    this.socket.emit("chat_message", { gameId, text });
+  }
+
   // Spectator methods
   joinAsSpectator(gameId: string) {
     if (!this.socket) return;
@@ -5,6 +5,7 @@ import { Router } from '../router';
 export interface Player {
   id: string;
   username: string;
+  color?: string; // Flow color assigned in the room (resolved so no two players match)
 }
 
 export interface Room {
@@ -18,6 +19,17 @@ export interface Room {
   spectatorCount?: number; // Number of spectators watching
 }
 
+export interface ChatMessage {
+  gameId: string;
+  playerId: string;
+  username: string;
+  text: string;
+  timestamp: number;
+}
+
+// Only the most recent chat messages are kept
+export const MAX_CHAT_MESSAGES = 50;
+
 export interface MultiplayerState {
   connected: boolean;
   connectionStatus: 'connected' | 'disconnected' | 'reconnecting' | 'connected_elsewhere';
@@ -31,6 +43,7 @@ export interface MultiplayerState {
   disconnectedPlayers: Set<string>; // Track which players are disconnected
   isSpectator: boolean; // Whether current user is spectating
   spectatorCount: number; // Number of spectators in current game
+  chatMessages: ChatMessage[]; // Recent room chat, oldest first
 }
 
 const initialState: MultiplayerState = {
@@ -46,6 +59,7 @@ const initialState: MultiplayerState = {
   disconnectedPlayers: new Set(),
   isSpectator: false,
   spectatorCount: 0,
+  chatMessages: [],
 };
 
 // Create the main store
@@ -134,6 +148,12 @@ function createMultiplayerStore() {
     setSpectatorCount: (spectatorCount: number) =>
       update(state => ({ ...state, spectatorCount })),
     
+    addChatMessage: (message: ChatMessage) =>
+      update(state => ({
+        ...state,
+        chatMessages: [...state.chatMessages, message].slice(-MAX_CHAT_MESSAGES)
+      })),
+    
     reset: () => set(initialState),
   };
 }
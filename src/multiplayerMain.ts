@@ -1,6 +1,7 @@
 // Multiplayer entry point
 import { mount } from 'svelte';
 import App from './multiplayer/App.svelte';
+import ChatPanel from './multiplayer/components/ChatPanel.svelte';
 import { store } from './redux/store';
 import { Renderer } from './rendering/renderer';
 import { InputHandler } from './input/inputHandler';
@@ -8,11 +9,16 @@ import { GameplayInputHandler } from './input/gameplayInputHandler';
 import { incrementFrame } from './animation/actions';
 import { processAnimations } from './animation/processor';
 import { updateFlowPreview } from './animation/flowPreview';
+import { updateBoardRotation } from './animation/rotationAnimations';
+import { getBoardRotationAngle } from './rendering/boardOrientation';
+import { detectNewPlacedTile, animateArrivedMove } from './animation/placementAnimations';
+import { createReplayDriver } from './animation/replay';
 import { HexPosition, Rotation } from './game/types';
 import { positionToKey } from './game/board';
 import { isPlayerBlocked } from './game/legality';
 import { multiplayerStore } from './multiplayer/stores/multiplayerStore';
 import { GameCoordinator } from './multiplayer/gameCoordinator';
+import { leaveGame, LEAVE_GAME_EVENT } from './multiplayer/leaveGame';
 import { setGameMode, resetGame, setSpectatorMode } from './redux/actions';
 import { Router } from './multiplayer/router';
 import { socket } from './multiplayer/socket';
@@ -43,6 +49,14 @@ const app = mount(App, {
   target: svelteRoot,
 });
 
+// Chat floats over the game canvas, so it lives outside the Svelte UI that is hidden during games
+const chatRoot = document.getElementById('chat-panel');
+if (chatRoot) {
+  mount(ChatPanel, {
+    target: chatRoot,
+  });
+}
+
 // Handle URL restoration with authentication
 // Deep linking requires socket connection first
 async function handleDeepLink() {
@@ -208,8 +222,9 @@ multiplayerStore.subscribe((state) => {
         gameCoordinator.stop();
         
         // Reset the Redux game state when transitioning to a new game
+        // This is a rematch rather than a return to the menu, so the series scores carry over
         console.log('[multiplayerMain] Resetting game state for new game');
-        store.dispatch(resetGame());
+        store.dispatch(resetGame(true));
       }
       
       console.log('[multiplayerMain] Creating new coordinator for gameId:', state.gameId);
@@ -222,8 +237,11 @@ multiplayerStore.subscribe((state) => {
     if (svelteRoot) svelteRoot.style.display = 'block';
     canvas.classList.remove('active');
     
-    // Clean up coordinator if leaving game
+    // Clean up coordinator and stop the room's updates if leaving game
     if (gameCoordinator && state.screen !== 'game') {
+      if (currentGameId) {
+        socket.unsubscribeRoom(currentGameId);
+      }
       gameCoordinator.stop();
       gameCoordinator = null;
       currentGameId = null;
@@ -268,11 +286,15 @@ function initializeGame() {
     }
   });
 
+  // Handle the exit button: leave the room or stop spectating and return to the lobby
+  window.addEventListener(LEAVE_GAME_EVENT, leaveGame);
+
   // Track previous state for flow preview updates and screen transitions
   let prevSelectedPosition: HexPosition | null = null;
   let prevRotation: Rotation = 0;
   let prevScreen: string | null = null;
   let supermoveAnimationActive = false;
+  let prevMoveCount = 0;
 
   // Subscribe to store changes
   store.subscribe(() => {
@@ -309,7 +331,8 @@ function initializeGame() {
           currentPlayer,
           state.game.players,
           state.game.teams,
-          state.game.boardRadius
+          state.game.boardRadius,
+          state.game.oppositeSidesVictory
         );
       }
       
@@ -335,6 +358,17 @@ function initializeGame() {
     
     prevScreen = state.game.screen;
     
+    // Fade in tiles placed by other players, then animate their flows, so the change is noticeable
+    // (update the count first: starting the animation dispatches and re-enters this listener)
+    const newMove = detectNewPlacedTile(prevMoveCount, state.game.moveHistory, state.ui.localPlayerId);
+    prevMoveCount = state.game.moveHistory.length;
+    if (newMove) {
+      animateArrivedMove(state.game, newMove);
+    }
+    
+    // Swing the board round when it is reoriented to another player's edge during play
+    updateBoardRotation(state.game.screen === 'gameplay' ? getBoardRotationAngle(state) : null);
+    
     // Check if we should update flow preview
     if (state.game.screen === 'gameplay') {
       const selectedPos = state.ui.selectedPosition;
@@ -356,10 +390,15 @@ function initializeGame() {
 
   // Animation loop for smooth rendering
   let frameSkipCounter = 0;
+  const stepReplayPlayback = createReplayDriver(store);
+  let lastFrameTime = performance.now();
   
-  function animate() {
+  function animate(now: number = performance.now()) {
     requestAnimationFrame(animate);
     
+    const deltaMs = now - lastFrameTime;
+    lastFrameTime = now;
+    
     const state = store.getState();
     
     // Skip if paused (for debugging)
@@ -367,6 +406,9 @@ function initializeGame() {
       return;
     }
     
+    // Step a move list replay forward
+    stepReplayPlayback(deltaMs);
+    
     // Apply debug slowdown from settings or window override
     const slowdown = window.ANIMATIONS_DEBUG_SLOWDOWN || state.ui.settings.debugAnimationSlowdown;
     frameSkipCounter++;
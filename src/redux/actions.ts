@@ -1,12 +1,13 @@
 // Redux action types and action creators
 
 import { HexPosition, Rotation, Player, Team } from "../game/types";
-import { GameSettings } from "./types";
+import { GameSettings, SHUFFLE_VERSION } from "./types";
 
 // Configuration actions
 export const ADD_PLAYER = "ADD_PLAYER";
 export const REMOVE_PLAYER = "REMOVE_PLAYER";
 export const CHANGE_PLAYER_COLOR = "CHANGE_PLAYER_COLOR";
+export const SET_PLAYER_AI = "SET_PLAYER_AI";
 export const START_GAME = "START_GAME";
 export const RETURN_TO_CONFIG = "RETURN_TO_CONFIG";
 
@@ -33,6 +34,12 @@ export const END_GAME = "END_GAME";
 export const RESET_GAME = "RESET_GAME";
 export const REMATCH_GAME = "REMATCH_GAME";
 export const RESTORE_GAME = "RESTORE_GAME";
+export const HAND_OVER_TO_AI = "HAND_OVER_TO_AI";
+export const RECLAIM_FROM_AI = "RECLAIM_FROM_AI";
+export const RESIGN = "RESIGN";
+export const TURN_TIMEOUT = "TURN_TIMEOUT";
+export const UNDO_MOVE = "UNDO_MOVE";
+export const SWAP_SIDES = "SWAP_SIDES";
 
 // UI actions
 export const SET_GAME_MODE = "SET_GAME_MODE";
@@ -42,13 +49,20 @@ export const SET_HOVERED_ELEMENT = "SET_HOVERED_ELEMENT";
 export const SET_SELECTED_POSITION = "SET_SELECTED_POSITION";
 export const SET_ROTATION = "SET_ROTATION";
 export const TOGGLE_LEGAL_MOVES = "TOGGLE_LEGAL_MOVES";
+export const TOGGLE_LEGAL_MOVES_ROTATION = "TOGGLE_LEGAL_MOVES_ROTATION";
 export const TOGGLE_SETTINGS = "TOGGLE_SETTINGS";
 export const UPDATE_SETTINGS = "UPDATE_SETTINGS";
 export const SHOW_HELP = "SHOW_HELP";
 export const HIDE_HELP = "HIDE_HELP";
+export const SHOW_TUTORIAL = "SHOW_TUTORIAL";
+export const HIDE_TUTORIAL = "HIDE_TUTORIAL";
 export const SHOW_MOVE_LIST = "SHOW_MOVE_LIST";
 export const HIDE_MOVE_LIST = "HIDE_MOVE_LIST";
 export const NAVIGATE_MOVE_LIST = "NAVIGATE_MOVE_LIST";
+export const JUMP_TO_MOVE = "JUMP_TO_MOVE";
+export const TOGGLE_REPLAY = "TOGGLE_REPLAY";
+export const STEP_REPLAY = "STEP_REPLAY";
+export const SET_REPLAY_SPEED = "SET_REPLAY_SPEED";
 
 // Player connection actions
 export const SET_PLAYER_CONNECTED = "SET_PLAYER_CONNECTED";
@@ -58,6 +72,12 @@ export const SET_USER_ID_MAPPING = "SET_USER_ID_MAPPING";
 // Spectator mode actions
 export const SET_SPECTATOR_MODE = "SET_SPECTATOR_MODE";
 export const SET_SPECTATOR_COUNT = "SET_SPECTATOR_COUNT";
+export const SET_REMATCH_VOTES = "SET_REMATCH_VOTES";
+export const SET_SERVER_NOTICE = "SET_SERVER_NOTICE";
+export const SET_AI_THINKING = "SET_AI_THINKING";
+export const SET_ORIENTATION_OVERRIDE = "SET_ORIENTATION_OVERRIDE";
+export const SHOW_HINT = "SHOW_HINT";
+export const HIDE_HINT = "HIDE_HINT";
 
 // Configuration action types
 export interface AddPlayerAction {
@@ -86,6 +106,14 @@ export interface ChangePlayerColorAction {
   };
 }
 
+export interface SetPlayerAIAction {
+  type: typeof SET_PLAYER_AI;
+  payload: {
+    playerId: string;
+    isAI: boolean;
+  };
+}
+
 export interface StartGameAction {
   type: typeof START_GAME;
   payload?: {
@@ -94,6 +122,11 @@ export interface StartGameAction {
     supermove?: boolean;
     singleSupermove?: boolean;
     supermoveAnyPlayer?: boolean;
+    oppositeSidesVictory?: boolean;
+    turnTimeLimit?: number;
+    noSelfStalemate?: boolean;
+    longestFlowVictory?: boolean;
+    shuffleVersion?: number; // Stamped by startGame; missing from logs recorded before versioning
   };
 }
 
@@ -173,12 +206,15 @@ export interface EndGameAction {
   type: typeof END_GAME;
   payload: {
     winners: string[];
-    winType: "flow" | "constraint" | "tie";
+    winType: "flow" | "constraint" | "tie" | "draw" | "resignation";
   };
 }
 
 export interface ResetGameAction {
   type: typeof RESET_GAME;
+  payload?: {
+    keepSessionScores?: boolean; // Going straight into a rematch, so the series carries on
+  };
 }
 
 export interface RematchGameAction {
@@ -189,6 +225,45 @@ export interface RestoreGameAction {
   type: typeof RESTORE_GAME;
 }
 
+export interface HandOverToAIAction {
+  type: typeof HAND_OVER_TO_AI;
+  payload: {
+    playerId: string; // Player ID or multiplayer user ID of the departing player
+    aiDifficulty?: number; // Strength every client plays the seat at (defaults to the strongest AI)
+  };
+}
+
+export interface ReclaimFromAIAction {
+  type: typeof RECLAIM_FROM_AI;
+  payload: {
+    playerId: string; // Player ID or multiplayer user ID of the returning player
+  };
+}
+
+export interface ResignAction {
+  type: typeof RESIGN;
+  payload: {
+    playerId: string;
+  };
+  playerId?: string; // Optional: User ID who performed the action (added by server in multiplayer)
+}
+
+export interface TurnTimeoutAction {
+  type: typeof TURN_TIMEOUT;
+}
+
+export interface UndoMoveAction {
+  type: typeof UNDO_MOVE;
+}
+
+export interface SwapSidesAction {
+  type: typeof SWAP_SIDES;
+  payload: {
+    playerId: string; // The second player, taking over the first player's side
+  };
+  playerId?: string; // Optional: User ID who performed the action (added by server in multiplayer)
+}
+
 // AI debug action types
 export interface SetAIScoringDataAction {
   type: typeof SET_AI_SCORING_DATA;
@@ -230,6 +305,10 @@ export interface ToggleLegalMovesAction {
   type: typeof TOGGLE_LEGAL_MOVES;
 }
 
+export interface ToggleLegalMovesRotationAction {
+  type: typeof TOGGLE_LEGAL_MOVES_ROTATION;
+}
+
 export interface ToggleSettingsAction {
   type: typeof TOGGLE_SETTINGS;
 }
@@ -250,6 +329,14 @@ export interface HideHelpAction {
   type: typeof HIDE_HELP;
 }
 
+export interface ShowTutorialAction {
+  type: typeof SHOW_TUTORIAL;
+}
+
+export interface HideTutorialAction {
+  type: typeof HIDE_TUTORIAL;
+}
+
 export interface ShowMoveListAction {
   type: typeof SHOW_MOVE_LIST;
   payload: {
@@ -268,6 +355,35 @@ export interface NavigateMoveListAction {
   };
 }
 
+export interface JumpToMoveAction {
+  type: typeof JUMP_TO_MOVE;
+  payload: {
+    index: number; // Number of moves to show; moveCount or more means current state
+    moveCount: number;
+  };
+}
+
+export interface ToggleReplayAction {
+  type: typeof TOGGLE_REPLAY;
+  payload: {
+    moveCount: number;
+  };
+}
+
+export interface StepReplayAction {
+  type: typeof STEP_REPLAY;
+  payload: {
+    moveCount: number;
+  };
+}
+
+export interface SetReplaySpeedAction {
+  type: typeof SET_REPLAY_SPEED;
+  payload: {
+    speed: number;
+  };
+}
+
 // Player connection action types
 export interface SetPlayerConnectedAction {
   type: typeof SET_PLAYER_CONNECTED;
@@ -304,11 +420,52 @@ export interface SetSpectatorCountAction {
   };
 }
 
+export interface SetRematchVotesAction {
+  type: typeof SET_REMATCH_VOTES;
+  payload: {
+    votes: { ready: number; total: number } | null;
+  };
+}
+
+export interface SetServerNoticeAction {
+  type: typeof SET_SERVER_NOTICE;
+  payload: {
+    message: string | null;
+  };
+}
+
+export interface SetAIThinkingAction {
+  type: typeof SET_AI_THINKING;
+  payload: {
+    thinking: boolean;
+  };
+}
+
+export interface SetOrientationOverrideAction {
+  type: typeof SET_ORIENTATION_OVERRIDE;
+  payload: {
+    playerId: string | null;
+  };
+}
+
+export interface ShowHintAction {
+  type: typeof SHOW_HINT;
+  payload: {
+    position: HexPosition;
+    rotation: Rotation;
+  };
+}
+
+export interface HideHintAction {
+  type: typeof HIDE_HINT;
+}
+
 // Combined action type
 export type GameAction =
   | AddPlayerAction
   | RemovePlayerAction
   | ChangePlayerColorAction
+  | SetPlayerAIAction
   | StartGameAction
   | ReturnToConfigAction
   | SetupGameAction
@@ -324,6 +481,12 @@ export type GameAction =
   | ResetGameAction
   | RematchGameAction
   | RestoreGameAction
+  | HandOverToAIAction
+  | ReclaimFromAIAction
+  | ResignAction
+  | TurnTimeoutAction
+  | UndoMoveAction
+  | SwapSidesAction
   | SetAIScoringDataAction;
 
 export type UIAction =
@@ -334,18 +497,31 @@ export type UIAction =
   | SetSelectedPositionAction
   | SetRotationAction
   | ToggleLegalMovesAction
+  | ToggleLegalMovesRotationAction
   | ToggleSettingsAction
   | UpdateSettingsAction
   | ShowHelpAction
   | HideHelpAction
+  | ShowTutorialAction
+  | HideTutorialAction
   | ShowMoveListAction
   | HideMoveListAction
   | NavigateMoveListAction
+  | JumpToMoveAction
+  | ToggleReplayAction
+  | StepReplayAction
+  | SetReplaySpeedAction
   | SetPlayerConnectedAction
   | SetPlayerDisconnectedAction
   | SetUserIdMappingAction
   | SetSpectatorModeAction
-  | SetSpectatorCountAction;
+  | SetSpectatorCountAction
+  | SetRematchVotesAction
+  | SetServerNoticeAction
+  | SetAIThinkingAction
+  | SetOrientationOverrideAction
+  | ShowHintAction
+  | HideHintAction;
 
 // Configuration action creators
 export const addPlayer = (color: string, edge: number, playerId?: string, userId?: string): AddPlayerAction => ({
@@ -366,24 +542,33 @@ export const changePlayerColor = (
   payload: { playerId, color },
 });
 
+export const setPlayerAI = (playerId: string, isAI: boolean): SetPlayerAIAction => ({
+  type: SET_PLAYER_AI,
+  payload: { playerId, isAI },
+});
+
 export const startGame = (params?: {
   boardRadius?: number;
   seed?: number;
   supermove?: boolean;
   singleSupermove?: boolean;
   supermoveAnyPlayer?: boolean;
+  oppositeSidesVictory?: boolean;
+  turnTimeLimit?: number;
+  noSelfStalemate?: boolean;
+  longestFlowVictory?: boolean;
 } | number): StartGameAction => {
   // Handle legacy call with just boardRadius number
   if (typeof params === 'number') {
     return {
       type: START_GAME,
-      payload: { boardRadius: params },
+      payload: { boardRadius: params, shuffleVersion: SHUFFLE_VERSION },
     };
   }
   
   return {
     type: START_GAME,
-    payload: params,
+    payload: { ...params, shuffleVersion: SHUFFLE_VERSION },
   };
 };
 
@@ -457,14 +642,15 @@ export const nextPlayer = (): NextPlayerAction => ({
 
 export const endGame = (
   winners: string[],
-  winType: "flow" | "constraint" | "tie",
+  winType: "flow" | "constraint" | "tie" | "draw" | "resignation",
 ): EndGameAction => ({
   type: END_GAME,
   payload: { winners, winType },
 });
 
-export const resetGame = (): ResetGameAction => ({
+export const resetGame = (keepSessionScores = false): ResetGameAction => ({
   type: RESET_GAME,
+  payload: { keepSessionScores },
 });
 
 export const rematchGame = (): RematchGameAction => ({
@@ -475,6 +661,34 @@ export const restoreGame = (): RestoreGameAction => ({
   type: RESTORE_GAME,
 });
 
+export const handOverToAI = (playerId: string, aiDifficulty?: number): HandOverToAIAction => ({
+  type: HAND_OVER_TO_AI,
+  payload: { playerId, aiDifficulty },
+});
+
+export const reclaimFromAI = (playerId: string): ReclaimFromAIAction => ({
+  type: RECLAIM_FROM_AI,
+  payload: { playerId },
+});
+
+export const resign = (playerId: string): ResignAction => ({
+  type: RESIGN,
+  payload: { playerId },
+});
+
+export const turnTimeout = (): TurnTimeoutAction => ({
+  type: TURN_TIMEOUT,
+});
+
+export const undoMove = (): UndoMoveAction => ({
+  type: UNDO_MOVE,
+});
+
+export const swapSides = (playerId: string): SwapSidesAction => ({
+  type: SWAP_SIDES,
+  payload: { playerId },
+});
+
 // UI action creators
 export const setGameMode = (
   mode: import("./types").GameMode,
@@ -520,6 +734,10 @@ export const toggleLegalMoves = (): ToggleLegalMovesAction => ({
   type: TOGGLE_LEGAL_MOVES,
 });
 
+export const toggleLegalMovesRotation = (): ToggleLegalMovesRotationAction => ({
+  type: TOGGLE_LEGAL_MOVES_ROTATION,
+});
+
 export const toggleSettings = (): ToggleSettingsAction => ({
   type: TOGGLE_SETTINGS,
 });
@@ -540,6 +758,14 @@ export const hideHelp = (): HideHelpAction => ({
   type: HIDE_HELP,
 });
 
+export const showTutorial = (): ShowTutorialAction => ({
+  type: SHOW_TUTORIAL,
+});
+
+export const hideTutorial = (): HideTutorialAction => ({
+  type: HIDE_TUTORIAL,
+});
+
 export const showMoveList = (corner: 0 | 1 | 2 | 3): ShowMoveListAction => ({
   type: SHOW_MOVE_LIST,
   payload: { corner },
@@ -556,6 +782,29 @@ export const navigateMoveList = (
   payload: { direction },
 });
 
+export const jumpToMove = (
+  index: number,
+  moveCount: number,
+): JumpToMoveAction => ({
+  type: JUMP_TO_MOVE,
+  payload: { index, moveCount },
+});
+
+export const toggleReplay = (moveCount: number): ToggleReplayAction => ({
+  type: TOGGLE_REPLAY,
+  payload: { moveCount },
+});
+
+export const stepReplay = (moveCount: number): StepReplayAction => ({
+  type: STEP_REPLAY,
+  payload: { moveCount },
+});
+
+export const setReplaySpeed = (speed: number): SetReplaySpeedAction => ({
+  type: SET_REPLAY_SPEED,
+  payload: { speed },
+});
+
 // AI debug action creators
 export const setAIScoringData = (
   data: Record<string, { rotation: number; score: number }[]> | undefined,
@@ -590,3 +839,35 @@ export const setSpectatorCount = (count: number): SetSpectatorCountAction => ({
   type: SET_SPECTATOR_COUNT,
   payload: { count },
 });
+
+export const setRematchVotes = (
+  votes: { ready: number; total: number } | null,
+): SetRematchVotesAction => ({
+  type: SET_REMATCH_VOTES,
+  payload: { votes },
+});
+
+export const setServerNotice = (message: string | null): SetServerNoticeAction => ({
+  type: SET_SERVER_NOTICE,
+  payload: { message },
+});
+
+export const setAIThinking = (thinking: boolean): SetAIThinkingAction => ({
+  type: SET_AI_THINKING,
+  payload: { thinking },
+});
+
+// Analysis action creators
+export const setOrientationOverride = (playerId: string | null): SetOrientationOverrideAction => ({
+  type: SET_ORIENTATION_OVERRIDE,
+  payload: { playerId },
+});
+
+export const showHint = (position: HexPosition, rotation: Rotation): ShowHintAction => ({
+  type: SHOW_HINT,
+  payload: { position, rotation },
+});
+
+export const hideHint = (): HideHintAction => ({
+  type: HIDE_HINT,
+});
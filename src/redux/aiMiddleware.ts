@@ -1,12 +1,16 @@
 // Redux middleware to handle AI player actions
 
-import { Middleware } from 'redux';
+import { Middleware, MiddlewareAPI } from 'redux';
 import { RootState } from './types';
 import {
   GameAction,
   SELECT_EDGE,
   DRAW_TILE,
   REPLACE_TILE,
+  HAND_OVER_TO_AI,
+  RESIGN,
+  TURN_TIMEOUT,
+  SWAP_SIDES,
   REMATCH_GAME,
   END_GAME,
   placeTile,
@@ -17,294 +21,287 @@ import {
   setAIScoringData,
   START_GAME,
   shuffleTiles,
+  setAIThinking,
 } from './actions';
-import { selectAIEdge, selectAIMove, generateMoveCandidates } from '../game/ai';
+import { selectAIEdge, selectAIMove, generateMoveCandidates, EvaluationStrategy, pathLengthEvaluator } from '../game/ai';
 import { positionToKey } from '../game/board';
+import { Player } from '../game/types';
 import { calculateTileCountsFromRatio } from './gameReducer';
+import { selectContinuationTileTypes } from './selectors';
+
+// Seed for the AI's choice among candidate moves below full difficulty
+// Derived from the game so that every client in a multiplayer game picks the same move
+function getAIMoveSeed(state: RootState): number {
+  return (state.game.seed ?? 0) + state.game.moveHistory.length;
+}
+
+// Strength the AI plays a seat at
+// Seats handed over mid-game carry a fixed difficulty, so every client in a multiplayer
+// game picks the same move for them whatever its own difficulty setting
+function getAIDifficulty(state: RootState, player: Player): number {
+  return player.aiDifficulty ?? state.ui.settings.aiDifficulty;
+}
 
 // Global counters for AI performance tracking
 let aiMoveCount = 0;
 let totalAITime = 0;
 
-// Middleware to automatically handle AI player turns
-export const aiMiddleware: Middleware<{}, RootState> = (store) => (next) => (action) => {
-  const gameAction = action as GameAction;
+// Let the browser paint a frame, showing that the AI is thinking, before the search blocks it
+function afterNextPaint(run: () => void): void {
+  requestAnimationFrame(() => setTimeout(run, 0));
+}
+
+// Play the current player's turn if it belongs to the AI
+function playAITurn(store: MiddlewareAPI<any, RootState>, evaluator: EvaluationStrategy): void {
+  const state = store.getState();
+  const { players, currentPlayerIndex, currentTile, board, teams, phase, supermoveInProgress } = state.game;
+  const currentPlayer = players[currentPlayerIndex];
+  if (phase !== 'playing' || currentTile === null || !currentPlayer?.isAI) {
+    return;
+  }
+
+  const supermoveEnabled = state.game.supermove;
+
+  // If supermove is already in progress, disable supermove for this move to prevent infinite replacements
+  const moveStartTime = performance.now();
+  const aiMove = selectAIMove(
+    board,
+    currentTile,
+    currentPlayer,
+    players,
+    teams,
+    supermoveEnabled && !supermoveInProgress, // Disable supermove if already in progress
+    state.game.boardRadius,
+    getAIDifficulty(state, currentPlayer),
+    getAIMoveSeed(state),
+    selectContinuationTileTypes(state),
+    evaluator,
+    state.game.oppositeSidesVictory
+  );
+  const moveEndTime = performance.now();
+  const moveTime = moveEndTime - moveStartTime;
   
-  // Reset counters on game start
-  if (gameAction.type === START_GAME) {
-    aiMoveCount = 0;
-    totalAITime = 0;
-    console.log('[AI Middleware] Starting new game, resetting AI performance counters');
+  aiMoveCount++;
+  totalAITime += moveTime;
+  console.log(`[AI Middleware] Move #${aiMoveCount} took ${moveTime.toFixed(2)}ms (cumulative: ${totalAITime.toFixed(2)}ms)`);
+  
+  if (aiMove) {
+    if (aiMove.isReplacement) {
+      const isSingleSupermove = state.game.singleSupermove;
+      store.dispatch(replaceTile(aiMove.position, aiMove.rotation, isSingleSupermove) as any);
+      
+      // If single supermove, advance to next player and draw a tile
+      if (isSingleSupermove) {
+        store.dispatch(nextPlayer() as any);
+        store.dispatch(drawTile() as any);
+      }
+      // Otherwise, the REPLACE_TILE action will trigger this middleware again
+      // with the replaced tile in hand, and we'll place it
+    } else {
+      store.dispatch(placeTile(aiMove.position, aiMove.rotation) as any);
+      
+      // After placing a tile (not a replacement), always advance to next player
+      // Even when completing a supermove (placing the replaced tile), we advance
+      store.dispatch(nextPlayer() as any);
+      store.dispatch(drawTile() as any);
+    }
+  } else {
+    // AI has no valid moves - should trigger constraint victory
+    console.log(`[AI Middleware] AI player ${currentPlayer.id} has no valid moves!`);
+    console.log(`[AI Middleware] Current tile: ${currentTile || 'null'}`);
+    console.log(`[AI Middleware] Board size: ${board.size}`);
+    console.log(`[AI Middleware] Supermove enabled: ${supermoveEnabled}`);
+    console.log(`[AI Middleware] This should be a constraint victory for the AI player`);
     
+    // Advance to next player and draw tile - this should trigger constraint victory check
+    store.dispatch(nextPlayer() as any);
+    store.dispatch(drawTile() as any);
+  }
+}
+
+// Create the middleware that plays AI player turns, scoring positions with the given evaluator
+// With deferMoves, tabletop AI moves run after the next paint so the "AI is thinking…"
+// indicator shows and the page stays responsive until the search starts. Multiplayer
+// clients replay the server's action log in one go and every client plays the AI's
+// moves itself, so there the move must land before the next logged action is applied.
+export const createAIMiddleware = (
+  evaluator: EvaluationStrategy = pathLengthEvaluator,
+  deferMoves = false
+): Middleware<{}, RootState> => (store) => {
+  // Play the AI's turn now, or schedule it, keeping at most one move pending
+  // A pending move reads the state when it runs, so actions in between can't make it stale
+  const requestAITurn = () => {
     const state = store.getState();
-    const { tileDistribution } = state.ui.settings;
-    const boardRadius = gameAction.payload?.boardRadius ?? state.game.boardRadius;
-    const seed = gameAction.payload?.seed;
+    if (!deferMoves || state.ui.gameMode === 'multiplayer') {
+      playAITurn(store, evaluator);
+      return;
+    }
+
+    const currentPlayer = state.game.players[state.game.currentPlayerIndex];
+    if (state.ui.aiThinking || state.game.phase !== 'playing' || state.game.currentTile === null || !currentPlayer?.isAI) {
+      return;
+    }
+
+    store.dispatch(setAIThinking(true) as any);
+    afterNextPaint(() => {
+      store.dispatch(setAIThinking(false) as any);
+      playAITurn(store, evaluator);
+    });
+  };
+
+  return (next) => (action) => {
+    const gameAction = action as GameAction;
+  
+    // Reset counters on game start
+    if (gameAction.type === START_GAME) {
+      aiMoveCount = 0;
+      totalAITime = 0;
+      console.log('[AI Middleware] Starting new game, resetting AI performance counters');
     
-    // Calculate the actual tile counts from the distribution ratio
-    const { distribution } = calculateTileCountsFromRatio(boardRadius, tileDistribution);
+      const state = store.getState();
+      const { tileDistribution } = state.ui.settings;
+      const boardRadius = gameAction.payload?.boardRadius ?? state.game.boardRadius;
+      const seed = gameAction.payload?.seed;
     
-    // Dispatch SHUFFLE_TILES before START_GAME is processed
-    store.dispatch(shuffleTiles(seed, distribution) as any);
-  }
+      // Calculate the actual tile counts from the distribution ratio
+      const { distribution } = calculateTileCountsFromRatio(boardRadius, tileDistribution);
+    
+      // Dispatch SHUFFLE_TILES before START_GAME is processed
+      store.dispatch(shuffleTiles(seed, distribution) as any);
+    }
   
-  // First, let the action pass through
-  const result = next(action);
+    // First, let the action pass through
+    const result = next(action);
   
-  // After the action has been processed, check if we need AI to act
-  const state = store.getState();
+    // After the action has been processed, check if we need AI to act
+    const state = store.getState();
   
-  // Handle AI edge selection during seating phase
-  // Trigger on SELECT_EDGE (when a player selects and we move to next) or START_GAME (if AI goes first)
-  if (gameAction.type === SELECT_EDGE || gameAction.type === START_GAME) {
-    const { seatingPhase, configPlayers } = state.game;
+    // Handle AI edge selection during seating phase
+    // Trigger on SELECT_EDGE (when a player selects and we move to next) or START_GAME (if AI goes first)
+    if (gameAction.type === SELECT_EDGE || gameAction.type === START_GAME) {
+      const { seatingPhase, configPlayers } = state.game;
     
-    if (seatingPhase && seatingPhase.active && seatingPhase.seatingIndex < seatingPhase.seatingOrder.length) {
-      const currentPlayerId = seatingPhase.seatingOrder[seatingPhase.seatingIndex];
-      const currentConfigPlayer = configPlayers.find((p: any) => p.id === currentPlayerId);
+      if (seatingPhase && seatingPhase.active && seatingPhase.seatingIndex < seatingPhase.seatingOrder.length) {
+        const currentPlayerId = seatingPhase.seatingOrder[seatingPhase.seatingIndex];
+        const currentConfigPlayer = configPlayers.find((p: any) => p.id === currentPlayerId);
       
-      if (currentConfigPlayer && currentConfigPlayer.isAI) {
-        // AI player needs to select an edge
-        // Find the human player's edge (assume there's one human player)
-        const humanPlayer = configPlayers.find((p: any) => !p.isAI);
-        let humanEdge = 0; // default
+        if (currentConfigPlayer && currentConfigPlayer.isAI) {
+          // AI player needs to select an edge
+          // Find the human player's edge (assume there's one human player)
+          const humanPlayer = configPlayers.find((p: any) => !p.isAI);
+          let humanEdge = 0; // default
         
-        if (humanPlayer) {
-          // Find the human player's assigned edge
-          const humanAssignedEdge = seatingPhase.edgeAssignments.get(humanPlayer.id);
-          if (humanAssignedEdge !== undefined) {
-            humanEdge = humanAssignedEdge;
+          if (humanPlayer) {
+            // Find the human player's assigned edge
+            const humanAssignedEdge = seatingPhase.edgeAssignments.get(humanPlayer.id);
+            if (humanAssignedEdge !== undefined) {
+              humanEdge = humanAssignedEdge;
+            }
           }
-        }
         
-        // Select an edge for AI that's not opposite to human
-        const aiEdge = selectAIEdge(humanEdge, seatingPhase.availableEdges);
+          // Select an edge for AI that's not opposite to human
+          const aiEdge = selectAIEdge(humanEdge, seatingPhase.availableEdges);
         
-        if (aiEdge !== null) {
-          // Dispatch edge selection for AI immediately (Redux is synchronous)
-          store.dispatch(selectEdge(currentPlayerId, aiEdge) as any);
+          if (aiEdge !== null) {
+            // Dispatch edge selection for AI immediately (Redux is synchronous)
+            store.dispatch(selectEdge(currentPlayerId, aiEdge) as any);
+          }
         }
       }
     }
-  }
   
-  // Handle transition to playing phase (after SELECT_EDGE completes seating)
-  // If we just transitioned to playing phase and current player is AI, make a move
-  if (gameAction.type === SELECT_EDGE) {
-    const { phase, players, currentPlayerIndex, currentTile } = state.game;
-    
-    if (phase === 'playing' && currentTile !== null && players.length > 0) {
-      const currentPlayer = players[currentPlayerIndex];
-      
-      if (currentPlayer && currentPlayer.isAI) {
-        // AI is first player and needs to make the first move
-        // Trigger AI move logic by manually calling the AI move handler
-        const { board, teams, supermoveInProgress, supermove, singleSupermove } = state.game;
-        
-        const moveStartTime = performance.now();
-        const aiMove = selectAIMove(
-          board,
-          currentTile,
-          currentPlayer,
-          players,
-          teams,
-          supermove && !supermoveInProgress,
-          state.game.boardRadius
-        );
-        const moveEndTime = performance.now();
-        const moveTime = moveEndTime - moveStartTime;
-        
-        aiMoveCount++;
-        totalAITime += moveTime;
-        console.log(`[AI Middleware] Move #${aiMoveCount} took ${moveTime.toFixed(2)}ms (cumulative: ${totalAITime.toFixed(2)}ms)`);
-        
-        if (aiMove) {
-          if (aiMove.isReplacement) {
-            store.dispatch(replaceTile(aiMove.position, aiMove.rotation, singleSupermove) as any);
-            
-            if (singleSupermove) {
-              store.dispatch(nextPlayer() as any);
-              store.dispatch(drawTile() as any);
-            }
-          } else {
-            store.dispatch(placeTile(aiMove.position, aiMove.rotation) as any);
-            store.dispatch(nextPlayer() as any);
-            store.dispatch(drawTile() as any);
-          }
-        }
-      }
+    // Handle transition to playing phase (after SELECT_EDGE completes seating)
+    // If we just transitioned to playing phase and current player is AI, make a move
+    if (gameAction.type === SELECT_EDGE && state.game.phase === 'playing') {
+      requestAITurn();
     }
-  }
   
-  // Handle AI move during gameplay - respond to DRAW_TILE or REPLACE_TILE (for supermoves)
-  if (gameAction.type === DRAW_TILE || gameAction.type === REPLACE_TILE) {
-    const { players, currentPlayerIndex, currentTile, board, teams, phase, supermoveInProgress } = state.game;
+    // Handle AI move during gameplay - respond to DRAW_TILE, REPLACE_TILE (for supermoves),
+    // HAND_OVER_TO_AI (a departing player's seat may be mid-turn with a tile in hand),
+    // RESIGN or TURN_TIMEOUT (a resigning player's tile in hand passes to the next player),
+    // or SWAP_SIDES (the opening player takes the tile in hand after a swap)
+    if (
+      gameAction.type === DRAW_TILE ||
+      gameAction.type === REPLACE_TILE ||
+      gameAction.type === HAND_OVER_TO_AI ||
+      gameAction.type === RESIGN ||
+      gameAction.type === TURN_TIMEOUT ||
+      gameAction.type === SWAP_SIDES
+    ) {
+      const { players, currentPlayerIndex, currentTile, board, teams, phase } = state.game;
     
-    // Only act if we're in playing phase and have a current tile
-    if (phase !== 'playing' || currentTile === null) {
-      // Clear AI scoring data if no tile
-      if (state.ui.settings.debugAIScoring && state.game.aiScoringData) {
-        store.dispatch(setAIScoringData(undefined) as any);
+      // Only act if we're in playing phase and have a current tile
+      if (phase !== 'playing' || currentTile === null) {
+        // Clear AI scoring data if no tile
+        if (state.ui.settings.debugAIScoring && state.game.aiScoringData) {
+          store.dispatch(setAIScoringData(undefined) as any);
+        }
+        return result;
       }
-      return result;
-    }
     
-    const currentPlayer = players[currentPlayerIndex];
-    const supermoveEnabled = state.game.supermove;
+      const currentPlayer = players[currentPlayerIndex];
+      const supermoveEnabled = state.game.supermove;
     
-    // Generate AI scoring data if debug mode is enabled (for any player)
-    if (state.ui.settings.debugAIScoring && currentPlayer) {
-      // Find an AI player to use for evaluation (or use current player if they're AI)
-      const aiPlayer = currentPlayer.isAI ? currentPlayer : players.find(p => p.isAI);
+      // Generate AI scoring data if debug mode is enabled (for any player)
+      if (state.ui.settings.debugAIScoring && currentPlayer) {
+        // Find an AI player to use for evaluation (or use current player if they're AI)
+        const aiPlayer = currentPlayer.isAI ? currentPlayer : players.find(p => p.isAI);
       
-      if (aiPlayer) {
-        const candidates = generateMoveCandidates(
-          board,
-          currentTile,
-          aiPlayer,
-          players,
-          teams,
-          supermoveEnabled,
-          state.game.boardRadius
-        );
+        if (aiPlayer) {
+          const candidates = generateMoveCandidates(
+            board,
+            currentTile,
+            aiPlayer,
+            players,
+            teams,
+            supermoveEnabled,
+            state.game.boardRadius,
+            true,
+            evaluator,
+            state.game.oppositeSidesVictory
+          );
         
-        // Group candidates by position
-        const scoringData: Record<string, { rotation: number; score: number }[]> = {};
-        for (const candidate of candidates) {
-          const key = positionToKey(candidate.position);
-          if (!scoringData[key]) {
-            scoringData[key] = [];
+          // Group candidates by position
+          const scoringData: Record<string, { rotation: number; score: number }[]> = {};
+          for (const candidate of candidates) {
+            const key = positionToKey(candidate.position);
+            if (!scoringData[key]) {
+              scoringData[key] = [];
+            }
+            scoringData[key].push({
+              rotation: candidate.rotation,
+              score: candidate.score,
+            });
           }
-          scoringData[key].push({
-            rotation: candidate.rotation,
-            score: candidate.score,
-          });
-        }
         
-        store.dispatch(setAIScoringData(scoringData) as any);
-      }
-    }
-    
-    // Check if current player is AI
-    if (currentPlayer && currentPlayer.isAI) {
-      // AI needs to make a move
-      // If supermove is already in progress, disable supermove for this move to prevent infinite replacements
-      const moveStartTime = performance.now();
-      const aiMove = selectAIMove(
-        board,
-        currentTile,
-        currentPlayer,
-        players,
-        teams,
-        supermoveEnabled && !supermoveInProgress, // Disable supermove if already in progress
-        state.game.boardRadius
-      );
-      const moveEndTime = performance.now();
-      const moveTime = moveEndTime - moveStartTime;
-      
-      aiMoveCount++;
-      totalAITime += moveTime;
-      console.log(`[AI Middleware] Move #${aiMoveCount} took ${moveTime.toFixed(2)}ms (cumulative: ${totalAITime.toFixed(2)}ms)`);
-      
-      if (aiMove) {
-        // Dispatch the move immediately (Redux is synchronous - no setTimeout needed)
-        if (aiMove.isReplacement) {
-          const isSingleSupermove = state.game.singleSupermove;
-          store.dispatch(replaceTile(aiMove.position, aiMove.rotation, isSingleSupermove) as any);
-          
-          // If single supermove, advance to next player and draw a tile
-          if (isSingleSupermove) {
-            store.dispatch(nextPlayer() as any);
-            store.dispatch(drawTile() as any);
-          }
-          // Otherwise, the REPLACE_TILE action will trigger this middleware again
-          // with the replaced tile in hand, and we'll place it
-        } else {
-          store.dispatch(placeTile(aiMove.position, aiMove.rotation) as any);
-          
-          // After placing a tile (not a replacement), always advance to next player
-          // Even when completing a supermove (placing the replaced tile), we advance
-          store.dispatch(nextPlayer() as any);
-          store.dispatch(drawTile() as any);
+          store.dispatch(setAIScoringData(scoringData) as any);
         }
-      } else {
-        // AI has no valid moves - should trigger constraint victory
-        console.log(`[AI Middleware] AI player ${currentPlayer.id} has no valid moves!`);
-        console.log(`[AI Middleware] Current tile: ${currentTile || 'null'}`);
-        console.log(`[AI Middleware] Board size: ${board.size}`);
-        console.log(`[AI Middleware] Supermove enabled: ${supermoveEnabled}`);
-        console.log(`[AI Middleware] This should be a constraint victory for the AI player`);
-        
-        // Advance to next player and draw tile - this should trigger constraint victory check
-        store.dispatch(nextPlayer() as any);
-        store.dispatch(drawTile() as any);
       }
+    
+      requestAITurn();
     }
-  }
   
-  // Handle AI move after REMATCH_GAME
-  // When a rematch happens, it directly transitions to playing phase with a current tile
-  // If the first player is AI, we need to trigger their move
-  if (gameAction.type === REMATCH_GAME) {
-    // Reset counters on rematch
-    aiMoveCount = 0;
-    totalAITime = 0;
-    console.log('[AI Middleware] Rematch started, resetting AI performance counters');
-    
-    const { players, currentPlayerIndex, currentTile, board, teams, phase, supermoveInProgress } = state.game;
+    // Handle AI move after REMATCH_GAME
+    // When a rematch happens, it directly transitions to playing phase with a current tile
+    // If the first player is AI, we need to trigger their move
+    if (gameAction.type === REMATCH_GAME) {
+      // Reset counters on rematch
+      aiMoveCount = 0;
+      totalAITime = 0;
+      console.log('[AI Middleware] Rematch started, resetting AI performance counters');
     
-    // Only act if we're in playing phase and have a current tile
-    if (phase === 'playing' && currentTile !== null && players.length > 0) {
-      const currentPlayer = players[currentPlayerIndex];
-      
-      // Check if current player is AI
-      if (currentPlayer && currentPlayer.isAI) {
-        const supermoveEnabled = state.game.supermove;
-        
-        // AI needs to make a move
-        const moveStartTime = performance.now();
-        const aiMove = selectAIMove(
-          board,
-          currentTile,
-          currentPlayer,
-          players,
-          teams,
-          supermoveEnabled && !supermoveInProgress,
-          state.game.boardRadius
-        );
-        const moveEndTime = performance.now();
-        const moveTime = moveEndTime - moveStartTime;
-        
-        aiMoveCount++;
-        totalAITime += moveTime;
-        console.log(`[AI Middleware] Move #${aiMoveCount} took ${moveTime.toFixed(2)}ms (cumulative: ${totalAITime.toFixed(2)}ms)`);
-        
-        if (aiMove) {
-          // Dispatch the move immediately (Redux is synchronous)
-          if (aiMove.isReplacement) {
-            const isSingleSupermove = state.game.singleSupermove;
-            store.dispatch(replaceTile(aiMove.position, aiMove.rotation, isSingleSupermove) as any);
-            
-            // If single supermove, advance to next player and draw a tile
-            if (isSingleSupermove) {
-              store.dispatch(nextPlayer() as any);
-              store.dispatch(drawTile() as any);
-            }
-          } else {
-            store.dispatch(placeTile(aiMove.position, aiMove.rotation) as any);
-            store.dispatch(nextPlayer() as any);
-            store.dispatch(drawTile() as any);
-          }
-        }
-      }
+      requestAITurn();
     }
-  }
   
-  // Log summary when game ends
-  if (gameAction.type === END_GAME && aiMoveCount > 0) {
-    const avgTime = totalAITime / aiMoveCount;
-    console.log(`[AI Middleware] Game ended. Total AI moves: ${aiMoveCount}, Total time: ${totalAITime.toFixed(2)}ms, Avg time per move: ${avgTime.toFixed(2)}ms`);
-  }
+    // Log summary when game ends
+    if (gameAction.type === END_GAME && aiMoveCount > 0) {
+      const avgTime = totalAITime / aiMoveCount;
+      console.log(`[AI Middleware] Game ended. Total AI moves: ${aiMoveCount}, Total time: ${totalAITime.toFixed(2)}ms, Avg time per move: ${avgTime.toFixed(2)}ms`);
+    }
   
-  return result;
+    return result;
+  };
 };
+
+// Middleware to automatically handle AI player turns with the default evaluator
+export const aiMiddleware = createAIMiddleware();
@@ -1,11 +1,12 @@
 // Redux reducer for game state management
 
-import { GameState, ConfigPlayer, MAX_PLAYERS, PLAYER_COLORS } from "./types";
+import { GameState, ConfigPlayer, MAX_PLAYERS, PLAYER_COLORS, TILE_DISTRIBUTION_CLASSIC, DEFAULT_GAME_SETTINGS } from "./types";
 import {
   GameAction,
   ADD_PLAYER,
   REMOVE_PLAYER,
   CHANGE_PLAYER_COLOR,
+  SET_PLAYER_AI,
   START_GAME,
   RETURN_TO_CONFIG,
   SETUP_GAME,
@@ -21,18 +22,28 @@ import {
   RESET_GAME,
   REMATCH_GAME,
   RESTORE_GAME,
+  HAND_OVER_TO_AI,
+  RECLAIM_FROM_AI,
+  RESIGN,
+  TURN_TIMEOUT,
+  UNDO_MOVE,
+  SWAP_SIDES,
   SET_AI_SCORING_DATA,
+  PlaceTileAction,
+  ReplaceTileAction,
+  resign,
 } from "./actions";
 import { TileType } from "../game/types";
 import { calculateFlows } from "../game/flows";
-import { checkVictory } from "../game/victory";
-import { positionToKey } from "../game/board";
+import { checkVictory, findPlayerTeam } from "../game/victory";
+import { positionToKey, isValidPosition } from "../game/board";
+import { MAX_AI_DIFFICULTY } from "../game/ai";
 
 // Initial state
 export const initialState: GameState = {
   screen: "configuration",
   configPlayers: [],
-  boardRadius: 3,
+  boardRadius: DEFAULT_GAME_SETTINGS.boardRadius,
   seatingPhase: {
     active: false,
     seatingOrder: [],
@@ -55,6 +66,7 @@ export const initialState: GameState = {
   supermove: true,  // Default to true
   singleSupermove: false,  // Default to false
   supermoveAnyPlayer: false,  // Default to false
+  oppositeSidesVictory: false,  // Default to reaching the specific goal edge
   supermoveInProgress: false,
   lastPlacedTilePosition: null,
 };
@@ -104,8 +116,7 @@ export function calculateTileCountsFromRatio(
 
   // Handle all-zeros case - default to balanced distribution
   if (groupSize === 0) {
-    const defaultRatio: [number, number, number, number] = [1, 1, 1, 1];
-    return calculateTileCountsFromRatio(boardRadius, defaultRatio);
+    return calculateTileCountsFromRatio(boardRadius, TILE_DISTRIBUTION_CLASSIC);
   }
 
   const numGroups = Math.ceil(boardSize / groupSize);
@@ -148,6 +159,17 @@ function createShuffledDeck(
   return shuffleArray(tiles, seed);
 }
 
+// Generate a seed for a new local game (multiplayer seeds come from the server, in the same range)
+export function generateGameSeed(): number {
+  return Math.floor(Math.random() * 1000000);
+}
+
+// Seed for a later shuffle in a seeded game, so each one draws a fresh sequence
+// instead of restarting the game's random stream; unseeded games stay random
+function deriveSeed(seed: number | undefined, offset: number): number | undefined {
+  return seed !== undefined ? seed + offset : undefined;
+}
+
 // Seeded random number generator
 function seededRandom(seed: number): () => number {
   let state = seed;
@@ -202,6 +224,151 @@ function determineGameplayOrder<T extends { id: string; edgePosition: number }>(
   return gameplayOrder;
 }
 
+// Index of the next player in turn order, skipping players who have resigned
+function getNextActivePlayerIndex(state: GameState): number {
+  const resigned = state.resignedPlayerIds ?? [];
+  let index = state.currentPlayerIndex;
+  for (let step = 0; step < state.players.length; step++) {
+    index = (index + 1) % state.players.length;
+    if (!resigned.includes(state.players[index].id)) {
+      break;
+    }
+  }
+  return index;
+}
+
+// Number of moves UNDO_MOVE would take back, or 0 if it can't undo
+// Undo rewinds to before the most recent human move, taking back any AI replies after it.
+// Supermove replacements can't be taken back, since the replaced tile isn't recorded,
+// and neither can the opening move once the sides have been swapped over it.
+export function getUndoableMoveCount(state: GameState): number {
+  if (state.phase !== "playing" || state.supermoveInProgress) {
+    return 0;
+  }
+
+  const { moveHistory } = state;
+  const isAIMove = (index: number) =>
+    state.players.find((p) => p.id === moveHistory[index].playerId)?.isAI ?? false;
+
+  let first = moveHistory.length - 1;
+  while (first >= 0 && isAIMove(first)) {
+    first--;
+  }
+  if (first < 0 || (state.resignedPlayerIds ?? []).includes(moveHistory[first].playerId)) {
+    return 0;
+  }
+  if (first === 0 && state.sidesSwapped) {
+    return 0;
+  }
+
+  // A move onto a hex that an earlier move filled was a replacement
+  for (let i = first; i < moveHistory.length; i++) {
+    const key = positionToKey(moveHistory[i].tile.position);
+    if (moveHistory.slice(0, i).some((m) => positionToKey(m.tile.position) === key)) {
+      return 0;
+    }
+  }
+
+  return moveHistory.length - first;
+}
+
+// Check whether the pie rule lets the second player swap sides now
+// Only in a two-player game, on the second player's first turn, right after the opening move
+export function canSwapSides(state: GameState): boolean {
+  return (
+    state.phase === "playing" &&
+    state.players.length === 2 &&
+    state.teams.length === 0 &&
+    state.moveHistory.length === 1 &&
+    state.moveHistory[0].playerId === state.players[0].id &&
+    state.currentPlayerIndex === 1 &&
+    !state.supermoveInProgress
+  );
+}
+
+// Result of checking whether the reducer would accept a tile action
+export type TileActionValidation =
+  | { valid: true }
+  | { valid: false; reason: string };
+
+// Check whether a PLACE_TILE or REPLACE_TILE action would be accepted, without applying it
+// Performs the same checks as the reducer (tile in hand, current player, on board, occupancy) so callers
+// can test a move without building the next state. Flow legality is not enforced by the
+// reducer; use isLegalMove or validateMoveHistory for that.
+export function validateTileAction(
+  state: GameState,
+  action: PlaceTileAction | ReplaceTileAction,
+): TileActionValidation {
+  if (state.currentTile === null) {
+    return { valid: false, reason: "No tile in hand" };
+  }
+
+  // Validate that the action is from the current player (multiplayer authorization)
+  // In tabletop mode, action.playerId is undefined so we skip this check
+  // In multiplayer mode, action.playerId is the userId (e.g., 'google:123') added by server
+  if (action.playerId !== undefined && state.players.length > 0) {
+    const currentPlayer = state.players[state.currentPlayerIndex];
+    if (currentPlayer.userId !== action.playerId) {
+      return {
+        valid: false,
+        reason: `Authorization failed: action from ${action.playerId} but current player is ${currentPlayer.id} with userId ${currentPlayer.userId}`,
+      };
+    }
+  }
+
+  // Hexes outside the board's radius, such as (3, 3) on a radius 3 board, can never hold a tile
+  if (!isValidPosition(action.payload.position, state.boardRadius)) {
+    return { valid: false, reason: "Position is off the board" };
+  }
+
+  const occupied = state.board.has(positionToKey(action.payload.position));
+  if (action.type === PLACE_TILE && occupied) {
+    return { valid: false, reason: "Position is already occupied" };
+  }
+  if (action.type === REPLACE_TILE && !occupied) {
+    return { valid: false, reason: "Position is empty" };
+  }
+
+  return { valid: true };
+}
+
+// Check that an action naming a seat was posted by that seat's user
+// In tabletop mode the poster is undefined and anyone may act for any seat;
+// in multiplayer the server stamps the poster's userId, so a player can't act for an opponent
+function isPostedBySeat(state: GameState, seatId: string, poster: string | undefined): boolean {
+  return poster === undefined || state.players.find((p) => p.id === seatId)?.userId === poster;
+}
+
+// Result of checking whether the reducer would accept a START_SEATING_PHASE order
+export type SeatingOrderValidation =
+  | { valid: true }
+  | { valid: false; reason: string };
+
+// Check a seating order supplied in place of the one START_GAME picks at random
+// The order must list every configured player exactly once, and can only be set once
+export function validateSeatingOrder(
+  state: GameState,
+  seatingOrder: string[],
+): SeatingOrderValidation {
+  if (state.seatingPhase.seatingOrder.length > 0) {
+    return { valid: false, reason: "Seating order already randomized" };
+  }
+
+  if (seatingOrder.length !== state.configPlayers.length) {
+    return {
+      valid: false,
+      reason: `Seating order length doesn't match the number of players (${seatingOrder.length} vs ${state.configPlayers.length})`,
+    };
+  }
+
+  const playerIds = new Set(state.configPlayers.map((p) => p.id));
+  if (new Set(seatingOrder).size !== seatingOrder.length || seatingOrder.some((id) => !playerIds.has(id))) {
+    return { valid: false, reason: "Seating order must list each player exactly once" };
+  }
+
+  return { valid: true };
+}
+
 // Reducer function
 export function gameReducer(
   state: GameState = initialState,
@@ -315,6 +482,22 @@ export function gameReducer(
       };
     }
 
+    case SET_PLAYER_AI: {
+      // Choose whether a configured seat is played by a person or the AI
+      const { playerId, isAI } = action.payload;
+
+      if (!state.configPlayers.some((p) => p.id === playerId)) {
+        return state;
+      }
+
+      return {
+        ...state,
+        configPlayers: state.configPlayers.map((p) =>
+          p.id === playerId ? { ...p, isAI } : p,
+        ),
+      };
+    }
+
     case START_GAME: {
       // Only allow starting game if at least one player is configured
       if (state.configPlayers.length === 0) {
@@ -362,9 +545,14 @@ export function gameReducer(
         phase: "seating",
         boardRadius: action.payload?.boardRadius ?? state.boardRadius,
         seed, // Store seed for later use when shuffling tiles
+        shuffleVersion: action.payload?.shuffleVersion,
         supermove: action.payload?.supermove ?? state.supermove,
         singleSupermove: action.payload?.singleSupermove ?? state.singleSupermove,
         supermoveAnyPlayer: action.payload?.supermoveAnyPlayer ?? state.supermoveAnyPlayer,
+        oppositeSidesVictory: action.payload?.oppositeSidesVictory ?? state.oppositeSidesVictory,
+        turnTimeLimit: action.payload?.turnTimeLimit ?? state.turnTimeLimit,
+        noSelfStalemate: action.payload?.noSelfStalemate ?? state.noSelfStalemate,
+        longestFlowVictory: action.payload?.longestFlowVictory ?? state.longestFlowVictory,
         seatingPhase: {
           active: true,
           seatingOrder,
@@ -399,7 +587,9 @@ export function gameReducer(
         flowEdges: new Map(),
         winners: [],
         winType: null,
+        resignedPlayerIds: [],
         moveHistory: [],
+        sidesSwapped: false,
       };
     }
 
@@ -419,6 +609,12 @@ export function gameReducer(
     case START_SEATING_PHASE: {
       const { seatingOrder } = action.payload;
 
+      const validation = validateSeatingOrder(state, seatingOrder);
+      if (!validation.valid) {
+        console.warn(`[START_SEATING_PHASE] ${validation.reason}`);
+        return state;
+      }
+
       return {
         ...state,
         screen: "seating",
@@ -539,6 +735,7 @@ export function gameReducer(
           flowEdges: new Map(),
           winners: [],
           winType: null,
+          resignedPlayerIds: [],
           moveHistory: [],
           seatingPhase: {
             ...seatingPhase,
@@ -606,10 +803,12 @@ export function gameReducer(
         state.teams,
         nextTile,
         state.boardRadius,
-        state.supermove
+        state.supermove,
+        state.oppositeSidesVictory,
+        state.longestFlowVictory
       );
 
-      if (victoryResult.winners.length > 0) {
+      if (victoryResult.winType !== null) {
         console.log(`[DRAW_TILE] Constraint victory detected! Winners: ${victoryResult.winners.join(', ')}, Type: ${victoryResult.winType}`);
         console.log(`[DRAW_TILE] Current tile: ${nextTile}, Board size: ${state.board.size}`);
         
@@ -630,35 +829,18 @@ export function gameReducer(
     }
 
     case PLACE_TILE: {
-      if (state.currentTile === null) {
+      const validation = validateTileAction(state, action);
+      if (!validation.valid) {
+        console.warn(`[PLACE_TILE] ${validation.reason}`);
         return state;
       }
 
-      // Validate that the action is from the current player (multiplayer authorization)
-      // In tabletop mode, action.playerId is undefined so we skip this check
-      // In multiplayer mode, action.playerId is the userId (e.g., 'google:123') added by server
-      if (action.playerId !== undefined && state.players.length > 0) {
-        const currentPlayer = state.players[state.currentPlayerIndex];
-        // Check if the current player's userId matches the action's playerId
-        if (currentPlayer.userId !== action.playerId) {
-          console.warn(
-            `[PLACE_TILE] Authorization failed: action from ${action.playerId} but current player is ${currentPlayer.id} with userId ${currentPlayer.userId}`
-          );
-          return state;
-        }
-      }
-
       const { position, rotation } = action.payload;
       const posKey = positionToKey(position);
 
-      // Check if position is already occupied
-      if (state.board.has(posKey)) {
-        return state;
-      }
-
       // Create new placed tile
       const placedTile = {
-        type: state.currentTile,
+        type: state.currentTile!, // Checked by validateTileAction
         rotation,
         position,
       };
@@ -681,6 +863,9 @@ export function gameReducer(
         state.teams,
         undefined,
         state.boardRadius,
+        false,
+        state.oppositeSidesVictory,
+        state.longestFlowVictory,
       );
 
       // Add to move history
@@ -701,8 +886,8 @@ export function gameReducer(
         lastPlacedTilePosition: position,
       };
 
-      // If there's a winner, update game state
-      if (victoryResult.winners.length > 0) {
+      // If there's a winner (or the board filled up in a draw), update game state
+      if (victoryResult.winType !== null) {
         return {
           ...newState,
           phase: "finished",
@@ -717,36 +902,19 @@ export function gameReducer(
 
     case REPLACE_TILE: {
       // Handle supermove tile replacement
-      if (state.currentTile === null) {
+      const validation = validateTileAction(state, action);
+      if (!validation.valid) {
+        console.warn(`[REPLACE_TILE] ${validation.reason}`);
         return state;
       }
 
-      // Validate that the action is from the current player (multiplayer authorization)
-      // In tabletop mode, action.playerId is undefined so we skip this check
-      // In multiplayer mode, action.playerId is the userId (e.g., 'google:123') added by server
-      if (action.playerId !== undefined && state.players.length > 0) {
-        const currentPlayer = state.players[state.currentPlayerIndex];
-        // Check if the current player's userId matches the action's playerId
-        if (currentPlayer.userId !== action.playerId) {
-          console.warn(
-            `[REPLACE_TILE] Authorization failed: action from ${action.playerId} but current player is ${currentPlayer.id} with userId ${currentPlayer.userId}`
-          );
-          return state;
-        }
-      }
-
       const { position, rotation, isSingleSupermove } = action.payload;
       const posKey = positionToKey(position);
-
-      // Position must be occupied for replacement
-      const oldTile = state.board.get(posKey);
-      if (!oldTile) {
-        return state;
-      }
+      const oldTile = state.board.get(posKey)!;
 
       // Create new placed tile from current tile in hand
       const newPlacedTile = {
-        type: state.currentTile,
+        type: state.currentTile!, // Checked by validateTileAction
         rotation,
         position,
       };
@@ -755,12 +923,12 @@ export function gameReducer(
       const newBoard = new Map(state.board);
       newBoard.set(posKey, newPlacedTile);
 
-      // Calculate new flows
-      const { flows: newFlows, flowEdges: newFlowEdges } = calculateFlows(
-        newBoard,
-        state.players,
-        state.boardRadius,
-      );
+      // Calculate new flows, unless the replacement is identical to the old tile
+      // (then the board's flow pattern is unchanged)
+      const boardChanged = oldTile.type !== newPlacedTile.type || oldTile.rotation !== rotation;
+      const { flows: newFlows, flowEdges: newFlowEdges } = boardChanged
+        ? calculateFlows(newBoard, state.players, state.boardRadius)
+        : { flows: state.flows, flowEdges: state.flowEdges };
 
       // Check for victory
       const victoryResult = checkVictory(
@@ -769,6 +937,9 @@ export function gameReducer(
         state.teams,
         undefined,
         state.boardRadius,
+        false,
+        state.oppositeSidesVictory,
+        state.longestFlowVictory,
       );
 
       // Add to move history (replacement move)
@@ -783,8 +954,12 @@ export function gameReducer(
         // Return the replaced tile to the bag
         const newAvailableTiles = [...state.availableTiles, oldTile.type];
 
-        // Shuffle the bag using the same seeded shuffle as tile distribution
-        const shuffled = shuffleArray(newAvailableTiles, state.seed);
+        // Shuffle the bag with a seed derived from the game's, varying per move
+        // Games logged before shuffles were versioned reshuffled with the game seed itself
+        const reshuffleSeed = state.shuffleVersion !== undefined
+          ? deriveSeed(state.seed, state.moveHistory.length + 1)
+          : state.seed;
+        const shuffled = shuffleArray(newAvailableTiles, reshuffleSeed);
 
         const newState: GameState = {
           ...state,
@@ -842,8 +1017,7 @@ export function gameReducer(
     case NEXT_PLAYER: {
       return {
         ...state,
-        currentPlayerIndex:
-          (state.currentPlayerIndex + 1) % state.players.length,
+        currentPlayerIndex: getNextActivePlayerIndex(state),
       };
     }
 
@@ -875,9 +1049,12 @@ export function gameReducer(
       // Get the previous edge assignments from the completed seating phase
       const edgeAssignments = state.seatingPhase.edgeAssignments;
 
+      // Seeded games carry on with a seed derived from the last game's
+      const seed = deriveSeed(state.seed, 1);
+
       // Randomize the player order for a new starting player
       const playerIds = state.players.map((p) => p.id);
-      const newSeatingOrder = randomizePlayerOrder(playerIds);
+      const newSeatingOrder = randomizePlayerOrder(playerIds, seed);
 
       // Determine gameplay order - players ordered clockwise from new starting player
       const orderedPlayers = determineGameplayOrder(
@@ -885,8 +1062,8 @@ export function gameReducer(
         newSeatingOrder,
       );
 
-      // Create a new shuffled deck (no seed for random shuffle)
-      const availableTiles = createShuffledDeck(state.boardRadius);
+      // Create a new shuffled deck
+      const availableTiles = createShuffledDeck(state.boardRadius, seed);
       const currentTile = availableTiles.length > 0 ? availableTiles[0] : null;
       const remainingTiles = availableTiles.slice(1);
 
@@ -905,9 +1082,11 @@ export function gameReducer(
         currentTile,
         flows: new Map(),
         flowEdges: new Map(),
+        seed,
         // Clear game status
         winners: [],
         winType: null,
+        resignedPlayerIds: [],
         moveHistory: [],
         supermoveInProgress: false,
         lastPlacedTilePosition: null,
@@ -928,6 +1107,174 @@ export function gameReducer(
       return state;
     }
 
+    case HAND_OVER_TO_AI: {
+      // A departing player's seat keeps its edge and color but is played by the AI.
+      // Multiplayer servers only know user IDs, so match on either identifier.
+      // The seat's difficulty is fixed here rather than taken from each client's settings,
+      // so every client replaying the game picks the same moves for it.
+      const { playerId } = action.payload;
+      const aiDifficulty = action.payload.aiDifficulty ?? MAX_AI_DIFFICULTY;
+      const matches = (p: { id: string; userId?: string }) =>
+        p.id === playerId || p.userId === playerId;
+
+      if (!state.players.some(matches)) {
+        return state;
+      }
+
+      return {
+        ...state,
+        players: state.players.map((p) => (matches(p) ? { ...p, isAI: true, aiDifficulty } : p)),
+        configPlayers: state.configPlayers.map((p) =>
+          matches(p) ? { ...p, isAI: true } : p,
+        ),
+      };
+    }
+
+    case RECLAIM_FROM_AI: {
+      // A player whose seat was handed over on disconnect has come back and plays it again
+      const { playerId } = action.payload;
+      const matches = (p: { id: string; userId?: string }) =>
+        p.id === playerId || p.userId === playerId;
+
+      if (!state.players.some(matches)) {
+        return state;
+      }
+
+      return {
+        ...state,
+        players: state.players.map((p) => (matches(p) ? { ...p, isAI: false } : p)),
+        configPlayers: state.configPlayers.map((p) =>
+          matches(p) ? { ...p, isAI: false } : p,
+        ),
+      };
+    }
+
+    case RESIGN: {
+      const { playerId } = action.payload;
+      const alreadyResigned = state.resignedPlayerIds ?? [];
+
+      if (
+        state.phase !== "playing" ||
+        alreadyResigned.includes(playerId) ||
+        !state.players.some((p) => p.id === playerId)
+      ) {
+        return state;
+      }
+
+      if (!isPostedBySeat(state, playerId, action.playerId)) {
+        console.warn(`[RESIGN] ${action.playerId} may not resign for ${playerId}`);
+        return state;
+      }
+
+      const resignedPlayerIds = [...alreadyResigned, playerId];
+      const remaining = state.players.filter((p) => !resignedPlayerIds.includes(p.id));
+
+      // A team stays in the game while either partner has not resigned
+      const sideOf = (id: string) => {
+        const team = findPlayerTeam(id, state.teams);
+        return team ? [team.player1Id, team.player2Id] : [id];
+      };
+      const sides = new Set(remaining.map((p) => sideOf(p.id).join(",")));
+
+      if (sides.size === 1) {
+        return {
+          ...state,
+          resignedPlayerIds,
+          phase: "finished",
+          winners: sideOf(remaining[0].id),
+          winType: "resignation",
+          screen: "game-over",
+        };
+      }
+
+      const resignedState = { ...state, resignedPlayerIds };
+
+      // Play passes on if the resigning player was on turn; the tile in hand
+      // goes to the next player, so the bag is not disturbed
+      if (state.players[state.currentPlayerIndex].id !== playerId) {
+        return resignedState;
+      }
+
+      return {
+        ...resignedState,
+        currentPlayerIndex: getNextActivePlayerIndex(resignedState),
+        supermoveInProgress: false,
+      };
+    }
+
+    case TURN_TIMEOUT: {
+      // The server ran out the clock on whoever is on turn, so they resign
+      if (state.phase !== "playing") {
+        return state;
+      }
+
+      return gameReducer(state, resign(state.players[state.currentPlayerIndex].id));
+    }
+
+    case UNDO_MOVE: {
+      const count = getUndoableMoveCount(state);
+      if (count === 0) {
+        return state;
+      }
+
+      const keptMoves = state.moveHistory.slice(0, -count);
+      const [undone, ...replies] = state.moveHistory.slice(-count);
+
+      const newBoard = new Map(state.board);
+      for (const move of [undone, ...replies]) {
+        newBoard.delete(positionToKey(move.tile.position));
+      }
+      const { flows, flowEdges } = calculateFlows(newBoard, state.players, state.boardRadius);
+
+      // Tiles drawn since the undone move go back on top of the bag in draw order,
+      // so play continues with exactly the same tiles
+      const returnedTiles = replies.map((m) => m.tile.type);
+      if (state.currentTile !== null) {
+        returnedTiles.push(state.currentTile);
+      }
+
+      return {
+        ...state,
+        board: newBoard,
+        flows,
+        flowEdges,
+        currentTile: undone.tile.type,
+        availableTiles: [...returnedTiles, ...state.availableTiles],
+        currentPlayerIndex: state.players.findIndex((p) => p.id === undone.playerId),
+        moveHistory: keptMoves,
+        lastPlacedTilePosition: keptMoves.length > 0 ? keptMoves[keptMoves.length - 1].tile.position : null,
+      };
+    }
+
+    case SWAP_SIDES: {
+      if (!canSwapSides(state) || action.payload.playerId !== state.players[1].id) {
+        return state;
+      }
+
+      if (!isPostedBySeat(state, action.payload.playerId, action.playerId)) {
+        console.warn(`[SWAP_SIDES] ${action.playerId} may not swap for ${action.payload.playerId}`);
+        return state;
+      }
+
+      // The players trade edges, so the opening tile now serves the swapping player;
+      // the turn and the tile in hand pass to the player who made the opening move
+      const [first, second] = state.players;
+      const players = [
+        { ...first, edgePosition: second.edgePosition },
+        { ...second, edgePosition: first.edgePosition },
+      ];
+      const { flows, flowEdges } = calculateFlows(state.board, players, state.boardRadius);
+
+      return {
+        ...state,
+        players,
+        flows,
+        flowEdges,
+        currentPlayerIndex: 0,
+        sidesSwapped: true,
+      };
+    }
+
     case SET_AI_SCORING_DATA: {
       return {
         ...state,
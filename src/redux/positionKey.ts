@@ -0,0 +1,74 @@
+// Position keys for Quortex/Flows games
+// A position key identifies the board and turn state independently of how the game
+// got there, so games reached by different move orders compare equal. Move history
+// and flows are left out: history is irrelevant and flows are derived from the board.
+
+import { GameState } from "./types";
+import { TileType } from "../game/types";
+
+// Build the key for a game state
+// Covers each placed tile (type and rotation), the player on turn, the tile in hand,
+// every player's seat and team, and how many of each tile type are left in the bag
+export function getPositionKey(state: GameState): string {
+  const tiles = Array.from(state.board.entries())
+    .map(([key, tile]) => `${key}:${tile.type}:${tile.rotation}`)
+    .sort()
+    .join(";");
+
+  const seats = state.players
+    .map((p) => `${p.id}@${p.edgePosition}`)
+    .join(",");
+
+  const teams = state.teams
+    .map((t) => [t.player1Id, t.player2Id].sort().join("+"))
+    .sort()
+    .join(",");
+
+  const bagCounts = [
+    TileType.NoSharps,
+    TileType.OneSharp,
+    TileType.TwoSharps,
+    TileType.ThreeSharps,
+  ].map((type) => state.availableTiles.filter((t) => t === type).length);
+
+  const currentPlayer = state.players[state.currentPlayerIndex];
+
+  return [
+    tiles,
+    currentPlayer ? currentPlayer.id : "",
+    state.currentTile ?? "",
+    seats,
+    teams,
+    bagCounts.join(","),
+  ].join("|");
+}
+
+// Check whether two game states are the same game
+// Stricter than comparing position keys: the bag must be in the same order and the
+// outcome must match. Flows, flow caches and move history are still left out, since
+// flows are derived from the board and a rebuilt game may have reached it differently.
+export function gamesEqual(a: GameState, b: GameState): boolean {
+  if (a.board.size !== b.board.size) {
+    return false;
+  }
+  for (const [key, tile] of a.board) {
+    const other = b.board.get(key);
+    if (!other || other.type !== tile.type || other.rotation !== tile.rotation) {
+      return false;
+    }
+  }
+
+  const seats = (state: GameState) =>
+    state.players.map((p) => `${p.id}@${p.edgePosition}`).join(",");
+  const currentPlayer = (state: GameState) =>
+    state.players[state.currentPlayerIndex]?.id ?? "";
+
+  return (
+    seats(a) === seats(b) &&
+    a.availableTiles.join(",") === b.availableTiles.join(",") &&
+    a.currentTile === b.currentTile &&
+    currentPlayer(a) === currentPlayer(b) &&
+    [...a.winners].sort().join(",") === [...b.winners].sort().join(",") &&
+    a.winType === b.winType
+  );
+}
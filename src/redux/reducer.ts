@@ -6,6 +6,7 @@ import { uiReducer } from './uiReducer';
 import { animationReducer } from '../animation/reducer';
 import { RESET_GAME, RESTORE_GAME } from './actions';
 import type { GameAction, UIAction } from './actions';
+import type { Player } from '../game/types';
 
 const combinedReducer = combineReducers({
   game: gameReducer,
@@ -13,15 +14,23 @@ const combinedReducer = combineReducers({
   animation: animationReducer,
 });
 
+// Series wins follow the multiplayer user where there is one, since seats are refilled for a rematch
+export function getSessionScoreKey(player: Player): string {
+  return player.userId ?? player.id;
+}
+
 // Custom root reducer to handle cross-slice actions
 export const rootReducer = (state: ReturnType<typeof combinedReducer> | undefined, action: GameAction | UIAction | any) => {
   // Handle RESET_GAME: save game state to UI before resetting
+  // Leaving to the menu also ends the series, so the session scores start over,
+  // but a multiplayer rematch resets the game on the way into the next one
   if (action.type === RESET_GAME && state) {
     const savedState = {
       ...state,
       ui: {
         ...state.ui,
         savedGameState: state.game,
+        sessionScores: action.payload?.keepSessionScores ? state.ui.sessionScores : {},
       },
     };
     return combinedReducer(savedState, action);
@@ -39,7 +48,24 @@ export const rootReducer = (state: ReturnType<typeof combinedReducer> | undefine
     };
   }
 
-  return combinedReducer(state, action);
+  const nextState = combinedReducer(state, action);
+
+  // Count a series win for every listed winner (all of them for shared victories)
+  // when a game is decided
+  if (state && state.game.winType === null && nextState.game.winType !== null) {
+    const sessionScores = { ...nextState.ui.sessionScores };
+    nextState.game.winners.forEach((playerId) => {
+      const player = nextState.game.players.find((p) => p.id === playerId);
+      const key = player ? getSessionScoreKey(player) : playerId;
+      sessionScores[key] = (sessionScores[key] ?? 0) + 1;
+    });
+    return {
+      ...nextState,
+      ui: { ...nextState.ui, sessionScores },
+    };
+  }
+
+  return nextState;
 };
 
 export type RootState = ReturnType<typeof rootReducer>;
@@ -0,0 +1,86 @@
+// Save files for Quortex/Flows games
+// A save is the game's action log with a small versioned header; loading replays
+// the log through the game reducer, so the board, current player, and outcome
+// are reconstructed exactly as they were played.
+
+import { GameAction, ADD_PLAYER, SETUP_GAME, PLACE_TILE, REPLACE_TILE } from "./actions";
+import { GameState } from "./types";
+import { gameReducer, initialState, resetPlayerIdCounter } from "./gameReducer";
+
+export const SAVE_FORMAT = "quortex-save";
+export const SAVE_FORMAT_VERSION = 1;
+
+// Serialized form of a save file
+export interface SaveFile {
+  format: typeof SAVE_FORMAT;
+  version: number;
+  actions: GameAction[];
+}
+
+export type SaveLoadResult =
+  | { valid: true; state: GameState; actions: GameAction[]; rejectedMoves: number[] }
+  | { valid: false; reason: string };
+
+// Actions a game log may begin with: configuring players, or setting up a game directly
+const GAME_START_ACTIONS: string[] = [ADD_PLAYER, SETUP_GAME];
+
+// Moves the reducer validates; it returns the state untouched when it rejects one
+const MOVE_ACTIONS: string[] = [PLACE_TILE, REPLACE_TILE];
+
+// Serialize a game's action log to a save string
+export function toSaveString(actions: GameAction[]): string {
+  const save: SaveFile = {
+    format: SAVE_FORMAT,
+    version: SAVE_FORMAT_VERSION,
+    actions,
+  };
+  return JSON.stringify(save);
+}
+
+// Parse a save string and replay its actions into a game state
+// Malformed saves are reported as a reason instead of throwing; moves the reducer
+// rejected are skipped as in live play and listed by action index in rejectedMoves
+export function fromSaveString(text: string): SaveLoadResult {
+  let save: Partial<SaveFile>;
+  try {
+    save = JSON.parse(text);
+  } catch {
+    return { valid: false, reason: "Save is not valid JSON" };
+  }
+
+  if (!save || typeof save !== "object" || save.format !== SAVE_FORMAT) {
+    return { valid: false, reason: "Not a Quortex save file" };
+  }
+  if (save.version !== SAVE_FORMAT_VERSION) {
+    return { valid: false, reason: `Unsupported save version ${save.version}` };
+  }
+  if (!Array.isArray(save.actions) || save.actions.length === 0) {
+    return { valid: false, reason: "Save contains no actions" };
+  }
+
+  const actions = save.actions;
+  if (!GAME_START_ACTIONS.includes(actions[0]?.type)) {
+    return {
+      valid: false,
+      reason: `Save must start with ${GAME_START_ACTIONS.join(" or ")}, found ${actions[0]?.type}`,
+    };
+  }
+
+  // Generated player IDs are numbered from P1, as in a freshly started session
+  resetPlayerIdCounter();
+
+  try {
+    let state = initialState;
+    const rejectedMoves: number[] = [];
+    actions.forEach((action, index) => {
+      const next = gameReducer(state, action);
+      if (next === state && MOVE_ACTIONS.includes(action.type)) {
+        rejectedMoves.push(index);
+      }
+      state = next;
+    });
+    return { valid: true, state, actions, rejectedMoves };
+  } catch (error) {
+    return { valid: false, reason: `Save could not be replayed: ${(error as Error).message}` };
+  }
+}
@@ -1,9 +1,13 @@
 // Memoized selectors for derived state
 
 import { RootState } from './types';
-import { HexPosition, Player } from '../game/types';
-import { getAllBoardPositions } from '../game/board';
-import { isLegalMove, getBlockedPlayers } from '../game/legality';
+import { HexPosition, Player, Rotation, TileType } from '../game/types';
+import { getAllBoardPositions, positionToKey } from '../game/board';
+import { isLegalMove, getBlockedPlayers, findAllLegalMoves, computeLegalityMap, legalityKey } from '../game/legality';
+import { getUniqueRotations } from '../game/tiles';
+import { selectAIMove, MAX_AI_DIFFICULTY, pathLengthEvaluator } from '../game/ai';
+import { getUndoableMoveCount, canSwapSides } from './gameReducer';
+import { getSessionScoreKey } from './reducer';
 
 // Get current player
 export const selectCurrentPlayer = (state: RootState): Player | null => {
@@ -14,6 +18,11 @@ export const selectCurrentPlayer = (state: RootState): Player | null => {
   return players[currentPlayerIndex];
 };
 
+// Get the tile types the no-self-stalemate rule checks placements against
+// Undefined when the rule is off, so legality checks skip the (expensive) continuation search
+export const selectContinuationTileTypes = (state: RootState): TileType[] | undefined =>
+  state.game.noSelfStalemate ? state.game.availableTiles : undefined;
+
 // Get legal positions for current tile
 export const selectLegalPositions = (state: RootState): HexPosition[] => {
   const { board, currentTile, players, teams } = state.game;
@@ -41,10 +50,59 @@ export const selectLegalPositions = (state: RootState): HexPosition[] => {
       position,
     };
 
-    return isLegalMove(board, placedTile, players, teams, state.game.boardRadius, state.game.supermove);
+    return isLegalMove(
+      board,
+      placedTile,
+      players,
+      teams,
+      state.game.boardRadius,
+      state.game.supermove,
+      selectContinuationTileTypes(state),
+      state.game.oppositeSidesVictory,
+    );
   });
 };
 
+// Get, for each empty hex, whether the tile in hand can be placed there in some rotation,
+// or only in the given rotation when one is passed
+// Keyed by position key; empty when no tile is held
+export const selectLegalTargets = (state: RootState, rotation?: Rotation): Map<string, boolean> => {
+  const { board, currentTile, players, teams, boardRadius, supermove, oppositeSidesVictory } = state.game;
+  const targets = new Map<string, boolean>();
+  if (currentTile === null) {
+    return targets;
+  }
+
+  // The legality map covers the path rules in one pass; the no-self-stalemate rule
+  // only rules out more placements, so it is checked just for those still legal
+  const legality = computeLegalityMap(board, currentTile, players, teams, boardRadius, supermove, oppositeSidesVictory);
+  const continuationTileTypes = selectContinuationTileTypes(state);
+  const rotations = rotation === undefined ? getUniqueRotations(currentTile) : [rotation];
+
+  for (const position of getAllBoardPositions(boardRadius)) {
+    const key = positionToKey(position);
+    if (board.has(key)) {
+      continue;
+    }
+    targets.set(key, rotations.some((r) =>
+      legality.get(legalityKey(position, r)) &&
+      (!continuationTileTypes ||
+        isLegalMove(board, { type: currentTile, rotation: r, position }, players, teams, boardRadius, supermove, continuationTileTypes, oppositeSidesVictory))
+    ));
+  }
+
+  return targets;
+};
+
+// Get every legal placement of the tile in hand, over all positions and distinct rotations
+export const selectLegalMovesForCurrentPlayer = (state: RootState): Array<{ position: HexPosition; rotation: Rotation }> => {
+  const { board, currentTile, players, teams, boardRadius, supermove, oppositeSidesVictory } = state.game;
+  if (currentTile === null) {
+    return [];
+  }
+  return findAllLegalMoves(board, currentTile, players, teams, boardRadius, supermove, selectContinuationTileTypes(state), oppositeSidesVictory);
+};
+
 // Get flows for rendering (convert Map to array format)
 export const selectFlowsForRendering = (state: RootState): Array<{
   playerId: string;
@@ -124,6 +182,103 @@ export const selectRemainingTileCounts = (state: RootState) => {
   return counts;
 };
 
+// Get the chance of drawing each tile type next, from the bag's composition
+// All zero when the bag is empty
+export const selectNextTileOdds = (state: RootState) => {
+  const { total, noSharps, oneSharp, twoSharps, threeSharps } = selectRemainingTileCounts(state);
+  const share = (count: number) => (total > 0 ? count / total : 0);
+
+  return {
+    noSharps: share(noSharps),
+    oneSharp: share(oneSharp),
+    twoSharps: share(twoSharps),
+    threeSharps: share(threeSharps),
+  };
+};
+
+// Check if the bag will run out before every player gets another draw
+export const willSomeoneMissDraw = (remainingTiles: number, playerCount: number): boolean =>
+  remainingTiles < playerCount;
+
+// Get whether to warn that the bag is about to run out (during play only)
+export const selectBagRunningOut = (state: RootState): boolean => {
+  const { phase, availableTiles, players } = state.game;
+  return phase === 'playing' && willSomeoneMissDraw(availableTiles.length, players.length);
+};
+
+// Get the number of the turn being played, counting from 1
+// Every recorded move counts, including supermove replacements
+export const selectTurnNumber = (state: RootState): number =>
+  state.game.moveHistory.length + 1;
+
+// Get how many tiles are on the board
+export const selectPlacedTileCount = (state: RootState): number =>
+  state.game.board.size;
+
+// Get the player the viewer may resign for: the current human player, on their own turn
+// In multiplayer only the local player can resign, and spectators never can
+export const selectResigningPlayer = (state: RootState): Player | null => {
+  const { phase, players, currentPlayerIndex } = state.game;
+  const { gameMode, localPlayerId, isSpectator } = state.ui;
+
+  if (phase !== 'playing' || isSpectator) {
+    return null;
+  }
+
+  const currentPlayer = players[currentPlayerIndex];
+  if (!currentPlayer || currentPlayer.isAI) {
+    return null;
+  }
+  if (gameMode === 'multiplayer' && currentPlayer.id !== localPlayerId) {
+    return null;
+  }
+  return currentPlayer;
+};
+
+// Check whether the viewer can take back the last move
+// Only tabletop games can undo; multiplayer moves are shared with the other clients
+export const selectCanUndo = (state: RootState): boolean =>
+  state.ui.gameMode === 'tabletop' &&
+  state.game.screen === 'gameplay' &&
+  getUndoableMoveCount(state.game) > 0;
+
+// Check whether the viewer is looking at an earlier position in the move list
+// The board is read-only while reviewing so a click can't play from the past
+export const selectIsReviewingHistory = (state: RootState): boolean =>
+  state.ui.moveListIndex !== -1;
+
+// Get the player the viewer may swap sides for under the pie rule, or null
+export const selectSwappingPlayer = (state: RootState): Player | null => {
+  const player = selectResigningPlayer(state);
+  return player && canSwapSides(state.game) ? player : null;
+};
+
+// Get the AI's recommended placement of the tile in hand for the player the viewer acts for
+// Only plain placements are suggested, so the hint is legal with or without supermove
+export const selectHintMove = (state: RootState): { position: HexPosition; rotation: Rotation } | null => {
+  const player = selectResigningPlayer(state);
+  const { board, currentTile, players, teams, boardRadius, oppositeSidesVictory } = state.game;
+  if (!player || currentTile === null) {
+    return null;
+  }
+
+  const move = selectAIMove(
+    board,
+    currentTile,
+    player,
+    players,
+    teams,
+    false,
+    boardRadius,
+    MAX_AI_DIFFICULTY,
+    0,
+    undefined,
+    pathLengthEvaluator,
+    oppositeSidesVictory,
+  );
+  return move ? { position: move.position, rotation: move.rotation } : null;
+};
+
 // Check if the current selected position would block any players
 export const selectBlockedPlayers = (state: RootState): Player[] => {
   const { board, currentTile, players, teams } = state.game;
@@ -139,8 +294,12 @@ export const selectBlockedPlayers = (state: RootState): Player[] => {
     position: selectedPosition,
   };
 
-  const blockedPlayerIds = getBlockedPlayers(board, placedTile, players, teams, state.game.boardRadius);
+  const blockedPlayerIds = getBlockedPlayers(board, placedTile, players, teams, state.game.boardRadius, state.game.oppositeSidesVictory);
   
   // Return the actual Player objects for the blocked players
   return players.filter(player => blockedPlayerIds.includes(player.id));
 };
+
+// Get a player's series wins since leaving the menu
+export const selectSessionScore = (state: RootState, player: Player): number =>
+  state.ui.sessionScores[getSessionScoreKey(player)] ?? 0;
@@ -0,0 +1,56 @@
+// Redux middleware to play sound effects for game events
+
+import { Middleware } from 'redux';
+import { RootState } from './types';
+import { GameAction, PLACE_TILE, SET_ROTATION, SET_SELECTED_POSITION } from './actions';
+import { SoundEffect, playSoundEffect } from '../audio/soundEffects';
+
+// Get the sound effects for an action, given the state before and after it
+export function getSoundEffects(
+  action: GameAction,
+  before: RootState,
+  after: RootState,
+): SoundEffect[] {
+  if (!after.ui.settings.soundEffects) {
+    return [];
+  }
+
+  const effects: SoundEffect[] = [];
+
+  if (action.type === PLACE_TILE && after.game.moveHistory.length > before.game.moveHistory.length) {
+    effects.push('place');
+  }
+
+  if (action.type === SET_ROTATION && after.ui.currentRotation !== before.ui.currentRotation) {
+    effects.push('rotate');
+  }
+
+  // The previewed tile snaps onto a newly selected hex
+  const selected = after.ui.selectedPosition;
+  const previous = before.ui.selectedPosition;
+  if (
+    action.type === SET_SELECTED_POSITION &&
+    selected !== null &&
+    (previous === null || selected.row !== previous.row || selected.col !== previous.col)
+  ) {
+    effects.push('snap');
+  }
+
+  if (before.game.winType === null && after.game.winType !== null) {
+    effects.push('victory');
+  }
+
+  return effects;
+}
+
+// Create the middleware, playing effects through the given player
+export const createSoundMiddleware = (
+  play: (effect: SoundEffect) => void = playSoundEffect
+): Middleware<{}, RootState> => (store) => (next) => (action) => {
+  const before = store.getState();
+  const result = next(action);
+  getSoundEffects(action as GameAction, before, store.getState()).forEach(play);
+  return result;
+};
+
+export const soundMiddleware = createSoundMiddleware();
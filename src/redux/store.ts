@@ -2,7 +2,9 @@
 
 import { createStore, applyMiddleware, compose } from 'redux';
 import { rootReducer } from './reducer';
-import { aiMiddleware } from './aiMiddleware';
+import { createAIMiddleware } from './aiMiddleware';
+import { pathLengthEvaluator } from '../game/ai';
+import { soundMiddleware } from './soundMiddleware';
 
 // Set up Redux DevTools Extension with middleware support
 const composeEnhancers =
@@ -12,8 +14,9 @@ const composeEnhancers =
     : compose;
 
 // Apply middleware
+// AI moves wait for the next paint so the board and "AI is thinking…" show before the search runs
 const enhancer = composeEnhancers(
-  applyMiddleware(aiMiddleware)
+  applyMiddleware(createAIMiddleware(pathLengthEvaluator, true), soundMiddleware)
 );
 
 export const store = createStore(rootReducer, enhancer);
@@ -11,7 +11,7 @@ import {
 
 export type Screen = 'configuration' | 'seating' | 'gameplay' | 'game-over';
 export type GamePhase = 'setup' | 'seating' | 'playing' | 'finished';
-export type WinType = 'flow' | 'constraint' | 'tie';
+export type WinType = 'flow' | 'constraint' | 'tie' | 'draw' | 'resignation';
 
 // Edge represents which edge of the display (0=bottom, 1=right, 2=top, 3=left)
 export type Edge = 0 | 1 | 2 | 3;
@@ -52,6 +52,7 @@ export interface GameState {
   
   // Random seed for deterministic behavior
   seed?: number;  // Optional seed for tile shuffling and seating order
+  shuffleVersion?: number; // How later shuffles are seeded (see SHUFFLE_VERSION); absent in older game logs
   
   // Seating phase
   seatingPhase: SeatingPhaseState;
@@ -70,9 +71,14 @@ export interface GameState {
   supermove: boolean;              // Whether supermove is enabled for this game
   singleSupermove: boolean;        // If true with supermove, replaced tile returns to bag
   supermoveAnyPlayer: boolean;     // If true with supermove, any player can supermove to unblock any player
+  oppositeSidesVictory: boolean;   // If true, connecting your edge to the opposite side wins (even in team games)
+  turnTimeLimit?: number;          // Seconds a multiplayer player has to move before the server resigns them; 0 for no limit
+  noSelfStalemate?: boolean;       // If true, a placement must leave a legal move for some tile still in the bag
+  longestFlowVictory?: boolean;    // If true, a board filled without a connection goes to the longest flow instead of a draw
   
   // Supermove state
   supermoveInProgress: boolean;    // True when player has replaced a tile and needs to place it
+  sidesSwapped?: boolean;          // True once the second player has taken the opening side (pie rule)
   
   // Flow tracking
   flows: Map<string, Set<string>>; // Player ID -> set of hex positions
@@ -82,6 +88,7 @@ export interface GameState {
   phase: GamePhase;
   winners: string[];  // Array of player IDs who won
   winType: WinType | null;
+  resignedPlayerIds?: string[];  // Players who have conceded; their turns are skipped
   
   // Move history
   moveHistory: Move[];
@@ -93,12 +100,27 @@ export interface GameState {
   aiScoringData?: Record<string, { rotation: number; score: number }[]>; // position key -> array of {rotation, score}
 }
 
+// Player colour palette
+export type Palette = 'default' | 'colorblind' | 'high-contrast';
+
 // Game settings
 export interface GameSettings {
   boardRadius: number;
   supermove: boolean;
   singleSupermove: boolean; // If true with supermove, replaced tile returns to bag and turn passes to next player
   supermoveAnyPlayer: boolean; // If true with supermove, any player can supermove to unblock any other player
+  oppositeSidesVictory: boolean; // If true, connecting your edge to the opposite side wins instead of reaching your partner's edge
+  noSelfStalemate: boolean; // Forbid placements that leave no legal move for any tile in the bag (expensive)
+  longestFlowVictory: boolean; // A full board with no connection goes to the player or team with the longest flow
+  autoRotateToLegal: boolean; // Snap the selected tile to its first legal rotation when the current one is illegal
+  highlightCriticalPaths: boolean; // Draw flows on each player's shortest winning path thicker
+  showCoordinates: boolean; // Label the board's rows and columns as in move notation
+  rotateBoardToCurrentPlayer: boolean; // Tabletop: turn the board so the player to move sits at the bottom
+  aiDifficulty: number; // 1 (easiest) to 3 (always plays its best move)
+  palette: Palette; // Colours used to draw players (see rendering/palette.ts)
+  animationSpeed: number; // Multiplier on animation speed; 0 skips animations
+  soundEffects: boolean; // Play sounds for placing, rotating and snapping tiles, and for victory
+  turnTimeLimit: number; // Seconds per turn in multiplayer games before the player is resigned; 0 disables the timer
   debugShowEdgeLabels: boolean;
   debugShowVictoryEdges: boolean;
   debugLegalityTest: boolean;
@@ -137,6 +159,7 @@ export interface UIState {
   
   // Visual preferences
   showLegalMoves: boolean;
+  legalMovesForHeldRotation: boolean; // Judge drop targets by the held rotation instead of any rotation
   showFlowMarkers: boolean;
   animationSpeed: number;
   
@@ -151,14 +174,22 @@ export interface UIState {
   // Help dialog
   showHelp: boolean;
   helpCorner: 0 | 1 | 2 | 3 | null; // 0=bottom-left, 1=bottom-right, 2=top-right, 3=top-left
+
+  // First-run tutorial overlay
+  showTutorial: boolean;
   
   // Move list dialog
   showMoveList: boolean;
   moveListCorner: 0 | 1 | 2 | 3 | null; // 0=bottom-left, 1=bottom-right, 2=top-right, 3=top-left
   moveListIndex: number; // Current position in move history when navigating
+  replayPlaying: boolean; // Whether the move list is auto-advancing through the history
+  replaySpeed: number; // Replay speed multiplier (see REPLAY_SPEEDS)
   
   // Saved game state (for back button on lobby)
   savedGameState: GameState | null;
+
+  // Series wins since leaving the menu (user ID, or player ID offline -> games won), carried across rematches
+  sessionScores: Record<string, number>;
   
   // Player connection state (for multiplayer)
   disconnectedPlayers: Set<string>; // Set of user IDs (e.g., 'google:...') that are currently disconnected
@@ -167,6 +198,15 @@ export interface UIState {
   // Spectator mode (for multiplayer)
   isSpectator: boolean; // Whether the user is currently spectating
   spectatorCount: number; // Number of spectators watching the current game
+  rematchVotes: { ready: number; total: number } | null; // Players who have asked for a rematch, or null when none is pending
+  serverNotice: string | null; // Brief multiplayer notice, e.g. why the server or client refused a move
+  aiThinking: boolean; // An AI move is waiting to run, so the player sees the AI is about to move
+
+  // Analysis-only board orientation override (independent of localPlayerId)
+  orientationPlayerId: string | null; // Draw the board from this player's edge, or null for the default
+
+  // Suggested placement for the tile in hand, shown until the player selects a hex or places a tile
+  hint: { position: HexPosition; rotation: Rotation } | null;
 }
 
 // Root state combining all state slices
@@ -187,3 +227,47 @@ export const PLAYER_COLORS = [
 ] as const;
 
 export const MAX_PLAYERS = 6;
+
+// Named tile distribution ratios: [NoSharps, OneSharp, TwoSharps, ThreeSharps]
+// Classic draws every type equally often; skewed favours gentle curves over sharp corners
+export const TILE_DISTRIBUTION_CLASSIC: [number, number, number, number] = [1, 1, 1, 1];
+export const TILE_DISTRIBUTION_SKEWED: [number, number, number, number] = [3, 2, 2, 1];
+
+// How a game's later shuffles are seeded, recorded in START_GAME so old logs replay as played
+// Logs without a version reshuffle a single supermove with the game seed itself;
+// version 1 derives a fresh seed for each move
+export const SHUFFLE_VERSION = 1;
+
+// Turn time limit choices in seconds, adjusted in steps from 0 (no limit)
+export const TURN_TIME_LIMIT_STEP = 30;
+export const MAX_TURN_TIME_LIMIT = 300;
+
+// Settings a fresh session starts from; a game started with them is a classic two-player game
+export const DEFAULT_GAME_SETTINGS: GameSettings = {
+  boardRadius: 3,
+  supermove: true,
+  singleSupermove: true,
+  supermoveAnyPlayer: false,
+  oppositeSidesVictory: false,
+  noSelfStalemate: false,
+  longestFlowVictory: false, // A full board is a draw
+  autoRotateToLegal: false,
+  highlightCriticalPaths: false,
+  showCoordinates: false,
+  rotateBoardToCurrentPlayer: false, // Tabletop boards stay put for players seated around them
+  aiDifficulty: 3, // Full strength by default
+  palette: 'default',
+  animationSpeed: 1,
+  soundEffects: true,
+  turnTimeLimit: 0, // No turn timer unless a room opts in
+  debugShowEdgeLabels: false,
+  debugShowVictoryEdges: false,
+  debugLegalityTest: false,
+  debugAnimationSlowdown: 1,
+  debugAIScoring: false,
+  debugHitTest: false, // Disabled by default - can be enabled in settings
+  tileDistribution: TILE_DISTRIBUTION_CLASSIC, // Default balanced distribution
+  enableDirtyRendering: true, // TEMPORARILY ENABLED FOR TESTING ON TABLETOP
+  debugShowDirtyRegions: false, // Debug visualization disabled for clean screenshots
+  debugShowRenderMetrics: false, // Debug metrics disabled for clean screenshots
+};
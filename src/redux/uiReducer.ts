@@ -1,6 +1,6 @@
 // Redux reducer for UI state management
 
-import { UIState } from './types';
+import { UIState, DEFAULT_GAME_SETTINGS } from './types';
 import {
   UIAction,
   SET_GAME_MODE,
@@ -10,18 +10,31 @@ import {
   SET_SELECTED_POSITION,
   SET_ROTATION,
   TOGGLE_LEGAL_MOVES,
+  TOGGLE_LEGAL_MOVES_ROTATION,
   TOGGLE_SETTINGS,
   UPDATE_SETTINGS,
   SHOW_HELP,
   HIDE_HELP,
+  SHOW_TUTORIAL,
+  HIDE_TUTORIAL,
   SHOW_MOVE_LIST,
   HIDE_MOVE_LIST,
   NAVIGATE_MOVE_LIST,
+  JUMP_TO_MOVE,
+  TOGGLE_REPLAY,
+  STEP_REPLAY,
+  SET_REPLAY_SPEED,
   SET_PLAYER_CONNECTED,
   SET_PLAYER_DISCONNECTED,
   SET_USER_ID_MAPPING,
   SET_SPECTATOR_MODE,
   SET_SPECTATOR_COUNT,
+  SET_REMATCH_VOTES,
+  SET_SERVER_NOTICE,
+  SET_AI_THINKING,
+  SET_ORIENTATION_OVERRIDE,
+  SHOW_HINT,
+  HIDE_HINT,
 } from './actions';
 
 // Initial UI state
@@ -33,6 +46,7 @@ export const initialUIState: UIState = {
   hoveredElement: null,
   currentRotation: 0,
   showLegalMoves: false,
+  legalMovesForHeldRotation: false, // Any rotation counts unless toggled
   showFlowMarkers: true,
   animationSpeed: 1.0,
   zoom: 1.0,
@@ -40,30 +54,24 @@ export const initialUIState: UIState = {
   showSettings: false,
   showHelp: false,
   helpCorner: null,
+  showTutorial: false,
   showMoveList: false,
   moveListCorner: null,
   moveListIndex: -1, // -1 means showing current state, 0+ means showing historical state
+  replayPlaying: false,
+  replaySpeed: 1,
   savedGameState: null,
+  sessionScores: {},
   disconnectedPlayers: new Set(), // Track disconnected players in multiplayer
   userIdToPlayerId: new Map(), // Maps user IDs to config player IDs
   isSpectator: false, // Track if user is in spectator mode
   spectatorCount: 0, // Track number of spectators watching
-  settings: {
-    boardRadius: 3,
-    supermove: true,
-    singleSupermove: true,
-    supermoveAnyPlayer: false,
-    debugShowEdgeLabels: false,
-    debugShowVictoryEdges: false,
-    debugLegalityTest: false,
-    debugAnimationSlowdown: 1,
-    debugAIScoring: false,
-    debugHitTest: false, // Disabled by default - can be enabled in settings
-    tileDistribution: [1, 1, 1, 1], // Default balanced distribution
-    enableDirtyRendering: true, // TEMPORARILY ENABLED FOR TESTING ON TABLETOP
-    debugShowDirtyRegions: false, // Debug visualization disabled for clean screenshots
-    debugShowRenderMetrics: false, // Debug metrics disabled for clean screenshots
-  },
+  rematchVotes: null,
+  serverNotice: null,
+  aiThinking: false,
+  orientationPlayerId: null, // No analysis orientation override
+  hint: null,
+  settings: DEFAULT_GAME_SETTINGS,
 };
 
 // UI Reducer function
@@ -104,6 +112,7 @@ export function uiReducer(
       return {
         ...state,
         selectedPosition: action.payload,
+        hint: action.payload ? null : state.hint, // Selecting a hex dismisses the hint
       };
     }
 
@@ -121,6 +130,13 @@ export function uiReducer(
       };
     }
 
+    case TOGGLE_LEGAL_MOVES_ROTATION: {
+      return {
+        ...state,
+        legalMovesForHeldRotation: !state.legalMovesForHeldRotation,
+      };
+    }
+
     case TOGGLE_SETTINGS: {
       return {
         ...state,
@@ -154,12 +170,27 @@ export function uiReducer(
       };
     }
 
+    case SHOW_TUTORIAL: {
+      return {
+        ...state,
+        showTutorial: true,
+      };
+    }
+
+    case HIDE_TUTORIAL: {
+      return {
+        ...state,
+        showTutorial: false,
+      };
+    }
+
     case SHOW_MOVE_LIST: {
       return {
         ...state,
         showMoveList: true,
         moveListCorner: action.payload.corner,
         moveListIndex: -1, // Start at current state
+        replayPlaying: false,
       };
     }
 
@@ -169,6 +200,7 @@ export function uiReducer(
         showMoveList: false,
         moveListCorner: null,
         moveListIndex: -1,
+        replayPlaying: false,
       };
     }
 
@@ -207,6 +239,52 @@ export function uiReducer(
       return {
         ...state,
         moveListIndex: newIndex,
+        replayPlaying: false, // Manual navigation pauses a replay
+      };
+    }
+
+    case JUMP_TO_MOVE: {
+      // Clamp to the game's history; reaching the end returns to the current state
+      const { index, moveCount } = action.payload;
+      return {
+        ...state,
+        moveListIndex: index >= moveCount ? -1 : Math.max(0, index),
+        replayPlaying: false, // Manual navigation pauses a replay
+      };
+    }
+
+    case TOGGLE_REPLAY: {
+      if (state.replayPlaying) {
+        return { ...state, replayPlaying: false };
+      }
+      if (action.payload.moveCount === 0) {
+        return state;
+      }
+      // Playing from the current state starts the replay from the empty board
+      return {
+        ...state,
+        replayPlaying: true,
+        moveListIndex: state.moveListIndex === -1 ? 0 : state.moveListIndex,
+      };
+    }
+
+    case STEP_REPLAY: {
+      if (!state.replayPlaying) {
+        return state;
+      }
+      // Stop once the replay reaches the current state
+      const { moveCount } = action.payload;
+      const nextIndex = (state.moveListIndex === -1 ? moveCount : state.moveListIndex) + 1;
+      if (nextIndex >= moveCount) {
+        return { ...state, moveListIndex: -1, replayPlaying: false };
+      }
+      return { ...state, moveListIndex: nextIndex };
+    }
+
+    case SET_REPLAY_SPEED: {
+      return {
+        ...state,
+        replaySpeed: action.payload.speed,
       };
     }
 
@@ -249,6 +327,48 @@ export function uiReducer(
       };
     }
 
+    case SET_REMATCH_VOTES: {
+      return {
+        ...state,
+        rematchVotes: action.payload.votes,
+      };
+    }
+
+    case SET_SERVER_NOTICE: {
+      return {
+        ...state,
+        serverNotice: action.payload.message,
+      };
+    }
+
+    case SET_AI_THINKING: {
+      return {
+        ...state,
+        aiThinking: action.payload.thinking,
+      };
+    }
+
+    case SET_ORIENTATION_OVERRIDE: {
+      return {
+        ...state,
+        orientationPlayerId: action.payload.playerId,
+      };
+    }
+
+    case SHOW_HINT: {
+      return {
+        ...state,
+        hint: action.payload,
+      };
+    }
+
+    case HIDE_HINT: {
+      return {
+        ...state,
+        hint: null,
+      };
+    }
+
     default:
       return state;
   }
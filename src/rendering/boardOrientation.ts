@@ -0,0 +1,49 @@
+// Board orientation: which player's edge is drawn at the bottom of the screen
+//
+// Orientation is independent of the viewer (localPlayerId), which decides whose
+// actions the UI acts for. An analysis override can rotate the board to any
+// player's perspective without changing the viewer. A tabletop game played on
+// one screen can instead turn to each player as their turn comes round.
+
+import { RootState } from '../redux/types';
+
+// Screen angle of each player edge before rotation
+const EDGE_ANGLES = [0, 60, 120, 180, 240, 300];
+
+// Get the ID of the player whose perspective the board is drawn from, or null for no rotation
+// The analysis override wins; otherwise multiplayer games are drawn from the local player's edge,
+// and tabletop games passed around one screen can follow the player to move
+export function getOrientationPlayerId(state: RootState): string | null {
+  if (state.ui.orientationPlayerId) {
+    return state.ui.orientationPlayerId;
+  }
+  if (state.ui.gameMode === 'multiplayer' && state.ui.localPlayerId) {
+    return state.ui.localPlayerId;
+  }
+  if (state.ui.gameMode === 'tabletop' && state.ui.settings.rotateBoardToCurrentPlayer) {
+    return state.game.players[state.game.currentPlayerIndex]?.id ?? null;
+  }
+  return null;
+}
+
+// Get the rotation angle (degrees) applied to the board
+export function getBoardRotationAngle(state: RootState): number {
+  const playerId = getOrientationPlayerId(state);
+  if (!playerId) {
+    return 0;
+  }
+
+  const player = state.game.players.find(p => p.id === playerId);
+  if (!player) {
+    return 0;
+  }
+
+  // Rotate so the player's edge is at the bottom; the extra 180° keeps it right-side-up
+  return -EDGE_ANGLES[player.edgePosition] + 180;
+}
+
+// Check if the board is drawn rotated to some player's perspective
+export function isBoardRotationActive(state: RootState): boolean {
+  const playerId = getOrientationPlayerId(state);
+  return playerId !== null && state.game.players.some(p => p.id === playerId);
+}
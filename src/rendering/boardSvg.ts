@@ -0,0 +1,139 @@
+// Board export: the whole board with placed tiles, flows and player edges as one SVG
+// Shares the flow curves with the tile atlas and the colours with the canvas
+// renderer so an exported board looks like the one on screen
+
+import { PlacedTile, Direction } from "../game/types";
+import { getAllBoardPositions, getEdgePositionsWithDirections, positionToKey } from "../game/board";
+import { getTileSegmentOwners } from "../game/flows";
+import { Palette } from "../redux/types";
+import {
+  HexLayout,
+  Point,
+  hexToPixel,
+  getHexVertices,
+  calculateBoardRadiusMultiplier,
+} from "./hexLayout";
+import { getPaletteColor } from "./palette";
+import { flowPath, formatPoint } from "./tileAtlas";
+
+// Everything the export draws; GameState satisfies this
+export interface BoardSvgGame {
+  board: Map<string, PlacedTile>;
+  players: Array<{ id: string; color: string; edgePosition: number }>;
+  flowEdges: Map<string, Map<Direction, string>>;
+  boardRadius: number;
+}
+
+export interface BoardSvgOptions {
+  hexSize?: number; // Distance from hex center to vertex, in pixels
+  rotation?: number; // Degrees to rotate the board, as returned by getBoardRotationAngle
+  palette?: Palette; // Colours used to draw players
+}
+
+const DEFAULT_HEX_SIZE = 40;
+
+// Colours from the canvas renderer's design spec
+const BOARD_HEX_BG = "#000000";
+const TILE_BG = "#2a2a2a";
+const TILE_BORDER = "#444444";
+const GRID_LINE = "#666666";
+const EMPTY_FLOW = "#888888";
+
+// Vertex indices bounding each direction's side of a pointy-top hex
+const VERTEX_PAIRS: Array<[number, number]> = [
+  [4, 5], // SouthWest
+  [3, 4], // West
+  [2, 3], // NorthWest
+  [1, 2], // NorthEast
+  [0, 1], // East
+  [5, 0], // SouthEast
+];
+
+// Draw a player's edge as a thick line along the board-side of their edge hexes
+function renderPlayerEdge(
+  edgePosition: number,
+  color: string,
+  layout: HexLayout,
+  boardRadius: number,
+): string {
+  const segments = getEdgePositionsWithDirections(edgePosition, boardRadius).map(({ pos, dir }) => {
+    const vertices = getHexVertices(hexToPixel(pos, layout), layout.size);
+    const [v1, v2] = VERTEX_PAIRS[dir];
+    return `M ${formatPoint(vertices[v1])} L ${formatPoint(vertices[v2])}`;
+  });
+
+  return `    <path class="player-edge" d="${segments.join(" ")}" fill="none" stroke="${color}" stroke-width="${(layout.size * 0.3).toFixed(2)}" stroke-linecap="round"/>`;
+}
+
+// Draw a placed tile with each flow in the colour of the player filling it
+function renderTile(
+  tile: PlacedTile,
+  game: BoardSvgGame,
+  layout: HexLayout,
+  colorOf: (playerId: string) => string,
+): string {
+  const center = hexToPixel(tile.position, layout);
+  const hexPoints = getHexVertices(center, layout.size).map(formatPoint).join(" ");
+  const flows = getTileSegmentOwners(tile, game.flowEdges).map(({ connection, owners }) => {
+    const color = owners.length > 0 ? colorOf(owners[0]) : EMPTY_FLOW;
+    const width = layout.size * (owners.length > 0 ? 0.18 : 0.08);
+    return `      <path class="flow" d="${flowPath(center, layout.size, connection)}" fill="none" stroke="${color}" stroke-width="${width.toFixed(2)}" stroke-linecap="round"/>`;
+  });
+
+  return [
+    `    <g class="tile" data-position="${positionToKey(tile.position)}" data-type="${tile.type}" data-rotation="${tile.rotation}">`,
+    `      <polygon points="${hexPoints}" fill="${TILE_BG}" stroke="${TILE_BORDER}" stroke-width="1"/>`,
+    ...flows,
+    `    </g>`,
+  ].join("\n");
+}
+
+// Generate an SVG of the board with its placed tiles, flows and player edges
+export function generateBoardSvg(game: BoardSvgGame, options: BoardSvgOptions = {}): string {
+  const size = options.hexSize ?? DEFAULT_HEX_SIZE;
+  const palette = options.palette ?? "default";
+
+  // The board hexagon reaches calculateBoardRadiusMultiplier hex sizes from the center
+  const boardHexRadius = size * calculateBoardRadiusMultiplier(game.boardRadius);
+  const extent = 2 * (boardHexRadius + size * 0.5);
+  const origin: Point = { x: extent / 2, y: extent / 2 };
+  const layout: HexLayout = { size, origin, canvasWidth: extent, canvasHeight: extent };
+
+  // Flat-top hexagon behind the tiles
+  const boardPoints = [0, 1, 2, 3, 4, 5]
+    .map((i) => formatPoint({
+      x: origin.x + boardHexRadius * Math.cos((Math.PI / 3) * i),
+      y: origin.y + boardHexRadius * Math.sin((Math.PI / 3) * i),
+    }))
+    .join(" ");
+
+  const colorOf = (playerId: string): string => {
+    const player = game.players.find((p) => p.id === playerId);
+    return player ? getPaletteColor(player.color, palette) : EMPTY_FLOW;
+  };
+
+  const edges = game.players.map((player) =>
+    renderPlayerEdge(player.edgePosition, getPaletteColor(player.color, palette), layout, game.boardRadius),
+  );
+
+  const grid = getAllBoardPositions(game.boardRadius).map((pos) => {
+    const hexPoints = getHexVertices(hexToPixel(pos, layout), size).map(formatPoint).join(" ");
+    return `    <polygon points="${hexPoints}" fill="none" stroke="${GRID_LINE}" stroke-width="1"/>`;
+  });
+
+  const tiles = Array.from(game.board.values()).map((tile) => renderTile(tile, game, layout, colorOf));
+
+  const rotation = options.rotation ?? 0;
+
+  return [
+    `<svg xmlns="http://www.w3.org/2000/svg" width="${extent.toFixed(0)}" height="${extent.toFixed(0)}" viewBox="0 0 ${extent.toFixed(2)} ${extent.toFixed(2)}">`,
+    `  <g transform="rotate(${rotation} ${formatPoint(origin).replace(",", " ")})">`,
+    `    <polygon points="${boardPoints}" fill="${BOARD_HEX_BG}"/>`,
+    ...edges,
+    ...grid,
+    ...tiles,
+    `  </g>`,
+    `</svg>`,
+    "",
+  ].join("\n");
+}
@@ -0,0 +1,39 @@
+// Corner buttons: the round buttons lined up along each player edge from its corner
+//
+// Each kind of button sits in a slot counted from the corner (exit button first),
+// so drawing and hit-testing share one layout instead of copying the offsets.
+
+// Diameter of a corner button
+export const CORNER_BUTTON_SIZE = 50;
+
+// Radius used both to draw a corner button and to hit-test clicks on it
+export const CORNER_BUTTON_RADIUS = CORNER_BUTTON_SIZE / 2;
+
+const CORNER_MARGIN = 10;
+const CORNER_SPACING = CORNER_BUTTON_SIZE * 0.15;
+
+export interface CornerButtonPosition {
+  x: number;
+  y: number;
+  edge: number; // 0 bottom, 1 right, 2 top, 3 left
+}
+
+// Get the centre of the button in a slot along each of the four edges
+export function getCornerButtonPositions(
+  slot: number,
+  layout: { canvasWidth: number; canvasHeight: number }
+): CornerButtonPosition[] {
+  const near = CORNER_MARGIN + CORNER_BUTTON_SIZE / 2;
+  const offset = slot * (CORNER_BUTTON_SIZE + CORNER_SPACING);
+
+  return [
+    // Edge 0 (bottom): running right from the bottom-left corner
+    { x: near + offset, y: layout.canvasHeight - near, edge: 0 },
+    // Edge 1 (right): running up from the bottom-right corner
+    { x: layout.canvasWidth - near, y: layout.canvasHeight - near - offset, edge: 1 },
+    // Edge 2 (top): running left from the top-right corner
+    { x: layout.canvasWidth - near - offset, y: near, edge: 2 },
+    // Edge 3 (left): running down from the top-left corner
+    { x: near, y: near + offset, edge: 3 },
+  ];
+}
@@ -4,6 +4,7 @@ import { RootState } from '../redux/types';
 import { DirtyRect } from './dirtyRegion';
 import { hexToPixel, HexLayout } from './hexLayout';
 import { keyToPosition } from '../game/board';
+import { isBoardRotationActive } from './boardOrientation';
 
 /**
  * Detects dirty regions by comparing Redux state changes
@@ -88,20 +89,13 @@ export class DirtyDetector {
 
     const dirtyRects: DirtyRect[] = [];
     
-    // Check if board rotation is active (multiplayer mode with local player)
+    // Check if board rotation is active (local player in multiplayer, or analysis override)
     // When the board is rotated, dirty region clipping becomes complex because
     // regions are calculated in screen coordinates but rendering happens in rotated coordinates.
     // For now, always do full redraw when rotation is active.
     // 
-    // NOTE: This logic matches gameplayRenderer.ts renderFull() and renderDirtyRegions() exactly.
-    // Future refactoring could extract this into a shared utility function like
-    // isBoardRotationActive(state: RootState): boolean
-    const hasBoardRotation = currentState.ui.gameMode === 'multiplayer' && 
-                              currentState.ui.localPlayerId && 
-                              currentState.game.players.length > 0 &&
-                              currentState.game.players.some(p => p.id === currentState.ui.localPlayerId);
-    
-    if (hasBoardRotation) {
+    // Uses the same check as gameplayRenderer.ts renderFull() and renderDirtyRegions().
+    if (isBoardRotationActive(currentState)) {
       // Full redraw needed when board rotation is active
       // TODO: In the future, transform dirty regions to match rotation instead of full redraw
       this.previousState = currentState;
@@ -129,6 +123,26 @@ export class DirtyDetector {
       dirtyRects.push({ x: 0, y: 0, width: canvasWidth, height: canvasHeight });
     }
 
+    // Check for hint changes (suggested placement overlay) - mark entire canvas dirty
+    if (this.previousState.ui.hint !== currentState.ui.hint) {
+      dirtyRects.push({ x: 0, y: 0, width: canvasWidth, height: canvasHeight });
+    }
+
+    // Check for rematch vote changes (waiting-for-players label) - mark entire canvas dirty
+    if (this.previousState.ui.rematchVotes !== currentState.ui.rematchVotes) {
+      dirtyRects.push({ x: 0, y: 0, width: canvasWidth, height: canvasHeight });
+    }
+
+    // Check for server notice changes (refused-move banner) - mark entire canvas dirty
+    if (this.previousState.ui.serverNotice !== currentState.ui.serverNotice) {
+      dirtyRects.push({ x: 0, y: 0, width: canvasWidth, height: canvasHeight });
+    }
+
+    // Check for the AI thinking indicator - mark entire canvas dirty
+    if (this.previousState.ui.aiThinking !== currentState.ui.aiThinking) {
+      dirtyRects.push({ x: 0, y: 0, width: canvasWidth, height: canvasHeight });
+    }
+
     // Check for rotation changes - mark entire canvas dirty
     if (this.previousState.ui.currentRotation !== currentState.ui.currentRotation) {
       dirtyRects.push({ x: 0, y: 0, width: canvasWidth, height: canvasHeight });
@@ -150,7 +164,9 @@ export class DirtyDetector {
         // Check for global animations by name
         const hasGlobalAnimation = currentState.animation.animations.some(anim => 
           anim.animationName === 'victory-flow-glow' || 
-          anim.animationName === 'supermove-glow'
+          anim.animationName === 'supermove-glow' ||
+          anim.animationName === 'held-tile-rotation' || // Brief, and the tile may be off the board
+          anim.animationName === 'board-rotation'
         );
 
         if (hasGlobalAnimation) {
@@ -182,9 +198,21 @@ export class DirtyDetector {
     // Check for dialog visibility changes
     if (
       this.previousState.ui.showHelp !== currentState.ui.showHelp ||
-      this.previousState.ui.showMoveList !== currentState.ui.showMoveList
+      this.previousState.ui.showMoveList !== currentState.ui.showMoveList ||
+      this.previousState.ui.showTutorial !== currentState.ui.showTutorial ||
+      this.previousState.ui.showLegalMoves !== currentState.ui.showLegalMoves ||
+      this.previousState.ui.legalMovesForHeldRotation !== currentState.ui.legalMovesForHeldRotation
+    ) {
+      // Dialog or overlay visibility changed - full redraw
+      dirtyRects.push({ x: 0, y: 0, width: canvasWidth, height: canvasHeight });
+    }
+
+    // Check for move list navigation and replay changes (board shows a historical position)
+    if (
+      this.previousState.ui.moveListIndex !== currentState.ui.moveListIndex ||
+      this.previousState.ui.replayPlaying !== currentState.ui.replayPlaying ||
+      this.previousState.ui.replaySpeed !== currentState.ui.replaySpeed
     ) {
-      // Dialog visibility changed - full redraw
       dirtyRects.push({ x: 0, y: 0, width: canvasWidth, height: canvasHeight });
     }
     
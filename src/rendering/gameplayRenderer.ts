@@ -1,6 +1,6 @@
 // Gameplay screen renderer for Phase 3
 
-import { RootState } from "../redux/types";
+import { RootState, Palette } from "../redux/types";
 import { store } from "../redux/store";
 import {
   HexLayout,
@@ -16,14 +16,21 @@ import {
 } from "./hexLayout";
 import {
   getAllBoardPositions,
+  getEdgePositions,
   getEdgePositionsWithDirections,
   getOppositeEdge,
   positionToKey,
+  keyToPosition,
 } from "../game/board";
+import { getTutorialInfo } from "../game/tutorial";
 import { victoryAnimationState } from "../animation/victoryAnimations";
-import { isConnectionInWinningPath } from "../game/victory";
-import { TileType, PlacedTile, Direction } from "../game/types";
+import { placedTileAnimationState } from "../animation/placementAnimations";
+import { heldTileRotationState, boardRotationState } from "../animation/rotationAnimations";
+import { getWinningPaths } from "../game/victory";
+import { TileType, PlacedTile, Direction, HexPosition } from "../game/types";
 import { getFlowConnections } from "../game/tiles";
+import { getTileSegmentOwners } from "../game/flows";
+import { computeOpponentSetback } from "../game/ai";
 import { getFlowPreviewData } from "../animation/flowPreview";
 import {
   isLegalMove,
@@ -31,14 +38,22 @@ import {
   getDebugPathInfo,
   isPlayerBlocked,
   isValidReplacementMove,
+  getCriticalPathSegments,
+  isSegmentOnCriticalPath,
+  segmentKey,
 } from "../game/legality";
 import { drawCircularArrow } from "./circularArrow";
-import { formatMoveHistory } from "../game/notation";
+import { getPaletteColor, getPlayerMarker } from "./palette";
+import { formatMoveHistory, getCoordinateLabels } from "../game/notation";
+import { selectBagRunningOut, selectResigningPlayer, selectRemainingTileCounts, selectNextTileOdds, selectCanUndo, selectTurnNumber, selectSwappingPlayer, selectContinuationTileTypes, selectIsReviewingHistory, selectLegalTargets, selectSessionScore } from "../redux/selectors";
+import { getPositionKey } from "../redux/positionKey";
+import { getBoardRotationAngle, isBoardRotationActive, getOrientationPlayerId } from "./boardOrientation";
 import cherryImageUrl from "../../assets/cherry.jpg";
 import { DirtyRegionTracker } from "./dirtyRegion";
 import { LayerCache } from "./layerCache";
 import { DirtyDetector } from "./dirtyDetector";
 import { OverlayCanvasPool } from "./overlayCanvasPool";
+import { CORNER_BUTTON_SIZE, CORNER_BUTTON_RADIUS, getCornerButtonPositions } from "./cornerButtons";
 
 // UI Colors from design spec
 const CANVAS_BG = "#e8e8e8"; // Light gray "table"
@@ -46,11 +61,30 @@ const BOARD_HEX_BG = "#000000"; // Black
 const TILE_BG = "#2a2a2a"; // Dark gray
 const TILE_BORDER = "#444444"; // Slightly lighter gray
 const BUTTON_ICON = "#ffffff"; // White
+const HINT_BORDER = "#00E5FF"; // Cyan, distinct from player colours and the red illegal border
+const LEGAL_TARGET_FILL = "rgba(0, 200, 83, 0.18)"; // Faint green over hexes the held tile can go
+const ILLEGAL_TARGET_FILL = "rgba(229, 57, 53, 0.18)"; // Faint red over hexes where no rotation is legal
+const ILLEGAL_TARGET_HATCH = "rgba(229, 57, 53, 0.35)"; // Hatching over those dead hexes
+const CRITICAL_FLOW_WIDTH_SCALE = 1.4; // Flow thickness on shortest winning paths
 
 export class GameplayRenderer {
   private ctx: CanvasRenderingContext2D;
   private layout: HexLayout;
   private bezierLengthCache: Map<string, number> = new Map();
+  // Opponent setback for the previewed move, cached while the preview is unchanged
+  private opponentSetbackCache: { key: string; setback: number } | null = null;
+  // Legality of the previewed move, cached while the position and preview are unchanged
+  private previewLegalityCache: { key: string; isLegal: boolean; blockedPlayerIds: string[] } | null = null;
+  // Legal drop targets for the held tile, cached while the position and held rotation are unchanged
+  private legalTargetsCache: { key: string; targets: Map<string, boolean> } | null = null;
+  // Flow segments on shortest winning paths, cached per board state
+  private criticalSegmentsCache: { key: string; segments: Set<string> } | null = null;
+  // Flow segments on the winners' edge-to-edge flows, cached per finished game
+  private winningSegmentsCache: { key: string; segments: Set<string> } | null = null;
+  // Line width multiplier applied when drawing flows (raised for critical path segments)
+  private flowWidthScale: number = 1.0;
+  // Player colour palette, taken from settings at the start of each frame
+  private palette: Palette = 'default';
   private boardRadius: number;
   private woodBackgroundCanvas: HTMLCanvasElement | null = null;
   private woodImage: HTMLImageElement | null = null;
@@ -188,6 +222,7 @@ export class GameplayRenderer {
 
   render(state: RootState): void {
     const startTime = performance.now();
+    this.palette = state.ui.settings.palette;
 
     // Phase 0: Don't render anything if the wood texture hasn't loaded yet
     // This prevents the "white flash" or "solid color" appearance on startup
@@ -280,23 +315,9 @@ export class GameplayRenderer {
     // Save canvas state before applying board rotation
     this.ctx.save();
     
-    // In multiplayer mode, rotate only the board/tiles so the local player's edge is at the bottom
-    if (state.ui.gameMode === 'multiplayer' && state.ui.localPlayerId && state.game.players.length > 0) {
-      // Find the local player's info
-      const localPlayer = state.game.players.find(p => p.id === state.ui.localPlayerId);
-      if (localPlayer) {
-        // Calculate rotation needed to put player's edge at bottom (edge 0 position)
-        // Edge positions: 0=bottom, 1=bottom-right, 2=top-right, 3=top, 4=top-left, 5=bottom-left
-        // We need to rotate so that player's edge becomes edge 0, and add 180° to keep it right-side-up
-        const edgeAngles = [0, 60, 120, 180, 240, 300];
-        const rotationAngle = -edgeAngles[localPlayer.edgePosition] + 180; // Add 180° to flip orientation
-        
-        // Rotate around the center of the canvas
-        this.ctx.translate(this.layout.canvasWidth / 2, this.layout.canvasHeight / 2);
-        this.ctx.rotate((rotationAngle * Math.PI) / 180);
-        this.ctx.translate(-this.layout.canvasWidth / 2, -this.layout.canvasHeight / 2);
-      }
-    }
+    // Rotate only the board/tiles so the oriented player's edge is at the bottom
+    // (the local player in multiplayer, or the analysis orientation override)
+    this.applyBoardRotation(state);
 
     // Layer 2: Board hexagon with colored edges (cached)
     this.renderBoardCached(state);
@@ -306,6 +327,11 @@ export class GameplayRenderer {
       this.renderEdgeDirectionLabels(state.game.boardRadius);
     }
 
+    // Layer 2.75: Row letters and column numbers around the board
+    if (state.ui.settings.showCoordinates) {
+      this.renderCoordinateLabels(state);
+    }
+
     // Layer 2.8: Debug - Highlight victory condition edges
     if (state.ui.settings.debugShowVictoryEdges) {
       this.renderVictoryConditionEdges(state);
@@ -319,12 +345,18 @@ export class GameplayRenderer {
     // Layer 3: Placed tiles
     this.renderPlacedTiles(state);
 
+    // Layer 3.2: Tint empty hexes by whether the held tile can be played there
+    this.renderLegalTargets(state);
+
     // Layer 3.5: Highlight most recently placed tile
     this.renderLastPlacedTileHighlight(state);
 
     // Layer 4: Current tile preview
     this.renderCurrentTilePreview(state);
 
+    // Layer 4.5: Hinted placement for the tile in hand
+    this.renderHint(state);
+
     // Layer 5: Action buttons (checkmark and X)
     this.renderActionButtons(state);
 
@@ -348,6 +380,11 @@ export class GameplayRenderer {
       this.renderDebugApexVertex(state);
     }
     
+    // Layer 9.5: Tutorial edge highlights (rotated with the board)
+    if (state.ui.showTutorial) {
+      this.renderTutorialHighlights(state);
+    }
+    
     // Restore canvas state after board/tile rendering (removes rotation)
     this.ctx.restore();
 
@@ -356,6 +393,10 @@ export class GameplayRenderer {
 
     // Layer 6.4: Spectator indicator (if in spectator mode)
     this.renderSpectatorIndicator(state);
+    this.renderBagWarning(state);
+    this.renderServerNotice(state);
+    this.renderAIThinking(state);
+    this.renderTileBagCounts(state);
 
     // Layer 6.5: Help buttons in corners (only show on current player's edge in multiplayer mode)
     this.renderHelpButtons(state);
@@ -363,9 +404,30 @@ export class GameplayRenderer {
     // Layer 6.6: Move list buttons in corners (only show on current player's edge in multiplayer mode)
     this.renderMoveListButtons(state);
 
+    // Layer 6.62: Resign buttons (while it's the viewing player's turn)
+    this.renderResignButtons(state);
+
+    // Layer 6.63: Hint buttons (while it's the viewing player's turn)
+    this.renderHintButtons(state);
+
+    // Layer 6.64: Undo buttons (tabletop games with a move to take back)
+    this.renderUndoButtons(state);
+
+    // Layer 6.645: Swap buttons (second player's first turn, under the pie rule)
+    this.renderSwapButtons(state);
+
     // Layer 6.65: Rematch buttons (if game over and not spectator)
     if (state.game.screen === 'game-over') {
+      this.renderDrawBanner(state);
+      this.renderSessionScores(state);
       this.renderRematchButtons(state);
+      this.renderTranscriptButtons(state);
+      this.renderExportButtons(state);
+    }
+
+    // Layer 6.66: First-run tutorial panel
+    if (state.ui.showTutorial) {
+      this.renderTutorialPanel(state);
     }
 
     // Layer 6.7: Help dialog if open
@@ -461,17 +523,7 @@ export class GameplayRenderer {
 
         // Apply board rotation for rotated layers
         this.ctx.save();
-        if (state.ui.gameMode === 'multiplayer' && state.ui.localPlayerId && state.game.players.length > 0) {
-          const localPlayer = state.game.players.find(p => p.id === state.ui.localPlayerId);
-          if (localPlayer) {
-            const edgeAngles = [0, 60, 120, 180, 240, 300];
-            const rotationAngle = -edgeAngles[localPlayer.edgePosition] + 180;
-
-            this.ctx.translate(this.layout.canvasWidth / 2, this.layout.canvasHeight / 2);
-            this.ctx.rotate((rotationAngle * Math.PI) / 180);
-            this.ctx.translate(-this.layout.canvasWidth / 2, -this.layout.canvasHeight / 2);
-          }
-        }
+        this.applyBoardRotation(state);
 
         // Render board directly (optimized to filter elements)
         this.renderBoardDirect(state, region);
@@ -506,6 +558,10 @@ export class GameplayRenderer {
           this.renderEdgeDirectionLabels(state.game.boardRadius);
         }
 
+        if (state.ui.settings.showCoordinates) {
+          this.renderCoordinateLabels(state);
+        }
+
         if (state.ui.settings.debugShowVictoryEdges) {
           this.renderVictoryConditionEdges(state);
         }
@@ -514,8 +570,10 @@ export class GameplayRenderer {
           this.renderAIScoring(state);
         }
 
+        this.renderLegalTargets(state);
         this.renderLastPlacedTileHighlight(state);
         this.renderCurrentTilePreview(state);
+        this.renderHint(state);
         this.renderActionButtons(state);
 
         if (state.game.screen === "game-over") {
@@ -534,14 +592,30 @@ export class GameplayRenderer {
           this.renderDebugApexVertex(state);
         }
 
+        if (state.ui.showTutorial) {
+          this.renderTutorialHighlights(state);
+        }
+
         // Restore rotation
         this.ctx.restore();
 
         // Render UI elements (not rotated)
         this.renderExitButtons(state);
         this.renderSpectatorIndicator(state);
+        this.renderBagWarning(state);
+        this.renderServerNotice(state);
+        this.renderAIThinking(state);
+        this.renderTileBagCounts(state);
         this.renderHelpButtons(state);
         this.renderMoveListButtons(state);
+        this.renderResignButtons(state);
+        this.renderHintButtons(state);
+        this.renderUndoButtons(state);
+        this.renderSwapButtons(state);
+
+        if (state.ui.showTutorial) {
+          this.renderTutorialPanel(state);
+        }
 
         if (state.ui.showHelp && state.ui.helpCorner !== null) {
           this.renderHelpDialog(state.ui.helpCorner, state);
@@ -626,6 +700,21 @@ export class GameplayRenderer {
     this.ctx.drawImage(canvas, 0, 0);
   }
 
+  /**
+   * Rotate the canvas about its center by the board angle, including any reorientation still animating
+   */
+  private applyBoardRotation(state: RootState): void {
+    const offset = boardRotationState.offset;
+    if (!isBoardRotationActive(state) && offset === 0) {
+      return;
+    }
+
+    const rotationAngle = getBoardRotationAngle(state);
+    this.ctx.translate(this.layout.canvasWidth / 2, this.layout.canvasHeight / 2);
+    this.ctx.rotate((rotationAngle * Math.PI) / 180 + offset);
+    this.ctx.translate(-this.layout.canvasWidth / 2, -this.layout.canvasHeight / 2);
+  }
+
   /**
    * Transform canonical coordinates (unrotated) to screen coordinates (possibly rotated)
    */
@@ -702,8 +791,9 @@ export class GameplayRenderer {
             center,
             boardRadius,
             player.edgePosition,
-            player.color,
+            this.displayColor(player.color),
             state.game.boardRadius,
+            getPlayerMarker(player.color, this.palette),
           );
         }
       });
@@ -756,8 +846,9 @@ export class GameplayRenderer {
             center,
             boardRadius,
             player.edgePosition,
-            player.color,
+            this.displayColor(player.color),
             state.game.boardRadius,
+            getPlayerMarker(player.color, this.palette),
           );
         });
       }
@@ -785,7 +876,7 @@ export class GameplayRenderer {
     if (!this.previousStateForCache) {
       this.previousStateForCache = {
         boardRadius: state.game.boardRadius,
-        players: state.game.players.map(p => ({ id: p.id, color: p.color, edgePosition: p.edgePosition })),
+        players: state.game.players.map(p => ({ id: p.id, color: this.displayColor(p.color), edgePosition: p.edgePosition })),
         canvasWidth: this.layout.canvasWidth,
         canvasHeight: this.layout.canvasHeight,
       };
@@ -807,7 +898,7 @@ export class GameplayRenderer {
         const currentPlayer = state.game.players[i];
         return !currentPlayer || 
                p.id !== currentPlayer.id || 
-               p.color !== currentPlayer.color || 
+               p.color !== this.displayColor(currentPlayer.color) || 
                p.edgePosition !== currentPlayer.edgePosition;
       });
     
@@ -818,10 +909,15 @@ export class GameplayRenderer {
         this.previousStateForCache.canvasHeight !== this.layout.canvasHeight) {
       this.layerCache.invalidateBoard();
       this.previousStateForCache.boardRadius = state.game.boardRadius;
-      this.previousStateForCache.players = state.game.players.map(p => ({ id: p.id, color: p.color, edgePosition: p.edgePosition }));
+      this.previousStateForCache.players = state.game.players.map(p => ({ id: p.id, color: this.displayColor(p.color), edgePosition: p.edgePosition }));
     }
   }
 
+  // Colour to draw for a player colour under the chosen palette
+  private displayColor(color: string): string {
+    return getPaletteColor(color, this.palette);
+  }
+
   private previousStateForCache: {
     boardRadius: number;
     players: Array<{ id: string; color: string; edgePosition: number }>;
@@ -850,6 +946,7 @@ export class GameplayRenderer {
     edgePosition: number,
     color: string,
     boardRadius: number,
+    marker: string | null = null,
   ): void {
     // Create a polygon from the zig-zag of source edges plus perpendiculars to board boundary
 
@@ -1066,6 +1163,24 @@ export class GameplayRenderer {
     ctx.lineCap = "round";
     ctx.lineJoin = "round";
     ctx.stroke();
+
+    // Draw the marker midway between the zig-zag and the board boundary
+    if (marker) {
+      const zigzagMiddle = zigzagVertices[Math.floor(zigzagVertices.length / 2)];
+      const markerX = (zigzagMiddle.x + (boardV1.x + boardV2.x) / 2) / 2;
+      const markerY = (zigzagMiddle.y + (boardV1.y + boardV2.y) / 2) / 2;
+
+      ctx.save();
+      ctx.font = `bold ${this.layout.size * 0.35}px sans-serif`;
+      ctx.textAlign = "center";
+      ctx.textBaseline = "middle";
+      ctx.strokeStyle = "#000000";
+      ctx.lineWidth = 3;
+      ctx.strokeText(marker, markerX, markerY);
+      ctx.fillStyle = "#FFFFFF";
+      ctx.fillText(marker, markerX, markerY);
+      ctx.restore();
+    }
   }
 
   // Render victory stars at player edges
@@ -1141,7 +1256,7 @@ export class GameplayRenderer {
           this.drawStar(
             starPosition,
             this.layout.size * 0.4,
-            player.color,
+            this.displayColor(player.color),
             glowIntensity,
           );
         }
@@ -1152,7 +1267,7 @@ export class GameplayRenderer {
           this.drawStar(
             starPosition,
             this.layout.size * 0.4,
-            player.color,
+            this.displayColor(player.color),
             glowIntensity,
           );
         }
@@ -1280,7 +1395,7 @@ export class GameplayRenderer {
         const v2 = vertices[v2Index];
 
         // Draw a dashed line in the player's color to indicate victory edge
-        this.ctx.strokeStyle = player.color;
+        this.ctx.strokeStyle = this.displayColor(player.color);
         this.ctx.lineWidth = this.layout.size * 0.15;
         this.ctx.lineCap = "round";
         this.ctx.setLineDash([5, 5]); // Dashed pattern
@@ -1323,6 +1438,41 @@ export class GameplayRenderer {
     });
   }
 
+  private renderCoordinateLabels(state: RootState): void {
+    // Label rows and columns as move notation does, from the oriented player's view
+    // (or the player on turn when the board isn't oriented to anyone)
+    const orientationPlayerId = getOrientationPlayerId(state);
+    const viewer =
+      state.game.players.find((p) => p.id === orientationPlayerId) ??
+      state.game.players[state.game.currentPlayerIndex];
+    const labels = getCoordinateLabels(viewer?.edgePosition ?? 0, state.game.boardRadius);
+
+    // Counter the board rotation so the labels stay upright, even while it turns
+    const angle =
+      (isBoardRotationActive(state) ? getBoardRotationAngle(state) : 0) +
+      (boardRotationState.offset * 180) / Math.PI;
+
+    this.ctx.font = `bold ${this.layout.size * 0.4}px sans-serif`;
+    this.ctx.textAlign = "center";
+    this.ctx.textBaseline = "middle";
+    this.ctx.fillStyle = "#ffffff";
+    this.ctx.strokeStyle = "#000000";
+    this.ctx.lineWidth = 3;
+
+    labels.forEach(({ text, inside, outside }) => {
+      // Just beyond the edge hex, on the way to the off-board hex
+      const from = hexToPixel(inside, this.layout);
+      const to = hexToPixel(outside, this.layout);
+
+      this.ctx.save();
+      this.ctx.translate(from.x + (to.x - from.x) * 0.6, from.y + (to.y - from.y) * 0.6);
+      this.ctx.rotate((-angle * Math.PI) / 180);
+      this.ctx.strokeText(text, 0, 0);
+      this.ctx.fillText(text, 0, 0);
+      this.ctx.restore();
+    });
+  }
+
   private renderAIScoring(state: RootState): void {
     // Debug rendering: Show AI evaluation scores for each rotation at each position
     if (!state.game.aiScoringData) return;
@@ -1374,25 +1524,47 @@ export class GameplayRenderer {
     // If viewing move history, reconstruct board at that point
     const boardToRender = this.getBoardAtMoveIndex(state);
 
+    // A tile that just arrived from another player fades in
+    const fadingPosition = placedTileAnimationState.position;
+    const fadingKey = fadingPosition ? positionToKey(fadingPosition) : null;
+    const opacityFor = (tile: PlacedTile): number =>
+      fadingKey !== null && positionToKey(tile.position) === fadingKey
+        ? placedTileAnimationState.opacity
+        : 1.0;
+    const withOpacity = (tile: PlacedTile, draw: () => void): void => {
+      const opacity = opacityFor(tile);
+      if (opacity >= 1.0) {
+        draw();
+        return;
+      }
+      this.ctx.save();
+      this.ctx.globalAlpha = opacity;
+      draw();
+      this.ctx.restore();
+    };
+
     // Multi-pass rendering for correct layering:
     // Pass 1: Draw all tile backgrounds
     boardToRender.forEach((tile) => {
-      this.renderTileBackground(tile, 1.0);
+      this.renderTileBackground(tile, opacityFor(tile));
     });
 
     // Pass 2: Draw all grey channels (unfilled connections)
     boardToRender.forEach((tile) => {
-      this.renderGreyChannels(tile, state);
+      withOpacity(tile, () => this.renderGreyChannels(tile, state));
     });
 
-    // Pass 3: Draw all filled flows
+    // Pass 3: Draw all filled flows (critical path segments thicker when enabled)
+    const criticalSegments = state.ui.settings.highlightCriticalPaths
+      ? this.getCriticalSegments(state, boardToRender)
+      : null;
     boardToRender.forEach((tile) => {
-      this.renderFilledFlows(tile, state);
+      withOpacity(tile, () => this.renderFilledFlows(tile, state, criticalSegments));
     });
 
     // Pass 4: Draw all animating flows
     boardToRender.forEach((tile) => {
-      this.renderAnimatingFlows(tile, state);
+      withOpacity(tile, () => this.renderAnimatingFlows(tile, state));
     });
   }
 
@@ -1458,12 +1630,30 @@ export class GameplayRenderer {
 
     // Draw dashed border in the placing player's color
     this.ctx.save();
-    this.ctx.strokeStyle = placingPlayer.color;
+    this.ctx.strokeStyle = this.displayColor(placingPlayer.color);
     this.ctx.lineWidth = 2;
     this.ctx.setLineDash([8, 4]); // Dashed pattern
     this.ctx.lineCap = "round";
     this.drawHexagon(center, this.layout.size, false);
     this.ctx.restore();
+
+    // Pulse the border of a tile that just arrived from another player
+    const pulsingPosition = placedTileAnimationState.position;
+    if (
+      pulsingPosition &&
+      placedTileAnimationState.pulseIntensity > 0 &&
+      pulsingPosition.row === state.game.lastPlacedTilePosition.row &&
+      pulsingPosition.col === state.game.lastPlacedTilePosition.col
+    ) {
+      this.ctx.save();
+      this.ctx.strokeStyle = this.displayColor(placingPlayer.color);
+      this.ctx.globalAlpha = placedTileAnimationState.pulseIntensity;
+      this.ctx.lineWidth = 2 + 4 * placedTileAnimationState.pulseIntensity;
+      this.ctx.shadowColor = this.displayColor(placingPlayer.color);
+      this.ctx.shadowBlur = 15 * placedTileAnimationState.pulseIntensity;
+      this.drawHexagon(center, this.layout.size, false);
+      this.ctx.restore();
+    }
   }
 
   private renderTile(
@@ -1496,24 +1686,70 @@ export class GameplayRenderer {
 
   private renderGreyChannels(tile: PlacedTile, state: RootState): void {
     const center = hexToPixel(tile.position, this.layout);
-    const connections = getFlowConnections(tile.type, tile.rotation);
-    const tileKey = `${tile.position.row},${tile.position.col}`;
-    const tileFlowEdges = state.game.flowEdges.get(tileKey);
 
-    connections.forEach(([dir1, dir2]) => {
+    getTileSegmentOwners(tile, state.game.flowEdges).forEach(({ connection, owners }) => {
       // Only draw grey if this connection doesn't have filled flow
-      // With one-way flows, only the entry direction is recorded, so check if EITHER direction has a player
-      const player1 = tileFlowEdges?.get(dir1);
-      const player2 = tileFlowEdges?.get(dir2);
-      const hasFlow = player1 !== undefined || player2 !== undefined;
-
-      if (!hasFlow) {
-        this.drawFlowConnection(center, dir1, dir2, "#888888", 1.0, false);
+      if (owners.length === 0) {
+        this.drawFlowConnection(center, connection[0], connection[1], "#888888", 1.0, false);
       }
     });
   }
 
-  private renderFilledFlows(tile: PlacedTile, state: RootState): void {
+  // Get the flow segments on each player's shortest winning path for the rendered board
+  private getCriticalSegments(
+    state: RootState,
+    board: Map<string, PlacedTile>,
+  ): Set<string> {
+    // The position key changes with every placement, replacement, undo and swap,
+    // which the move count alone would miss
+    const key = `${getPositionKey(state.game)}-${state.ui.moveListIndex}`;
+    if (this.criticalSegmentsCache?.key === key) {
+      return this.criticalSegmentsCache.segments;
+    }
+
+    const segments = getCriticalPathSegments(
+      board,
+      state.game.players,
+      state.game.teams,
+      state.game.boardRadius,
+      state.game.oppositeSidesVictory,
+    );
+    this.criticalSegmentsCache = { key, segments };
+    return segments;
+  }
+
+  // Check whether a flow segment lies on a winner's edge-to-edge flow
+  private isOnWinningPath(
+    state: RootState,
+    position: HexPosition,
+    dir1: Direction,
+    dir2: Direction,
+  ): boolean {
+    const key = `${state.game.moveHistory.length}-${state.game.winners.join(",")}`;
+    if (this.winningSegmentsCache?.key !== key) {
+      const segments = new Set<string>();
+      for (const path of getWinningPaths(
+        state.game.board,
+        state.game.players,
+        state.game.teams,
+        state.game.winners,
+        state.game.boardRadius,
+        state.game.oppositeSidesVictory,
+      )) {
+        for (const { position: segmentPosition, entry, exit } of path.segments) {
+          segments.add(segmentKey(segmentPosition, entry, exit));
+        }
+      }
+      this.winningSegmentsCache = { key, segments };
+    }
+    return this.winningSegmentsCache.segments.has(segmentKey(position, dir1, dir2));
+  }
+
+  private renderFilledFlows(
+    tile: PlacedTile,
+    state: RootState,
+    criticalSegments: Set<string> | null = null,
+  ): void {
     const center = hexToPixel(tile.position, this.layout);
     const connections = getFlowConnections(tile.type, tile.rotation);
     const tileKey = `${tile.position.row},${tile.position.col}`;
@@ -1525,6 +1761,12 @@ export class GameplayRenderer {
     const winnerIds = state.game.winners;
 
     connections.forEach(([dir1, dir2]) => {
+      this.flowWidthScale =
+        criticalSegments &&
+        isSegmentOnCriticalPath(criticalSegments, tile.position, dir1, dir2)
+          ? CRITICAL_FLOW_WIDTH_SCALE
+          : 1.0;
+
       // Check both possible direction orderings for animation data
       const animKey1 = `flow-preview-${tileKey}-${dir1}-${dir2}`;
       const animKey2 = `flow-preview-${tileKey}-${dir2}-${dir1}`;
@@ -1551,21 +1793,18 @@ export class GameplayRenderer {
             const shouldGlow1 =
               isGameOver &&
               winnerIds.includes(player1) &&
-              isConnectionInWinningPath(
+              this.isOnWinningPath(
+                state,
                 tile.position,
                 dir1 as Direction,
                 dir2 as Direction,
-                player1,
-                state.game.flows,
-                state.game.flowEdges,
-                state.game.boardRadius,
               );
 
             this.drawFlowConnection(
               center,
               dir1,
               dir2,
-              player1Obj.color,
+              this.displayColor(player1Obj.color),
               1.0,
               false,
               shouldGlow1,
@@ -1576,21 +1815,18 @@ export class GameplayRenderer {
             const shouldGlow2 =
               isGameOver &&
               winnerIds.includes(player2) &&
-              isConnectionInWinningPath(
+              this.isOnWinningPath(
+                state,
                 tile.position,
                 dir1 as Direction,
                 dir2 as Direction,
-                player2,
-                state.game.flows,
-                state.game.flowEdges,
-                state.game.boardRadius,
               );
 
             this.drawFlowConnection(
               center,
               dir1,
               dir2,
-              player2Obj.color,
+              this.displayColor(player2Obj.color),
               1.0,
               false,
               shouldGlow2,
@@ -1610,21 +1846,18 @@ export class GameplayRenderer {
             const shouldGlow =
               isGameOver &&
               winnerIds.includes(playerId) &&
-              isConnectionInWinningPath(
+              this.isOnWinningPath(
+                state,
                 tile.position,
                 dir1 as Direction,
                 dir2 as Direction,
-                playerId,
-                state.game.flows,
-                state.game.flowEdges,
-                state.game.boardRadius,
               );
 
             this.drawFlowConnection(
               center,
               dir1,
               dir2,
-              player.color,
+              this.displayColor(player.color),
               1.0,
               false,
               shouldGlow,
@@ -1642,21 +1875,18 @@ export class GameplayRenderer {
           const shouldGlow =
             isGameOver &&
             winnerIds.includes(playerId) &&
-            isConnectionInWinningPath(
+            this.isOnWinningPath(
+              state,
               tile.position,
               animData.direction1 as Direction,
               animData.direction2 as Direction,
-              playerId,
-              state.game.flows,
-              state.game.flowEdges,
-              state.game.boardRadius,
             );
 
           this.drawFlowConnection(
             center,
             animData.direction1,
             animData.direction2,
-            player.color,
+            this.displayColor(player.color),
             1.0,
             false,
             shouldGlow,
@@ -1664,6 +1894,8 @@ export class GameplayRenderer {
         }
       }
     });
+
+    this.flowWidthScale = 1.0;
   }
 
   private renderAnimatingFlows(tile: PlacedTile, state: RootState): void {
@@ -1690,7 +1922,7 @@ export class GameplayRenderer {
               center,
               animData.direction1,
               animData.direction2,
-              player.color,
+              this.displayColor(player.color),
               animationProgress,
               true,
             );
@@ -1842,7 +2074,7 @@ export class GameplayRenderer {
 
     this.ctx.strokeStyle = color;
     // Increase stroke width slightly from 0.15 to 0.18
-    this.ctx.lineWidth = this.layout.size * 0.18;
+    this.ctx.lineWidth = this.layout.size * 0.18 * this.flowWidthScale;
     this.ctx.lineCap = "round";
 
     if (isAnimating) {
@@ -1933,17 +2165,11 @@ export class GameplayRenderer {
         position: state.ui.selectedPosition,
       };
 
-      this.renderTile(tile, state, 0.7); // 70% opacity
+      const previewCenter = hexToPixel(state.ui.selectedPosition, this.layout);
+      this.withHeldTileTurn(previewCenter, () => this.renderTile(tile, state, 0.7)); // 70% opacity
 
       // Add red border if illegal placement
-      const isLegal = isLegalMove(
-        state.game.board,
-        tile,
-        state.game.players,
-        state.game.teams,
-        state.game.boardRadius,
-        state.game.supermove,
-      );
+      const { isLegal } = this.getPreviewLegality(state, tile);
 
       if (!isLegal) {
         const center = hexToPixel(state.ui.selectedPosition, this.layout);
@@ -1961,12 +2187,15 @@ export class GameplayRenderer {
         state.game.boardRadius,
       );
       // Use grey for preview flows (not yet placed on board)
-      this.renderTileAtPosition(
-        state.game.currentTile,
-        state.ui.currentRotation,
-        edgePos,
-        "#888888", // Neutral grey for unplaced tiles
-        1.0,
+      const tileType = state.game.currentTile;
+      this.withHeldTileTurn(edgePos, () =>
+        this.renderTileAtPosition(
+          tileType,
+          state.ui.currentRotation,
+          edgePos,
+          "#888888", // Neutral grey for unplaced tiles
+          1.0,
+        ),
       );
       
       // If current player is disconnected, add a red dot indicator
@@ -1984,6 +2213,39 @@ export class GameplayRenderer {
     }
   }
 
+  // Draw the held tile turned about its center by what remains of its rotation animation
+  private withHeldTileTurn(center: Point, draw: () => void): void {
+    const offset = heldTileRotationState.offset;
+    if (offset === 0) {
+      draw();
+      return;
+    }
+
+    this.ctx.save();
+    this.ctx.translate(center.x, center.y);
+    this.ctx.rotate(offset);
+    this.ctx.translate(-center.x, -center.y);
+    draw();
+    this.ctx.restore();
+  }
+
+  private renderHint(state: RootState): void {
+    // Ghost the suggested placement, outlined so it can't be mistaken for the selected tile
+    const { hint } = state.ui;
+    if (!hint || state.game.currentTile == null || !selectResigningPlayer(state)) {
+      return;
+    }
+
+    const center = hexToPixel(hint.position, this.layout);
+    this.renderTileAtPosition(state.game.currentTile, hint.rotation, center, "#888888", 0.5);
+
+    this.ctx.save();
+    this.ctx.strokeStyle = HINT_BORDER;
+    this.ctx.lineWidth = 3;
+    this.drawHexagon(center, this.layout.size, false);
+    this.ctx.restore();
+  }
+
   // Draw a red dot to indicate disconnected player
   private drawDisconnectedIndicator(center: Point): void {
     this.ctx.save();
@@ -2041,25 +2303,11 @@ export class GameplayRenderer {
       position: state.ui.selectedPosition,
     };
 
-    const isLegal = isLegalMove(
-      state.game.board,
-      placedTile,
-      state.game.players,
-      state.game.teams,
-      state.game.boardRadius,
-      state.game.supermove,
-    );
+    const { isLegal, blockedPlayerIds } = this.getPreviewLegality(state, placedTile);
 
     // Get blocked players if move is illegal
     let blockedPlayers: typeof state.game.players = [];
     if (!isLegal) {
-      const blockedPlayerIds = getBlockedPlayers(
-        state.game.board,
-        placedTile,
-        state.game.players,
-        state.game.teams,
-        state.game.boardRadius,
-      );
       blockedPlayers = state.game.players.filter((p) =>
         blockedPlayerIds.includes(p.id),
       );
@@ -2089,7 +2337,8 @@ export class GameplayRenderer {
           state.game.players,
           state.game.teams,
           state.game.boardRadius,
-          true // supermoveAnyPlayer
+          true, // supermoveAnyPlayer
+          state.game.oppositeSidesVictory,
         );
       } else {
         // Standard supermove - current player must be blocked
@@ -2099,6 +2348,7 @@ export class GameplayRenderer {
           state.game.players,
           state.game.teams,
           state.game.boardRadius,
+          state.game.oppositeSidesVictory,
         );
       }
     }
@@ -2144,6 +2394,152 @@ export class GameplayRenderer {
     if (!isLegal && blockedPlayers.length > 0) {
       this.renderBlockedPlayersWarning(center, blockedPlayers);
     }
+
+    // Show how far a legal placement sets back the closest opponent
+    if (isLegal && !isOccupied && currentPlayer) {
+      const setback = this.getOpponentSetback(state, placedTile, currentPlayer);
+      if (setback > 0 && setback !== Infinity) {
+        this.renderOpponentSetback(center, setback);
+      }
+    }
+  }
+
+  // Tint every empty hex green if the held tile has a legal rotation there, red if it has none
+  // Dead hexes are also hatched so they stand out at a glance. With legalMovesForHeldRotation,
+  // only the held rotation counts. Shown to the player whose turn the viewer is taking.
+  private renderLegalTargets(state: RootState): void {
+    const tileType = state.game.currentTile;
+    if (
+      !state.ui.showLegalMoves ||
+      tileType === null ||
+      !selectResigningPlayer(state) ||
+      selectIsReviewingHistory(state)
+    ) {
+      return;
+    }
+
+    // Computed once per game state (and held rotation), not every frame
+    const rotation = state.ui.legalMovesForHeldRotation ? state.ui.currentRotation : undefined;
+    const key = `${getPositionKey(state.game)}-${state.game.supermove}-${state.game.noSelfStalemate ?? false}-${state.game.oppositeSidesVictory}-${rotation ?? "any"}`;
+    if (this.legalTargetsCache?.key !== key) {
+      this.legalTargetsCache = { key, targets: selectLegalTargets(state, rotation) };
+    }
+
+    for (const [posKey, isLegal] of this.legalTargetsCache.targets) {
+      const center = hexToPixel(keyToPosition(posKey), this.layout);
+      this.ctx.save();
+      this.ctx.fillStyle = isLegal ? LEGAL_TARGET_FILL : ILLEGAL_TARGET_FILL;
+      this.drawHexagon(center, this.layout.size, true);
+
+      if (!isLegal) {
+        // Hatch within the hex: the filled path above is still current, so clip to it
+        this.ctx.clip();
+        this.ctx.strokeStyle = ILLEGAL_TARGET_HATCH;
+        this.ctx.lineWidth = 1;
+        const size = this.layout.size;
+        const spacing = size / 4;
+        this.ctx.beginPath();
+        for (let offset = -2 * size; offset <= 2 * size; offset += spacing) {
+          this.ctx.moveTo(center.x + offset - size, center.y + size);
+          this.ctx.lineTo(center.x + offset + size, center.y - size);
+        }
+        this.ctx.stroke();
+      }
+      this.ctx.restore();
+    }
+  }
+
+  // Get the legality of the previewed move, recomputing only when the position or preview changes
+  // Repeated frames of an unchanged preview would otherwise rerun every player's path search
+  private getPreviewLegality(
+    state: RootState,
+    placedTile: PlacedTile,
+  ): { isLegal: boolean; blockedPlayerIds: string[] } {
+    const key = `${getPositionKey(state.game)}-${state.game.supermove}-${state.game.noSelfStalemate ?? false}-${state.game.oppositeSidesVictory}-${positionToKey(placedTile.position)}-${placedTile.type}-${placedTile.rotation}`;
+    if (this.previewLegalityCache?.key === key) {
+      return this.previewLegalityCache;
+    }
+
+    const isLegal = isLegalMove(
+      state.game.board,
+      placedTile,
+      state.game.players,
+      state.game.teams,
+      state.game.boardRadius,
+      state.game.supermove,
+      selectContinuationTileTypes(state),
+      state.game.oppositeSidesVictory,
+    );
+    const blockedPlayerIds = isLegal
+      ? []
+      : getBlockedPlayers(
+          state.game.board,
+          placedTile,
+          state.game.players,
+          state.game.teams,
+          state.game.boardRadius,
+          state.game.oppositeSidesVictory,
+        );
+
+    this.previewLegalityCache = { key, isLegal, blockedPlayerIds };
+    return this.previewLegalityCache;
+  }
+
+  // Get the opponent setback for the previewed move, recomputing only when the preview changes
+  private getOpponentSetback(
+    state: RootState,
+    placedTile: PlacedTile,
+    currentPlayer: (typeof state.game.players)[number],
+  ): number {
+    const key = `${getPositionKey(state.game)}-${currentPlayer.id}-${positionToKey(placedTile.position)}-${placedTile.type}-${placedTile.rotation}`;
+    if (this.opponentSetbackCache?.key === key) {
+      return this.opponentSetbackCache.setback;
+    }
+
+    const boardAfter = new Map(state.game.board);
+    boardAfter.set(positionToKey(placedTile.position), placedTile);
+    const setback = computeOpponentSetback(
+      state.game.board,
+      boardAfter,
+      currentPlayer,
+      state.game.players,
+      state.game.teams,
+      state.game.boardRadius,
+      state.game.oppositeSidesVictory,
+    );
+
+    this.opponentSetbackCache = { key, setback };
+    return setback;
+  }
+
+  private renderOpponentSetback(tileCenter: Point, setback: number): void {
+    // Show the label above the tile
+    const labelY = tileCenter.y - this.layout.size * 1.5;
+    const labelText = `+${setback} to opponent`;
+    const padding = 6;
+
+    this.ctx.font = "bold 14px sans-serif";
+    const textWidth = this.ctx.measureText(labelText).width;
+    const boxWidth = textWidth + padding * 2;
+    const boxHeight = 14 + padding * 2;
+
+    // Draw semi-transparent background
+    this.ctx.fillStyle = "rgba(0, 0, 0, 0.75)";
+    this.ctx.fillRect(
+      tileCenter.x - boxWidth / 2,
+      labelY - boxHeight / 2,
+      boxWidth,
+      boxHeight,
+    );
+
+    this.ctx.fillStyle = "rgba(120, 220, 120, 1)";
+    this.ctx.textAlign = "center";
+    this.ctx.textBaseline = "middle";
+    this.ctx.fillText(labelText, tileCenter.x, labelY);
+
+    // Reset text alignment
+    this.ctx.textAlign = "left";
+    this.ctx.textBaseline = "alphabetic";
   }
 
   // Calculate button positions oriented toward the player's edge
@@ -2410,7 +2806,7 @@ export class GameplayRenderer {
       // Draw colored circle indicator
       const circleRadius = 6;
       const circleX = tileCenter.x - maxPlayerWidth / 2 + padding;
-      this.ctx.fillStyle = player.color;
+      this.ctx.fillStyle = this.displayColor(player.color);
       this.ctx.beginPath();
       this.ctx.arc(circleX, y + lineHeight / 2, circleRadius, 0, Math.PI * 2);
       this.ctx.fill();
@@ -2534,70 +2930,318 @@ export class GameplayRenderer {
     this.ctx.restore();
   }
 
-  private renderHelpButtons(state: RootState): void {
-    // Render ? buttons next to X buttons in each corner
-    // Each button represents one edge's lower-left position
-    // In multiplayer mode, only render on the bottom edge (from current player's perspective)
-    const cornerSize = 50;
-    const margin = 10;
-    const spacing = cornerSize * 0.15;
+  private renderBagWarning(state: RootState): void {
+    if (!selectBagRunningOut(state)) return;
 
-    const corners = [
-      {
-        // Edge 0 (bottom): lower-left from bottom perspective = bottom-left corner, next to exit
-        x: margin + cornerSize / 2 + cornerSize + spacing,
-        y: this.layout.canvasHeight - margin - cornerSize / 2,
-        corner: 0,
-        edge: 0, // Bottom edge
-      },
-      {
-        // Edge 1 (right): lower-left from right perspective = bottom-right, next to exit
-        x: this.layout.canvasWidth - margin - cornerSize / 2,
-        y:
-          this.layout.canvasHeight -
-          margin -
-          cornerSize / 2 -
-          cornerSize -
-          spacing,
-        corner: 1,
-        edge: 1, // Right edge
-      },
-      {
-        // Edge 2 (top): lower-left from top perspective = top-right corner, next to exit
-        x:
-          this.layout.canvasWidth -
-          margin -
-          cornerSize / 2 -
-          cornerSize -
-          spacing,
-        y: margin + cornerSize / 2,
-        corner: 2,
-        edge: 2, // Top edge
-      },
-      {
-        // Edge 3 (left): lower-left from left perspective = top-left, next to exit
-        x: margin + cornerSize / 2,
-        y: margin + cornerSize / 2 + cornerSize + spacing,
-        corner: 3,
-        edge: 3, // Left edge
-      },
-    ];
+    const remaining = state.game.availableTiles.length;
+    const text = remaining === 1
+      ? '⚠️ 1 tile left - not everyone will draw again'
+      : `⚠️ ${remaining} tiles left - not everyone will draw again`;
 
-    corners.forEach((corner) => {
-      // In multiplayer mode, only show buttons on the bottom edge (edge 0)
-      if (state.ui.gameMode === 'multiplayer' && corner.edge !== 0) {
-        return;
-      }
-      
-      const centerX = corner.x;
-      const centerY = corner.y;
-      const radius = cornerSize / 2;
+    this.ctx.save();
 
-      // Draw circle background
-      this.ctx.fillStyle = "#2196F3"; // Blue for help
-      this.ctx.beginPath();
-      this.ctx.arc(centerX, centerY, radius, 0, 2 * Math.PI);
-      this.ctx.fill();
+    // Background box at bottom center
+    this.ctx.font = 'bold 16px sans-serif';
+    const metrics = this.ctx.measureText(text);
+    const boxWidth = metrics.width + 40;
+    const boxHeight = 32;
+    const x = this.layout.canvasWidth / 2;
+    const y = this.layout.canvasHeight - boxHeight - 20;
+
+    this.ctx.fillStyle = 'rgba(255, 152, 0, 0.9)'; // Amber background
+    this.ctx.strokeStyle = 'rgba(255, 255, 255, 0.8)';
+    this.ctx.lineWidth = 2;
+
+    this.ctx.beginPath();
+    this.ctx.roundRect(x - boxWidth / 2, y, boxWidth, boxHeight, 8);
+    this.ctx.fill();
+    this.ctx.stroke();
+
+    // Text
+    this.ctx.fillStyle = '#ffffff';
+    this.ctx.textAlign = 'center';
+    this.ctx.textBaseline = 'middle';
+    this.ctx.fillText(text, x, y + boxHeight / 2);
+
+    this.ctx.restore();
+  }
+
+  // Render a transient message from the server (e.g. a refused move) above the bag warning
+  private renderServerNotice(state: RootState): void {
+    const text = state.ui.serverNotice;
+    if (!text) return;
+
+    this.ctx.save();
+
+    this.ctx.font = 'bold 16px sans-serif';
+    const metrics = this.ctx.measureText(text);
+    const boxWidth = metrics.width + 40;
+    const boxHeight = 32;
+    const x = this.layout.canvasWidth / 2;
+    const y = this.layout.canvasHeight - 2 * boxHeight - 32;
+
+    this.ctx.fillStyle = 'rgba(211, 47, 47, 0.9)'; // Red background
+    this.ctx.strokeStyle = 'rgba(255, 255, 255, 0.8)';
+    this.ctx.lineWidth = 2;
+
+    this.ctx.beginPath();
+    this.ctx.roundRect(x - boxWidth / 2, y, boxWidth, boxHeight, 8);
+    this.ctx.fill();
+    this.ctx.stroke();
+
+    this.ctx.fillStyle = '#ffffff';
+    this.ctx.textAlign = 'center';
+    this.ctx.textBaseline = 'middle';
+    this.ctx.fillText(text, x, y + boxHeight / 2);
+
+    this.ctx.restore();
+  }
+
+  // Render "AI is thinking…" while an AI move waits to run
+  // AI moves are only deferred in tabletop games, so this takes the server notice's place
+  private renderAIThinking(state: RootState): void {
+    if (!state.ui.aiThinking) return;
+
+    const text = 'AI is thinking…';
+
+    this.ctx.save();
+
+    this.ctx.font = 'bold 16px sans-serif';
+    const metrics = this.ctx.measureText(text);
+    const boxWidth = metrics.width + 40;
+    const boxHeight = 32;
+    const x = this.layout.canvasWidth / 2;
+    const y = this.layout.canvasHeight - 2 * boxHeight - 32;
+
+    this.ctx.fillStyle = 'rgba(0, 0, 0, 0.75)';
+    this.ctx.strokeStyle = 'rgba(255, 255, 255, 0.8)';
+    this.ctx.lineWidth = 2;
+
+    this.ctx.beginPath();
+    this.ctx.roundRect(x - boxWidth / 2, y, boxWidth, boxHeight, 8);
+    this.ctx.fill();
+    this.ctx.stroke();
+
+    this.ctx.fillStyle = '#ffffff';
+    this.ctx.textAlign = 'center';
+    this.ctx.textBaseline = 'middle';
+    this.ctx.fillText(text, x, y + boxHeight / 2);
+
+    this.ctx.restore();
+  }
+
+  // Render how many of each tile type are left in the bag, at top center
+  private renderTileBagCounts(state: RootState): void {
+    if (state.game.screen !== 'gameplay') return;
+
+    const counts = selectRemainingTileCounts(state);
+    const odds = selectNextTileOdds(state);
+    const entries = [
+      { type: TileType.NoSharps, count: counts.noSharps, odds: odds.noSharps },
+      { type: TileType.OneSharp, count: counts.oneSharp, odds: odds.oneSharp },
+      { type: TileType.TwoSharps, count: counts.twoSharps, odds: odds.twoSharps },
+      { type: TileType.ThreeSharps, count: counts.threeSharps, odds: odds.threeSharps },
+    ];
+
+    const tileSize = 12;
+    const entryWidth = 64;
+    const turnWidth = 80; // Turn number, ahead of the tile counts
+    const boxWidth = turnWidth + entryWidth * entries.length + 16;
+    const boxHeight = 48;
+    const barWidth = entryWidth - 12;
+    const barHeight = 4;
+    const x = this.layout.canvasWidth / 2 - boxWidth / 2;
+    // Sit below the spectator indicator when it is showing
+    const y = state.ui.isSpectator ? 66 : 20;
+
+    this.ctx.save();
+
+    this.ctx.fillStyle = 'rgba(0, 0, 0, 0.5)';
+    this.ctx.beginPath();
+    this.ctx.roundRect(x, y, boxWidth, boxHeight, 8);
+    this.ctx.fill();
+
+    this.ctx.font = 'bold 16px sans-serif';
+    this.ctx.textAlign = 'left';
+    this.ctx.textBaseline = 'middle';
+
+    this.ctx.fillStyle = '#ffffff';
+    this.ctx.fillText(`Turn ${selectTurnNumber(state)}`, x + 12, y + boxHeight / 2);
+
+    entries.forEach((entry, index) => {
+      const centerX = x + 8 + turnWidth + index * entryWidth + tileSize + 4;
+      const centerY = y + boxHeight / 2 - 4;
+
+      // Exhausted tile types are dimmed
+      this.ctx.globalAlpha = entry.count > 0 ? 1 : 0.4;
+      this.renderSmallTile(
+        { type: entry.type, rotation: 0, position: { row: 0, col: 0 } },
+        centerX,
+        centerY,
+        tileSize,
+      );
+
+      this.ctx.fillStyle = '#ffffff';
+      this.ctx.fillText(`×${entry.count}`, centerX + tileSize + 4, centerY);
+
+      // Bar showing the chance this type is drawn next
+      const barX = centerX - tileSize;
+      const barY = y + boxHeight - barHeight - 6;
+      this.ctx.globalAlpha = 1;
+      this.ctx.fillStyle = 'rgba(255, 255, 255, 0.2)';
+      this.ctx.fillRect(barX, barY, barWidth, barHeight);
+      this.ctx.fillStyle = '#ffffff';
+      this.ctx.fillRect(barX, barY, barWidth * entry.odds, barHeight);
+    });
+
+    this.ctx.restore();
+  }
+
+  // Render the "Draw!" heading when the board filled up with no winner
+  private renderDrawBanner(state: RootState): void {
+    if (state.game.winType !== 'draw') return;
+
+    const text = 'Draw!';
+
+    this.ctx.save();
+
+    // Background box at top center
+    this.ctx.font = 'bold 36px sans-serif';
+    const metrics = this.ctx.measureText(text);
+    const boxWidth = metrics.width + 60;
+    const boxHeight = 60;
+    const x = this.layout.canvasWidth / 2;
+    const y = 20;
+
+    this.ctx.fillStyle = 'rgba(0, 0, 0, 0.75)';
+    this.ctx.strokeStyle = 'rgba(255, 255, 255, 0.8)';
+    this.ctx.lineWidth = 2;
+
+    this.ctx.beginPath();
+    this.ctx.roundRect(x - boxWidth / 2, y, boxWidth, boxHeight, 10);
+    this.ctx.fill();
+    this.ctx.stroke();
+
+    // Text
+    this.ctx.fillStyle = '#ffffff';
+    this.ctx.textAlign = 'center';
+    this.ctx.textBaseline = 'middle';
+    this.ctx.fillText(text, x, y + boxHeight / 2);
+
+    this.ctx.restore();
+  }
+
+  private renderSessionScores(state: RootState): void {
+    // Series wins for each player since leaving the menu
+    const players = state.game.players;
+    if (players.length === 0) return;
+
+    const entrySpacing = 56;
+    const boxWidth = 80 + players.length * entrySpacing;
+    const boxHeight = 40;
+    const x = this.layout.canvasWidth / 2 - boxWidth / 2;
+    // Sit below the draw banner when it is showing
+    const y = state.game.winType === 'draw' ? 90 : 20;
+
+    this.ctx.save();
+
+    this.ctx.fillStyle = 'rgba(0, 0, 0, 0.75)';
+    this.ctx.strokeStyle = 'rgba(255, 255, 255, 0.8)';
+    this.ctx.lineWidth = 2;
+    this.ctx.beginPath();
+    this.ctx.roundRect(x, y, boxWidth, boxHeight, 10);
+    this.ctx.fill();
+    this.ctx.stroke();
+
+    this.ctx.font = 'bold 16px sans-serif';
+    this.ctx.textBaseline = 'middle';
+    this.ctx.textAlign = 'left';
+    this.ctx.fillStyle = '#cccccc';
+    this.ctx.fillText('Series', x + 15, y + boxHeight / 2);
+
+    players.forEach((player, index) => {
+      const entryX = x + 80 + index * entrySpacing;
+
+      this.ctx.fillStyle = this.displayColor(player.color);
+      this.ctx.beginPath();
+      this.ctx.arc(entryX + 8, y + boxHeight / 2, 8, 0, 2 * Math.PI);
+      this.ctx.fill();
+
+      this.ctx.fillStyle = '#ffffff';
+      this.ctx.fillText(
+        String(selectSessionScore(state, player)),
+        entryX + 22,
+        y + boxHeight / 2,
+      );
+    });
+
+    this.ctx.restore();
+  }
+
+  private renderHelpButtons(state: RootState): void {
+    // Render ? buttons next to X buttons in each corner
+    // Each button represents one edge's lower-left position
+    // In multiplayer mode, only render on the bottom edge (from current player's perspective)
+    const cornerSize = 50;
+    const margin = 10;
+    const spacing = cornerSize * 0.15;
+
+    const corners = [
+      {
+        // Edge 0 (bottom): lower-left from bottom perspective = bottom-left corner, next to exit
+        x: margin + cornerSize / 2 + cornerSize + spacing,
+        y: this.layout.canvasHeight - margin - cornerSize / 2,
+        corner: 0,
+        edge: 0, // Bottom edge
+      },
+      {
+        // Edge 1 (right): lower-left from right perspective = bottom-right, next to exit
+        x: this.layout.canvasWidth - margin - cornerSize / 2,
+        y:
+          this.layout.canvasHeight -
+          margin -
+          cornerSize / 2 -
+          cornerSize -
+          spacing,
+        corner: 1,
+        edge: 1, // Right edge
+      },
+      {
+        // Edge 2 (top): lower-left from top perspective = top-right corner, next to exit
+        x:
+          this.layout.canvasWidth -
+          margin -
+          cornerSize / 2 -
+          cornerSize -
+          spacing,
+        y: margin + cornerSize / 2,
+        corner: 2,
+        edge: 2, // Top edge
+      },
+      {
+        // Edge 3 (left): lower-left from left perspective = top-left, next to exit
+        x: margin + cornerSize / 2,
+        y: margin + cornerSize / 2 + cornerSize + spacing,
+        corner: 3,
+        edge: 3, // Left edge
+      },
+    ];
+
+    corners.forEach((corner) => {
+      // In multiplayer mode, only show buttons on the bottom edge (edge 0)
+      if (state.ui.gameMode === 'multiplayer' && corner.edge !== 0) {
+        return;
+      }
+      
+      const centerX = corner.x;
+      const centerY = corner.y;
+      const radius = cornerSize / 2;
+
+      // Draw circle background
+      this.ctx.fillStyle = "#2196F3"; // Blue for help
+      this.ctx.beginPath();
+      this.ctx.arc(centerX, centerY, radius, 0, 2 * Math.PI);
+      this.ctx.fill();
 
       // Draw border
       this.ctx.strokeStyle = "#ffffff";
@@ -2627,6 +3271,64 @@ export class GameplayRenderer {
     });
   }
 
+  // Highlight the current player's start edge (solid) and goal edge (dashed)
+  private renderTutorialHighlights(state: RootState): void {
+    const info = getTutorialInfo(state.game);
+    if (!info) return;
+
+    const player = state.game.players.find(p => p.id === info.playerId)!;
+
+    this.ctx.save();
+    this.ctx.strokeStyle = this.displayColor(player.color);
+    this.ctx.lineWidth = 4;
+
+    for (const pos of getEdgePositions(info.startEdge, state.game.boardRadius)) {
+      this.drawHexagon(hexToPixel(pos, this.layout), this.layout.size, false);
+    }
+
+    this.ctx.setLineDash([8, 6]);
+    for (const pos of getEdgePositions(info.goalEdge, state.game.boardRadius)) {
+      this.drawHexagon(hexToPixel(pos, this.layout), this.layout.size, false);
+    }
+
+    this.ctx.restore();
+  }
+
+  // Explain the goal, the held tile and the blocking rule (dismissed by the next tap)
+  private renderTutorialPanel(state: RootState): void {
+    const info = getTutorialInfo(state.game);
+    if (!info) return;
+
+    const lines = [...info.lines, '', 'Tap anywhere to continue'];
+    const lineHeight = 24;
+    const panelWidth = Math.min(460, this.layout.canvasWidth - 40);
+    const panelHeight = 60 + lines.length * lineHeight;
+    const x = (this.layout.canvasWidth - panelWidth) / 2;
+    const y = 20;
+
+    this.ctx.save();
+
+    this.ctx.fillStyle = "rgba(42, 42, 62, 0.92)";
+    this.ctx.fillRect(x, y, panelWidth, panelHeight);
+    this.ctx.strokeStyle = "#2196F3";
+    this.ctx.lineWidth = 3;
+    this.ctx.strokeRect(x, y, panelWidth, panelHeight);
+
+    this.ctx.fillStyle = "#ffffff";
+    this.ctx.font = "bold 20px sans-serif";
+    this.ctx.textAlign = "center";
+    this.ctx.textBaseline = "top";
+    this.ctx.fillText("Welcome to Quortex!", x + panelWidth / 2, y + 16);
+
+    this.ctx.font = "15px sans-serif";
+    this.ctx.textAlign = "left";
+    lines.forEach((line, index) => {
+      this.ctx.fillText(line, x + 20, y + 50 + index * lineHeight);
+    });
+
+    this.ctx.restore();
+  }
+
   private renderHelpDialog(corner: number, state: RootState): void {
     // Semi-transparent overlay
     this.ctx.fillStyle = "rgba(0, 0, 0, 0.7)";
@@ -2858,6 +3560,371 @@ export class GameplayRenderer {
       );
 
       this.ctx.restore();
+
+      // In multiplayer the rematch starts once every player has asked; show who's ready so far
+      const rematchVotes = state.ui.rematchVotes;
+      if (state.ui.gameMode === 'multiplayer' && rematchVotes) {
+        this.ctx.save();
+        this.ctx.font = 'bold 16px sans-serif';
+        this.ctx.fillStyle = '#ffffff';
+        this.ctx.textAlign = 'left';
+        this.ctx.textBaseline = 'middle';
+        this.ctx.fillText(
+          `Waiting for rematch: ${rematchVotes.ready}/${rematchVotes.total} ready`,
+          centerX - radius,
+          centerY - radius - 16
+        );
+        this.ctx.restore();
+      }
+    });
+  }
+
+  private renderResignButtons(state: RootState): void {
+    // Render flag buttons in the rematch slot so the player on turn can concede
+    if (state.game.screen !== 'gameplay' || !selectResigningPlayer(state)) {
+      return;
+    }
+
+    const cornerSize = 50;
+    const margin = 10;
+    const spacing = cornerSize * 0.15;
+    const radius = cornerSize / 2;
+    const tripleSpacing = 3 * (cornerSize + spacing); // Position after exit, help, and move list buttons
+
+    const corners = [
+      {
+        // Edge 0 (bottom): after exit, help, and move list buttons
+        x: margin + cornerSize / 2 + tripleSpacing,
+        y: this.layout.canvasHeight - margin - cornerSize / 2,
+        edge: 0,
+      },
+      {
+        // Edge 1 (right): after exit, help, and move list buttons
+        x: this.layout.canvasWidth - margin - cornerSize / 2,
+        y: this.layout.canvasHeight - margin - cornerSize / 2 - tripleSpacing,
+        edge: 1,
+      },
+      {
+        // Edge 2 (top): after exit, help, and move list buttons
+        x: this.layout.canvasWidth - margin - cornerSize / 2 - tripleSpacing,
+        y: margin + cornerSize / 2,
+        edge: 2,
+      },
+      {
+        // Edge 3 (left): after exit, help, and move list buttons
+        x: margin + cornerSize / 2,
+        y: margin + cornerSize / 2 + tripleSpacing,
+        edge: 3,
+      },
+    ];
+
+    corners.forEach((corner) => {
+      // In multiplayer mode, only show buttons on the bottom edge (edge 0)
+      if (state.ui.gameMode === 'multiplayer' && corner.edge !== 0) {
+        return;
+      }
+
+      // Draw circle background
+      this.ctx.fillStyle = "#C62828"; // Red for resign
+      this.ctx.beginPath();
+      this.ctx.arc(corner.x, corner.y, radius, 0, 2 * Math.PI);
+      this.ctx.fill();
+
+      // Draw border
+      this.ctx.strokeStyle = "#ffffff";
+      this.ctx.lineWidth = 2;
+      this.ctx.stroke();
+
+      // Rotate the flag to face the edge, as for the rematch arrows
+      let rotation = corner.edge * 90;
+      if (corner.edge === 1 || corner.edge === 3) {
+        rotation += 180;
+      }
+
+      this.ctx.save();
+      this.ctx.translate(corner.x, corner.y);
+      this.ctx.rotate((rotation * Math.PI) / 180);
+
+      // Draw a white flag: a pole with a pennant
+      const size = cornerSize * 0.25;
+      this.ctx.strokeStyle = BUTTON_ICON;
+      this.ctx.lineWidth = 3;
+      this.ctx.lineCap = "round";
+      this.ctx.beginPath();
+      this.ctx.moveTo(-size * 0.6, size);
+      this.ctx.lineTo(-size * 0.6, -size);
+      this.ctx.stroke();
+
+      this.ctx.fillStyle = BUTTON_ICON;
+      this.ctx.beginPath();
+      this.ctx.moveTo(-size * 0.6, -size);
+      this.ctx.lineTo(size, -size * 0.5);
+      this.ctx.lineTo(-size * 0.6, 0);
+      this.ctx.closePath();
+      this.ctx.fill();
+
+      this.ctx.restore();
+    });
+  }
+
+  private renderHintButtons(state: RootState): void {
+    // Render light bulb buttons after the resign buttons so the player on turn can ask for a hint
+    if (state.game.screen !== 'gameplay' || !selectResigningPlayer(state)) {
+      return;
+    }
+
+    const corners = getCornerButtonPositions(4, this.layout); // After the move list and resign buttons
+
+    corners.forEach((corner) => {
+      // In multiplayer mode, only show buttons on the bottom edge (edge 0)
+      if (state.ui.gameMode === 'multiplayer' && corner.edge !== 0) {
+        return;
+      }
+
+      // Draw circle background, outlined in the overlay colour while a hint is showing
+      this.ctx.fillStyle = "#F9A825"; // Amber for hint
+      this.ctx.beginPath();
+      this.ctx.arc(corner.x, corner.y, CORNER_BUTTON_RADIUS, 0, 2 * Math.PI);
+      this.ctx.fill();
+
+      this.ctx.strokeStyle = state.ui.hint ? HINT_BORDER : "#ffffff";
+      this.ctx.lineWidth = state.ui.hint ? 4 : 2;
+      this.ctx.stroke();
+
+      // Rotate the bulb to face the edge, as for the resign flags
+      let rotation = corner.edge * 90;
+      if (corner.edge === 1 || corner.edge === 3) {
+        rotation += 180;
+      }
+
+      this.ctx.save();
+      this.ctx.translate(corner.x, corner.y);
+      this.ctx.rotate((rotation * Math.PI) / 180);
+
+      // Draw a light bulb: a round glass above a two-line base
+      const size = CORNER_BUTTON_SIZE * 0.25;
+      this.ctx.strokeStyle = BUTTON_ICON;
+      this.ctx.lineWidth = 3;
+      this.ctx.lineCap = "round";
+      this.ctx.beginPath();
+      this.ctx.arc(0, -size * 0.3, size * 0.7, 0.75 * Math.PI, 0.25 * Math.PI);
+      this.ctx.stroke();
+
+      this.ctx.beginPath();
+      this.ctx.moveTo(-size * 0.35, size * 0.6);
+      this.ctx.lineTo(size * 0.35, size * 0.6);
+      this.ctx.moveTo(-size * 0.25, size);
+      this.ctx.lineTo(size * 0.25, size);
+      this.ctx.stroke();
+
+      this.ctx.restore();
+    });
+  }
+
+  private renderTranscriptButtons(state: RootState): void {
+    // Render clipboard buttons after the rematch buttons so a finished game can be shared
+    const corners = getCornerButtonPositions(4, this.layout); // After the move list and rematch buttons
+
+    corners.forEach((corner) => {
+      // In multiplayer mode, only show buttons on the bottom edge (edge 0)
+      if (state.ui.gameMode === 'multiplayer' && corner.edge !== 0) {
+        return;
+      }
+
+      // Draw circle background
+      this.ctx.fillStyle = "#0173B2"; // Blue for copy transcript
+      this.ctx.beginPath();
+      this.ctx.arc(corner.x, corner.y, CORNER_BUTTON_RADIUS, 0, 2 * Math.PI);
+      this.ctx.fill();
+
+      this.ctx.strokeStyle = "#ffffff";
+      this.ctx.lineWidth = 2;
+      this.ctx.stroke();
+
+      // Rotate the clipboard to face the edge, as for the rematch arrows
+      let rotation = corner.edge * 90;
+      if (corner.edge === 1 || corner.edge === 3) {
+        rotation += 180;
+      }
+
+      this.ctx.save();
+      this.ctx.translate(corner.x, corner.y);
+      this.ctx.rotate((rotation * Math.PI) / 180);
+
+      // Draw a clipboard: a board with a clip at the top and two lines of text
+      const size = CORNER_BUTTON_SIZE * 0.25;
+      this.ctx.strokeStyle = BUTTON_ICON;
+      this.ctx.lineWidth = 3;
+      this.ctx.lineCap = "round";
+      this.ctx.strokeRect(-size * 0.7, -size * 0.8, size * 1.4, size * 1.8);
+
+      this.ctx.beginPath();
+      this.ctx.moveTo(-size * 0.3, -size);
+      this.ctx.lineTo(size * 0.3, -size);
+      this.ctx.moveTo(-size * 0.35, -size * 0.1);
+      this.ctx.lineTo(size * 0.35, -size * 0.1);
+      this.ctx.moveTo(-size * 0.35, size * 0.4);
+      this.ctx.lineTo(size * 0.35, size * 0.4);
+      this.ctx.stroke();
+
+      this.ctx.restore();
+    });
+  }
+
+  private renderExportButtons(state: RootState): void {
+    // Render picture buttons after the transcript buttons so the final board can be saved as an image
+    const corners = getCornerButtonPositions(5, this.layout); // After the transcript buttons
+
+    corners.forEach((corner) => {
+      // In multiplayer mode, only show buttons on the bottom edge (edge 0)
+      if (state.ui.gameMode === 'multiplayer' && corner.edge !== 0) {
+        return;
+      }
+
+      // Draw circle background
+      this.ctx.fillStyle = "#0173B2"; // Blue, like the transcript button
+      this.ctx.beginPath();
+      this.ctx.arc(corner.x, corner.y, CORNER_BUTTON_RADIUS, 0, 2 * Math.PI);
+      this.ctx.fill();
+
+      this.ctx.strokeStyle = "#ffffff";
+      this.ctx.lineWidth = 2;
+      this.ctx.stroke();
+
+      // Rotate the picture to face the edge, as for the rematch arrows
+      let rotation = corner.edge * 90;
+      if (corner.edge === 1 || corner.edge === 3) {
+        rotation += 180;
+      }
+
+      this.ctx.save();
+      this.ctx.translate(corner.x, corner.y);
+      this.ctx.rotate((rotation * Math.PI) / 180);
+
+      // Draw a picture: a frame around a mountain with the sun above it
+      const size = CORNER_BUTTON_SIZE * 0.25;
+      this.ctx.strokeStyle = BUTTON_ICON;
+      this.ctx.lineWidth = 3;
+      this.ctx.lineCap = "round";
+      this.ctx.lineJoin = "round";
+      this.ctx.strokeRect(-size, -size * 0.8, size * 2, size * 1.6);
+
+      this.ctx.beginPath();
+      this.ctx.moveTo(-size * 0.7, size * 0.5);
+      this.ctx.lineTo(-size * 0.15, -size * 0.2);
+      this.ctx.lineTo(size * 0.2, size * 0.2);
+      this.ctx.lineTo(size * 0.4, 0);
+      this.ctx.lineTo(size * 0.7, size * 0.5);
+      this.ctx.stroke();
+
+      this.ctx.fillStyle = BUTTON_ICON;
+      this.ctx.beginPath();
+      this.ctx.arc(size * 0.45, -size * 0.4, size * 0.15, 0, 2 * Math.PI);
+      this.ctx.fill();
+
+      this.ctx.restore();
+    });
+  }
+
+  private renderUndoButtons(state: RootState): void {
+    // Render undo arrow buttons after the hint buttons so a tabletop move can be taken back
+    if (!selectCanUndo(state)) {
+      return;
+    }
+
+    const corners = getCornerButtonPositions(5, this.layout); // After the hint buttons
+
+    corners.forEach((corner) => {
+      // Draw circle background
+      this.ctx.fillStyle = "#607D8B"; // Blue grey for undo
+      this.ctx.beginPath();
+      this.ctx.arc(corner.x, corner.y, CORNER_BUTTON_RADIUS, 0, 2 * Math.PI);
+      this.ctx.fill();
+
+      // Draw border
+      this.ctx.strokeStyle = "#ffffff";
+      this.ctx.lineWidth = 2;
+      this.ctx.stroke();
+
+      // Rotate the arrow to face the edge, as for the rematch arrows
+      let rotation = corner.edge * 90;
+      if (corner.edge === 1 || corner.edge === 3) {
+        rotation += 180;
+      }
+
+      this.ctx.save();
+      this.ctx.translate(corner.x, corner.y);
+      this.ctx.rotate((rotation * Math.PI) / 180);
+
+      // Draw a half-turn arrow curling back to the left (shorter than the rematch arrow)
+      const arrowSize = CORNER_BUTTON_SIZE;
+      drawCircularArrow(
+        this.ctx,
+        0,
+        0,
+        arrowSize * 0.25,
+        Math.PI,
+        Math.PI * 2,
+        true,
+        arrowSize
+      );
+
+      this.ctx.restore();
+    });
+  }
+
+  private renderSwapButtons(state: RootState): void {
+    // Render swap buttons after the undo buttons so the second player can take the opening move
+    if (state.game.screen !== 'gameplay' || !selectSwappingPlayer(state)) {
+      return;
+    }
+
+    const corners = getCornerButtonPositions(6, this.layout); // After the undo buttons
+
+    corners.forEach((corner) => {
+      // In multiplayer mode, only show buttons on the bottom edge (edge 0)
+      if (state.ui.gameMode === 'multiplayer' && corner.edge !== 0) {
+        return;
+      }
+
+      // Draw circle background
+      this.ctx.fillStyle = "#7B1FA2"; // Purple for swap
+      this.ctx.beginPath();
+      this.ctx.arc(corner.x, corner.y, CORNER_BUTTON_RADIUS, 0, 2 * Math.PI);
+      this.ctx.fill();
+
+      // Draw border
+      this.ctx.strokeStyle = "#ffffff";
+      this.ctx.lineWidth = 2;
+      this.ctx.stroke();
+
+      // Rotate the arrows to face the edge, as for the resign flags
+      let rotation = corner.edge * 90;
+      if (corner.edge === 1 || corner.edge === 3) {
+        rotation += 180;
+      }
+
+      this.ctx.save();
+      this.ctx.translate(corner.x, corner.y);
+      this.ctx.rotate((rotation * Math.PI) / 180);
+
+      // Draw a pair of opposing arrows, one above the other
+      const size = CORNER_BUTTON_SIZE * 0.25;
+      const head = size * 0.45;
+      this.ctx.strokeStyle = BUTTON_ICON;
+      this.ctx.lineWidth = 3;
+      this.ctx.lineCap = "round";
+      this.ctx.lineJoin = "round";
+      this.ctx.beginPath();
+      this.ctx.moveTo(-size, -size * 0.4);
+      this.ctx.lineTo(size, -size * 0.4);
+      this.ctx.lineTo(size - head, -size * 0.4 - head);
+      this.ctx.moveTo(size, size * 0.4);
+      this.ctx.lineTo(-size, size * 0.4);
+      this.ctx.lineTo(-size + head, size * 0.4 + head);
+      this.ctx.stroke();
+
+      this.ctx.restore();
     });
   }
 
@@ -3027,11 +4094,26 @@ export class GameplayRenderer {
     const statusText = `Viewing: ${viewingIndex} of ${moves.length} moves`;
     this.ctx.fillText(statusText, dialogX + 20, controlsY + 20);
 
+    // Return to live button, only while reviewing an earlier position
+    if (state.ui.moveListIndex !== -1) {
+      const liveX = dialogX + dialogWidth - 80;
+      const liveY = controlsY + 8;
+      this.ctx.fillStyle = "#4CAF50";
+      this.ctx.fillRect(liveX, liveY, 60, 26);
+      this.ctx.fillStyle = "#ffffff";
+      this.ctx.font = "bold 14px sans-serif";
+      this.ctx.textAlign = "center";
+      this.ctx.textBaseline = "middle";
+      this.ctx.fillText("Live", liveX + 30, liveY + 13);
+      this.ctx.font = "14px sans-serif";
+      this.ctx.textBaseline = "top";
+    }
+
     // Content area
     const contentX = dialogX + 20;
     let contentY = controlsY + controlsHeight + 10;
     const lineHeight = 44; // Increased to 44 to prevent tile overlap
-    const bottomMargin = 85; // Increased space for navigation buttons
+    const bottomMargin = 105; // Space for the scrubber and navigation buttons
     const maxLines = Math.floor(
       (dialogHeight - (contentY - dialogY) - bottomMargin) / lineHeight,
     );
@@ -3142,6 +4224,48 @@ export class GameplayRenderer {
       }
     }
 
+    // Scrubber for jumping anywhere in the history, with replay controls to its right
+    if (moves.length > 0) {
+      const sliderY = dialogY + dialogHeight - 92;
+      const sliderX = dialogX + 20;
+      const sliderWidth = dialogWidth - 40 - 84;
+      const thumbX = sliderX + (sliderWidth * viewingIndex) / moves.length;
+
+      this.ctx.strokeStyle = "#666666";
+      this.ctx.lineWidth = 4;
+      this.ctx.lineCap = "round";
+      this.ctx.beginPath();
+      this.ctx.moveTo(sliderX, sliderY);
+      this.ctx.lineTo(sliderX + sliderWidth, sliderY);
+      this.ctx.stroke();
+
+      this.ctx.strokeStyle = "#4CAF50";
+      this.ctx.beginPath();
+      this.ctx.moveTo(sliderX, sliderY);
+      this.ctx.lineTo(thumbX, sliderY);
+      this.ctx.stroke();
+
+      this.ctx.fillStyle = "#ffffff";
+      this.ctx.beginPath();
+      this.ctx.arc(thumbX, sliderY, 7, 0, 2 * Math.PI);
+      this.ctx.fill();
+
+      // Play/pause and speed buttons
+      const replayButtons = [
+        { label: state.ui.replayPlaying ? "❚❚" : "▶", x: dialogX + dialogWidth - 96 },
+        { label: `${state.ui.replaySpeed}x`, x: dialogX + dialogWidth - 56 },
+      ];
+      replayButtons.forEach((button) => {
+        this.ctx.fillStyle = "#4CAF50";
+        this.ctx.fillRect(button.x, sliderY - 11, 36, 22);
+        this.ctx.fillStyle = "#ffffff";
+        this.ctx.font = "bold 12px sans-serif";
+        this.ctx.textAlign = "center";
+        this.ctx.textBaseline = "middle";
+        this.ctx.fillText(button.label, button.x + 18, sliderY);
+      });
+    }
+
     // Navigation buttons at bottom
     const buttonY = dialogY + dialogHeight - 55;
     const buttonWidth = 40;
@@ -3413,6 +4537,7 @@ export class GameplayRenderer {
       state.game.players,
       state.game.teams,
       state.game.boardRadius,
+      state.game.oppositeSidesVictory,
     );
 
     // Render each player's path information
@@ -3710,19 +4835,13 @@ export class GameplayRenderer {
     };
   }
 
-  // Get the rotation angle applied to the board in multiplayer mode
+  // Get the rotation angle applied to the board
   getBoardRotationAngle(state: RootState): number {
-    if (state.ui.gameMode === 'multiplayer' && state.ui.localPlayerId && state.game.players.length > 0) {
-      const localPlayer = state.game.players.find(p => p.id === state.ui.localPlayerId);
-      if (localPlayer) {
-        const edgeAngles = [0, 60, 120, 180, 240, 300];
-        return -edgeAngles[localPlayer.edgePosition] + 180;
-      }
-    }
-    return 0;
+    return getBoardRotationAngle(state);
   }
 
   // Transform input coordinates to account for board rotation in multiplayer mode
+  // Uses the final angle, so a reorientation still animating doesn't throw off placement
   transformInputCoordinates(x: number, y: number, state: RootState): { x: number; y: number } {
     const rotationAngle = this.getBoardRotationAngle(state);
     if (rotationAngle === 0) {
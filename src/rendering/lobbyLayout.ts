@@ -60,10 +60,16 @@ export interface PlayerListEntry {
     y: number;
     size: number;
   };
+  // The color swatch, which toggles AI control of the seat; tabletop mode only
+  aiButton?: {
+    x: number;
+    y: number;
+    size: number;
+  };
 }
 
 export interface SettingsControl {
-  type: 'checkbox' | 'number' | 'close' | 'reset-distribution';
+  type: 'checkbox' | 'number' | 'close' | 'reset-distribution' | 'skewed-distribution' | 'palette';
   x: number;
   y: number;
   width: number;
@@ -380,6 +386,18 @@ export function calculateLobbyLayout(
         canvasHeight,
       );
 
+      // The AI toggle is the color swatch at the left of the entry
+      const aiButtonSize = entryHeight * 0.6;
+      const aiBtnCenter = transformPoint(
+        x + 10 + aiButtonSize / 2,
+        y + entryHeight / 2,
+        rotation,
+        screenCenterX,
+        screenCenterY,
+        canvasWidth,
+        canvasHeight,
+      );
+
       playerLists[edge].push({
         player,
         x,
@@ -394,6 +412,11 @@ export function calculateLobbyLayout(
           y: transformedCenter.y - removeButtonSize / 2,
           size: removeButtonSize,
         },
+        aiButton: gameMode === 'tabletop' ? {
+          x: aiBtnCenter.x - aiButtonSize / 2,
+          y: aiBtnCenter.y - aiButtonSize / 2,
+          size: aiButtonSize,
+        } : undefined,
       });
     });
   }
@@ -1,6 +1,6 @@
 // Lobby renderer for the redesigned edge-based lobby
 
-import { ConfigPlayer } from "../redux/types";
+import { ConfigPlayer, Palette, MAX_TURN_TIME_LIMIT } from "../redux/types";
 import {
   LobbyLayout,
   EdgeButton,
@@ -13,7 +13,10 @@ import {
 import { calculateTileCountsFromRatio } from "../redux/gameReducer";
 import { TileType } from "../game/types";
 import { getFlowConnections } from "../game/tiles";
+import { MIN_AI_DIFFICULTY, MAX_AI_DIFFICULTY } from "../game/ai";
+import { MAX_ANIMATION_SPEED } from "../animation/actions";
 import { getEdgeMidpoint, getPerpendicularVector, getHexVertex } from "./hexLayout";
+import { getPaletteColor, PALETTE_NAMES } from "./palette";
 
 // Tile rendering constants (matching gameplayRenderer)
 const TILE_BG = "#2a2a2a";
@@ -22,6 +25,7 @@ const TILE_BORDER = "#444444";
 export class LobbyRenderer {
   private ctx: CanvasRenderingContext2D;
   private layout: LobbyLayout | null = null;
+  private palette: Palette = 'default';
 
   constructor(ctx: CanvasRenderingContext2D) {
     this.ctx = ctx;
@@ -40,6 +44,7 @@ export class LobbyRenderer {
     disconnectedPlayers: Set<string> = new Set(),
   ): LobbyLayout {
     this.layout = calculateLobbyLayout(canvasWidth, canvasHeight, players, gameMode);
+    this.palette = settings?.palette ?? 'default';
 
     // Clear canvas
     this.ctx.fillStyle = "#1a1a2e";
@@ -80,7 +85,7 @@ export class LobbyRenderer {
       this.ctx.save();
 
       // Draw button background
-      this.ctx.fillStyle = button.color;
+      this.ctx.fillStyle = getPaletteColor(button.color, this.palette);
       this.ctx.fillRect(button.x, button.y, button.size, button.size);
 
       // Draw border
@@ -386,11 +391,23 @@ export class LobbyRenderer {
     const colorX = -entry.width / 2 + 10;
     const colorY = -(colorSize / 2);
 
-    this.ctx.fillStyle = entry.player.color;
+    this.ctx.fillStyle = getPaletteColor(entry.player.color, this.palette);
     this.ctx.fillRect(colorX, colorY, colorSize, colorSize);
     this.ctx.strokeStyle = "#ffffff";
     this.ctx.lineWidth = 2;
     this.ctx.strokeRect(colorX, colorY, colorSize, colorSize);
+
+    // Label the swatch when the AI plays this seat (tapping the swatch toggles it)
+    if (entry.player.isAI) {
+      this.ctx.fillStyle = "#ffffff";
+      this.ctx.strokeStyle = "#000000";
+      this.ctx.lineWidth = 3;
+      this.ctx.font = `bold ${colorSize * 0.5}px sans-serif`;
+      this.ctx.textAlign = "center";
+      this.ctx.textBaseline = "middle";
+      this.ctx.strokeText("AI", colorX + colorSize / 2, 0);
+      this.ctx.fillText("AI", colorX + colorSize / 2, 0);
+    }
     
     // If player is disconnected, draw a red dot indicator on the color tile
     if (isDisconnected) {
@@ -453,7 +470,7 @@ export class LobbyRenderer {
 
     // Dialog box
     const dialogWidth = Math.min(500, canvasWidth * 0.8);
-    const dialogHeight = Math.min(795, canvasHeight * 0.9); // Increased from 750 to accommodate Single Supermove line
+    const dialogHeight = Math.min(1335, canvasHeight * 0.9); // Increased from 795 to accommodate Opposite Sides Victory, Longest Flow Wins Full Board, No Self-Stalemate, Auto-Rotate to Legal, Highlight Critical Paths, Show Coordinates, Rotate Board to Player, Palette, Sound Effects, Animation Speed, Turn Time Limit and AI Difficulty lines
     const dialogX = (canvasWidth - dialogWidth) / 2;
     const dialogY = (canvasHeight - dialogHeight) / 2;
 
@@ -558,6 +575,233 @@ export class LobbyRenderer {
     }
     contentY += lineHeight;
 
+    // Opposite Sides Victory
+    this.renderCheckbox(contentX + dialogWidth - 80, contentY, checkboxSize, settings.oppositeSidesVictory);
+    this.ctx.fillStyle = "#ffffff"; // Reset to white after checkbox
+    this.ctx.textAlign = "left"; // Ensure left alignment
+    this.ctx.fillText("Opposite Sides Victory", contentX, contentY + checkboxSize / 2);
+    controls.push({
+      type: 'checkbox',
+      x: contentX + dialogWidth - 80,
+      y: contentY,
+      width: checkboxSize,
+      height: checkboxSize,
+      settingKey: 'oppositeSidesVictory',
+    });
+    contentY += lineHeight;
+
+    // Longest flow wins a board filled without a connection
+    this.renderCheckbox(contentX + dialogWidth - 80, contentY, checkboxSize, settings.longestFlowVictory);
+    this.ctx.fillStyle = "#ffffff"; // Reset to white after checkbox
+    this.ctx.textAlign = "left"; // Ensure left alignment
+    this.ctx.fillText("Longest Flow Wins Full Board", contentX, contentY + checkboxSize / 2);
+    controls.push({
+      type: 'checkbox',
+      x: contentX + dialogWidth - 80,
+      y: contentY,
+      width: checkboxSize,
+      height: checkboxSize,
+      settingKey: 'longestFlowVictory',
+    });
+    contentY += lineHeight;
+
+    // Forbid placements that leave no legal move for any tile in the bag
+    this.renderCheckbox(contentX + dialogWidth - 80, contentY, checkboxSize, settings.noSelfStalemate);
+    this.ctx.fillStyle = "#ffffff"; // Reset to white after checkbox
+    this.ctx.textAlign = "left"; // Ensure left alignment
+    this.ctx.fillText("No Self-Stalemate", contentX, contentY + checkboxSize / 2);
+    controls.push({
+      type: 'checkbox',
+      x: contentX + dialogWidth - 80,
+      y: contentY,
+      width: checkboxSize,
+      height: checkboxSize,
+      settingKey: 'noSelfStalemate',
+    });
+    contentY += lineHeight;
+
+    // Auto-rotate the selected tile to a legal orientation
+    this.renderCheckbox(contentX + dialogWidth - 80, contentY, checkboxSize, settings.autoRotateToLegal);
+    this.ctx.fillStyle = "#ffffff"; // Reset to white after checkbox
+    this.ctx.textAlign = "left"; // Ensure left alignment
+    this.ctx.fillText("Auto-Rotate to Legal", contentX, contentY + checkboxSize / 2);
+    controls.push({
+      type: 'checkbox',
+      x: contentX + dialogWidth - 80,
+      y: contentY,
+      width: checkboxSize,
+      height: checkboxSize,
+      settingKey: 'autoRotateToLegal',
+    });
+    contentY += lineHeight;
+
+    // Highlight flows on each player's shortest winning path
+    this.renderCheckbox(contentX + dialogWidth - 80, contentY, checkboxSize, settings.highlightCriticalPaths);
+    this.ctx.fillStyle = "#ffffff"; // Reset to white after checkbox
+    this.ctx.textAlign = "left"; // Ensure left alignment
+    this.ctx.fillText("Highlight Critical Paths", contentX, contentY + checkboxSize / 2);
+    controls.push({
+      type: 'checkbox',
+      x: contentX + dialogWidth - 80,
+      y: contentY,
+      width: checkboxSize,
+      height: checkboxSize,
+      settingKey: 'highlightCriticalPaths',
+    });
+    contentY += lineHeight;
+
+    // Label rows and columns around the board
+    this.renderCheckbox(contentX + dialogWidth - 80, contentY, checkboxSize, settings.showCoordinates);
+    this.ctx.fillStyle = "#ffffff"; // Reset to white after checkbox
+    this.ctx.textAlign = "left"; // Ensure left alignment
+    this.ctx.fillText("Show Coordinates", contentX, contentY + checkboxSize / 2);
+    controls.push({
+      type: 'checkbox',
+      x: contentX + dialogWidth - 80,
+      y: contentY,
+      width: checkboxSize,
+      height: checkboxSize,
+      settingKey: 'showCoordinates',
+    });
+    contentY += lineHeight;
+
+    // Turn the board to the player to move when a tabletop game is passed around
+    this.renderCheckbox(contentX + dialogWidth - 80, contentY, checkboxSize, settings.rotateBoardToCurrentPlayer);
+    this.ctx.fillStyle = "#ffffff"; // Reset to white after checkbox
+    this.ctx.textAlign = "left"; // Ensure left alignment
+    this.ctx.fillText("Rotate Board to Player", contentX, contentY + checkboxSize / 2);
+    controls.push({
+      type: 'checkbox',
+      x: contentX + dialogWidth - 80,
+      y: contentY,
+      width: checkboxSize,
+      height: checkboxSize,
+      settingKey: 'rotateBoardToCurrentPlayer',
+    });
+    contentY += lineHeight;
+
+    // Player colour palette, cycled by clicking the button
+    this.ctx.fillStyle = "#ffffff";
+    this.ctx.font = "18px sans-serif";
+    this.ctx.textAlign = "left";
+    this.ctx.fillText("Palette:", contentX, contentY + buttonHeight / 2);
+    const paletteX = contentX + 175;
+    const paletteWidth = 160;
+    this.ctx.fillStyle = "#444444";
+    this.ctx.fillRect(paletteX, contentY, paletteWidth, buttonHeight);
+    this.ctx.strokeStyle = "#ffffff";
+    this.ctx.lineWidth = 2;
+    this.ctx.strokeRect(paletteX, contentY, paletteWidth, buttonHeight);
+    this.ctx.fillStyle = "#ffffff";
+    this.ctx.textAlign = "center";
+    this.ctx.fillText(PALETTE_NAMES[settings.palette], paletteX + paletteWidth / 2, contentY + buttonHeight / 2);
+    this.ctx.textAlign = "left";
+    controls.push({
+      type: 'palette',
+      x: paletteX,
+      y: contentY,
+      width: paletteWidth,
+      height: buttonHeight,
+      settingKey: 'palette',
+    });
+    contentY += lineHeight;
+
+    // Sound effects for tile placement, rotation and victory
+    this.renderCheckbox(contentX + dialogWidth - 80, contentY, checkboxSize, settings.soundEffects);
+    this.ctx.fillStyle = "#ffffff"; // Reset to white after checkbox
+    this.ctx.textAlign = "left"; // Ensure left alignment
+    this.ctx.fillText("Sound Effects", contentX, contentY + checkboxSize / 2);
+    controls.push({
+      type: 'checkbox',
+      x: contentX + dialogWidth - 80,
+      y: contentY,
+      width: checkboxSize,
+      height: checkboxSize,
+      settingKey: 'soundEffects',
+    });
+    contentY += lineHeight;
+
+    // Animation speed multiplier (0 turns animations off)
+    this.ctx.fillStyle = "#ffffff";
+    this.ctx.font = "18px sans-serif";
+    this.ctx.textAlign = "left";
+    this.ctx.fillText("Animation Speed:", contentX, contentY + buttonHeight / 2);
+    const speedX = contentX + 200;
+    this.renderNumberControl(speedX, contentY, settings.animationSpeed, 0, MAX_ANIMATION_SPEED);
+    controls.push({
+      type: 'number',
+      x: speedX - 25,
+      y: contentY,
+      width: 30,
+      height: buttonHeight,
+      settingKey: 'animationSpeed',
+      label: '-',
+    });
+    controls.push({
+      type: 'number',
+      x: speedX + 40,
+      y: contentY,
+      width: 30,
+      height: buttonHeight,
+      settingKey: 'animationSpeed',
+      label: '+',
+    });
+    contentY += lineHeight;
+
+    // Seconds per turn in multiplayer games (0 turns the timer off)
+    this.ctx.fillStyle = "#ffffff";
+    this.ctx.font = "18px sans-serif";
+    this.ctx.textAlign = "left";
+    this.ctx.fillText("Turn Time Limit (s):", contentX, contentY + buttonHeight / 2);
+    const turnLimitX = contentX + 200;
+    this.renderNumberControl(turnLimitX, contentY, settings.turnTimeLimit, 0, MAX_TURN_TIME_LIMIT);
+    controls.push({
+      type: 'number',
+      x: turnLimitX - 25,
+      y: contentY,
+      width: 30,
+      height: buttonHeight,
+      settingKey: 'turnTimeLimit',
+      label: '-',
+    });
+    controls.push({
+      type: 'number',
+      x: turnLimitX + 40,
+      y: contentY,
+      width: 30,
+      height: buttonHeight,
+      settingKey: 'turnTimeLimit',
+      label: '+',
+    });
+    contentY += lineHeight;
+
+    // AI Difficulty
+    this.ctx.fillStyle = "#ffffff";
+    this.ctx.font = "18px sans-serif";
+    this.ctx.textAlign = "left";
+    this.ctx.fillText("AI Difficulty:", contentX, contentY + buttonHeight / 2);
+    const difficultyX = contentX + 200;
+    this.renderNumberControl(difficultyX, contentY, settings.aiDifficulty, MIN_AI_DIFFICULTY, MAX_AI_DIFFICULTY);
+    controls.push({
+      type: 'number',
+      x: difficultyX - 25,
+      y: contentY,
+      width: 30,
+      height: buttonHeight,
+      settingKey: 'aiDifficulty',
+      label: '-',
+    });
+    controls.push({
+      type: 'number',
+      x: difficultyX + 40,
+      y: contentY,
+      width: 30,
+      height: buttonHeight,
+      settingKey: 'aiDifficulty',
+      label: '+',
+    });
+    contentY += lineHeight;
+
     // Tile Distribution section
     contentY += 10;
     this.ctx.font = "bold 20px sans-serif";
@@ -619,26 +863,34 @@ export class LobbyRenderer {
     this.ctx.fillText(`Total: ${totalTiles} tiles (${numGroups} groups)`, contentX, contentY);
     contentY += lineHeight;
 
-    // Reset button
+    // Preset buttons: reset to the classic distribution, or switch to the skewed one
     const resetButtonWidth = 150;
     const resetButtonHeight = 30;
-    const resetButtonX = contentX + (dialogWidth - 60 - resetButtonWidth) / 2;
-    this.ctx.fillStyle = "#555555";
-    this.ctx.fillRect(resetButtonX, contentY, resetButtonWidth, resetButtonHeight);
-    this.ctx.strokeStyle = "#ffffff";
-    this.ctx.lineWidth = 1;
-    this.ctx.strokeRect(resetButtonX, contentY, resetButtonWidth, resetButtonHeight);
-    this.ctx.fillStyle = "#ffffff";
-    this.ctx.font = "16px sans-serif";
-    this.ctx.textAlign = "center";
-    this.ctx.fillText("Reset to Default", resetButtonX + resetButtonWidth / 2, contentY + resetButtonHeight / 2);
-    controls.push({
-      type: 'reset-distribution',
-      x: resetButtonX,
-      y: contentY,
-      width: resetButtonWidth,
-      height: resetButtonHeight,
-    });
+    const presetGap = 20;
+    const resetButtonX = contentX + (dialogWidth - 60 - resetButtonWidth * 2 - presetGap) / 2;
+    const skewedButtonX = resetButtonX + resetButtonWidth + presetGap;
+    const presets = [
+      { x: resetButtonX, label: "Reset to Default", type: 'reset-distribution' as const },
+      { x: skewedButtonX, label: "Skewed", type: 'skewed-distribution' as const },
+    ];
+    for (const preset of presets) {
+      this.ctx.fillStyle = "#555555";
+      this.ctx.fillRect(preset.x, contentY, resetButtonWidth, resetButtonHeight);
+      this.ctx.strokeStyle = "#ffffff";
+      this.ctx.lineWidth = 1;
+      this.ctx.strokeRect(preset.x, contentY, resetButtonWidth, resetButtonHeight);
+      this.ctx.fillStyle = "#ffffff";
+      this.ctx.font = "16px sans-serif";
+      this.ctx.textAlign = "center";
+      this.ctx.fillText(preset.label, preset.x + resetButtonWidth / 2, contentY + resetButtonHeight / 2);
+      controls.push({
+        type: preset.type,
+        x: preset.x,
+        y: contentY,
+        width: resetButtonWidth,
+        height: resetButtonHeight,
+      });
+    }
     contentY += lineHeight;
 
     // Reset text alignment and ensure white color for debug section
@@ -991,6 +1243,7 @@ export class LobbyRenderer {
     const helpLines = [
       "Adding Players:",
       "• Tap a coloured + button to choose your colour",
+      "• Tap a player's colour to let the AI play that seat",
       "",
       "Starting the Game:",
       "• 1 player starts a game vs AI",
@@ -0,0 +1,49 @@
+// Player colour palettes
+//
+// Players keep their PLAYER_COLORS entry in game state; the chosen palette only
+// changes how that colour is drawn, so every client can pick its own palette.
+
+import { Palette, PLAYER_COLORS } from '../redux/types';
+
+// Palettes in the order the settings control cycles through them
+export const PALETTES: Palette[] = ['default', 'colorblind', 'high-contrast'];
+
+// Display names for the settings dialog
+export const PALETTE_NAMES: Record<Palette, string> = {
+  default: 'Default',
+  colorblind: 'Colorblind Safe',
+  'high-contrast': 'High Contrast',
+};
+
+// Colours for each palette, indexed like PLAYER_COLORS
+const PALETTE_COLORS: Record<Palette, readonly string[]> = {
+  default: PLAYER_COLORS,
+  // Paul Tol's "bright" qualitative scheme, distinguishable under common colour blindness
+  colorblind: ['#4477AA', '#EE6677', '#228833', '#CCBB44', '#AA3377', '#66CCEE'],
+  // Fully saturated colours that stand out against the black board
+  'high-contrast': ['#1E90FF', '#FF8C00', '#00E000', '#FFFF00', '#FF00FF', '#FF2020'],
+};
+
+// Letter drawn on each player's edge in the colorblind palette, indexed like PLAYER_COLORS
+const PLAYER_MARKERS = ['A', 'B', 'C', 'D', 'E', 'F'];
+
+// Get the colour to draw for a player colour under a palette
+// Colours outside PLAYER_COLORS are drawn unchanged
+export function getPaletteColor(color: string, palette: Palette): string {
+  const index = (PLAYER_COLORS as readonly string[]).indexOf(color);
+  return index === -1 ? color : PALETTE_COLORS[palette][index];
+}
+
+// Get the marker drawn on a player's edge so colour isn't the only cue, or null for none
+export function getPlayerMarker(color: string, palette: Palette): string | null {
+  if (palette !== 'colorblind') {
+    return null;
+  }
+  const index = (PLAYER_COLORS as readonly string[]).indexOf(color);
+  return index === -1 ? null : PLAYER_MARKERS[index];
+}
+
+// Get the palette after the given one, wrapping around
+export function getNextPalette(palette: Palette): Palette {
+  return PALETTES[(PALETTES.indexOf(palette) + 1) % PALETTES.length];
+}
@@ -163,7 +163,8 @@ export class Renderer {
       this.canvas.width,
       this.canvas.height,
       state.game,
-      disconnectedConfigPlayerIds
+      disconnectedConfigPlayerIds,
+      state.ui.settings.palette
     );
 
     // Return empty UILayout for compatibility
@@ -1,6 +1,7 @@
 // Seating phase renderer
 
-import { GameState, ConfigPlayer } from '../redux/types';
+import { GameState, ConfigPlayer, Palette } from '../redux/types';
+import { getPaletteColor } from './palette';
 import { calculateHexLayout, HexLayout, Point, calculateBoardRadiusMultiplier } from './hexLayout';
 
 // Edge button information for seating phase
@@ -21,12 +22,14 @@ export interface SeatingLayout {
 export class SeatingRenderer {
   private ctx: CanvasRenderingContext2D;
   private layout: SeatingLayout | null = null;
+  private palette: Palette = 'default';
 
   constructor(ctx: CanvasRenderingContext2D) {
     this.ctx = ctx;
   }
 
-  render(canvasWidth: number, canvasHeight: number, state: GameState, disconnectedPlayers: Set<string>): SeatingLayout {
+  render(canvasWidth: number, canvasHeight: number, state: GameState, disconnectedPlayers: Set<string>, palette: Palette = 'default'): SeatingLayout {
+    this.palette = palette;
     const hexLayout = calculateHexLayout(canvasWidth, canvasHeight, state.boardRadius);
     
     // Calculate edge button positions
@@ -37,7 +40,7 @@ export class SeatingRenderer {
     state.seatingPhase.edgeAssignments.forEach((edge, playerId) => {
       const configPlayer = state.configPlayers.find(cp => cp.id === playerId);
       if (configPlayer) {
-        selectedEdges.set(edge, configPlayer.color);
+        selectedEdges.set(edge, getPaletteColor(configPlayer.color, this.palette));
       }
     });
     
@@ -194,7 +197,7 @@ export class SeatingRenderer {
       this.ctx.save();
       
       // Draw circular button background
-      this.ctx.fillStyle = currentPlayer.color;
+      this.ctx.fillStyle = getPaletteColor(currentPlayer.color, this.palette);
       this.ctx.beginPath();
       this.ctx.arc(button.position.x, button.position.y, button.radius, 0, Math.PI * 2);
       this.ctx.fill();
@@ -0,0 +1,135 @@
+// Tile atlas generation: every tile type in every distinct rotation as one SVG
+// Uses the same hex geometry as the canvas renderer so the atlas can be used to
+// verify flow geometry and for documentation
+
+import { TileType, Rotation, FlowConnection } from "../game/types";
+import { getFlowConnections, getUniqueRotations } from "../game/tiles";
+import {
+  Point,
+  getHexVertices,
+  getEdgeMidpoint,
+  getPerpendicularVector,
+} from "./hexLayout";
+import { ORIENTATION_NAMES, tileTypeToNotation } from "../game/notation";
+
+// A single cell in the atlas grid
+export interface TileAtlasCell {
+  type: TileType;
+  rotation: Rotation;
+}
+
+export interface TileAtlasOptions {
+  hexSize?: number; // Distance from hex center to vertex, in pixels
+  padding?: number; // Space around each cell, in pixels
+}
+
+const DEFAULT_HEX_SIZE = 60;
+const DEFAULT_PADDING = 20;
+const LABEL_HEIGHT = 24;
+
+const TILE_TYPES: TileType[] = [
+  TileType.NoSharps,
+  TileType.OneSharp,
+  TileType.TwoSharps,
+  TileType.ThreeSharps,
+];
+
+// Distinct rotations come from the game logic so the atlas and legality agree
+export { getUniqueRotations };
+
+// Get the file name (without extension) for a tile image, e.g. "T2_SE"
+// Uses the notation's tile type and orientation names, unadjusted for any player's view
+export function getTileSvgName(type: TileType, rotation: Rotation): string {
+  return `${tileTypeToNotation(type)}_${ORIENTATION_NAMES[rotation]}`;
+}
+
+// Get the atlas cells: one row per tile type, one column per unique rotation
+export function getTileAtlasCells(): TileAtlasCell[][] {
+  return TILE_TYPES.map((type) =>
+    getUniqueRotations(type).map((rotation) => ({ type, rotation })),
+  );
+}
+
+export function formatPoint(p: Point): string {
+  return `${p.x.toFixed(2)},${p.y.toFixed(2)}`;
+}
+
+// Build the SVG path for one flow, matching the renderer's Bézier curves
+export function flowPath(center: Point, size: number, connection: FlowConnection): string {
+  const [dir1, dir2] = connection;
+  const start = getEdgeMidpoint(center, size, dir1);
+  const end = getEdgeMidpoint(center, size, dir2);
+  const control1Vec = getPerpendicularVector(dir1, size);
+  const control2Vec = getPerpendicularVector(dir2, size);
+  const control1 = { x: start.x + control1Vec.x, y: start.y + control1Vec.y };
+  const control2 = { x: end.x + control2Vec.x, y: end.y + control2Vec.y };
+  return `M ${formatPoint(start)} C ${formatPoint(control1)} ${formatPoint(control2)} ${formatPoint(end)}`;
+}
+
+// Render one tile cell as an SVG group with a label underneath
+function renderCell(cell: TileAtlasCell, center: Point, size: number): string {
+  const hexPoints = getHexVertices(center, size).map(formatPoint).join(" ");
+  const flows = getFlowConnections(cell.type, cell.rotation)
+    .map(
+      (connection) =>
+        `    <path d="${flowPath(center, size, connection)}" fill="none" stroke="#ffffff" stroke-width="${(size * 0.18).toFixed(2)}" stroke-linecap="round"/>`,
+    )
+    .join("\n");
+  const label = `${TileType[cell.type]} r${cell.rotation}`;
+  const labelY = center.y + size + LABEL_HEIGHT * 0.75;
+
+  return [
+    `  <g class="tile-cell" data-type="${cell.type}" data-rotation="${cell.rotation}">`,
+    `    <polygon points="${hexPoints}" fill="#2a2a2a" stroke="#666666" stroke-width="1"/>`,
+    flows,
+    `    <text x="${center.x.toFixed(2)}" y="${labelY.toFixed(2)}" text-anchor="middle" font-family="sans-serif" font-size="14" fill="#333333">${label}</text>`,
+    `  </g>`,
+  ].join("\n");
+}
+
+// Lay out rows of cells in a grid and wrap them in an SVG document
+function renderGrid(rows: TileAtlasCell[][], options: TileAtlasOptions): string {
+  const size = options.hexSize ?? DEFAULT_HEX_SIZE;
+  const padding = options.padding ?? DEFAULT_PADDING;
+
+  // Pointy-top hexes are sqrt(3) * size wide and 2 * size tall
+  const cellWidth = Math.sqrt(3) * size + padding * 2;
+  const cellHeight = 2 * size + LABEL_HEIGHT + padding * 2;
+
+  const columns = Math.max(...rows.map((row) => row.length));
+  const width = cellWidth * columns;
+  const height = cellHeight * rows.length;
+
+  const cells: string[] = [];
+  rows.forEach((row, rowIndex) => {
+    row.forEach((cell, colIndex) => {
+      const center = {
+        x: colIndex * cellWidth + cellWidth / 2,
+        y: rowIndex * cellHeight + padding + size,
+      };
+      cells.push(renderCell(cell, center, size));
+    });
+  });
+
+  return [
+    `<svg xmlns="http://www.w3.org/2000/svg" width="${width.toFixed(0)}" height="${height.toFixed(0)}" viewBox="0 0 ${width.toFixed(2)} ${height.toFixed(2)}">`,
+    `  <rect width="100%" height="100%" fill="#ffffff"/>`,
+    ...cells,
+    `</svg>`,
+    "",
+  ].join("\n");
+}
+
+// Generate an SVG of a single tile in the given rotation
+export function generateTileSvg(
+  type: TileType,
+  rotation: Rotation,
+  options: TileAtlasOptions = {},
+): string {
+  return renderGrid([[{ type, rotation }]], options);
+}
+
+// Generate an SVG atlas of all tile types across their unique rotations
+export function generateTileAtlasSvg(options: TileAtlasOptions = {}): string {
+  return renderGrid(getTileAtlasCells(), options);
+}
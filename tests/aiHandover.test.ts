@@ -0,0 +1,202 @@
+// Tests for handing a departing player's seat over to AI control
+// When a player leaves or times out mid-game, the server issues HAND_OVER_TO_AI and
+// every client's AI middleware plays that seat from then on
+
+import { describe, it, expect, beforeEach } from 'vitest';
+import { createStore, applyMiddleware } from 'redux';
+import { rootReducer } from '../src/redux/reducer';
+import { aiMiddleware } from '../src/redux/aiMiddleware';
+import { resetPlayerIdCounter } from '../src/redux/gameReducer';
+import {
+  startGame,
+  selectEdge,
+  placeTile,
+  nextPlayer,
+  drawTile,
+  updateSettings,
+  handOverToAI,
+  reclaimFromAI,
+  GameAction,
+} from '../src/redux/actions';
+import { findLegalMoves } from '../src/game/legality';
+import { MIN_AI_DIFFICULTY, MAX_AI_DIFFICULTY } from '../src/game/ai';
+import type { RootState } from '../src/redux/types';
+import { assertGamesEqual } from './utils/boardAssertions';
+
+function createHumanGame(aiDifficulty = MAX_AI_DIFFICULTY) {
+  const store = createStore(rootReducer, applyMiddleware(aiMiddleware));
+
+  // Supermoves are irrelevant to the handover and slow the AI down
+  store.dispatch(updateSettings({ supermove: false, aiDifficulty }));
+
+  store.dispatch({
+    type: 'ADD_PLAYER',
+    payload: { color: '#DE8F05', edge: 0, isAI: false },
+  } as GameAction);
+  store.dispatch({
+    type: 'ADD_PLAYER',
+    payload: { color: '#0173B2', edge: 1, isAI: false },
+  } as GameAction);
+
+  const settings = (store.getState() as RootState).ui.settings;
+  store.dispatch(startGame({
+    supermove: settings.supermove,
+    singleSupermove: settings.singleSupermove,
+    seed: 12345,
+  }) as any);
+
+  // Both humans pick an edge in seating order
+  const { seatingOrder } = (store.getState() as RootState).game.seatingPhase;
+  seatingOrder.forEach((playerId, index) => {
+    store.dispatch(selectEdge(playerId, index * 3) as any);
+  });
+
+  return store;
+}
+
+// Actions for one legal move by the current (human) player, passing the turn
+function humanMoveActions(store: ReturnType<typeof createHumanGame>): GameAction[] {
+  const { game } = store.getState() as RootState;
+  const legal = findLegalMoves(
+    game.board,
+    game.currentTile!,
+    0,
+    game.players,
+    game.teams,
+    game.boardRadius,
+    false
+  );
+  return [placeTile(legal[0], 0), nextPlayer(), drawTile()];
+}
+
+// Play one legal move for the current (human) player and pass the turn
+function playHumanMove(store: ReturnType<typeof createHumanGame>) {
+  humanMoveActions(store).forEach((action) => store.dispatch(action as any));
+}
+
+describe('AI handover', () => {
+  beforeEach(() => {
+    resetPlayerIdCounter();
+  });
+
+  it('should let the AI play the departing player\'s current turn', { timeout: 30000 }, () => {
+    const store = createHumanGame();
+    let state = store.getState() as RootState;
+    expect(state.game.phase).toBe('playing');
+    expect(state.game.board.size).toBe(0);
+
+    const departingId = state.game.players[state.game.currentPlayerIndex].id;
+    store.dispatch(handOverToAI(departingId) as any);
+
+    state = store.getState() as RootState;
+    expect(state.game.players.find(p => p.id === departingId)!.isAI).toBe(true);
+    expect(state.game.configPlayers.find(p => p.id === departingId)!.isAI).toBe(true);
+
+    // The AI placed a tile for the departing seat and passed the turn back
+    expect(state.game.board.size).toBe(1);
+    expect(state.game.moveHistory[0].playerId).toBe(departingId);
+    expect(state.game.players[state.game.currentPlayerIndex].id).not.toBe(departingId);
+  });
+
+  it('should keep making the seat\'s subsequent moves', { timeout: 30000 }, () => {
+    const store = createHumanGame();
+    let state = store.getState() as RootState;
+
+    // Hand over the seat that is not on turn; nothing happens until it comes around
+    const remainingId = state.game.players[state.game.currentPlayerIndex].id;
+    const departingId = state.game.players.find(p => p.id !== remainingId)!.id;
+    store.dispatch(handOverToAI(departingId) as any);
+    expect((store.getState() as RootState).game.board.size).toBe(0);
+
+    for (let turn = 0; turn < 3; turn++) {
+      playHumanMove(store);
+      state = store.getState() as RootState;
+      if (state.game.phase !== 'playing') break;
+
+      // The AI answered immediately, returning the turn to the remaining human
+      expect(state.game.moveHistory.length).toBe((turn + 1) * 2);
+      expect(state.game.moveHistory[turn * 2 + 1].playerId).toBe(departingId);
+      expect(state.game.players[state.game.currentPlayerIndex].id).toBe(remainingId);
+    }
+  });
+
+  it('should play a handed-over seat the same whatever each client\'s difficulty', { timeout: 60000 }, () => {
+    // One client, set to the weakest AI, plays the game and records the shared action log
+    const lenient = createHumanGame(MIN_AI_DIFFICULTY);
+    const { game } = lenient.getState() as RootState;
+    const remainingId = game.players[game.currentPlayerIndex].id;
+    const departingId = game.players.find(p => p.id !== remainingId)!.id;
+
+    const log: GameAction[] = [handOverToAI(departingId)];
+    lenient.dispatch(log[0] as any);
+    for (let turn = 0; turn < 4; turn++) {
+      if ((lenient.getState() as RootState).game.phase !== 'playing') break;
+      const moves = humanMoveActions(lenient);
+      log.push(...moves);
+      moves.forEach((action) => lenient.dispatch(action as any));
+    }
+
+    // Another client, set to the strongest AI, replays the same log
+    resetPlayerIdCounter();
+    const strict = createHumanGame(MAX_AI_DIFFICULTY);
+    log.forEach((action) => strict.dispatch(action as any));
+
+    const lenientGame = (lenient.getState() as RootState).game;
+    expect(lenientGame.players.find(p => p.id === departingId)!.aiDifficulty).toBe(MAX_AI_DIFFICULTY);
+    assertGamesEqual((strict.getState() as RootState).game, lenientGame);
+  });
+
+  it('should give the seat back to a returning player', { timeout: 30000 }, () => {
+    const store = createHumanGame();
+    let state = store.getState() as RootState;
+    const remainingId = state.game.players[state.game.currentPlayerIndex].id;
+    const returningId = state.game.players.find(p => p.id !== remainingId)!.id;
+
+    store.dispatch(handOverToAI(returningId) as any);
+    playHumanMove(store);
+    expect((store.getState() as RootState).game.moveHistory).toHaveLength(2);
+
+    store.dispatch(reclaimFromAI(returningId) as any);
+    state = store.getState() as RootState;
+    expect(state.game.players.find(p => p.id === returningId)!.isAI).toBe(false);
+    expect(state.game.configPlayers.find(p => p.id === returningId)!.isAI).toBe(false);
+
+    // The AI no longer answers for the seat, so its player is on turn after the next move
+    playHumanMove(store);
+    state = store.getState() as RootState;
+    expect(state.game.moveHistory).toHaveLength(3);
+    expect(state.game.players[state.game.currentPlayerIndex].id).toBe(returningId);
+  });
+
+  it('should match multiplayer seats by user ID', () => {
+    const store = createStore(rootReducer);
+    store.dispatch({
+      type: 'ADD_PLAYER',
+      payload: { color: '#DE8F05', edge: 0, isAI: false, userId: 'google:123' },
+    } as GameAction);
+    store.dispatch({
+      type: 'ADD_PLAYER',
+      payload: { color: '#0173B2', edge: 1, isAI: false, userId: 'google:456' },
+    } as GameAction);
+    store.dispatch(startGame({ seed: 1 }) as any);
+    const { seatingOrder } = (store.getState() as RootState).game.seatingPhase;
+    seatingOrder.forEach((playerId, index) => {
+      store.dispatch(selectEdge(playerId, index * 3) as any);
+    });
+
+    store.dispatch(handOverToAI('google:456') as any);
+
+    const { game } = store.getState() as RootState;
+    expect(game.players.find(p => p.userId === 'google:456')!.isAI).toBe(true);
+    expect(game.players.find(p => p.userId === 'google:123')!.isAI).toBe(false);
+  });
+
+  it('should ignore handovers for unknown players', () => {
+    const store = createHumanGame();
+    const before = (store.getState() as RootState).game;
+
+    store.dispatch(handOverToAI('nobody') as any);
+
+    expect((store.getState() as RootState).game).toBe(before);
+  });
+});
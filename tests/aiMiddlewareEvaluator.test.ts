@@ -0,0 +1,35 @@
+// Tests for plugging a custom evaluation strategy into the AI middleware
+
+import { describe, it, expect, beforeEach, vi } from 'vitest';
+import { createStore, applyMiddleware } from 'redux';
+import { rootReducer } from '../src/redux/reducer';
+import { createAIMiddleware } from '../src/redux/aiMiddleware';
+import { resetPlayerIdCounter } from '../src/redux/gameReducer';
+import { startGame, updateSettings, GameAction } from '../src/redux/actions';
+import { controlEvaluator } from '../src/game/ai';
+import type { RootState } from '../src/redux/types';
+
+describe('AI middleware evaluator', () => {
+  beforeEach(() => {
+    resetPlayerIdCounter();
+  });
+
+  it('should score AI moves with the evaluator it was created with', { timeout: 30000 }, () => {
+    const evaluator = vi.fn(controlEvaluator);
+    const store = createStore(rootReducer, applyMiddleware(createAIMiddleware(evaluator)));
+
+    // Supermoves are irrelevant here and slow the AI down
+    store.dispatch(updateSettings({ supermove: false }));
+    store.dispatch({ type: 'ADD_PLAYER', payload: { color: '#DE8F05', edge: 0, isAI: true } } as GameAction);
+    store.dispatch({ type: 'ADD_PLAYER', payload: { color: '#0173B2', edge: 1, isAI: true } } as GameAction);
+    store.dispatch(startGame({ seed: 42, supermove: false }) as any);
+
+    // Two AI players play the whole game synchronously
+    const state = store.getState() as RootState;
+    expect(state.game.screen).toBe('game-over');
+    expect(evaluator).toHaveBeenCalled();
+
+    const [, aiPlayer] = evaluator.mock.calls[0];
+    expect(aiPlayer.isAI).toBe(true);
+  });
+});
@@ -0,0 +1,71 @@
+// Tests for deferring AI moves past the next paint while "AI is thinking…" shows
+
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import { createStore, applyMiddleware } from 'redux';
+import { rootReducer } from '../src/redux/reducer';
+import { createAIMiddleware } from '../src/redux/aiMiddleware';
+import { resetPlayerIdCounter } from '../src/redux/gameReducer';
+import { startGame, updateSettings, handOverToAI, GameAction } from '../src/redux/actions';
+import { pathLengthEvaluator } from '../src/game/ai';
+import type { RootState } from '../src/redux/types';
+
+describe('AI middleware with deferred moves', () => {
+  const FRAME_MS = 16;
+
+  beforeEach(() => {
+    resetPlayerIdCounter();
+    vi.useFakeTimers();
+    vi.stubGlobal('requestAnimationFrame', (run: (time: number) => void) => setTimeout(() => run(0), FRAME_MS));
+  });
+
+  afterEach(() => {
+    vi.unstubAllGlobals();
+    vi.useRealTimers();
+  });
+
+  const startAIGame = () => {
+    const store = createStore(rootReducer, applyMiddleware(createAIMiddleware(pathLengthEvaluator, true)));
+    store.dispatch(updateSettings({ supermove: false }));
+    store.dispatch({ type: 'ADD_PLAYER', payload: { color: '#DE8F05', edge: 0, isAI: true } } as GameAction);
+    store.dispatch({ type: 'ADD_PLAYER', payload: { color: '#0173B2', edge: 1, isAI: true } } as GameAction);
+    store.dispatch(startGame({ seed: 42, supermove: false }) as any);
+    return store;
+  };
+
+  it('should show the AI thinking and wait for the next paint before moving', () => {
+    const store = startAIGame();
+
+    let state = store.getState() as RootState;
+    expect(state.game.phase).toBe('playing');
+    expect(state.game.moveHistory).toHaveLength(0);
+    expect(state.ui.aiThinking).toBe(true);
+
+    vi.advanceTimersByTime(FRAME_MS);
+
+    // One move per paint: the second AI's turn is now pending in turn
+    state = store.getState() as RootState;
+    expect(state.game.moveHistory).toHaveLength(1);
+    expect(state.ui.aiThinking).toBe(true);
+  });
+
+  it('should keep at most one AI move pending', () => {
+    const store = startAIGame();
+    const before = store.getState() as RootState;
+
+    // Another action that hands the turn to the AI doesn't queue a second move
+    store.dispatch(handOverToAI(before.game.players[0].id));
+    vi.advanceTimersByTime(FRAME_MS);
+
+    expect((store.getState() as RootState).game.moveHistory).toHaveLength(1);
+  });
+
+  it('should play a whole game one move per paint', () => {
+    const store = startAIGame();
+
+    vi.runAllTimers();
+
+    const state = store.getState() as RootState;
+    expect(state.game.screen).toBe('game-over');
+    expect(state.ui.aiThinking).toBe(false);
+  });
+});
@@ -10,7 +10,9 @@ import {
   resumeAnimations,
   stepFrame,
   cancelAnimationsByName,
+  scaleAnimationFrames,
 } from '../src/animation/actions';
+import { processAnimations } from '../src/animation/processor';
 import {
   defineAnimation,
   getAnimationFunction,
@@ -281,4 +283,60 @@ describe('Animation Framework', () => {
       expect(state.animations[0].animationName).toBe('other-anim');
     });
   });
+
+  describe('Animation Speed', () => {
+    const mockStore = (animationSpeed?: number) => {
+      (global as any).window = {
+        __REDUX_STORE__: {
+          getState: () => ({
+            animation: { frameCounter: 100 },
+            ui: animationSpeed === undefined ? undefined : { settings: { animationSpeed } },
+          })
+        }
+      };
+    };
+
+    it('should scale frame counts by the speed', () => {
+      expect(scaleAnimationFrames(30, 1)).toBe(30);
+      expect(scaleAnimationFrames(30, 2)).toBe(15);
+      expect(scaleAnimationFrames(30, 0.5)).toBe(60);
+      expect(scaleAnimationFrames(30, 0)).toBe(0);
+    });
+
+    it('should scale one-shot animations but not looping ones', () => {
+      mockStore(2);
+
+      const oneShot = registerAnimation('fast', 20, 10);
+      expect(oneShot.payload.startFrame).toBe(105);
+      expect(oneShot.payload.endFrame).toBe(115);
+
+      const looping = registerAnimation('glow', 20, 0, true);
+      expect(looping.payload.endFrame).toBe(120);
+    });
+
+    it('should use normal speed when no setting is available', () => {
+      mockStore();
+
+      const action = registerAnimation('normal', 20);
+      expect(action.payload.endFrame).toBe(120);
+    });
+
+    it('should complete zero-length animations at once', () => {
+      const calls: number[] = [];
+      defineAnimation('instant', (t: number) => calls.push(t));
+      const dispatched: any[] = [];
+
+      processAnimations(
+        {
+          frameCounter: 7,
+          paused: false,
+          animations: [{ id: 'a', animationName: 'instant', startFrame: 7, endFrame: 7 }],
+        },
+        (action) => dispatched.push(action),
+      );
+
+      expect(calls).toEqual([1]);
+      expect(dispatched[0].payload.animations).toEqual([]);
+    });
+  });
 });
@@ -6,6 +6,17 @@ import {
   selectAIMove,
   generateMoveCandidates,
   MoveCandidate,
+  getOpponentPathLength,
+  computeOpponentSetback,
+  getEvaluationKey,
+  getLastSearchStats,
+  MIN_AI_DIFFICULTY,
+  MAX_AI_DIFFICULTY,
+  pathLengthEvaluator,
+  controlEvaluator,
+  orderMoves,
+  analyzePosition,
+  EvaluationStrategy,
 } from '../../src/game/ai';
 import { Player, Team, TileType, PlacedTile } from '../../src/game/types';
 
@@ -232,6 +243,138 @@ describe('AI Move Generation', () => {
   });
 });
 
+describe('AI evaluation memo', () => {
+  const players: Player[] = [
+    { id: 'ai1', color: '#0173B2', edgePosition: 0, isAI: true },
+    { id: 'p1', color: '#DE8F05', edgePosition: 3, isAI: false },
+  ];
+
+  // A fixed mid-game position
+  const createBoard = () => {
+    const board = new Map<string, PlacedTile>();
+    const tiles: PlacedTile[] = [
+      { type: TileType.NoSharps, rotation: 0, position: { row: -3, col: 0 } },
+      { type: TileType.OneSharp, rotation: 2, position: { row: -2, col: 0 } },
+      { type: TileType.TwoSharps, rotation: 1, position: { row: 3, col: 0 } },
+      { type: TileType.NoSharps, rotation: 4, position: { row: 2, col: 0 } },
+    ];
+    tiles.forEach(tile => board.set(`${tile.position.row},${tile.position.col}`, tile));
+    return board;
+  };
+
+  it('should give rotations that connect the same ports the same key', () => {
+    const board0 = new Map<string, PlacedTile>([
+      ['0,0', { type: TileType.ThreeSharps, rotation: 0, position: { row: 0, col: 0 } }],
+    ]);
+    const board2 = new Map<string, PlacedTile>([
+      ['0,0', { type: TileType.ThreeSharps, rotation: 2, position: { row: 0, col: 0 } }],
+    ]);
+    const board1 = new Map<string, PlacedTile>([
+      ['0,0', { type: TileType.ThreeSharps, rotation: 1, position: { row: 0, col: 0 } }],
+    ]);
+
+    expect(getEvaluationKey(board0)).toBe(getEvaluationKey(board2));
+    expect(getEvaluationKey(board0)).not.toBe(getEvaluationKey(board1));
+  });
+
+  it('should not depend on the order tiles were placed', () => {
+    const board = createBoard();
+    const reversed = new Map([...board.entries()].reverse());
+
+    expect(getEvaluationKey(reversed)).toBe(getEvaluationKey(board));
+  });
+
+  it('should evaluate fewer positions with the same results', () => {
+    const board = createBoard();
+
+    const without = generateMoveCandidates(
+      board, TileType.ThreeSharps, players[0], players, [], false, 3, false
+    );
+    const withoutStats = getLastSearchStats();
+
+    const withMemo = generateMoveCandidates(
+      board, TileType.ThreeSharps, players[0], players, [], false, 3, true
+    );
+    const withStats = getLastSearchStats();
+
+    expect(withMemo).toEqual(without);
+    expect(withoutStats.cacheHits).toBe(0);
+    expect(withoutStats.evaluations).toBe(without.length);
+    expect(withStats.cacheHits).toBeGreaterThan(0);
+    expect(withStats.evaluations + withStats.cacheHits).toBe(withoutStats.evaluations);
+    // ThreeSharps looks the same at every other rotation, so two thirds are cache hits
+    expect(withStats.evaluations * 3).toBe(withoutStats.evaluations);
+  });
+
+  it('should call the evaluator once per distinct position', () => {
+    const board = createBoard();
+    let calls = 0;
+    const countingEvaluator: EvaluationStrategy = (...args) => {
+      calls++;
+      return pathLengthEvaluator(...args);
+    };
+
+    generateMoveCandidates(
+      board, TileType.ThreeSharps, players[0], players, [], false, 3, false, countingEvaluator
+    );
+    const callsWithout = calls;
+
+    calls = 0;
+    generateMoveCandidates(
+      board, TileType.ThreeSharps, players[0], players, [], false, 3, true, countingEvaluator
+    );
+
+    expect(calls).toBe(getLastSearchStats().evaluations);
+    expect(calls * 3).toBe(callsWithout);
+  });
+});
+
+describe('AI Difficulty', () => {
+  const players: Player[] = [
+    { id: 'ai1', color: '#0173B2', edgePosition: 0, isAI: true },
+    { id: 'p1', color: '#DE8F05', edgePosition: 3, isAI: false },
+  ];
+  const board = new Map<string, PlacedTile>([
+    ['-3,0', { type: TileType.NoSharps, rotation: 0, position: { row: -3, col: 0 } }],
+  ]);
+
+  const rankedScores = () =>
+    generateMoveCandidates(board, TileType.OneSharp, players[0], players, [], false, 3)
+      .map(c => c.score)
+      .sort((a, b) => b - a);
+
+  it('should always play the best move at full difficulty', () => {
+    const best = rankedScores()[0];
+
+    for (let seed = 0; seed < 5; seed++) {
+      const move = selectAIMove(board, TileType.OneSharp, players[0], players, [], false, 3, MAX_AI_DIFFICULTY, seed);
+      expect(move!.score).toBe(best);
+    }
+  });
+
+  it('should choose from the better half at the lowest difficulty', () => {
+    const scores = rankedScores();
+    const cutoff = scores[Math.ceil(scores.length * 0.5) - 1];
+
+    const chosen = new Set<string>();
+    for (let seed = 0; seed < 10; seed++) {
+      const move = selectAIMove(board, TileType.OneSharp, players[0], players, [], false, 3, MIN_AI_DIFFICULTY, seed);
+      expect(move!.score).toBeGreaterThanOrEqual(cutoff);
+      chosen.add(`${move!.position.row},${move!.position.col},${move!.rotation}`);
+    }
+
+    // Different seeds lead to different choices
+    expect(chosen.size).toBeGreaterThan(1);
+  });
+
+  it('should make the same choice for the same seed', () => {
+    const first = selectAIMove(board, TileType.OneSharp, players[0], players, [], false, 3, MIN_AI_DIFFICULTY, 17);
+    const second = selectAIMove(board, TileType.OneSharp, players[0], players, [], false, 3, MIN_AI_DIFFICULTY, 17);
+
+    expect(second).toEqual(first);
+  });
+});
+
 describe('AI Move Selection', () => {
   const aiPlayer: Player = {
     id: 'ai1',
@@ -544,3 +687,270 @@ describe('AI Move Selection', () => {
     expect(uniquePositions.size).toBeGreaterThanOrEqual(6);
   });
 });
+
+describe('Opponent Setback', () => {
+  const players: Player[] = [
+    { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+    { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: false },
+  ];
+  const teams: Team[] = [];
+
+  // p2's path from edge 3 up column 0, missing only the tile at the top
+  const createNearlyCompleteBoard = () => {
+    const board = new Map<string, PlacedTile>();
+    for (let row = -2; row <= 3; row++) {
+      board.set(`${row},0`, { type: TileType.TwoSharps, rotation: 2, position: { row, col: 0 } });
+    }
+    return board;
+  };
+
+  const withTile = (board: Map<string, PlacedTile>, tile: PlacedTile) => {
+    const newBoard = new Map(board);
+    newBoard.set(`${tile.position.row},${tile.position.col}`, tile);
+    return newBoard;
+  };
+
+  it('should measure how many tiles the closest opponent needs', () => {
+    expect(getOpponentPathLength(createNearlyCompleteBoard(), players[0], players, teams)).toBe(1);
+  });
+
+  it('should report a negative setback when the move helps the opponent', () => {
+    const before = createNearlyCompleteBoard();
+    const after = withTile(before, { type: TileType.TwoSharps, rotation: 2, position: { row: -3, col: 0 } });
+
+    expect(computeOpponentSetback(before, after, players[0], players, teams)).toBe(-1);
+  });
+
+  it('should report no setback for a move away from the opponent path', () => {
+    const before = createNearlyCompleteBoard();
+    const after = withTile(before, { type: TileType.NoSharps, rotation: 0, position: { row: 0, col: 3 } });
+
+    expect(computeOpponentSetback(before, after, players[0], players, teams)).toBe(0);
+  });
+
+  it('should report an infinite setback when the move blocks the opponent', () => {
+    // Wall across row 0 with a single gap at the center
+    const before = new Map<string, PlacedTile>();
+    for (let col = -3; col <= 3; col++) {
+      if (col === 0) continue;
+      before.set(`0,${col}`, { type: TileType.ThreeSharps, rotation: 0, position: { row: 0, col } });
+    }
+    const after = withTile(before, { type: TileType.ThreeSharps, rotation: 0, position: { row: 0, col: 0 } });
+
+    expect(computeOpponentSetback(before, after, players[0], players, teams)).toBe(Infinity);
+  });
+
+  it('should report no setback when the opponent is already blocked', () => {
+    const before = new Map<string, PlacedTile>();
+    for (let col = -3; col <= 3; col++) {
+      before.set(`0,${col}`, { type: TileType.ThreeSharps, rotation: 0, position: { row: 0, col } });
+    }
+    const after = withTile(before, { type: TileType.NoSharps, rotation: 0, position: { row: 2, col: 0 } });
+
+    expect(computeOpponentSetback(before, after, players[0], players, teams)).toBe(0);
+  });
+
+  it('should ignore teammates when measuring opponents', () => {
+    const teamPlayers: Player[] = [
+      { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+      { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: false },
+      { id: 'p3', color: '#029E73', edgePosition: 1, isAI: false },
+      { id: 'p4', color: '#ECE133', edgePosition: 4, isAI: false },
+    ];
+    const teamList: Team[] = [
+      { player1Id: 'p1', player2Id: 'p2' },
+      { player1Id: 'p3', player2Id: 'p4' },
+    ];
+
+    // Only p2 (a teammate) is close to finishing, so the closest opponent is further away
+    expect(getOpponentPathLength(createNearlyCompleteBoard(), teamPlayers[0], teamPlayers, teamList)).toBeGreaterThan(1);
+  });
+
+  it('should measure opponents to their partner\'s edge in team games', () => {
+    // Four players on adjacent edges: each team's partner sits two edges away, not opposite
+    const adjacentPlayers: Player[] = [
+      { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+      { id: 'p2', color: '#DE8F05', edgePosition: 1, isAI: false },
+      { id: 'p3', color: '#029E73', edgePosition: 2, isAI: false },
+      { id: 'p4', color: '#ECE133', edgePosition: 3, isAI: false },
+    ];
+    const teamList: Team[] = [
+      { player1Id: 'p1', player2Id: 'p3' },
+      { player1Id: 'p2', player2Id: 'p4' },
+    ];
+    const emptyBoard = new Map<string, PlacedTile>();
+
+    const toPartner = getOpponentPathLength(emptyBoard, adjacentPlayers[0], adjacentPlayers, teamList);
+    const toOpposite = getOpponentPathLength(emptyBoard, adjacentPlayers[0], adjacentPlayers, []);
+
+    // Connecting edges 1 and 3 takes fewer tiles than crossing the whole board
+    expect(toPartner).toBeLessThan(toOpposite);
+  });
+
+  describe('with opposite-sides victory', () => {
+    // p2 and p4 sit on adjacent-but-one edges, so p2's partner edge is not the one its column reaches
+    const teamPlayers: Player[] = [
+      { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+      { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: true },
+      { id: 'p3', color: '#029E73', edgePosition: 4, isAI: false },
+      { id: 'p4', color: '#ECE133', edgePosition: 1, isAI: false },
+    ];
+    const teamList: Team[] = [
+      { player1Id: 'p1', player2Id: 'p3' },
+      { player1Id: 'p2', player2Id: 'p4' },
+    ];
+
+    it('should measure opponents to their opposite edge', () => {
+      const board = createNearlyCompleteBoard();
+
+      expect(getOpponentPathLength(board, teamPlayers[0], teamPlayers, teamList)).toBeGreaterThan(1);
+      expect(getOpponentPathLength(board, teamPlayers[0], teamPlayers, teamList, 3, true)).toBe(1);
+    });
+
+    it('should let the AI take a win across to its opposite edge', () => {
+      const board = createNearlyCompleteBoard();
+      const move = selectAIMove(
+        board,
+        TileType.TwoSharps,
+        teamPlayers[1],
+        teamPlayers,
+        teamList,
+        false,
+        3,
+        MAX_AI_DIFFICULTY,
+        0,
+        undefined,
+        pathLengthEvaluator,
+        true,
+      );
+
+      expect(move?.isWinningMove).toBe(true);
+      expect(move?.position).toEqual({ row: -3, col: 0 });
+    });
+  });
+});
+
+describe('AI evaluation strategies', () => {
+  const aiPlayer: Player = { id: 'p0', color: '#0173B2', edgePosition: 0, isAI: true };
+  const opponent: Player = { id: 'p1', color: '#DE8F05', edgePosition: 3, isAI: false };
+  const players: Player[] = [aiPlayer, opponent];
+
+  // How much the control evaluator adds on top of path lengths for a board
+  const controlTerm = (board: Map<string, PlacedTile>) =>
+    controlEvaluator(board, aiPlayer, players, [], 3, false) -
+    pathLengthEvaluator(board, aiPlayer, players, [], 3, false);
+
+  const place = (board: Map<string, PlacedTile>, row: number, col: number) =>
+    board.set(`${row},${col}`, { type: TileType.NoSharps, rotation: 0, position: { row, col } });
+
+  it('should match the path-length evaluator on an empty board', () => {
+    expect(controlTerm(new Map())).toBe(0);
+  });
+
+  it('should reward flow that reaches toward the centre', () => {
+    const board = new Map<string, PlacedTile>();
+    place(board, -3, 1); // On the edge: no centrality bonus
+    expect(controlTerm(board)).toBe(0);
+
+    place(board, -2, 0); // The flow continues one ring inward
+    expect(controlTerm(board)).toBeGreaterThan(0);
+  });
+
+  it('should penalize flow boxed into a corner', () => {
+    const board = new Map<string, PlacedTile>();
+    place(board, -3, 3);
+
+    expect(controlTerm(board)).toBeLessThan(0);
+  });
+});
+
+describe('AI move ordering', () => {
+  const aiPlayer: Player = { id: 'p0', color: '#0173B2', edgePosition: 0, isAI: true };
+  const opponent: Player = { id: 'p1', color: '#DE8F05', edgePosition: 3, isAI: false };
+  const players: Player[] = [aiPlayer, opponent];
+
+  it('should order moves by the five priorities', () => {
+    const board = new Map<string, PlacedTile>();
+    const place = (row: number, col: number) =>
+      board.set(`${row},${col}`, { type: TileType.NoSharps, rotation: 0, position: { row, col } });
+    place(-3, 1); // The AI's flow
+    place(3, -1); // The opponent's flow
+    place(1, 1); // The move before last
+    place(0, 0); // The last move
+
+    const moves = [
+      { position: { row: -1, col: -2 } }, // Nowhere near anything
+      { position: { row: 2, col: -1 } }, // Next to the opponent's flow
+      { position: { row: -1, col: -1 } }, // Nowhere near anything
+      { position: { row: -3, col: 2 } }, // Next to the AI's flow
+      { position: { row: 2, col: 1 } }, // Next to the move before last
+      { position: { row: 0, col: 1 } }, // Next to the last move
+    ];
+
+    const ordered = orderMoves(moves, board, aiPlayer, players, [{ row: 0, col: 0 }, { row: 1, col: 1 }], 3);
+
+    expect(ordered.map(({ position }) => `${position.row},${position.col}`)).toEqual([
+      '0,1',
+      '2,1',
+      '-3,2',
+      '2,-1',
+      '-1,-2',
+      '-1,-1',
+    ]);
+  });
+
+  it('should keep the original order without recent moves or flows', () => {
+    const moves = [{ position: { row: 1, col: 0 } }, { position: { row: 0, col: 0 } }];
+
+    expect(orderMoves(moves, new Map(), aiPlayer, players, [], 3)).toEqual(moves);
+  });
+});
+
+describe('Position analysis', () => {
+  const aiPlayer: Player = { id: 'p0', color: '#0173B2', edgePosition: 0, isAI: true };
+  const opponent: Player = { id: 'p1', color: '#DE8F05', edgePosition: 3, isAI: false };
+  const players: Player[] = [aiPlayer, opponent];
+
+  // A radius 1 board with two empty hexes, (1,-1) and (1,0)
+  // Of the five legal TwoSharps placements, only (1,0) at rotation 2 completes the AI's flow
+  const board = new Map<string, PlacedTile>();
+  const tiles: PlacedTile[] = [
+    { type: TileType.TwoSharps, rotation: 0, position: { row: -1, col: 0 } },
+    { type: TileType.TwoSharps, rotation: 2, position: { row: -1, col: 1 } },
+    { type: TileType.TwoSharps, rotation: 3, position: { row: 0, col: -1 } },
+    { type: TileType.TwoSharps, rotation: 1, position: { row: 0, col: 0 } },
+    { type: TileType.ThreeSharps, rotation: 3, position: { row: 0, col: 1 } },
+  ];
+  for (const tile of tiles) {
+    board.set(`${tile.position.row},${tile.position.col}`, tile);
+  }
+
+  it('should score every legal move, best first', () => {
+    const analysis = analyzePosition(board, TileType.TwoSharps, aiPlayer, players, [], false, 1);
+
+    expect(analysis).toHaveLength(5);
+    expect(analysis[0]).toMatchObject({ position: { row: 1, col: 0 }, rotation: 2 });
+    for (let i = 1; i < analysis.length; i++) {
+      expect(analysis[i].score).toBeLessThan(analysis[0].score);
+      expect(analysis[i].score).toBeLessThanOrEqual(analysis[i - 1].score);
+    }
+  });
+
+  it('should return the same list on every call', () => {
+    const first = analyzePosition(board, TileType.TwoSharps, aiPlayer, players, [], false, 1);
+    const second = analyzePosition(board, TileType.TwoSharps, aiPlayer, players, [], false, 1);
+
+    expect(second).toEqual(first);
+  });
+
+  it('should score moves with the given evaluator', () => {
+    const analysis = analyzePosition(board, TileType.TwoSharps, aiPlayer, players, [], false, 1, () => 7);
+
+    expect(analysis.every(({ score }) => score === 7)).toBe(true);
+    // Equal scores fall back to board order
+    expect(analysis.map(({ position }) => position.row * 10 + position.col)).toEqual(
+      [...analysis.map(({ position }) => position.row * 10 + position.col)].sort((a, b) => a - b),
+    );
+  });
+});
+
@@ -8,7 +8,9 @@ import {
   isValidPosition,
   getNeighborInDirection,
   getNeighbors,
+  getNeighborsWithDirections,
   getDirection,
+  directionFromOffset,
   getOppositeDirection,
   getEdgePositions,
   getOppositeEdge,
@@ -152,6 +154,29 @@ describe('board utilities', () => {
     });
   });
 
+  describe('getNeighborsWithDirections', () => {
+    it('should yield six neighbors for the center hex', () => {
+      const neighbors = getNeighborsWithDirections({ row: 0, col: 0 });
+      expect(neighbors.length).toBe(6);
+      expect(neighbors.map((n) => n.direction)).toEqual([0, 1, 2, 3, 4, 5]);
+    });
+
+    it('should yield fewer neighbors for an edge hex', () => {
+      const neighbors = getNeighborsWithDirections({ row: -3, col: 1 });
+      expect(neighbors.length).toBe(4);
+      neighbors.forEach(({ position }) => {
+        expect(isValidPosition(position)).toBe(true);
+      });
+    });
+
+    it('should pair each neighbor with the direction that reaches it', () => {
+      const origin = { row: 0, col: 0 };
+      for (const { direction, position } of getNeighborsWithDirections(origin)) {
+        expect(getNeighborInDirection(origin, direction)).toEqual(position);
+      }
+    });
+  });
+
   describe('getNeighbors', () => {
     it('should return 6 neighbors for center position', () => {
       const neighbors = getNeighbors({ row: 0, col: 0 });
@@ -219,6 +244,23 @@ describe('board utilities', () => {
     });
   });
 
+  describe('directionFromOffset', () => {
+    it('should invert getNeighborInDirection for all six directions', () => {
+      for (let dir = 0; dir < 6; dir++) {
+        const offset = getNeighborInDirection({ row: 0, col: 0 }, dir as Direction);
+        expect(directionFromOffset(offset)).toBe(dir);
+      }
+    });
+
+    it('should return null for vectors that are not neighbor offsets', () => {
+      expect(directionFromOffset({ row: 0, col: 0 })).toBe(null);
+      expect(directionFromOffset({ row: 1, col: 1 })).toBe(null);
+      expect(directionFromOffset({ row: -1, col: -1 })).toBe(null);
+      expect(directionFromOffset({ row: 2, col: -1 })).toBe(null);
+      expect(directionFromOffset({ row: 0, col: 2 })).toBe(null);
+    });
+  });
+
   describe('getOppositeDirection', () => {
     it('should return opposite directions correctly', () => {
       expect(getOppositeDirection(Direction.East)).toBe(Direction.West);
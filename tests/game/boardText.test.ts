@@ -0,0 +1,134 @@
+// Unit tests for plain-text board rendering
+
+import { describe, it, expect } from 'vitest';
+import { boardToText } from '../../src/game/boardText';
+import { calculateFlows } from '../../src/game/flows';
+import { positionToKey } from '../../src/game/board';
+import { TileType, PlacedTile, Player } from '../../src/game/types';
+
+describe('boardToText', () => {
+  const players: Player[] = [
+    { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+    { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: false },
+  ];
+
+  const boardLines = (text: string, radius: number) => text.split('\n').slice(0, 2 * radius + 1);
+
+  it('should render every row at the same width', () => {
+    const board = new Map<string, PlacedTile>();
+    const tile: PlacedTile = { type: TileType.TwoSharps, rotation: 0, position: { row: 0, col: 0 } };
+    board.set(positionToKey(tile.position), tile);
+    const { flowEdges } = calculateFlows(board, players, 3);
+
+    const lines = boardLines(boardToText(board, players, flowEdges, 3), 3);
+
+    expect(lines).toHaveLength(7);
+    expect(new Set(lines.map((line) => line.length)).size).toBe(1);
+    expect(lines[0].length).toBe(42);
+  });
+
+  it('should keep the same width as tiles are placed', () => {
+    const empty = boardLines(boardToText(new Map(), players, new Map(), 3), 3);
+
+    const board = new Map<string, PlacedTile>();
+    for (let col = 0; col <= 3; col++) {
+      const tile: PlacedTile = { type: TileType.NoSharps, rotation: 0, position: { row: -3, col } };
+      board.set(positionToKey(tile.position), tile);
+    }
+    const { flowEdges } = calculateFlows(board, players, 3);
+    const filled = boardLines(boardToText(board, players, flowEdges, 3), 3);
+
+    expect(filled.map((line) => line.length)).toEqual(empty.map((line) => line.length));
+  });
+
+  it('should contain a glyph for each placed tile', () => {
+    const board = new Map<string, PlacedTile>();
+    const tiles: PlacedTile[] = [
+      { type: TileType.NoSharps, rotation: 0, position: { row: -3, col: 0 } },
+      { type: TileType.OneSharp, rotation: 0, position: { row: 0, col: 0 } },
+      { type: TileType.TwoSharps, rotation: 0, position: { row: 1, col: -1 } },
+      { type: TileType.ThreeSharps, rotation: 0, position: { row: 3, col: -3 } },
+    ];
+    tiles.forEach((tile) => board.set(positionToKey(tile.position), tile));
+    const { flowEdges } = calculateFlows(board, players, 3);
+
+    const text = boardLines(boardToText(board, players, flowEdges, 3), 3).join('\n');
+
+    const tileGlyphs = text.match(/[○◔◑●]/g) ?? [];
+    expect(tileGlyphs).toHaveLength(tiles.length);
+    expect((text.match(/·/g) ?? []).length).toBe(37 - tiles.length);
+  });
+
+  it('should mark tiles carrying a flow with the player number', () => {
+    const board = new Map<string, PlacedTile>();
+    const tile: PlacedTile = { type: TileType.NoSharps, rotation: 0, position: { row: -3, col: 0 } };
+    board.set(positionToKey(tile.position), tile);
+    const { flowEdges } = calculateFlows(board, players, 3);
+
+    const text = boardToText(board, players, flowEdges, 3);
+
+    expect(text.split('\n')[0]).toContain('○01');
+    expect(text).toContain('1 p1 #0173B2 (edge 0)');
+    expect(text).toContain('2 p2 #DE8F05 (edge 3)');
+  });
+
+  it('should mark tiles without flow', () => {
+    const board = new Map<string, PlacedTile>();
+    const tile: PlacedTile = { type: TileType.OneSharp, rotation: 0, position: { row: 0, col: 0 } };
+    board.set(positionToKey(tile.position), tile);
+
+    const text = boardToText(board, players, new Map(), 3);
+
+    expect(text).toContain('◔0-');
+  });
+
+  it('should mark tiles shared by several players', () => {
+    const board = new Map<string, PlacedTile>();
+    const tile: PlacedTile = { type: TileType.TwoSharps, rotation: 0, position: { row: 0, col: 0 } };
+    board.set(positionToKey(tile.position), tile);
+    const flowEdges = new Map([['0,0', new Map([[1, 'p1'], [4, 'p2']])]]) as any;
+
+    expect(boardToText(board, players, flowEdges, 3)).toContain('◑0*');
+  });
+
+  it('should treat flows from unknown players as shared', () => {
+    const board = new Map<string, PlacedTile>();
+    const tile: PlacedTile = { type: TileType.TwoSharps, rotation: 0, position: { row: 0, col: 0 } };
+    board.set(positionToKey(tile.position), tile);
+    const flowEdges = new Map([['0,0', new Map([[1, 'ghost']])]]) as any;
+
+    expect(boardToText(board, players, flowEdges, 3)).toContain('◑0*');
+  });
+
+  it('should show each tile\'s rotation', () => {
+    const board = new Map<string, PlacedTile>();
+    const tile: PlacedTile = { type: TileType.ThreeSharps, rotation: 4, position: { row: 0, col: 0 } };
+    board.set(positionToKey(tile.position), tile);
+
+    expect(boardToText(board, players, new Map(), 3)).toContain('●4-');
+  });
+
+  it('should render a small known board exactly', () => {
+    const board = new Map<string, PlacedTile>();
+    const tile: PlacedTile = { type: TileType.OneSharp, rotation: 2, position: { row: 0, col: 0 } };
+    board.set(positionToKey(tile.position), tile);
+    const flowEdges = new Map([['0,0', new Map([[0, 'p2']])]]) as any;
+
+    expect(boardToText(board, players, flowEdges, 1)).toBe(
+      [
+        '     ·     ·      ',
+        '  ·     ◔22   ·   ',
+        '     ·     ·      ',
+        '',
+        'Cells: tile (○ 0, ◔ 1, ◑ 2, ● 3 sharps), rotation 0-5, flow (player number, - none, * shared)',
+        '1 p1 #0173B2 (edge 0)',
+        '2 p2 #DE8F05 (edge 3)',
+      ].join('\n'),
+    );
+  });
+
+  it('should default to a radius 3 board', () => {
+    const lines = boardLines(boardToText(new Map(), [], new Map()), 3);
+    expect(lines[3].length).toBe(42);
+  });
+});
@@ -0,0 +1,63 @@
+// Tests that the reducer only recomputes flows when the board actually changes
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import * as flows from '../../src/game/flows';
+import { gameReducer, initialState } from '../../src/redux/gameReducer';
+import { setupGame, shuffleTiles, drawTile, placeTile, replaceTile, nextPlayer } from '../../src/redux/actions';
+import { TileType } from '../../src/game/types';
+import { GameState } from '../../src/redux/types';
+
+vi.mock('../../src/game/flows', async (importOriginal) => {
+  const actual = await importOriginal<typeof import('../../src/game/flows')>();
+  return { ...actual, calculateFlows: vi.fn(actual.calculateFlows) };
+});
+
+describe('Flow recomputation', () => {
+  const players = [
+    { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+    { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: false },
+  ];
+  const calculateFlows = vi.mocked(flows.calculateFlows);
+
+  let state: GameState;
+
+  beforeEach(() => {
+    state = gameReducer(initialState, setupGame(players, []));
+    state = gameReducer(state, shuffleTiles(444));
+    calculateFlows.mockClear();
+  });
+
+  it('should not recompute flows for draws or turn changes', () => {
+    state = gameReducer(state, drawTile());
+    state = gameReducer(state, nextPlayer());
+
+    expect(calculateFlows).not.toHaveBeenCalled();
+  });
+
+  it('should recompute flows once per placement', () => {
+    state = gameReducer(state, drawTile());
+    state = gameReducer(state, placeTile({ row: 0, col: 0 }, 0));
+
+    expect(calculateFlows).toHaveBeenCalledTimes(1);
+  });
+
+  it('should not recompute flows for a rejected placement', () => {
+    state = gameReducer(state, placeTile({ row: 0, col: 0 }, 0));
+
+    expect(calculateFlows).not.toHaveBeenCalled();
+  });
+
+  it('should skip recomputing flows when a replacement leaves the board unchanged', () => {
+    const withTile: GameState = {
+      ...state,
+      board: new Map([['0,0', { type: TileType.TwoSharps, rotation: 1, position: { row: 0, col: 0 } }]]),
+      currentTile: TileType.TwoSharps,
+    };
+
+    const same = gameReducer(withTile, replaceTile({ row: 0, col: 0 }, 1));
+    expect(calculateFlows).not.toHaveBeenCalled();
+    expect(same.flows).toBe(withTile.flows);
+
+    gameReducer(withTile, replaceTile({ row: 0, col: 0 }, 2));
+    expect(calculateFlows).toHaveBeenCalledTimes(1);
+  });
+});
@@ -6,6 +6,9 @@ import {
   calculateFlows,
   areConnected,
   areSetsConnected,
+  getTileSegmentOwners,
+  getTileFlowPlayers,
+  isEdgeConnectedToStart,
 } from '../../src/game/flows';
 import { TileType, Direction, PlacedTile, Player } from '../../src/game/types';
 import { positionToKey } from '../../src/game/board';
@@ -489,4 +492,126 @@ describe('flow propagation', () => {
       expect(result).toBe(true);
     });
   });
+
+  describe('getTileSegmentOwners', () => {
+    it('should hold different players on different segments of one tile', () => {
+      // The corner hex (-3,3) sits on both edge 0 and edge 1
+      // TwoSharps rotated once has segments SW-W, NW-SE and NE-E:
+      // p1 enters from SW, p2 enters from SE and E
+      const players: Player[] = [
+        { id: 'p1', color: 'blue', edgePosition: 0, isAI: false },
+        { id: 'p2', color: 'red', edgePosition: 1, isAI: false },
+      ];
+      const tile: PlacedTile = {
+        type: TileType.TwoSharps,
+        rotation: 1,
+        position: { row: -3, col: 3 },
+      };
+      const board = new Map<string, PlacedTile>();
+      board.set(positionToKey(tile.position), tile);
+
+      const { flowEdges } = calculateFlows(board, players, 3);
+      const segments = getTileSegmentOwners(tile, flowEdges);
+
+      expect(segments).toEqual([
+        { connection: [Direction.West, Direction.SouthWest], owners: ['p1'] },
+        { connection: [Direction.NorthWest, Direction.SouthEast], owners: ['p2'] },
+        { connection: [Direction.NorthEast, Direction.East], owners: ['p2'] },
+      ]);
+    });
+
+    it('should report both players on a segment entered from opposite ends', () => {
+      const tile: PlacedTile = {
+        type: TileType.TwoSharps,
+        rotation: 0,
+        position: { row: 0, col: 0 },
+      };
+      const flowEdges = new Map([
+        [positionToKey(tile.position), new Map([[Direction.West, 'p1'], [Direction.East, 'p2']])],
+      ]);
+
+      const segments = getTileSegmentOwners(tile, flowEdges);
+
+      expect(segments[0].owners).toEqual([]);
+      expect(segments[1]).toEqual({ connection: [Direction.West, Direction.East], owners: ['p1', 'p2'] });
+      expect(segments[2].owners).toEqual([]);
+    });
+
+    it('should report no owners for a tile without flow', () => {
+      const tile: PlacedTile = {
+        type: TileType.NoSharps,
+        rotation: 0,
+        position: { row: 0, col: 0 },
+      };
+
+      const segments = getTileSegmentOwners(tile, new Map());
+
+      expect(segments).toHaveLength(3);
+      expect(segments.every(({ owners }) => owners.length === 0)).toBe(true);
+    });
+  });
+
+  describe('getTileFlowPlayers', () => {
+    it('should list each player flowing through a tile once', () => {
+      // Same corner tile as above: p1 fills one segment, p2 fills two
+      const players: Player[] = [
+        { id: 'p1', color: 'blue', edgePosition: 0, isAI: false },
+        { id: 'p2', color: 'red', edgePosition: 1, isAI: false },
+      ];
+      const tile: PlacedTile = {
+        type: TileType.TwoSharps,
+        rotation: 1,
+        position: { row: -3, col: 3 },
+      };
+      const board = new Map<string, PlacedTile>();
+      board.set(positionToKey(tile.position), tile);
+
+      const { flowEdges } = calculateFlows(board, players, 3);
+
+      expect(getTileFlowPlayers(tile, flowEdges)).toEqual(['p1', 'p2']);
+    });
+
+    it('should list no players for a tile without flow', () => {
+      const tile: PlacedTile = {
+        type: TileType.NoSharps,
+        rotation: 0,
+        position: { row: 0, col: 0 },
+      };
+
+      expect(getTileFlowPlayers(tile, new Map())).toEqual([]);
+    });
+  });
+
+  describe('isEdgeConnectedToStart', () => {
+    const p1: Player = { id: 'p1', color: 'blue', edgePosition: 0, isAI: false };
+    const p2: Player = { id: 'p2', color: 'red', edgePosition: 1, isAI: false };
+    const tile: PlacedTile = {
+      type: TileType.TwoSharps,
+      rotation: 1,
+      position: { row: -3, col: 3 },
+    };
+    const board = new Map<string, PlacedTile>();
+    board.set(positionToKey(tile.position), tile);
+
+    it('should report both ends of the segment a player\'s flow fills', () => {
+      expect(isEdgeConnectedToStart(board, tile.position, Direction.SouthWest, p1, 3)).toBe(true);
+      expect(isEdgeConnectedToStart(board, tile.position, Direction.West, p1, 3)).toBe(true);
+      expect(isEdgeConnectedToStart(board, tile.position, Direction.NorthWest, p2, 3)).toBe(true);
+      expect(isEdgeConnectedToStart(board, tile.position, Direction.SouthEast, p2, 3)).toBe(true);
+    });
+
+    it('should not report edges carrying only another player\'s flow', () => {
+      expect(isEdgeConnectedToStart(board, tile.position, Direction.NorthWest, p1, 3)).toBe(false);
+      expect(isEdgeConnectedToStart(board, tile.position, Direction.West, p2, 3)).toBe(false);
+    });
+
+    it('should not report edges of a tile the flow never reaches', () => {
+      const position = { row: 0, col: 0 };
+      const lonelyBoard = new Map(board);
+      lonelyBoard.set(positionToKey(position), { type: TileType.NoSharps, rotation: 0, position });
+
+      expect(isEdgeConnectedToStart(lonelyBoard, position, Direction.West, p1, 3)).toBe(false);
+      expect(isEdgeConnectedToStart(board, position, Direction.West, p1, 3)).toBe(false);
+    });
+  });
 });
@@ -4,12 +4,31 @@ import { describe, it, expect } from 'vitest';
 import {
   isLegalMove,
   findLegalMoves,
+  findAllLegalMoves,
   canTileBePlacedAnywhere,
   getDebugPathInfo,
   getBlockedPlayers,
+  explainIllegal,
+  explainLegality,
+  findLegalRotations,
+  findFirstLegalRotation,
+  computeLegalityMap,
+  legalityKey,
+  validateMoveHistory,
+  getCriticalPathSegments,
+  isSegmentOnCriticalPath,
+  segmentKey,
+  hasContinuation,
+  getSideRoutes,
+  isPlayerBlocked,
+  hasViablePath,
 } from '../../src/game/legality';
-import { TileType, PlacedTile, Player, Team } from '../../src/game/types';
-import { positionToKey, getAllBoardPositions } from '../../src/game/board';
+import { getOpponentPathLength } from '../../src/game/ai';
+import { checkFlowVictory } from '../../src/game/victory';
+import { getUniqueRotations } from '../../src/game/tiles';
+import { TileType, PlacedTile, Player, Team, Rotation } from '../../src/game/types';
+import { positionToKey, getAllBoardPositions, getOppositeEdge } from '../../src/game/board';
+import { Move } from '../../src/redux/types';
 
 describe('legal move validation', () => {
   const createPlayer = (id: string, edge: number): Player => ({
@@ -154,6 +173,132 @@ describe('legal move validation', () => {
     });
   });
 
+  describe('findFirstLegalRotation', () => {
+    // Barrier across row 0 with a single gap at the center
+    const createGapBoard = (withGap: boolean) => {
+      const board = new Map<string, PlacedTile>();
+      for (let col = -3; col <= 3; col++) {
+        if (withGap && col === 0) continue;
+        const position = { row: 0, col };
+        board.set(positionToKey(position), {
+          type: TileType.ThreeSharps,
+          rotation: 0,
+          position,
+        });
+      }
+      return board;
+    };
+
+    it('should snap to the first legal rotation when the current one is illegal', () => {
+      const board = createGapBoard(true);
+      const players = [createPlayer('p1', 0), createPlayer('p2', 3)];
+      const teams: Team[] = [];
+      const gap = { row: 0, col: 0 };
+
+      // Rotation 0 of ThreeSharps closes the gap, so it is illegal
+      expect(isLegalMove(board, { type: TileType.ThreeSharps, rotation: 0, position: gap }, players, teams, 3, false)).toBe(false);
+
+      const legalRotations = findLegalRotations(board, TileType.ThreeSharps, gap, players, teams, 3, false);
+      expect(legalRotations.length).toBeGreaterThan(0);
+      expect(legalRotations).not.toContain(0);
+
+      const rotation = findFirstLegalRotation(board, TileType.ThreeSharps, gap, 0, players, teams, 3, false);
+      expect(rotation).toBe(legalRotations[0]);
+      expect(isLegalMove(board, { type: TileType.ThreeSharps, rotation, position: gap }, players, teams, 3, false)).toBe(true);
+    });
+
+    it('should keep the current rotation when it is already legal', () => {
+      const board = new Map<string, PlacedTile>();
+      const players = [createPlayer('p1', 0), createPlayer('p2', 3)];
+      const teams: Team[] = [];
+
+      expect(findFirstLegalRotation(board, TileType.OneSharp, { row: 0, col: 0 }, 4, players, teams, 3, false)).toBe(4);
+    });
+
+    it('should keep the current rotation when no rotation is legal', () => {
+      const board = createGapBoard(false);
+      const players = [createPlayer('p1', 0), createPlayer('p2', 3)];
+      const teams: Team[] = [];
+      const position = { row: -1, col: 0 };
+
+      expect(findLegalRotations(board, TileType.NoSharps, position, players, teams, 3, false)).toEqual([]);
+      expect(findFirstLegalRotation(board, TileType.NoSharps, position, 2, players, teams, 3, false)).toBe(2);
+    });
+  });
+
+  describe('computeLegalityMap', () => {
+    const createSampleBoard = () => {
+      const board = new Map<string, PlacedTile>();
+      const tiles: PlacedTile[] = [
+        { type: TileType.ThreeSharps, rotation: 0, position: { row: 0, col: -3 } },
+        { type: TileType.ThreeSharps, rotation: 0, position: { row: 0, col: -2 } },
+        { type: TileType.ThreeSharps, rotation: 0, position: { row: 0, col: -1 } },
+        { type: TileType.ThreeSharps, rotation: 0, position: { row: 0, col: 1 } },
+        { type: TileType.ThreeSharps, rotation: 0, position: { row: 0, col: 2 } },
+        { type: TileType.ThreeSharps, rotation: 0, position: { row: 0, col: 3 } },
+        { type: TileType.OneSharp, rotation: 2, position: { row: -3, col: 1 } },
+        { type: TileType.TwoSharps, rotation: 4, position: { row: 2, col: -1 } },
+      ];
+      tiles.forEach((tile) => board.set(positionToKey(tile.position), tile));
+      return board;
+    };
+
+    const expectAgreement = (
+      board: Map<string, PlacedTile>,
+      tileType: TileType,
+      players: Player[],
+      teams: Team[],
+      supermove: boolean,
+    ) => {
+      const legality = computeLegalityMap(board, tileType, players, teams, 3, supermove);
+
+      for (const position of getAllBoardPositions(3)) {
+        for (let rotation = 0; rotation < 6; rotation++) {
+          const rot = rotation as Rotation;
+          const key = legalityKey(position, rot);
+          if (board.has(positionToKey(position))) {
+            expect(legality.has(key)).toBe(false);
+            continue;
+          }
+          const tile: PlacedTile = { type: tileType, rotation: rot, position };
+          expect(legality.get(key)).toBe(isLegalMove(board, tile, players, teams, 3, supermove));
+        }
+      }
+    };
+
+    it('should agree with isLegalMove for every candidate in a 2-player game', () => {
+      const board = createSampleBoard();
+      const players = [createPlayer('p1', 0), createPlayer('p2', 3)];
+
+      for (const tileType of [TileType.NoSharps, TileType.OneSharp, TileType.TwoSharps, TileType.ThreeSharps]) {
+        expectAgreement(board, tileType, players, [], false);
+      }
+    });
+
+    it('should agree with isLegalMove in a team game', () => {
+      const board = createSampleBoard();
+      const players = [
+        createPlayer('p1', 0),
+        createPlayer('p2', 1),
+        createPlayer('p3', 3),
+        createPlayer('p4', 4),
+      ];
+      const teams: Team[] = [
+        { player1Id: 'p1', player2Id: 'p3' },
+        { player1Id: 'p2', player2Id: 'p4' },
+      ];
+
+      expectAgreement(board, TileType.ThreeSharps, players, teams, false);
+    });
+
+    it('should mark every empty position legal with supermove enabled', () => {
+      const board = createSampleBoard();
+      const players = [createPlayer('p1', 0), createPlayer('p2', 3)];
+
+      expectAgreement(board, TileType.ThreeSharps, players, [], true);
+    });
+  });
+
   describe('canTileBePlacedAnywhere', () => {
     it('should return true on empty board', () => {
       const board = new Map<string, PlacedTile>();
@@ -614,6 +759,134 @@ describe('legal move validation', () => {
     });
   });
 
+  describe('getSideRoutes', () => {
+    it('should give each player in an individual game one route to the opposite edge', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 2)];
+
+      expect(getSideRoutes(players, [])).toEqual([
+        [{ player: players[0], targetEdge: 3 }],
+        [{ player: players[1], targetEdge: 5 }],
+      ]);
+    });
+
+    it('should give each team a route from each partner to the other', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 1), createPlayer('p3', 3), createPlayer('p4', 4)];
+      const teams: Team[] = [
+        { player1Id: 'p1', player2Id: 'p3' },
+        { player1Id: 'p2', player2Id: 'p4' },
+        { player1Id: 'p5', player2Id: 'p6' }, // Incomplete teams are skipped
+      ];
+
+      expect(getSideRoutes(players, teams)).toEqual([
+        [{ player: players[0], targetEdge: 3 }, { player: players[2], targetEdge: 0 }],
+        [{ player: players[1], targetEdge: 4 }, { player: players[3], targetEdge: 1 }],
+      ]);
+    });
+
+    it('should route each partner to their own opposite edge with opposite-sides victory', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 1), createPlayer('p3', 3), createPlayer('p4', 4)];
+      const teams: Team[] = [
+        { player1Id: 'p1', player2Id: 'p2' },
+        { player1Id: 'p3', player2Id: 'p4' },
+      ];
+
+      expect(getSideRoutes(players, teams, true)).toEqual([
+        [{ player: players[0], targetEdge: 3 }, { player: players[1], targetEdge: 4 }],
+        [{ player: players[2], targetEdge: 0 }, { player: players[3], targetEdge: 1 }],
+      ]);
+    });
+
+    it('should judge team placements by the opposite-sides goal when it is enabled', () => {
+      // Partners sit on adjacent edges, so their goals differ between the two win conditions
+      const players = [createPlayer('p1', 0), createPlayer('p2', 1), createPlayer('p3', 3), createPlayer('p4', 4)];
+      const teams: Team[] = [
+        { player1Id: 'p1', player2Id: 'p2' },
+        { player1Id: 'p3', player2Id: 'p4' },
+      ];
+      let seed = 4242;
+      const random = (n: number) => {
+        seed = (seed * 16807) % 2147483647;
+        return seed % n;
+      };
+      const positions = getAllBoardPositions(3);
+
+      for (let game = 0; game < 5; game++) {
+        const board = new Map<string, PlacedTile>();
+        for (const position of positions) {
+          if (random(2) > 0) {
+            board.set(positionToKey(position), {
+              type: random(4) as TileType,
+              rotation: random(6) as Rotation,
+              position,
+            });
+          }
+        }
+
+        for (const tileType of [TileType.NoSharps, TileType.ThreeSharps]) {
+          const legality = computeLegalityMap(board, tileType, players, teams, 3, false, true);
+
+          for (const position of positions.filter((pos) => !board.has(positionToKey(pos)))) {
+            for (const rotation of getUniqueRotations(tileType)) {
+              const tile: PlacedTile = { type: tileType, rotation, position };
+              const testBoard = new Map(board);
+              testBoard.set(positionToKey(position), tile);
+
+              // Legal if it wins under the variant, or every team keeps a partner able to reach their opposite edge
+              const expected =
+                checkFlowVictory(testBoard, players, teams, 3, true).winners.length > 0 ||
+                teams.every((team) =>
+                  [team.player1Id, team.player2Id].some((id) => {
+                    const player = players.find((p) => p.id === id)!;
+                    return hasViablePath(testBoard, player, getOppositeEdge(player.edgePosition), false, true, 3);
+                  })
+                );
+
+              expect(isLegalMove(board, tile, players, teams, 3, false, undefined, true)).toBe(expected);
+              expect(legality.get(legalityKey(position, rotation))).toBe(expected);
+              expect(getBlockedPlayers(board, tile, players, teams, 3, true).length === 0).toBe(expected);
+            }
+          }
+        }
+      }
+    });
+
+    it('should let blocking checks and the AI agree on who is blocked', () => {
+      // Random boards, seeded so failures are reproducible
+      let seed = 12345;
+      const random = (n: number) => {
+        seed = (seed * 16807) % 2147483647;
+        return seed % n;
+      };
+      const positions = getAllBoardPositions(3);
+
+      const individual = { players: [createPlayer('p1', 0), createPlayer('p2', 3)], teams: [] as Team[] };
+      const team = {
+        players: [createPlayer('p1', 0), createPlayer('p2', 1), createPlayer('p3', 3), createPlayer('p4', 4)],
+        teams: [{ player1Id: 'p1', player2Id: 'p3' }, { player1Id: 'p2', player2Id: 'p4' }],
+      };
+
+      for (let game = 0; game < 20; game++) {
+        const board = new Map<string, PlacedTile>();
+        for (const position of positions) {
+          if (random(3) > 0) {
+            board.set(positionToKey(position), {
+              type: random(4) as TileType,
+              rotation: random(6) as Rotation,
+              position,
+            });
+          }
+        }
+
+        for (const { players, teams } of [individual, team]) {
+          const [player, opponent] = players;
+          expect(isPlayerBlocked(board, opponent, players, teams, 3)).toBe(
+            getOpponentPathLength(board, player, players, teams, 3) === Infinity,
+          );
+        }
+      }
+    });
+  });
+
   describe('getDebugPathInfo', () => {
     it('should return debug info for individual players', () => {
       const board = new Map<string, PlacedTile>();
@@ -653,6 +926,31 @@ describe('legal move validation', () => {
     });
   });
 
+  describe('findAllLegalMoves', () => {
+    it('should list each legal position once per distinct rotation', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 3)];
+      const board = new Map<string, PlacedTile>();
+
+      const moves = findAllLegalMoves(board, TileType.ThreeSharps, players, [], 3, false);
+
+      expect(new Set(moves.map((m) => m.rotation))).toEqual(new Set([0, 1]));
+      for (const rotation of [0, 1] as const) {
+        const positions = findLegalMoves(board, TileType.ThreeSharps, rotation, players, [], 3, false);
+        expect(moves.filter((m) => m.rotation === rotation).map((m) => m.position)).toEqual(positions);
+      }
+    });
+
+    it('should return nothing when the board is full', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 3)];
+      const board = new Map<string, PlacedTile>();
+      for (const position of getAllBoardPositions(1)) {
+        board.set(positionToKey(position), { type: TileType.NoSharps, rotation: 0, position });
+      }
+
+      expect(findAllLegalMoves(board, TileType.OneSharp, players, [], 1, false)).toEqual([]);
+    });
+  });
+
   describe('getBlockedPlayers', () => {
     it('should return empty array for occupied position', () => {
       const board = new Map<string, PlacedTile>();
@@ -744,4 +1042,352 @@ describe('legal move validation', () => {
       expect(blocked.length).toBeGreaterThan(0);
     });
   });
+
+  describe('explainLegality', () => {
+    // Wall of ThreeSharps across row -2 seals edge 0 off from the rest of the board
+    // once the last gap at (-2, 3) is filled
+    const createSealingWall = () => {
+      const board = new Map<string, PlacedTile>();
+      for (const col of [-1, 0, 1, 2]) {
+        const position = { row: -2, col };
+        board.set(positionToKey(position), { type: TileType.ThreeSharps, rotation: 0, position });
+      }
+      return board;
+    };
+    const sealingTile: PlacedTile = {
+      type: TileType.ThreeSharps,
+      rotation: 0,
+      position: { row: -2, col: 3 },
+    };
+
+    it('should assign one path per player for a legal move', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 3)];
+      const tile: PlacedTile = { type: TileType.NoSharps, rotation: 0, position: { row: 0, col: 0 } };
+
+      const explanation = explainLegality(new Map(), tile, players, [], 3, false);
+
+      expect(explanation.legal).toBe(true);
+      expect(explanation.reason).toBe('paths-open');
+      expect(explanation.blockedPlayerIds).toEqual([]);
+      expect(explanation.paths.map((p) => p.playerId)).toEqual(['p1', 'p2']);
+      for (const { path } of explanation.paths) {
+        expect(path.length).toBeGreaterThan(0);
+      }
+    });
+
+    it('should name the blocked player and keep the others\' paths', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 2), createPlayer('p3', 4)];
+      const board = createSealingWall();
+
+      const explanation = explainLegality(board, sealingTile, players, [], 3, false);
+
+      expect(explanation.legal).toBe(false);
+      expect(explanation.reason).toBe('blocked');
+      expect(explanation.blockedPlayerIds).toEqual(['p1']);
+      expect(explanation.paths.map((p) => p.playerId)).toEqual(['p2', 'p3']);
+      expect(isLegalMove(board, sealingTile, players, [], 3, false)).toBe(false);
+    });
+
+    it('should name every player a single move blocks', () => {
+      // Sealing edge 0 strands p1 and also p2, whose goal is edge 0
+      const players = [createPlayer('p1', 0), createPlayer('p2', 3), createPlayer('p3', 4)];
+      const board = createSealingWall();
+
+      const explanation = explainLegality(board, sealingTile, players, [], 3, false);
+
+      expect(explanation.legal).toBe(false);
+      expect(explanation.blockedPlayerIds).toEqual(['p1', 'p2']);
+      expect(explanation.paths.map((p) => p.playerId)).toEqual(['p3']);
+      expect(getBlockedPlayers(board, sealingTile, players, [], 3)).toEqual(['p1', 'p2']);
+    });
+
+    it('should describe the region a blocked player is sealed into', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 2), createPlayer('p3', 4)];
+      const board = createSealingWall();
+
+      const explanation = explainIllegal(board, sealingTile, players, [], 3, false);
+
+      expect(explanation).not.toBeNull();
+      expect(explanation!.map((e) => e.playerId)).toEqual(['p1']);
+      const regionKeys = explanation![0].region.map(positionToKey);
+      expect(regionKeys).toContain('-3,0');
+      expect(regionKeys).not.toContain('0,0');
+      expect(new Set(regionKeys).size).toBe(regionKeys.length);
+    });
+
+    it('should not explain legal or occupied placements', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 2), createPlayer('p3', 4)];
+      const board = createSealingWall();
+      const wallTile = board.get('-2,0')!;
+
+      expect(explainIllegal(board, sealingTile, players, [], 3, true)).toBeNull();
+      expect(explainIllegal(board, wallTile, players, [], 3, false)).toBeNull();
+    });
+
+    it('should block both members of a team that cannot connect', () => {
+      const players = [
+        createPlayer('p1', 0),
+        createPlayer('p2', 1),
+        createPlayer('p3', 3),
+        createPlayer('p4', 4),
+      ];
+      const teams: Team[] = [
+        { player1Id: 'p1', player2Id: 'p3' },
+        { player1Id: 'p2', player2Id: 'p4' },
+      ];
+
+      const explanation = explainLegality(createSealingWall(), sealingTile, players, teams, 3, false);
+
+      expect(explanation.legal).toBe(false);
+      expect(explanation.blockedPlayerIds).toEqual(['p1', 'p3']);
+      expect(explanation.paths.map((p) => p.playerId)).toEqual(['p2', 'p4']);
+    });
+
+    it('should report blocked players but stay legal with supermove enabled', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 2), createPlayer('p3', 4)];
+
+      const explanation = explainLegality(createSealingWall(), sealingTile, players, [], 3, true);
+
+      expect(explanation.legal).toBe(true);
+      expect(explanation.reason).toBe('supermove');
+      expect(explanation.blockedPlayerIds).toEqual(['p1']);
+    });
+
+    it('should explain occupied positions and winning moves', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 3)];
+      const board = new Map<string, PlacedTile>();
+      for (let row = -3; row <= 2; row++) {
+        const position = { row, col: 0 };
+        board.set(positionToKey(position), { type: TileType.TwoSharps, rotation: 5, position });
+      }
+
+      const occupied = explainLegality(
+        board,
+        { type: TileType.NoSharps, rotation: 0, position: { row: 0, col: 0 } },
+        players,
+        [],
+        3,
+        false
+      );
+      expect(occupied).toEqual({ legal: false, reason: 'occupied', paths: [], blockedPlayerIds: [] });
+
+      const victory = explainLegality(
+        board,
+        { type: TileType.TwoSharps, rotation: 5, position: { row: 3, col: 0 } },
+        players,
+        [],
+        3,
+        false
+      );
+      expect(victory.legal).toBe(true);
+      expect(victory.reason).toBe('victory');
+    });
+  });
+
+  describe('validateMoveHistory', () => {
+    const players = [createPlayer('p1', 0), createPlayer('p2', 3)];
+    const teams: Team[] = [];
+
+    const move = (playerId: string, type: TileType, rotation: Rotation, row: number, col: number): Move => ({
+      playerId,
+      tile: { type, rotation, position: { row, col } },
+      timestamp: 0,
+    });
+
+    // Barrier across row 0 leaving a single gap at the center, alternating players
+    const barrierMoves = (): Move[] =>
+      [-3, -2, -1, 1, 2, 3].map((col, i) =>
+        move(i % 2 === 0 ? 'p1' : 'p2', TileType.ThreeSharps, 0, 0, col)
+      );
+
+    it('should accept an empty history', () => {
+      expect(validateMoveHistory([], players, teams, 3, false)).toEqual({ valid: true });
+    });
+
+    it('should accept a history of legal moves', () => {
+      expect(validateMoveHistory(barrierMoves(), players, teams, 3, false)).toEqual({ valid: true });
+    });
+
+    it('should report the index of a move that closed the last path', () => {
+      const history = [...barrierMoves(), move('p1', TileType.ThreeSharps, 0, 0, 0)];
+
+      const result = validateMoveHistory(history, players, teams, 3, false);
+
+      expect(result.valid).toBe(false);
+      if (!result.valid) {
+        expect(result.moveIndex).toBe(6);
+        expect(result.reason).toContain('0,0');
+      }
+    });
+
+    it('should allow blocking placements when supermove is enabled', () => {
+      const history = [...barrierMoves(), move('p1', TileType.ThreeSharps, 0, 0, 0)];
+      expect(validateMoveHistory(history, players, teams, 3, true)).toEqual({ valid: true });
+    });
+
+    it('should reject a move onto an occupied position without supermove', () => {
+      const history = [
+        move('p1', TileType.NoSharps, 0, 0, 0),
+        move('p2', TileType.OneSharp, 0, 0, 0),
+      ];
+
+      expect(validateMoveHistory(history, players, teams, 3, false)).toEqual({
+        valid: false,
+        moveIndex: 1,
+        reason: 'Position 0,0 is already occupied',
+      });
+    });
+
+    it('should reject a replacement by a player who is not blocked', () => {
+      const history = [
+        move('p1', TileType.NoSharps, 0, 0, 0),
+        move('p2', TileType.OneSharp, 0, 0, 0),
+      ];
+
+      const result = validateMoveHistory(history, players, teams, 3, true);
+
+      expect(result.valid).toBe(false);
+      if (!result.valid) {
+        expect(result.moveIndex).toBe(1);
+      }
+    });
+
+    it('should reject moves off the board', () => {
+      const history = [move('p1', TileType.NoSharps, 0, 4, 0)];
+
+      const result = validateMoveHistory(history, players, teams, 3, false);
+
+      expect(result).toEqual({ valid: false, moveIndex: 0, reason: 'Position 4,0 is off the board' });
+    });
+
+    it('should reject moves by unknown players', () => {
+      const history = [move('p9', TileType.NoSharps, 0, 0, 0)];
+
+      expect(validateMoveHistory(history, players, teams, 3, false)).toEqual({
+        valid: false,
+        moveIndex: 0,
+        reason: 'Unknown player p9',
+      });
+    });
+  });
+
+  describe('critical path segments', () => {
+    const players = [createPlayer('p1', 0), createPlayer('p2', 3)];
+    const teams: Team[] = [];
+
+    // Straight column from edge 3 up to row -2 (NE-SW segments), top hex left empty
+    const createColumnBoard = () => {
+      const board = new Map<string, PlacedTile>();
+      for (let row = -2; row <= 3; row++) {
+        const position = { row, col: 0 };
+        board.set(positionToKey(position), { type: TileType.TwoSharps, rotation: 2, position });
+      }
+      return board;
+    };
+
+    it('should key segments independently of direction order', () => {
+      expect(segmentKey({ row: 1, col: -1 }, 4, 1)).toBe('1,-1:1-4');
+      expect(segmentKey({ row: 1, col: -1 }, 1, 4)).toBe('1,-1:1-4');
+    });
+
+    it('should have no critical segments on an empty board', () => {
+      expect(getCriticalPathSegments(new Map(), players, teams, 3).size).toBe(0);
+    });
+
+    it('should mark the flow a shortest winning path runs through', () => {
+      const segments = getCriticalPathSegments(createColumnBoard(), players, teams, 3);
+
+      // NorthEast (3) to SouthWest (0) runs along the column
+      expect(isSegmentOnCriticalPath(segments, { row: 0, col: 0 }, 3, 0)).toBe(true);
+      expect(isSegmentOnCriticalPath(segments, { row: 3, col: 0 }, 0, 3)).toBe(true);
+    });
+
+    it('should not mark incidental flows on the same tile', () => {
+      const segments = getCriticalPathSegments(createColumnBoard(), players, teams, 3);
+
+      // NorthWest-West and East-SouthEast branch off the column
+      expect(isSegmentOnCriticalPath(segments, { row: 0, col: 0 }, 2, 1)).toBe(false);
+      expect(isSegmentOnCriticalPath(segments, { row: 0, col: 0 }, 4, 5)).toBe(false);
+    });
+
+    it('should only include segments on placed tiles', () => {
+      const segments = getCriticalPathSegments(createColumnBoard(), players, teams, 3);
+
+      for (const key of segments) {
+        expect(key.startsWith('-3,')).toBe(false);
+      }
+    });
+
+    it('should use partner edges as targets in team games', () => {
+      const teamPlayers = [createPlayer('p1', 0), createPlayer('p2', 3), createPlayer('p3', 1), createPlayer('p4', 4)];
+      const teamList: Team[] = [
+        { player1Id: 'p1', player2Id: 'p2' },
+        { player1Id: 'p3', player2Id: 'p4' },
+      ];
+
+      const segments = getCriticalPathSegments(createColumnBoard(), teamPlayers, teamList, 3);
+
+      expect(isSegmentOnCriticalPath(segments, { row: 0, col: 0 }, 3, 0)).toBe(true);
+    });
+  });
+
+  describe('no self-stalemate rule', () => {
+    // A near-full radius 1 board with two empty hexes, (-1,1) and (0,1)
+    const players: Player[] = [
+      { id: 'p1', color: 'blue', edgePosition: 0, isAI: false },
+      { id: 'p2', color: 'red', edgePosition: 3, isAI: false },
+    ];
+    const board = new Map<string, PlacedTile>();
+    const tiles: PlacedTile[] = [
+      { type: TileType.NoSharps, rotation: 2, position: { row: -1, col: 0 } },
+      { type: TileType.NoSharps, rotation: 3, position: { row: 0, col: -1 } },
+      { type: TileType.ThreeSharps, rotation: 3, position: { row: 0, col: 0 } },
+      { type: TileType.ThreeSharps, rotation: 1, position: { row: 1, col: -1 } },
+      { type: TileType.NoSharps, rotation: 3, position: { row: 1, col: 0 } },
+    ];
+    for (const tile of tiles) {
+      board.set(positionToKey(tile.position), tile);
+    }
+    // Legal on its own, but afterwards only a NoSharps or OneSharp can go in (0,1)
+    const move: PlacedTile = { type: TileType.NoSharps, rotation: 2, position: { row: -1, col: 1 } };
+    const sharpBag = [TileType.TwoSharps, TileType.ThreeSharps];
+
+    it('should allow the placement when the rule is off', () => {
+      expect(isLegalMove(board, move, players, [], 1, false)).toBe(true);
+    });
+
+    it('should forbid a placement that leaves no legal move for the bag', () => {
+      expect(isLegalMove(board, move, players, [], 1, false, sharpBag)).toBe(false);
+    });
+
+    it('should allow the placement while some tile in the bag can still be placed', () => {
+      expect(isLegalMove(board, move, players, [], 1, false, [...sharpBag, TileType.NoSharps])).toBe(true);
+    });
+
+    it('should explain the rejection as no continuation', () => {
+      const explanation = explainLegality(board, move, players, [], 1, false, sharpBag);
+
+      expect(explanation.legal).toBe(false);
+      expect(explanation.reason).toBe('no-continuation');
+      expect(explanation.blockedPlayerIds).toEqual([]);
+    });
+
+    it('should leave that move out of the legal moves', () => {
+      const moves = findAllLegalMoves(board, TileType.NoSharps, players, [], 1, false, sharpBag);
+
+      expect(moves).not.toContainEqual({ position: move.position, rotation: move.rotation });
+    });
+
+    it('should count a full board or an empty bag as a continuation', () => {
+      const testBoard = new Map(board);
+      testBoard.set(positionToKey(move.position), move);
+
+      expect(hasContinuation(testBoard, sharpBag, players, [], 1, false)).toBe(false);
+      expect(hasContinuation(testBoard, [], players, [], 1, false)).toBe(true);
+
+      testBoard.set('0,1', { type: TileType.NoSharps, rotation: 0, position: { row: 0, col: 1 } });
+      expect(hasContinuation(testBoard, sharpBag, players, [], 1, false)).toBe(true);
+    });
+  });
 });
+
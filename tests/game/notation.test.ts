@@ -9,8 +9,17 @@ import {
   formatMoveHistory,
   formatGameRecord,
   getPlayerNumber,
+  notationToPosition,
+  orientationToRotation,
+  parseMoveNotation,
+  formatTranscript,
+  parseTranscript,
+  GameTranscript,
+  getCoordinateLabels,
+  playerViewToPosition,
 } from '../../src/game/notation';
 import { TileType, Rotation } from '../../src/game/types';
+import { getAllBoardPositions, isValidPosition } from '../../src/game/board';
 import { Move } from '../../src/redux/types';
 
 describe('Game Notation', () => {
@@ -129,6 +138,102 @@ describe('Game Notation', () => {
     });
   });
 
+  describe('notationToPosition', () => {
+    it('should reverse positionToNotation for every square and edge', () => {
+      for (let edge = 0; edge < 6; edge++) {
+        for (const position of getAllBoardPositions(3)) {
+          const notation = positionToNotation(position, edge, 3);
+          expect(notationToPosition(notation, edge, 3)).toEqual(position);
+        }
+      }
+    });
+
+    it('should reject squares off the board', () => {
+      expect(notationToPosition('H1', 0, 3)).toBeNull();
+      expect(notationToPosition('A5', 0, 3)).toBeNull();
+      expect(notationToPosition('D0', 0, 3)).toBeNull();
+      expect(notationToPosition('D', 0, 3)).toBeNull();
+    });
+  });
+
+  describe('orientationToRotation', () => {
+    it('should reverse rotationToOrientation for every rotation and edge', () => {
+      for (let edge = 0; edge < 6; edge++) {
+        for (let rotation = 0; rotation < 6; rotation++) {
+          const orientation = rotationToOrientation(rotation as Rotation, edge);
+          expect(orientationToRotation(orientation, edge)).toBe(rotation);
+        }
+      }
+    });
+
+    it('should reject unknown orientations', () => {
+      expect(orientationToRotation('E', 0)).toBeNull();
+    });
+  });
+
+  describe('parseMoveNotation', () => {
+    const players = [
+      { id: 'p1', edgePosition: 0 },
+      { id: 'p2', edgePosition: 2 },
+      { id: 'p3', edgePosition: 4 },
+    ];
+
+    it('should round-trip formatted moves for every player', () => {
+      const types = [TileType.NoSharps, TileType.OneSharp, TileType.TwoSharps, TileType.ThreeSharps];
+      getAllBoardPositions(3).forEach((position, i) => {
+        players.forEach((player, playerIndex) => {
+          const move: Move = {
+            playerId: player.id,
+            tile: { type: types[i % 4], rotation: ((i + playerIndex) % 6) as Rotation, position },
+            timestamp: i,
+          };
+          const notation = formatMoveNotation(move, playerIndex + 1, player.edgePosition, 3);
+
+          expect(parseMoveNotation(notation, players, 3)).toEqual({
+            valid: true,
+            playerId: move.playerId,
+            tile: move.tile,
+          });
+        });
+      });
+    });
+
+    it('should parse a hand-written move', () => {
+      expect(parseMoveNotation('P1A1T0S', players, 3)).toEqual({
+        valid: true,
+        playerId: 'p1',
+        tile: { type: TileType.NoSharps, rotation: 0, position: { row: -3, col: 3 } },
+      });
+    });
+
+    it('should describe malformed input', () => {
+      expect(parseMoveNotation('hello', players, 3)).toEqual({
+        valid: false,
+        reason: 'Malformed move "hello", expected P<player><row><column>T<type><orientation>',
+      });
+      expect(parseMoveNotation('P4D4T0N', players, 3)).toEqual({
+        valid: false,
+        reason: 'Player 4 is out of range 1-3',
+      });
+      expect(parseMoveNotation('P0D4T0N', players, 3)).toEqual({
+        valid: false,
+        reason: 'Player 0 is out of range 1-3',
+      });
+      expect(parseMoveNotation('P1Z1T0N', players, 3)).toEqual({
+        valid: false,
+        reason: 'Square Z1 is off the board',
+      });
+      expect(parseMoveNotation('P1D4T7N', players, 3)).toEqual({
+        valid: false,
+        reason: 'Unknown tile type T7',
+      });
+      expect(parseMoveNotation('P1D4T0E', players, 3)).toEqual({
+        valid: false,
+        reason: 'Unknown orientation "E"',
+      });
+    });
+  });
+
   describe('getPlayerNumber', () => {
     it('should return 1-based player number', () => {
       const playerIds = ['p1', 'p2', 'p3'];
@@ -224,4 +329,126 @@ describe('Game Notation', () => {
       expect(record).toContain('P1');
     });
   });
+
+  describe('transcripts', () => {
+    const move = (playerId: string, row: number, col: number, type: TileType, rotation: number) => ({
+      playerId,
+      tile: { type, rotation: rotation as Rotation, position: { row, col } },
+    });
+
+    // Player 2 replaces a tile under supermove, so their follow-up gets a round of its own
+    const game: GameTranscript = {
+      players: [
+        { id: 'p1', edgePosition: 0 },
+        { id: 'p2', edgePosition: 3 },
+      ],
+      boardRadius: 3,
+      supermove: true,
+      singleSupermove: false,
+      supermoveAnyPlayer: false,
+      oppositeSidesVictory: false,
+      winners: ['p2'],
+      winType: 'flow',
+      moveHistory: [
+        move('p1', -3, 3, TileType.NoSharps, 0),
+        move('p2', 3, -3, TileType.TwoSharps, 1),
+        move('p1', 0, 0, TileType.ThreeSharps, 2),
+        move('p2', 0, 1, TileType.OneSharp, 0),
+        move('p2', 1, 0, TileType.NoSharps, 5),
+        move('p1', -1, 1, TileType.TwoSharps, 4),
+      ],
+    };
+
+    it('should format a header and one column per player', () => {
+      expect(formatTranscript(game)).toBe(
+        '[Edges "0 3"]\n' +
+        '[BoardRadius "3"]\n' +
+        '[Supermove "true"]\n' +
+        '[SingleSupermove "false"]\n' +
+        '[SupermoveAnyPlayer "false"]\n' +
+        '[OppositeSidesVictory "false"]\n' +
+        '[Result "P2 flow"]\n' +
+        '\n' +
+        '1. P1A1T0S   P2A1T2NE\n' +
+        '2. P1D4T3NW  P2D5T1N\n' +
+        '3. --        P2C4T0NW\n' +
+        '4. P1C3T2NE\n'
+      );
+    });
+
+    it('should mark an unfinished game with *', () => {
+      const transcript = formatTranscript({ ...game, winners: [], winType: null });
+      expect(transcript).toContain('[Result "*"]');
+    });
+
+    it('should round-trip through parseTranscript', () => {
+      const text = formatTranscript(game);
+      const parsed = parseTranscript(text);
+
+      expect(parsed.valid).toBe(true);
+      if (!parsed.valid) return;
+
+      const { transcript } = parsed;
+      expect(transcript.players).toEqual([
+        { id: 'P1', edgePosition: 0 },
+        { id: 'P2', edgePosition: 3 },
+      ]);
+      expect(transcript.supermove).toBe(true);
+      expect(transcript.winners).toEqual(['P2']);
+      expect(transcript.winType).toBe('flow');
+      expect(transcript.moveHistory.map(m => m.tile)).toEqual(game.moveHistory.map(m => m.tile));
+      expect(formatTranscript(transcript)).toBe(text);
+    });
+
+    it('should reject a transcript without seating', () => {
+      const parsed = parseTranscript('[BoardRadius "3"]\n\n1. P1A1T0S\n');
+
+      expect(parsed.valid).toBe(false);
+    });
+
+    it('should report the line of a bad move', () => {
+      const text = formatTranscript(game).replace('P2D5T1N', 'P2Z9T1N');
+      const parsed = parseTranscript(text);
+
+      expect(parsed).toEqual({ valid: false, reason: 'Line 10: Square Z9 is off the board' });
+    });
+  });
+
+  describe('playerViewToPosition', () => {
+    it('should undo the rotation applied by positionToNotation', () => {
+      for (let edge = 0; edge < 6; edge++) {
+        expect(playerViewToPosition({ row: -3, col: 3 }, edge)).toEqual(notationToPosition('A1', edge, 3));
+      }
+    });
+  });
+
+  describe('getCoordinateLabels', () => {
+    it('should label both ends of every row and both ends of every column', () => {
+      const labels = getCoordinateLabels(0, 3);
+      const rowLabels = labels.filter(label => /[A-Z]/.test(label.text));
+      const columnLabels = labels.filter(label => /\d/.test(label.text));
+
+      expect(rowLabels.map(label => label.text)).toEqual(
+        ['A', 'A', 'B', 'B', 'C', 'C', 'D', 'D', 'E', 'E', 'F', 'F', 'G', 'G']
+      );
+      expect(new Set(columnLabels.map(label => label.text))).toEqual(new Set(['1', '2', '3', '4', '5', '6', '7']));
+      expect(columnLabels).toHaveLength(14);
+    });
+
+    it('should place each label between a matching edge hex and the hex beyond it', () => {
+      for (let edge = 0; edge < 6; edge++) {
+        for (const { text, inside, outside } of getCoordinateLabels(edge, 3)) {
+          const notation = positionToNotation(inside, edge, 3);
+
+          expect(isValidPosition(inside, 3)).toBe(true);
+          expect(isValidPosition(outside, 3)).toBe(false);
+          if (/[A-Z]/.test(text)) {
+            expect(notation[0]).toBe(text);
+          } else {
+            expect(notation.slice(1)).toBe(text);
+          }
+        }
+      }
+    });
+  });
 });
@@ -1,7 +1,8 @@
 // Tests for single supermove functionality
 import { describe, it, expect } from 'vitest';
 import { gameReducer, initialState } from '../../src/redux/gameReducer';
-import { placeTile, replaceTile, drawTile } from '../../src/redux/actions';
+import { placeTile, replaceTile, drawTile, startGame, addPlayer } from '../../src/redux/actions';
+import { SHUFFLE_VERSION } from '../../src/redux/types';
 import { TileType } from '../../src/game/types';
 import { positionToKey } from '../../src/game/board';
 
@@ -236,4 +237,47 @@ describe('Single Supermove', () => {
     expect(counts[TileType.TwoSharps]).toBe(2);
     expect(counts[TileType.ThreeSharps]).toBe(2);
   });
+
+  describe('reshuffle seed', () => {
+    const { NoSharps: N, OneSharp: O, TwoSharps: T, ThreeSharps: S } = TileType;
+    const posToReplace = { row: 0, col: 0 };
+
+    // A seeded game about to return a OneSharp to the bag with a single supermove
+    const createSeededState = (shuffleVersion?: number) => ({
+      ...initialState,
+      players: [
+        { id: 'p1', color: 'blue', edgePosition: 0, isAI: false },
+        { id: 'p2', color: 'red', edgePosition: 3, isAI: false },
+      ],
+      board: new Map([
+        [positionToKey(posToReplace), { type: O, rotation: 0 as const, position: posToReplace }],
+      ]),
+      currentPlayerIndex: 0,
+      currentTile: N,
+      availableTiles: [T, S, N, O, T, S, N, O, T, S],
+      phase: 'playing' as const,
+      seed: 7,
+      shuffleVersion,
+    });
+
+    it('should reshuffle games logged before versioning with the game seed, as they were played', () => {
+      const newState = gameReducer(createSeededState(), replaceTile(posToReplace, 0, true));
+
+      expect(newState.availableTiles).toEqual([O, T, O, N, S, T, T, O, S, S, N]);
+    });
+
+    it('should reshuffle versioned games with a seed derived from the move count', () => {
+      const newState = gameReducer(createSeededState(SHUFFLE_VERSION), replaceTile(posToReplace, 0, true));
+
+      expect(newState.availableTiles).toEqual([T, S, S, T, N, O, S, O, O, T, N]);
+    });
+
+    it('should record the shuffle version when a game is started', () => {
+      const action = startGame({ seed: 7 });
+      expect(action.payload?.shuffleVersion).toBe(SHUFFLE_VERSION);
+
+      const state = gameReducer(gameReducer(initialState, addPlayer('#0173B2', 0)), action);
+      expect(state.shuffleVersion).toBe(SHUFFLE_VERSION);
+    });
+  });
 });
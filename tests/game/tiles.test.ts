@@ -1,6 +1,6 @@
 // Unit tests for tile utilities
 
-import { describe, it, expect } from 'vitest';
+import { describe, it, expect, afterEach } from 'vitest';
 import {
   TILE_FLOWS,
   getFlowConnections,
@@ -8,9 +8,16 @@ import {
   areDirectionsConnected,
   createTileDeck,
   shuffleDeck,
+  isValidPortPairing,
+  defineTileFlows,
+  clearTileFlowDefinitions,
+  getTileFlows,
+  getUniqueRotations,
+  getEquivalentRotation,
 } from '../../src/game/tiles';
+import { traceFlow } from '../../src/game/flows';
 import { rotateDirection } from '../../src/game/board';
-import { TileType, Direction, PlacedTile } from '../../src/game/types';
+import { TileType, Direction, PlacedTile, Rotation } from '../../src/game/types';
 
 describe('tile utilities', () => {
   describe('TILE_FLOWS', () => {
@@ -86,6 +93,43 @@ describe('tile utilities', () => {
     });
   });
 
+  describe('flow pattern caching', () => {
+    it('should compute each rotation\'s connections once', () => {
+      const first = getFlowConnections(TileType.OneSharp, 3);
+      expect(getFlowConnections(TileType.OneSharp, 3)).toBe(first);
+      expect(getFlowConnections(TileType.OneSharp, 9 as Rotation)).toBe(first);
+    });
+  });
+
+  describe('getUniqueRotations', () => {
+    it('should skip rotations that repeat a symmetric tile\'s pattern', () => {
+      expect(getUniqueRotations(TileType.NoSharps)).toEqual([0, 1, 2]);
+      expect(getUniqueRotations(TileType.OneSharp)).toEqual([0, 1, 2, 3, 4, 5]);
+      expect(getUniqueRotations(TileType.TwoSharps)).toEqual([0, 1, 2]);
+      expect(getUniqueRotations(TileType.ThreeSharps)).toEqual([0, 1]);
+    });
+
+    it('should match each tile\'s rotational symmetry', () => {
+      // A tile with n-fold symmetry has 6 / n distinct rotations
+      const symmetry = {
+        [TileType.NoSharps]: 2,
+        [TileType.OneSharp]: 1,
+        [TileType.TwoSharps]: 2,
+        [TileType.ThreeSharps]: 3,
+      };
+      for (const [type, fold] of Object.entries(symmetry)) {
+        expect(getUniqueRotations(Number(type) as TileType)).toHaveLength(6 / fold);
+      }
+    });
+
+    it('should map each rotation to the first rotation with the same flows', () => {
+      expect(getEquivalentRotation(TileType.ThreeSharps, 4)).toBe(0);
+      expect(getEquivalentRotation(TileType.ThreeSharps, 5)).toBe(1);
+      expect(getEquivalentRotation(TileType.TwoSharps, 4)).toBe(1);
+      expect(getEquivalentRotation(TileType.OneSharp, 4)).toBe(4);
+    });
+  });
+
   describe('getFlowExit', () => {
     it('should return exit direction for valid entry', () => {
       const tile: PlacedTile = {
@@ -169,6 +213,76 @@ describe('tile utilities', () => {
     });
   });
 
+  describe('variant tiles', () => {
+    // Dead-end tile: W and E are left unpaired, so flows entering there stop
+    const DEAD_END = 100 as TileType;
+
+    afterEach(() => {
+      clearTileFlowDefinitions();
+    });
+
+    it('should validate port pairings', () => {
+      expect(isValidPortPairing([[Direction.West, Direction.East]])).toBe(true);
+      expect(isValidPortPairing([])).toBe(true);
+      expect(isValidPortPairing([[Direction.West, Direction.West]])).toBe(false);
+      expect(isValidPortPairing([
+        [Direction.West, Direction.East],
+        [Direction.East, Direction.NorthEast],
+      ])).toBe(false);
+    });
+
+    it('should reject invalid or built-in definitions', () => {
+      expect(() => defineTileFlows(DEAD_END, [[Direction.West, Direction.West]])).toThrow();
+      expect(() => defineTileFlows(TileType.NoSharps, [])).toThrow();
+      expect(() => getTileFlows(DEAD_END)).toThrow();
+    });
+
+    it('should rotate variant tile flows', () => {
+      defineTileFlows(DEAD_END, [
+        [Direction.SouthWest, Direction.SouthEast],
+        [Direction.NorthWest, Direction.NorthEast],
+      ]);
+
+      expect(getFlowConnections(DEAD_END, 1)).toEqual([
+        [Direction.West, Direction.SouthWest],
+        [Direction.NorthEast, Direction.East],
+      ]);
+    });
+
+    it('should pick up a redefined variant after clearing definitions', () => {
+      defineTileFlows(DEAD_END, [[Direction.SouthWest, Direction.SouthEast]]);
+      expect(getFlowConnections(DEAD_END, 1)).toEqual([[Direction.West, Direction.SouthWest]]);
+      expect(getEquivalentRotation(DEAD_END, 1)).toBe(1);
+
+      clearTileFlowDefinitions();
+      defineTileFlows(DEAD_END, [[Direction.West, Direction.East]]);
+
+      expect(getFlowConnections(DEAD_END, 1)).toEqual([[Direction.NorthWest, Direction.SouthEast]]);
+      expect(getEquivalentRotation(DEAD_END, 4)).toBe(1);
+    });
+
+    it('should stop flows at a dead-end tile', () => {
+      defineTileFlows(DEAD_END, [
+        [Direction.SouthWest, Direction.SouthEast],
+        [Direction.NorthWest, Direction.NorthEast],
+      ]);
+
+      const deadEnd: PlacedTile = { type: DEAD_END, rotation: 0, position: { row: 0, col: 1 } };
+      expect(getFlowExit(deadEnd, Direction.West)).toBeNull();
+      expect(getFlowExit(deadEnd, Direction.NorthWest)).toBe(Direction.NorthEast);
+
+      // A straight W-E flow runs into the dead end and goes no further
+      const board = new Map<string, PlacedTile>([
+        ['0,0', { type: TileType.NoSharps, rotation: 0, position: { row: 0, col: 0 } }],
+        ['0,1', deadEnd],
+        ['0,2', { type: TileType.NoSharps, rotation: 0, position: { row: 0, col: 2 } }],
+      ]);
+      const { positions } = traceFlow(board, { row: 0, col: 0 }, Direction.West, 'p1', 3);
+
+      expect([...positions]).toEqual(['0,0']);
+    });
+  });
+
   describe('createTileDeck', () => {
     it('should create deck with 40 tiles', () => {
       const deck = createTileDeck();
@@ -0,0 +1,45 @@
+// Unit tests for grouping move history into turns
+
+import { describe, it, expect } from 'vitest';
+import { groupMovesIntoTurns } from '../../src/game/turns';
+import { Move } from '../../src/redux/types';
+import { TileType } from '../../src/game/types';
+
+describe('groupMovesIntoTurns', () => {
+  const createMove = (playerId: string, row: number, col: number, type = TileType.NoSharps): Move => ({
+    playerId,
+    tile: { type, rotation: 0, position: { row, col } },
+    timestamp: 0,
+  });
+
+  it('should return no turns for an empty history', () => {
+    expect(groupMovesIntoTurns([])).toEqual([]);
+  });
+
+  it('should yield one turn per placement in a two-turn game', () => {
+    const moves = [createMove('P1', -3, 0), createMove('P2', 3, 0)];
+    const turns = groupMovesIntoTurns(moves);
+
+    expect(turns.length).toBe(2);
+    expect(turns[0].turnNumber).toBe(1);
+    expect(turns[0].playerId).toBe('P1');
+    expect(turns[0].placement.tile.position).toEqual({ row: -3, col: 0 });
+    expect(turns[1].turnNumber).toBe(2);
+    expect(turns[1].playerId).toBe('P2');
+    expect(turns[1].placement.tile.position).toEqual({ row: 3, col: 0 });
+  });
+
+  it('should bundle a supermove replacement with the following placement', () => {
+    const moves = [
+      createMove('P1', -3, 0),
+      createMove('P2', -3, 0, TileType.TwoSharps), // Replacement
+      createMove('P2', 0, 0),                      // Placement of the replaced tile
+      createMove('P1', 1, 0),
+    ];
+    const turns = groupMovesIntoTurns(moves);
+
+    expect(turns.map((turn) => turn.playerId)).toEqual(['P1', 'P2', 'P1']);
+    expect(turns[1].moves.length).toBe(2);
+    expect(turns[1].placement).toBe(moves[2]);
+  });
+});
@@ -0,0 +1,69 @@
+// Unit tests for the first-run tutorial
+import { describe, it, expect } from 'vitest';
+import { getTutorialInfo } from '../../src/game/tutorial';
+import { Player, Team, TileType } from '../../src/game/types';
+
+describe('tutorial', () => {
+  const twoPlayers: Player[] = [
+    { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+    { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: false },
+  ];
+
+  const fourPlayers: Player[] = [
+    { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+    { id: 'p2', color: '#DE8F05', edgePosition: 1, isAI: false },
+    { id: 'p3', color: '#029E73', edgePosition: 3, isAI: false },
+    { id: 'p4', color: '#ECE133', edgePosition: 4, isAI: false },
+  ];
+  const teams: Team[] = [
+    { player1Id: 'p1', player2Id: 'p3' },
+    { player1Id: 'p2', player2Id: 'p4' },
+  ];
+
+  const baseGame = {
+    players: twoPlayers,
+    teams: [] as Team[],
+    currentPlayerIndex: 0,
+    currentTile: null as TileType | null,
+    supermove: false,
+    oppositeSidesVictory: false,
+  };
+
+  describe('getTutorialInfo', () => {
+    it('should identify the current player start and goal sides', () => {
+      const info = getTutorialInfo({ ...baseGame, currentPlayerIndex: 1 });
+
+      expect(info?.playerId).toBe('p2');
+      expect(info?.startEdge).toBe(3);
+      expect(info?.goalEdge).toBe(0);
+    });
+
+    it('should use the partner edge as the goal in team games', () => {
+      const info = getTutorialInfo({ ...baseGame, players: fourPlayers, teams, currentPlayerIndex: 3 });
+
+      expect(info?.startEdge).toBe(4);
+      expect(info?.goalEdge).toBe(1);
+      expect(info?.lines).toContain("Connect it to your partner's edge (outlined) to win.");
+    });
+
+    it('should point out the held tile', () => {
+      const info = getTutorialInfo({ ...baseGame, currentTile: TileType.TwoSharps });
+
+      expect(info?.heldTile).toBe(TileType.TwoSharps);
+      expect(info?.lines).toContain('You are holding a tile with two sharp turns.');
+    });
+
+    it('should explain the blocking rule and supermove', () => {
+      const without = getTutorialInfo(baseGame)!;
+      const withSupermove = getTutorialInfo({ ...baseGame, supermove: true })!;
+
+      expect(without.lines).toContain('A move may never completely block any player');
+      expect(without.lines.some(line => line.includes('replace a tile'))).toBe(false);
+      expect(withSupermove.lines.some(line => line.includes('replace a tile'))).toBe(true);
+    });
+
+    it('should return null without a current player', () => {
+      expect(getTutorialInfo({ ...baseGame, players: [] })).toBeNull();
+    });
+  });
+});
@@ -6,7 +6,11 @@ import {
   checkTeamFlowVictory,
   checkFlowVictory,
   checkVictory,
+  getGoalEdge,
+  getLongestFlowLength,
+  isBoardFull,
   isConnectionInWinningPath,
+  getWinningPaths,
 } from '../../src/game/victory';
 import { Player, Team, TileType, PlacedTile, Direction } from '../../src/game/types';
 import { positionToKey, getEdgePositions, getAllBoardPositions, getEdgePositionsWithDirections, getOppositeDirection } from '../../src/game/board';
@@ -373,6 +377,26 @@ describe('victory conditions', () => {
       expect(result.winType).toBe('flow');
     });
 
+    it('should award a team the win for reaching the opposite side when oppositeSidesVictory is set', () => {
+      // Team partners sit on adjacent edges, so a flow from edge 0 to edge 3
+      // does not reach the partner's edge
+      const players = [createPlayer('p1', 0), createPlayer('p2', 1)];
+      const teams: Team[] = [{ player1Id: 'p1', player2Id: 'p2' }];
+      const board = new Map<string, PlacedTile>();
+
+      for (let row = -3; row <= 3; row++) {
+        const tile: PlacedTile = { type: TileType.TwoSharps, rotation: 5, position: { row, col: 0 } };
+        board.set(positionToKey(tile.position), tile);
+      }
+
+      const specificGoal = checkFlowVictory(board, players, teams);
+      expect(specificGoal.winners).toEqual([]);
+
+      const oppositeSides = checkFlowVictory(board, players, teams, 3, true);
+      expect(oppositeSides.winners).toEqual(['p1', 'p2']);
+      expect(oppositeSides.winType).toBe('flow');
+    });
+
     it('should detect team victory', () => {
       const players = [
         createPlayer('p1', 0),
@@ -548,6 +572,122 @@ describe('victory conditions', () => {
       expect(result.winners).toEqual(['constraint']);
       expect(result.winType).toBe('constraint');
     });
+
+    it('should declare a draw when a placement fills the board with no winner', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 3)];
+      const board = new Map<string, PlacedTile>();
+      getAllBoardPositions().forEach(pos => {
+        board.set(positionToKey(pos), { type: TileType.ThreeSharps, rotation: 0, position: pos });
+      });
+
+      const result = checkVictory(board, players, [], undefined, 3);
+
+      expect(result.winners).toEqual([]);
+      expect(result.winType).toBe('draw');
+    });
+  });
+
+  describe('longest flow victory', () => {
+    // Sharp turns everywhere: no flow crosses the board, and each runs only a tile or two
+    const createSharpsBoard = () => {
+      const board = new Map<string, PlacedTile>();
+      getAllBoardPositions().forEach(pos => {
+        board.set(positionToKey(pos), { type: TileType.ThreeSharps, rotation: 0, position: pos });
+      });
+      return board;
+    };
+
+    it('should measure the longest flow from a player\'s edge in tiles', () => {
+      const board = createSharpsBoard();
+
+      expect(getLongestFlowLength(board, createPlayer('p1', 0), 3)).toBe(1);
+      expect(getLongestFlowLength(board, createPlayer('p2', 3), 3)).toBe(2);
+      expect(getLongestFlowLength(new Map(), createPlayer('p1', 0), 3)).toBe(0);
+    });
+
+    it('should award a full board to the player with the longest connected flow', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 3)];
+
+      const result = checkVictory(createSharpsBoard(), players, [], undefined, 3, false, false, true);
+
+      expect(result.winners).toEqual(['p2']);
+      expect(result.winType).toBe('flow');
+    });
+
+    it('should tie players whose longest flows are equal', () => {
+      const players = [createPlayer('p1', 1), createPlayer('p2', 3)];
+
+      const result = checkVictory(createSharpsBoard(), players, [], undefined, 3, false, false, true);
+
+      expect(result.winners).toEqual(['p1', 'p2']);
+      expect(result.winType).toBe('tie');
+    });
+
+    it('should credit both players of the team with the longest flow', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 1), createPlayer('p3', 2), createPlayer('p4', 4)];
+      const teams: Team[] = [
+        { player1Id: 'p1', player2Id: 'p3' },
+        { player1Id: 'p2', player2Id: 'p4' },
+      ];
+
+      const result = checkVictory(createSharpsBoard(), players, teams, undefined, 3, false, false, true);
+
+      expect(result.winners).toEqual(['p2', 'p4']);
+      expect(result.winType).toBe('flow');
+    });
+
+    it('should leave a board with empty hexes undecided', () => {
+      const board = createSharpsBoard();
+      board.delete(positionToKey({ row: 0, col: 0 }));
+      const players = [createPlayer('p1', 0), createPlayer('p2', 3)];
+
+      const result = checkVictory(board, players, [], undefined, 3, false, false, true);
+
+      expect(result.winType).toBeNull();
+    });
+  });
+
+  describe('getGoalEdge', () => {
+    const fourPlayers = [createPlayer('p1', 0), createPlayer('p2', 1), createPlayer('p3', 2), createPlayer('p4', 3)];
+    const teams: Team[] = [
+      { player1Id: 'p1', player2Id: 'p3' },
+      { player1Id: 'p2', player2Id: 'p4' },
+    ];
+
+    it('should use the partner\'s edge in team games', () => {
+      expect(getGoalEdge(fourPlayers[0], fourPlayers, teams)).toBe(2);
+      expect(getGoalEdge(fourPlayers[3], fourPlayers, teams)).toBe(1);
+    });
+
+    it('should use the opposite edge when opposite-sides victory is enabled', () => {
+      expect(getGoalEdge(fourPlayers[1], fourPlayers, teams, true)).toBe(4);
+    });
+
+    it('should fall back to the opposite edge if the partner is missing', () => {
+      const partial = fourPlayers.filter(p => p.id !== 'p3');
+      expect(getGoalEdge(fourPlayers[0], partial, teams)).toBe(3);
+    });
+
+    it('should use the opposite edge for players without a team', () => {
+      const fivePlayers = [...fourPlayers, createPlayer('p5', 4)];
+      expect(getGoalEdge(fivePlayers[4], fivePlayers, [])).toBe(1);
+    });
+  });
+
+  describe('isBoardFull', () => {
+    it('should only report a board with every hex occupied as full', () => {
+      const board = new Map<string, PlacedTile>();
+      const allPositions = getAllBoardPositions(2);
+      allPositions.slice(1).forEach(pos => {
+        board.set(positionToKey(pos), { type: TileType.NoSharps, rotation: 0, position: pos });
+      });
+
+      expect(isBoardFull(board, 2)).toBe(false);
+
+      board.set(positionToKey(allPositions[0]), { type: TileType.NoSharps, rotation: 0, position: allPositions[0] });
+      expect(isBoardFull(board, 2)).toBe(true);
+      expect(isBoardFull(board, 3)).toBe(false);
+    });
   });
 
   describe('checkTeamFlowVictory - edge cases', () => {
@@ -1025,6 +1165,59 @@ describe('victory conditions', () => {
     });
   });
 
+  describe('getWinningPaths', () => {
+    const createColumn = (): Map<string, PlacedTile> => {
+      const board = new Map<string, PlacedTile>();
+      for (let row = -3; row <= 3; row++) {
+        const tile: PlacedTile = { type: TileType.TwoSharps, rotation: 5, position: { row, col: 0 } };
+        board.set(positionToKey(tile.position), tile);
+      }
+      return board;
+    };
+
+    it('should trace the winning flow from the start edge to the goal edge', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 1)];
+      const board = createColumn();
+
+      const paths = getWinningPaths(board, players, [], ['p1'], 3);
+
+      expect(paths).toHaveLength(1);
+      expect(paths[0].playerId).toBe('p1');
+      expect(paths[0].segments.map((s) => s.position.row)).toEqual([-3, -2, -1, 0, 1, 2, 3]);
+      for (let i = 1; i < paths[0].segments.length; i++) {
+        expect(paths[0].segments[i].entry).toBe(getOppositeDirection(paths[0].segments[i - 1].exit));
+      }
+    });
+
+    it('should trace each team winner along their own flow', () => {
+      const players = [
+        createPlayer('p1', 0),
+        createPlayer('p2', 1),
+        createPlayer('p3', 3),
+        createPlayer('p4', 4),
+      ];
+      const teams: Team[] = [
+        { player1Id: 'p1', player2Id: 'p3' },
+        { player1Id: 'p2', player2Id: 'p4' },
+      ];
+      const board = createColumn();
+
+      const paths = getWinningPaths(board, players, teams, ['p1', 'p3'], 3);
+
+      expect(paths.map((p) => p.playerId)).toEqual(['p1', 'p3']);
+      expect(paths[1].segments.map((s) => s.position.row)).toEqual([3, 2, 1, 0, -1, -2, -3]);
+    });
+
+    it('should omit winners without a connecting flow and unknown players', () => {
+      const players = [createPlayer('p1', 0), createPlayer('p2', 1)];
+      const board = createColumn();
+      // Break the column so the flow never reaches the goal edge
+      board.delete(positionToKey({ row: 3, col: 0 }));
+
+      expect(getWinningPaths(board, players, [], ['p1', 'p2', 'ghost'], 3)).toEqual([]);
+    });
+  });
+
   describe('checkPlayerFlowVictory - additional coverage', () => {
     it('should handle missing edgeMap for target edge', () => {
       const player = createPlayer('p1', 0);
@@ -0,0 +1,56 @@
+// Tests for gameplay keyboard controls - specifically turning the board to another player's view
+
+import { describe, it, expect, beforeEach } from 'vitest';
+import { store } from '../src/redux/store';
+import { resetGame, navigateMoveList, setOrientationOverride } from '../src/redux/actions';
+import { resetPlayerIdCounter } from '../src/redux/gameReducer';
+import { GameplayInputHandler } from '../src/input/gameplayInputHandler';
+import { GameplayRenderer } from '../src/rendering/gameplayRenderer';
+import { generateRandomGame } from './utils/gameGenerator';
+
+describe('GameplayInputHandler - orientation key', () => {
+  // Key presses don't draw or hit-test, so no real renderer is needed
+  const handler = new GameplayInputHandler({} as GameplayRenderer);
+
+  beforeEach(() => {
+    resetPlayerIdCounter();
+    const actions = generateRandomGame(42, 4);
+
+    resetPlayerIdCounter();
+    store.dispatch(resetGame());
+    store.dispatch(navigateMoveList('last'));
+    store.dispatch(setOrientationOverride(null));
+    actions.forEach((action) => store.dispatch(action));
+  });
+
+  it('should view the board from each player in turn, then return to normal', () => {
+    const [first, second] = store.getState().game.players;
+    expect(store.getState().game.screen).toBe('gameplay');
+
+    expect(handler.handleKeyDown('o')).toBe(true);
+    expect(store.getState().ui.orientationPlayerId).toBe(first.id);
+
+    expect(handler.handleKeyDown('O')).toBe(true);
+    expect(store.getState().ui.orientationPlayerId).toBe(second.id);
+
+    expect(handler.handleKeyDown('o')).toBe(true);
+    expect(store.getState().ui.orientationPlayerId).toBeNull();
+  });
+
+  it('should turn the board while reviewing earlier moves', () => {
+    store.dispatch(navigateMoveList('first'));
+
+    // Playing keys are ignored while reviewing, but the view can still turn
+    expect(handler.handleKeyDown('e')).toBe(false);
+    expect(handler.handleKeyDown('o')).toBe(true);
+    expect(store.getState().ui.orientationPlayerId).toBe(store.getState().game.players[0].id);
+  });
+
+  it('should leave the rest of the game untouched', () => {
+    const before = store.getState().game;
+
+    handler.handleKeyDown('o');
+
+    expect(store.getState().game).toBe(before);
+  });
+});
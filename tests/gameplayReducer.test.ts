@@ -1,15 +1,20 @@
 // Unit tests for gameplay Redux actions and reducer
 
 import { describe, it, expect } from 'vitest';
-import { gameReducer, initialState } from '../src/redux/gameReducer';
+import { gameReducer, initialState, validateTileAction, getUndoableMoveCount } from '../src/redux/gameReducer';
 import {
   setupGame,
   shuffleTiles,
   drawTile,
   placeTile,
+  replaceTile,
   nextPlayer,
   endGame,
   resetGame,
+  resign,
+  turnTimeout,
+  undoMove,
+  swapSides,
 } from '../src/redux/actions';
 import { TileType } from '../src/game/types';
 import { GameState } from '../src/redux/types';
@@ -80,6 +85,25 @@ describe('gameReducer - Gameplay Actions', () => {
       expect(state1.availableTiles).not.toEqual(state2.availableTiles);
     });
 
+    it('should draw each tile type in proportion to the bag across seeded games', () => {
+      // Skewed bag: 15 NoSharps, 10 OneSharp, 10 TwoSharps, 5 ThreeSharps
+      const distribution: [number, number, number, number] = [15, 10, 10, 5];
+      const total = 40;
+      const games = 2000;
+      const drawn = [0, 0, 0, 0];
+
+      for (let seed = 0; seed < games; seed++) {
+        // Draws take tiles from the front of the shuffled bag
+        const bag = gameReducer(initialState, shuffleTiles(seed, distribution)).availableTiles;
+        drawn[bag[0]]++;
+        drawn[bag[1]]++;
+      }
+
+      distribution.forEach((count, type) => {
+        expect(Math.abs(drawn[type] / (2 * games) - count / total)).toBeLessThan(0.03);
+      });
+    });
+
     it('should have correct tile distribution', () => {
       const state = gameReducer(initialState, shuffleTiles());
 
@@ -161,6 +185,34 @@ describe('gameReducer - Gameplay Actions', () => {
       expect(state.currentTile).toBeNull();
     });
 
+    it('should end the game in a draw when the last hex fills with no winner', () => {
+      const players = [
+        { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+        { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: false },
+      ];
+      let state = gameReducer(initialState, setupGame(players, []));
+
+      // ThreeSharps tiles only make sharp turns, so a board of them connects no edges
+      const board = new Map(state.board);
+      const lastPosition = { row: 3, col: 0 };
+      for (let row = -3; row <= 3; row++) {
+        for (let col = Math.max(-3, -3 - row); col <= Math.min(3, 3 - row); col++) {
+          if (row !== lastPosition.row || col !== lastPosition.col) {
+            board.set(`${row},${col}`, { type: TileType.ThreeSharps, rotation: 0, position: { row, col } });
+          }
+        }
+      }
+      state = { ...state, board, currentTile: TileType.ThreeSharps };
+
+      state = gameReducer(state, placeTile(lastPosition, 0));
+
+      expect(state.board.size).toBe(37);
+      expect(state.phase).toBe('finished');
+      expect(state.winners).toEqual([]);
+      expect(state.winType).toBe('draw');
+      expect(state.screen).toBe('game-over');
+    });
+
     it('should not place tile if no current tile', () => {
       const state = gameReducer(initialState, placeTile({ row: 0, col: 0 }, 0));
 
@@ -209,6 +261,76 @@ describe('gameReducer - Gameplay Actions', () => {
     });
   });
 
+  describe('validateTileAction', () => {
+    const players = [
+      { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false, userId: 'user-1' },
+      { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: false, userId: 'user-2' },
+    ];
+
+    const setupWithTileInHand = (): GameState => {
+      let state = gameReducer(initialState, setupGame(players, []));
+      state = gameReducer(state, shuffleTiles(333));
+      return gameReducer(state, drawTile());
+    };
+
+    it('should agree with the reducer without changing state', () => {
+      const state = setupWithTileInHand();
+      const occupied = gameReducer(state, placeTile({ row: 0, col: 0 }, 0));
+      const withTile = gameReducer(occupied, drawTile());
+
+      const cases: Array<[GameState, ReturnType<typeof placeTile> | ReturnType<typeof replaceTile>]> = [
+        [state, placeTile({ row: 0, col: 0 }, 0)],
+        [initialState, placeTile({ row: 0, col: 0 }, 0)],
+        [withTile, placeTile({ row: 0, col: 0 }, 0)],
+        [withTile, replaceTile({ row: 0, col: 0 }, 0)],
+        [withTile, replaceTile({ row: 1, col: 0 }, 0)],
+        [state, { ...placeTile({ row: 0, col: 0 }, 0), playerId: 'user-2' }],
+        [state, { ...placeTile({ row: 0, col: 0 }, 0), playerId: 'user-1' }],
+        [state, placeTile({ row: 3, col: 3 }, 0)],
+        [state, placeTile({ row: -3, col: -3 }, 0)],
+        [state, placeTile({ row: 4, col: 0 }, 0)],
+      ];
+
+      for (const [before, action] of cases) {
+        const board = new Map(before.board);
+        const result = validateTileAction(before, action);
+
+        expect(before.board).toEqual(board);
+        expect(result.valid).toBe(gameReducer(before, action) !== before);
+      }
+    });
+
+    it('should explain why an action is rejected', () => {
+      const state = setupWithTileInHand();
+
+      expect(validateTileAction(initialState, placeTile({ row: 0, col: 0 }, 0))).toEqual({
+        valid: false,
+        reason: 'No tile in hand',
+      });
+      expect(validateTileAction(state, replaceTile({ row: 0, col: 0 }, 0))).toEqual({
+        valid: false,
+        reason: 'Position is empty',
+      });
+      const result = validateTileAction(state, { ...placeTile({ row: 0, col: 0 }, 0), playerId: 'user-2' });
+      expect(result.valid).toBe(false);
+   
+    it('should reject placements in the corners outside the hexagon', () => {
+      const state = setupWithTileInHand();
+
+      for (const position of [{ row: 3, col: 3 }, { row: -3, col: -3 }, { row: 3, col: 1 }, { row: -2, col: -2 }]) {
+        expect(validateTileAction(state, placeTile(position, 0))).toEqual({
+          valid: false,
+          reason: 'Position is off the board',
+        });
+        expect(gameReducer(state, placeTile(position, 0))).toBe(state);
+      }
+
+      // The corners of the hexagon itself are on the board
+      expect(validateTileAction(state, placeTile({ row: 3, col: -3 }, 0))).toEqual({ valid: true });
+      expect(validateTileAction(state, placeTile({ row: -3, col: 3 }, 0))).toEqual({ valid: true });
+    });
+  });
+
   describe('NEXT_PLAYER', () => {
     it('should advance to next player', () => {
       let state = gameReducer(initialState, setupGame([
@@ -235,6 +357,275 @@ describe('gameReducer - Gameplay Actions', () => {
     });
   });
 
+  describe('UNDO_MOVE', () => {
+    const startGame = (p2IsAI: boolean) => {
+      const players = [
+        { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+        { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: p2IsAI },
+      ];
+      let state = gameReducer(initialState, setupGame(players, []));
+      state = gameReducer(state, shuffleTiles(42));
+      return gameReducer(state, drawTile());
+    };
+    const playMove = (state: GameState, row: number, col: number) => {
+      state = gameReducer(state, placeTile({ row, col }, 0));
+      state = gameReducer(state, nextPlayer());
+      return gameReducer(state, drawTile());
+    };
+
+    it('should take back the last move with the same tile in hand and bag', () => {
+      const before = startGame(false);
+      let state = playMove(before, 0, 0);
+      state = playMove(state, 1, 0);
+
+      state = gameReducer(state, undoMove());
+
+      expect(state.board.size).toBe(1);
+      expect(state.currentPlayerIndex).toBe(1);
+      expect(state.moveHistory).toHaveLength(1);
+      expect(state.lastPlacedTilePosition).toEqual({ row: 0, col: 0 });
+
+      state = gameReducer(state, undoMove());
+
+      expect(state.board.size).toBe(0);
+      expect(state.currentTile).toBe(before.currentTile);
+      expect(state.availableTiles).toEqual(before.availableTiles);
+      expect(state.currentPlayerIndex).toBe(0);
+      expect(state.lastPlacedTilePosition).toBeNull();
+    });
+
+    it('should take back AI replies along with the human move', () => {
+      const before = startGame(true);
+      let state = playMove(before, 0, 0);
+      state = playMove(state, 1, 0);
+      expect(getUndoableMoveCount(state)).toBe(2);
+
+      state = gameReducer(state, undoMove());
+
+      expect(state.board.size).toBe(0);
+      expect(state.moveHistory).toEqual([]);
+      expect(state.currentTile).toBe(before.currentTile);
+      expect(state.availableTiles).toEqual(before.availableTiles);
+      expect(state.currentPlayerIndex).toBe(0);
+    });
+
+    it('should ignore undo with no move, after a replacement, or once the game is over', () => {
+      const fresh = startGame(false);
+      expect(gameReducer(fresh, undoMove())).toBe(fresh);
+
+      let replaced = playMove(fresh, 0, 0);
+      replaced = gameReducer(replaced, replaceTile({ row: 0, col: 0 }, 1, true));
+      expect(getUndoableMoveCount(replaced)).toBe(0);
+
+      const finished = { ...playMove(fresh, 0, 0), phase: 'finished' as const };
+      expect(gameReducer(finished, undoMove())).toBe(finished);
+    });
+  });
+
+  describe('SWAP_SIDES', () => {
+    const players = [
+      { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+      { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: false },
+    ];
+    const startGame = (gamePlayers = players) => {
+      let state = gameReducer(initialState, setupGame(gamePlayers, []));
+      state = gameReducer(state, shuffleTiles(42));
+      return gameReducer(state, drawTile());
+    };
+    const playMove = (state: GameState, row: number, col: number) => {
+      state = gameReducer(state, placeTile({ row, col }, 0));
+      state = gameReducer(state, nextPlayer());
+      return gameReducer(state, drawTile());
+    };
+
+    it('should give the second player the opening side and pass the turn back', () => {
+      // The opening tile sits on p1's edge, so its flow belongs to whoever holds edge 0
+      const opened = playMove(startGame(), -3, 1);
+      expect(opened.flows.get('p1')?.has('-3,1')).toBe(true);
+
+      const state = gameReducer(opened, swapSides('p2'));
+
+      expect(state.players.map((p) => p.edgePosition)).toEqual([3, 0]);
+      expect(state.currentPlayerIndex).toBe(0);
+      expect(state.currentTile).toBe(opened.currentTile);
+      expect(state.flows.get('p2')?.has('-3,1')).toBe(true);
+      expect(state.flows.get('p1')?.has('-3,1') ?? false).toBe(false);
+    });
+
+    it('should only allow the swap on the second player\'s first turn', () => {
+      const fresh = startGame();
+      const opened = playMove(fresh, -3, 1);
+      const later = playMove(opened, 3, -3);
+      const threePlayer = playMove(
+        startGame([...players, { id: 'p3', color: '#029E73', edgePosition: 1, isAI: false }]),
+        -3,
+        1,
+      );
+
+      expect(gameReducer(fresh, swapSides('p2'))).toBe(fresh);
+      expect(gameReducer(opened, swapSides('p1'))).toBe(opened);
+      expect(gameReducer(later, swapSides('p2'))).toBe(later);
+      expect(gameReducer(threePlayer, swapSides('p2'))).toBe(threePlayer);
+    });
+
+    it('should not let the opening player swap on the second player\'s behalf', () => {
+      const seated = players.map((p, i) => ({ ...p, userId: `google:${i + 1}` }));
+      const opened = playMove(startGame(seated), -3, 1);
+
+      const forged = { ...swapSides('p2'), playerId: 'google:1' };
+      expect(gameReducer(opened, forged)).toBe(opened);
+
+      const genuine = gameReducer(opened, { ...swapSides('p2'), playerId: 'google:2' });
+      expect(genuine.players.map((p) => p.edgePosition)).toEqual([3, 0]);
+    });
+
+    it('should keep the opening move and the swapped sides through an undo', () => {
+      const swapped = gameReducer(playMove(startGame(), -3, 1), swapSides('p2'));
+
+      expect(getUndoableMoveCount(swapped)).toBe(0);
+      expect(gameReducer(swapped, undoMove())).toBe(swapped);
+
+      // A later move can still be taken back, leaving the players on their swapped edges
+      const state = gameReducer(playMove(swapped, 3, -3), undoMove());
+
+      expect(state.moveHistory).toHaveLength(1);
+      expect(state.board.has('-3,1')).toBe(true);
+      expect(state.players.map((p) => p.edgePosition)).toEqual([3, 0]);
+      expect(state.currentPlayerIndex).toBe(0);
+      expect(state.currentTile).toBe(swapped.currentTile);
+    });
+  });
+
+  describe('RESIGN', () => {
+    const threePlayers = [
+      { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+      { id: 'p2', color: '#DE8F05', edgePosition: 2, isAI: false },
+      { id: 'p3', color: '#029E73', edgePosition: 4, isAI: false },
+    ];
+
+    it('should award the game to the last player standing', () => {
+      let state = gameReducer(initialState, setupGame(threePlayers.slice(0, 2), []));
+
+      state = gameReducer(state, resign('p2'));
+
+      expect(state.phase).toBe('finished');
+      expect(state.winners).toEqual(['p1']);
+      expect(state.winType).toBe('resignation');
+      expect(state.screen).toBe('game-over');
+      expect(state.resignedPlayerIds).toEqual(['p2']);
+    });
+
+    it('should keep playing while more than one player remains', () => {
+      let state = gameReducer(initialState, setupGame(threePlayers, []));
+
+      state = gameReducer(state, resign('p2'));
+
+      expect(state.phase).toBe('playing');
+      expect(state.resignedPlayerIds).toEqual(['p2']);
+      expect(state.currentPlayerIndex).toBe(0);
+
+      // Turn advancement skips the resigned player
+      state = gameReducer(state, nextPlayer());
+      expect(state.currentPlayerIndex).toBe(2);
+      state = gameReducer(state, nextPlayer());
+      expect(state.currentPlayerIndex).toBe(0);
+    });
+
+    it('should pass the turn and tile in hand on when the current player resigns', () => {
+      let state = gameReducer(initialState, setupGame(threePlayers, []));
+      state = { ...state, currentTile: TileType.OneSharp, supermoveInProgress: true };
+
+      state = gameReducer(state, resign('p1'));
+
+      expect(state.currentPlayerIndex).toBe(1);
+      expect(state.currentTile).toBe(TileType.OneSharp);
+      expect(state.supermoveInProgress).toBe(false);
+    });
+
+    it('should keep a team in the game while either partner remains', () => {
+      const players = [
+        { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+        { id: 'p2', color: '#DE8F05', edgePosition: 1, isAI: false },
+        { id: 'p3', color: '#029E73', edgePosition: 3, isAI: false },
+        { id: 'p4', color: '#D55E00', edgePosition: 4, isAI: false },
+      ];
+      const teams = [
+        { player1Id: 'p1', player2Id: 'p3' },
+        { player1Id: 'p2', player2Id: 'p4' },
+      ];
+      let state = gameReducer(initialState, setupGame(players, teams));
+
+      state = gameReducer(state, resign('p2'));
+      expect(state.phase).toBe('playing');
+
+      state = gameReducer(state, resign('p4'));
+      expect(state.phase).toBe('finished');
+      expect(state.winners).toEqual(['p1', 'p3']);
+      expect(state.winType).toBe('resignation');
+    });
+
+    it('should only let a multiplayer user resign their own seat', () => {
+      const seated = threePlayers.slice(0, 2).map((p, i) => ({ ...p, userId: `google:${i + 1}` }));
+      const state = gameReducer(initialState, setupGame(seated, []));
+
+      // p1's user posts a resignation on p2's behalf
+      const forged = { ...resign('p2'), playerId: 'google:1' };
+      expect(gameReducer(state, forged)).toBe(state);
+
+      const genuine = gameReducer(state, { ...resign('p2'), playerId: 'google:2' });
+      expect(genuine.phase).toBe('finished');
+      expect(genuine.winners).toEqual(['p1']);
+    });
+
+    it('should ignore repeat, unknown, and post-game resignations', () => {
+      let state = gameReducer(initialState, setupGame(threePlayers, []));
+      state = gameReducer(state, resign('p3'));
+
+      expect(gameReducer(state, resign('p3'))).toBe(state);
+      expect(gameReducer(state, resign('nobody'))).toBe(state);
+
+      state = gameReducer(state, resign('p2'));
+      expect(state.phase).toBe('finished');
+      expect(gameReducer(state, resign('p1'))).toBe(state);
+    });
+  });
+
+  describe('TURN_TIMEOUT', () => {
+    const threePlayers = [
+      { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+      { id: 'p2', color: '#DE8F05', edgePosition: 2, isAI: false },
+      { id: 'p3', color: '#029E73', edgePosition: 4, isAI: false },
+    ];
+
+    it('should resign the player on turn', () => {
+      let state = gameReducer(initialState, setupGame(threePlayers, []));
+      state = gameReducer(state, nextPlayer());
+
+      state = gameReducer(state, turnTimeout());
+
+      expect(state.resignedPlayerIds).toEqual(['p2']);
+      expect(state.currentPlayerIndex).toBe(2);
+      expect(state.phase).toBe('playing');
+    });
+
+    it('should end a two-player game in favor of the waiting player', () => {
+      let state = gameReducer(initialState, setupGame(threePlayers.slice(0, 2), []));
+
+      state = gameReducer(state, turnTimeout());
+
+      expect(state.phase).toBe('finished');
+      expect(state.winners).toEqual(['p2']);
+      expect(state.winType).toBe('resignation');
+    });
+
+    it('should be ignored once the game is over', () => {
+      let state = gameReducer(initialState, setupGame(threePlayers.slice(0, 2), []));
+      state = gameReducer(state, resign('p2'));
+
+      expect(gameReducer(state, turnTimeout())).toBe(state);
+    });
+  });
+
   describe('END_GAME', () => {
     it('should set winner and end game', () => {
       const state = gameReducer(initialState, endGame(['p1'], 'flow'));
@@ -251,6 +642,9 @@ describe('gameReducer - Gameplay Actions', () => {
 
       const state2 = gameReducer(initialState, endGame(['p2'], 'tie'));
       expect(state2.winType).toBe('tie');
+
+      const state3 = gameReducer(initialState, endGame([], 'draw'));
+      expect(state3.winType).toBe('draw');
     });
   });
 
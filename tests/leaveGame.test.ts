@@ -0,0 +1,59 @@
+// Tests for leaving a multiplayer game from the exit button
+
+import { describe, it, expect, beforeEach, vi } from 'vitest';
+
+vi.mock('../src/multiplayer/socket', () => ({
+  socket: {
+    leaveRoom: vi.fn(),
+    leaveSpectator: vi.fn(),
+  },
+}));
+
+vi.mock('../src/multiplayer/router', () => ({
+  Router: {
+    navigate: vi.fn(),
+    isDiscordContext: () => false,
+  },
+}));
+
+import { leaveGame } from '../src/multiplayer/leaveGame';
+import { socket } from '../src/multiplayer/socket';
+import { multiplayerStore } from '../src/multiplayer/stores/multiplayerStore';
+import { store } from '../src/redux/store';
+import { addPlayer } from '../src/redux/actions';
+import { PLAYER_COLORS } from '../src/redux/types';
+
+describe('leaveGame', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    multiplayerStore.reset();
+    multiplayerStore.setScreen('game', { id: 'game-1' });
+    multiplayerStore.setGameId('game-1');
+  });
+
+  it('should leave the room and return a player to the lobby', () => {
+    leaveGame();
+
+    expect(socket.leaveRoom).toHaveBeenCalledWith('game-1');
+    expect(socket.leaveSpectator).not.toHaveBeenCalled();
+    expect(multiplayerStore.get().screen).toBe('lobby');
+  });
+
+  it('should stop spectating instead of leaving the room', () => {
+    multiplayerStore.setIsSpectator(true);
+
+    leaveGame();
+
+    expect(socket.leaveSpectator).toHaveBeenCalledWith('game-1');
+    expect(socket.leaveRoom).not.toHaveBeenCalled();
+    expect(multiplayerStore.get().isSpectator).toBe(false);
+  });
+
+  it('should reset the local game', () => {
+    store.dispatch(addPlayer(PLAYER_COLORS[0], 0));
+
+    leaveGame();
+
+    expect(store.getState().game.configPlayers).toHaveLength(0);
+  });
+});
@@ -2,7 +2,7 @@
 
 import { describe, it, expect, beforeEach, vi } from 'vitest';
 import { store } from '../src/redux/store';
-import { setGameMode } from '../src/redux/actions';
+import { setGameMode, addPlayer } from '../src/redux/actions';
 import { multiplayerStore } from '../src/multiplayer/stores/multiplayerStore';
 import { LobbyInputHandler } from '../src/input/lobbyInputHandler';
 import { LobbyLayout } from '../src/rendering/lobbyLayout';
@@ -69,6 +69,55 @@ describe('LobbyInputHandler - userId propagation', () => {
     console.log('✓ ADD_PLAYER action includes userId:', addPlayerAction.payload.userId);
   });
 
+  it('should use the color chosen in the room when in multiplayer mode', () => {
+    store.dispatch(setGameMode('multiplayer', 'test-user-id'));
+
+    const mockUserId = 'google:123456789';
+    multiplayerStore.setUsername('TestUser', mockUserId);
+    multiplayerStore.setCurrentRoom({
+      id: 'room-1',
+      name: 'Room',
+      hostId: mockUserId,
+      maxPlayers: 2,
+      status: 'playing',
+      players: [{ id: mockUserId, username: 'TestUser', color: '#029E73' }],
+    });
+
+    const mockLayout: Partial<LobbyLayout> = {
+      edgeButtons: [
+        {
+          x: 100,
+          y: 100,
+          size: 50,
+          color: '#FF0000',
+          edge: 0,
+          rotation: 0
+        }
+      ],
+      exitButtons: [],
+      helpButtons: [],
+      backButtons: [],
+      playerLists: [],
+      settingsButton: { x: 0, y: 0, size: 0 },
+      startButton: { x: 0, y: 0, size: 0, enabled: false },
+      settingsDialog: null
+    };
+
+    const dispatchSpy = vi.spyOn(store, 'dispatch');
+
+    handler.handleClick(125, 115, mockLayout as LobbyLayout);
+
+    const addPlayerAction = dispatchSpy.mock.calls
+      .map(call => call[0] as any)
+      .find(action => action && action.type === 'ADD_PLAYER');
+
+    expect(addPlayerAction.payload.color).toBe('#029E73');
+    expect(addPlayerAction.payload.edge).toBe(0);
+
+    multiplayerStore.setCurrentRoom(null);
+    dispatchSpy.mockRestore();
+  });
+
   it('should NOT include userId in ADD_PLAYER action when in tabletop mode', () => {
     // Set up tabletop mode
     store.dispatch(setGameMode('tabletop'));
@@ -114,4 +163,37 @@ describe('LobbyInputHandler - userId propagation', () => {
     
     console.log('✓ ADD_PLAYER action in tabletop mode does not include userId');
   });
+
+  it('should toggle AI control when a player\'s colour swatch is tapped', () => {
+    store.dispatch(setGameMode('tabletop'));
+    store.dispatch(addPlayer('#FF0000', 0));
+    const player = store.getState().game.configPlayers[0];
+
+    const mockLayout: Partial<LobbyLayout> = {
+      edgeButtons: [],
+      exitButtons: [],
+      helpButtons: [],
+      backButtons: [],
+      playerLists: [[
+        {
+          player,
+          x: 0,
+          y: 0,
+          width: 200,
+          height: 50,
+          edge: 0,
+          rotation: 0,
+          removeButton: { x: 170, y: 10, size: 25 },
+          aiButton: { x: 10, y: 10, size: 30 },
+        },
+      ]],
+      settingsButton: { x: 0, y: 0, size: 0 },
+      startButton: { x: 0, y: 0, size: 0, enabled: false },
+      settingsDialog: null
+    };
+
+    handler.handleClick(25, 25, mockLayout as LobbyLayout);
+
+    expect(store.getState().game.configPlayers[0].isAI).toBe(true);
+  });
 });
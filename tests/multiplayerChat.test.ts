@@ -0,0 +1,32 @@
+// Tests for the room chat history kept in the multiplayer store
+
+import { describe, it, expect, beforeEach } from 'vitest';
+import { multiplayerStore, MAX_CHAT_MESSAGES } from '../src/multiplayer/stores/multiplayerStore';
+
+function chatMessage(text: string, timestamp: number) {
+  return { gameId: 'game-1', playerId: 'player-1', username: 'Alice', text, timestamp };
+}
+
+describe('multiplayerStore - chat', () => {
+  beforeEach(() => {
+    multiplayerStore.reset();
+  });
+
+  it('should append chat messages in arrival order', () => {
+    multiplayerStore.addChatMessage(chatMessage('hello', 1));
+    multiplayerStore.addChatMessage(chatMessage('good luck', 2));
+
+    expect(multiplayerStore.get().chatMessages.map(m => m.text)).toEqual(['hello', 'good luck']);
+  });
+
+  it('should keep only the most recent messages', () => {
+    for (let i = 0; i < MAX_CHAT_MESSAGES + 5; i++) {
+      multiplayerStore.addChatMessage(chatMessage(`message ${i}`, i));
+    }
+
+    const messages = multiplayerStore.get().chatMessages;
+    expect(messages).toHaveLength(MAX_CHAT_MESSAGES);
+    expect(messages[0].text).toBe('message 5');
+    expect(messages[messages.length - 1].text).toBe(`message ${MAX_CHAT_MESSAGES + 4}`);
+  });
+});
@@ -0,0 +1,89 @@
+// Tests for animating the flows of tiles placed by other players
+
+import { describe, it, expect, beforeEach } from 'vitest';
+import {
+  triggerOpponentFlowAnimation,
+  updateFlowPreview,
+  hasFlowAnimation,
+  hasOpponentFlowAnimation,
+  getFlowPreviewData,
+} from '../src/animation/flowPreview';
+import { getAnimationFunction, clearAnimations } from '../src/animation/registry';
+import { getEdgePositions, positionToKey } from '../src/game/board';
+import { PlacedTile, Player, TileType } from '../src/game/types';
+import { Move } from '../src/redux/types';
+
+describe('Opponent Flow Animation', () => {
+  const players: Player[] = [
+    { id: 'P1', color: '#0173B2', edgePosition: 0, isAI: false },
+    { id: 'P2', color: '#DE8F05', edgePosition: 3, isAI: true },
+  ];
+
+  // A game where P2 has just placed a tile on P1's edge, extending P1's flow
+  const createGame = () => {
+    const tile: PlacedTile = {
+      type: TileType.NoSharps,
+      rotation: 0,
+      position: getEdgePositions(0, 3)[1],
+    };
+    const moveHistory: Move[] = [{ playerId: 'P2', tile, timestamp: 0 }];
+    const board = new Map<string, PlacedTile>([[positionToKey(tile.position), tile]]);
+    return { board, moveHistory, players, boardRadius: 3 };
+  };
+
+  // Run every flow animation to completion
+  const finishAnimations = () => {
+    Object.keys(getFlowPreviewData()).forEach((name) => getAnimationFunction(name)?.(1));
+  };
+
+  beforeEach(() => {
+    (global as any).window = {
+      __REDUX_STORE__: {
+        getState: () => ({
+          animation: { frameCounter: 0, paused: false, animations: [] },
+        }),
+      },
+    };
+    clearAnimations();
+  });
+
+  it('should report no animation before anything is triggered', () => {
+    expect(hasFlowAnimation()).toBe(false);
+    expect(hasOpponentFlowAnimation()).toBe(false);
+  });
+
+  it('should animate the flows extended by the placed tile', () => {
+    const game = createGame();
+    triggerOpponentFlowAnimation(game, game.moveHistory[0].tile.position);
+
+    const segments = Object.values(getFlowPreviewData());
+    expect(segments.length).toBeGreaterThan(0);
+    expect(segments.every((segment) => segment.opponent && segment.playerId === 'P1')).toBe(true);
+    expect(hasFlowAnimation()).toBe(true);
+    expect(hasOpponentFlowAnimation()).toBe(true);
+  });
+
+  it('should stop reporting the animation once it completes', () => {
+    const game = createGame();
+    triggerOpponentFlowAnimation(game, game.moveHistory[0].tile.position);
+    finishAnimations();
+
+    expect(hasFlowAnimation()).toBe(false);
+    expect(hasOpponentFlowAnimation()).toBe(false);
+  });
+
+  it('should keep animating when the local hover preview changes', () => {
+    const game = createGame();
+    triggerOpponentFlowAnimation(game, game.moveHistory[0].tile.position);
+    updateFlowPreview(null, 0, null);
+
+    expect(hasOpponentFlowAnimation()).toBe(true);
+  });
+
+  it('should do nothing for a position without a placed tile', () => {
+    const game = createGame();
+    triggerOpponentFlowAnimation(game, { row: 0, col: 0 });
+
+    expect(hasFlowAnimation()).toBe(false);
+  });
+});
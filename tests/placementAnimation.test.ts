@@ -0,0 +1,131 @@
+// Tests for the fade-in of tiles placed by other players
+
+import { describe, it, expect, beforeEach, vi } from 'vitest';
+import {
+  detectNewPlacedTile,
+  initPlacedTileAnimation,
+  animateArrivedMove,
+  placedTileAnimationState,
+  PLACED_TILE_FADE_IN_FRAMES,
+} from '../src/animation/placementAnimations';
+import { getAnimationFunction } from '../src/animation/registry';
+import { hasOpponentFlowAnimation } from '../src/animation/flowPreview';
+import { store } from '../src/redux/store';
+import { Move } from '../src/redux/types';
+import { PlacedTile, Player, TileType } from '../src/game/types';
+import { getEdgePositions, positionToKey } from '../src/game/board';
+
+describe('Placement Animations', () => {
+  const createMove = (playerId: string, row: number, col: number): Move => ({
+    playerId,
+    tile: { type: TileType.NoSharps, rotation: 0, position: { row, col } },
+    timestamp: 0,
+  });
+
+  beforeEach(() => {
+    (global as any).window = {
+      __REDUX_STORE__: {
+        dispatch: vi.fn(),
+        getState: vi.fn(() => ({
+          animation: { frameCounter: 0, paused: false, animations: [] },
+        })),
+      },
+    };
+  });
+
+  describe('detectNewPlacedTile', () => {
+    it('should detect a tile placed by an opponent since the last frame', () => {
+      const history = [createMove('P1', 0, 0), createMove('P2', 1, 0)];
+      expect(detectNewPlacedTile(1, history, 'P1')).toBe(history[1]);
+    });
+
+    it('should ignore tiles placed by the local player', () => {
+      const history = [createMove('P2', 0, 0), createMove('P1', 1, 0)];
+      expect(detectNewPlacedTile(1, history, 'P1')).toBeNull();
+    });
+
+    it('should not fire again once the move has been seen', () => {
+      const history = [createMove('P2', 0, 0)];
+      expect(detectNewPlacedTile(1, history, 'P1')).toBeNull();
+    });
+
+    it('should not fire when the history shrinks (rematch)', () => {
+      expect(detectNewPlacedTile(5, [], 'P1')).toBeNull();
+    });
+
+    it('should not fire in tabletop mode without a local player', () => {
+      const history = [createMove('P2', 0, 0)];
+      expect(detectNewPlacedTile(0, history, null)).toBeNull();
+    });
+  });
+
+  describe('initPlacedTileAnimation', () => {
+    it('should register the fade animation for the new tile', () => {
+      const mockDispatch = vi.fn();
+      (global as any).window.__REDUX_STORE__.dispatch = mockDispatch;
+
+      initPlacedTileAnimation({ row: 1, col: 0 });
+
+      expect(placedTileAnimationState.position).toEqual({ row: 1, col: 0 });
+      expect(placedTileAnimationState.opacity).toBe(0);
+      expect(mockDispatch).toHaveBeenCalledWith(
+        expect.objectContaining({
+          type: 'REGISTER_ANIMATION',
+          payload: expect.objectContaining({ animationName: 'placed-tile-fade' }),
+        })
+      );
+    });
+
+    it('should fade in, then pulse, then clear', () => {
+      initPlacedTileAnimation({ row: 1, col: 0 });
+      const animFn = getAnimationFunction('placed-tile-fade')!;
+      expect(animFn).toBeDefined();
+
+      animFn(0.2);
+      expect(placedTileAnimationState.opacity).toBeCloseTo(0.5);
+      expect(placedTileAnimationState.pulseIntensity).toBe(0);
+
+      animFn(0.7);
+      expect(placedTileAnimationState.opacity).toBe(1.0);
+      expect(placedTileAnimationState.pulseIntensity).toBeCloseTo(1.0);
+
+      animFn(1);
+      expect(placedTileAnimationState.position).toBeNull();
+      expect(placedTileAnimationState.pulseIntensity).toBe(0);
+    });
+  });
+
+  describe('animateArrivedMove', () => {
+    const players: Player[] = [
+      { id: 'P1', color: '#0173B2', edgePosition: 0, isAI: false },
+      { id: 'P2', color: '#DE8F05', edgePosition: 3, isAI: true },
+    ];
+
+    it('should fade the tile in, then draw the flows it extends', () => {
+      const tile: PlacedTile = {
+        type: TileType.NoSharps,
+        rotation: 0,
+        position: getEdgePositions(0, 3)[1],
+      };
+      const move: Move = { playerId: 'P2', tile, timestamp: 0 };
+      const game = {
+        board: new Map([[positionToKey(tile.position), tile]]),
+        moveHistory: [move],
+        players,
+        boardRadius: 3,
+      };
+
+      animateArrivedMove(game, move);
+
+      expect(placedTileAnimationState.position).toEqual(tile.position);
+      expect(hasOpponentFlowAnimation()).toBe(true);
+
+      // The first flow segment waits for the fade-in to finish
+      const flowAnimations = store
+        .getState()
+        .animation.animations.filter((anim) => anim.animationName.startsWith('flow-preview-'));
+      expect(flowAnimations.length).toBeGreaterThan(0);
+      expect(Math.min(...flowAnimations.map((anim) => anim.startFrame))).toBe(PLACED_TILE_FADE_IN_FRAMES);
+    });
+  });
+});
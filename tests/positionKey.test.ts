@@ -0,0 +1,98 @@
+// Tests for position keys identifying a game's board and turn state
+
+import { describe, it, expect } from 'vitest';
+import { getPositionKey, gamesEqual } from '../src/redux/positionKey';
+import { gameReducer, initialState } from '../src/redux/gameReducer';
+import { setupGame, placeTile, nextPlayer } from '../src/redux/actions';
+import { GameState } from '../src/redux/types';
+import { HexPosition, Rotation, TileType } from '../src/game/types';
+
+const players = [
+  { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+  { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: false },
+];
+
+function createGame(): GameState {
+  const state = gameReducer(initialState, setupGame(players, []));
+  return {
+    ...state,
+    availableTiles: [TileType.NoSharps, TileType.TwoSharps, TileType.OneSharp],
+  };
+}
+
+// Place a tile for the current player and pass the turn
+function play(state: GameState, type: TileType, position: HexPosition, rotation: Rotation): GameState {
+  state = gameReducer({ ...state, currentTile: type }, placeTile(position, rotation));
+  return gameReducer(state, nextPlayer());
+}
+
+describe('getPositionKey', () => {
+  it('should match games reached by transposed move orders', () => {
+    let first = createGame();
+    first = play(first, TileType.NoSharps, { row: -3, col: 0 }, 0);
+    first = play(first, TileType.OneSharp, { row: 3, col: 0 }, 2);
+    first = play(first, TileType.TwoSharps, { row: -3, col: 1 }, 1);
+    first = play(first, TileType.ThreeSharps, { row: 3, col: -1 }, 4);
+
+    let second = createGame();
+    second = play(second, TileType.TwoSharps, { row: -3, col: 1 }, 1);
+    second = play(second, TileType.ThreeSharps, { row: 3, col: -1 }, 4);
+    second = play(second, TileType.NoSharps, { row: -3, col: 0 }, 0);
+    second = play(second, TileType.OneSharp, { row: 3, col: 0 }, 2);
+
+    expect(second.moveHistory).not.toEqual(first.moveHistory);
+    expect(getPositionKey(second)).toBe(getPositionKey(first));
+  });
+
+  it('should differ after a single differing rotation', () => {
+    const base = createGame();
+
+    const a = play(base, TileType.OneSharp, { row: -3, col: 0 }, 0);
+    const b = play(base, TileType.OneSharp, { row: -3, col: 0 }, 1);
+
+    expect(getPositionKey(a)).not.toBe(getPositionKey(b));
+  });
+
+  it('should differ by the player on turn and the bag contents', () => {
+    const base = createGame();
+
+    expect(getPositionKey(gameReducer(base, nextPlayer()))).not.toBe(getPositionKey(base));
+    expect(getPositionKey({ ...base, availableTiles: [TileType.NoSharps] })).not.toBe(getPositionKey(base));
+  });
+
+  it('should ignore the order of tiles in the bag', () => {
+    const base = createGame();
+    const reordered = { ...base, availableTiles: [...base.availableTiles].reverse() };
+
+    expect(getPositionKey(reordered)).toBe(getPositionKey(base));
+  });
+
+  it('should handle a game that has not been set up', () => {
+    expect(getPositionKey(initialState)).toBe('|||||0,0,0,0');
+  });
+});
+
+describe('gamesEqual', () => {
+  it('should match a game replayed from the same moves', () => {
+    const first = play(createGame(), TileType.NoSharps, { row: -3, col: 0 }, 0);
+    const second = play(createGame(), TileType.NoSharps, { row: -3, col: 0 }, 0);
+
+    expect(gamesEqual(first, second)).toBe(true);
+  });
+
+  it('should ignore flows', () => {
+    const game = play(createGame(), TileType.NoSharps, { row: -3, col: 0 }, 0);
+
+    expect(gamesEqual({ ...game, flows: new Map(), flowEdges: new Map() }, game)).toBe(true);
+  });
+
+  it('should differ by tile rotation, bag order, and outcome', () => {
+    const base = createGame();
+    const a = play(base, TileType.OneSharp, { row: -3, col: 0 }, 0);
+    const b = play(base, TileType.OneSharp, { row: -3, col: 0 }, 1);
+
+    expect(gamesEqual(a, b)).toBe(false);
+    expect(gamesEqual({ ...base, availableTiles: [...base.availableTiles].reverse() }, base)).toBe(false);
+    expect(gamesEqual({ ...base, winners: ['p1'], winType: 'flow' }, base)).toBe(false);
+  });
+});
@@ -5,19 +5,24 @@ import {
   gameReducer,
   initialState,
   resetPlayerIdCounter,
+  calculateTileCountsFromRatio,
 } from "../src/redux/gameReducer";
 import {
   addPlayer,
   removePlayer,
   changePlayerColor,
+  setPlayerAI,
   startGame,
   returnToConfig,
   selectEdge,
   setAIScoringData,
   resetGame,
   restoreGame,
+  endGame,
+  rematchGame,
+  shuffleTiles,
 } from "../src/redux/actions";
-import { MAX_PLAYERS, PLAYER_COLORS } from "../src/redux/types";
+import { MAX_PLAYERS, PLAYER_COLORS, DEFAULT_GAME_SETTINGS } from "../src/redux/types";
 import { rootReducer } from "../src/redux/reducer";
 
 describe("gameReducer", () => {
@@ -261,6 +266,47 @@ describe("gameReducer", () => {
     });
   });
 
+  describe("SET_PLAYER_AI", () => {
+    it("should hand any seat to the AI and back", () => {
+      let state = initialState;
+      state = gameReducer(state, addPlayer(PLAYER_COLORS[0], 0));
+      state = gameReducer(state, addPlayer(PLAYER_COLORS[1], 1));
+      state = gameReducer(state, addPlayer(PLAYER_COLORS[2], 2));
+
+      // The AI takes the second seat of three, leaving the first to a person
+      const secondId = state.configPlayers[1].id;
+      state = gameReducer(state, setPlayerAI(secondId, true));
+      expect(state.configPlayers.map((p) => p.isAI)).toEqual([false, true, false]);
+
+      state = gameReducer(state, setPlayerAI(secondId, false));
+      expect(state.configPlayers.map((p) => p.isAI)).toEqual([false, false, false]);
+    });
+
+    it("should carry the AI seat into the game without adding another AI", () => {
+      resetPlayerIdCounter();
+      let state = initialState;
+      state = gameReducer(state, addPlayer(PLAYER_COLORS[0], 0));
+      state = gameReducer(state, addPlayer(PLAYER_COLORS[1], 1));
+      const aiId = state.configPlayers[0].id;
+      state = gameReducer(state, setPlayerAI(aiId, true));
+      state = gameReducer(state, startGame({ seed: 3 }));
+
+      expect(state.configPlayers.length).toBe(2);
+      state.seatingPhase.seatingOrder.forEach((playerId, index) => {
+        state = gameReducer(state, selectEdge(playerId, index * 3));
+      });
+
+      expect(state.players.find((p) => p.id === aiId)?.isAI).toBe(true);
+      expect(state.players.filter((p) => p.isAI).length).toBe(1);
+    });
+
+    it("should ignore unknown players", () => {
+      const state = gameReducer(initialState, addPlayer(PLAYER_COLORS[0], 0));
+
+      expect(gameReducer(state, setPlayerAI("non-existent-id", true))).toBe(state);
+    });
+  });
+
   describe("START_GAME", () => {
     it("should transition to seating screen when players exist", () => {
       let state = initialState;
@@ -369,6 +415,42 @@ describe("gameReducer", () => {
       );
     });
 
+    it("should seat 5 players as individuals racing to opposite edges", () => {
+      let state = initialState;
+      for (let i = 0; i < 5; i++) {
+        state = gameReducer(state, addPlayer(PLAYER_COLORS[i], i % 4));
+      }
+      state = gameReducer(state, startGame());
+
+      // Seat everyone clockwise, leaving edge 5 unoccupied
+      state.seatingPhase.seatingOrder.forEach((playerId, edge) => {
+        state = gameReducer(state, selectEdge(playerId, edge));
+      });
+
+      expect(state.screen).toBe("gameplay");
+      expect(state.players.length).toBe(5);
+      expect(state.teams).toEqual([]);
+    });
+
+    it("should pair 6 players with the player on the opposite edge", () => {
+      let state = initialState;
+      for (let i = 0; i < 6; i++) {
+        state = gameReducer(state, addPlayer(PLAYER_COLORS[i], i % 4));
+      }
+      state = gameReducer(state, startGame());
+
+      state.seatingPhase.seatingOrder.forEach((playerId, edge) => {
+        state = gameReducer(state, selectEdge(playerId, edge));
+      });
+
+      expect(state.screen).toBe("gameplay");
+      expect(state.teams.length).toBe(3);
+      const edgeOf = (id: string) => state.players.find((p) => p.id === id)!.edgePosition;
+      for (const team of state.teams) {
+        expect((edgeOf(team.player1Id) + 3) % 6).toBe(edgeOf(team.player2Id));
+      }
+    });
+
     it("should create teams for 4 players after seating", () => {
       let state = initialState;
       state = gameReducer(state, addPlayer(PLAYER_COLORS[0], 0));
@@ -523,6 +605,45 @@ describe("gameReducer", () => {
     });
   });
 
+  describe("DEFAULT_GAME_SETTINGS", () => {
+    it("should start a two-player game from the default settings", () => {
+      const settings = DEFAULT_GAME_SETTINGS;
+      let state = gameReducer(initialState, addPlayer(PLAYER_COLORS[0], 0));
+      state = gameReducer(state, addPlayer(PLAYER_COLORS[1], 1));
+
+      // Mirror the lobby: shuffle the bag from the distribution ratio, then start
+      const { distribution, totalTiles } = calculateTileCountsFromRatio(
+        settings.boardRadius,
+        settings.tileDistribution,
+      );
+      state = gameReducer(state, shuffleTiles(12345, distribution));
+      state = gameReducer(
+        state,
+        startGame({
+          seed: 12345,
+          boardRadius: settings.boardRadius,
+          supermove: settings.supermove,
+          singleSupermove: settings.singleSupermove,
+          supermoveAnyPlayer: settings.supermoveAnyPlayer,
+          oppositeSidesVictory: settings.oppositeSidesVictory,
+          turnTimeLimit: settings.turnTimeLimit,
+          noSelfStalemate: settings.noSelfStalemate,
+          longestFlowVictory: settings.longestFlowVictory,
+        }),
+      );
+
+      for (const playerId of state.seatingPhase.seatingOrder) {
+        state = gameReducer(state, selectEdge(playerId, state.seatingPhase.availableEdges[0]));
+      }
+
+      expect(state.phase).toBe("playing");
+      expect(state.players).toHaveLength(2);
+      expect(state.boardRadius).toBe(initialState.boardRadius);
+      expect(state.currentTile).not.toBeNull();
+      expect(state.availableTiles.length + 1).toBe(totalTiles);
+    });
+  });
+
   describe("SET_AI_SCORING_DATA", () => {
     it("should set AI scoring data", () => {
       const scoringData = new Map<
@@ -618,3 +739,75 @@ describe("gameReducer", () => {
     });
   });
 });
+
+describe("session scores", () => {
+  it("should count a win for the winner each time a game is decided", () => {
+    let state = rootReducer(undefined, { type: "@@INIT" });
+    state = rootReducer(state, endGame(["P1"], "flow"));
+    expect(state.ui.sessionScores).toEqual({ P1: 1 });
+
+    state = rootReducer(state, rematchGame());
+    state = rootReducer(state, endGame(["P1"], "resignation"));
+    expect(state.ui.sessionScores).toEqual({ P1: 2 });
+  });
+
+  it("should count a win for every player in a shared victory", () => {
+    let state = rootReducer(undefined, { type: "@@INIT" });
+    state = rootReducer(state, endGame(["P1", "P3"], "flow"));
+
+    expect(state.ui.sessionScores).toEqual({ P1: 1, P3: 1 });
+  });
+
+  it("should not count draws or repeated game-over actions", () => {
+    let state = rootReducer(undefined, { type: "@@INIT" });
+    state = rootReducer(state, endGame([], "draw"));
+    expect(state.ui.sessionScores).toEqual({});
+
+    state = rootReducer(state, rematchGame());
+    state = rootReducer(state, endGame(["P2"], "flow"));
+    state = rootReducer(state, endGame(["P2"], "flow"));
+    expect(state.ui.sessionScores).toEqual({ P2: 1 });
+  });
+
+  it("should start over when leaving to the menu", () => {
+    let state = rootReducer(undefined, { type: "@@INIT" });
+    state = rootReducer(state, endGame(["P1"], "flow"));
+    state = rootReducer(state, resetGame());
+
+    expect(state.ui.sessionScores).toEqual({});
+  });
+});
+
+describe("session scores in multiplayer", () => {
+  // Seat two signed-in users; a rematch may seat them under new player IDs
+  const seatUsers = (state: ReturnType<typeof rootReducer>, ids: [string, string]) => ({
+    ...state,
+    game: {
+      ...state.game,
+      players: [
+        { id: ids[0], color: PLAYER_COLORS[0], edgePosition: 0, isAI: false, userId: "google:alice" },
+        { id: ids[1], color: PLAYER_COLORS[1], edgePosition: 3, isAI: false, userId: "google:bob" },
+      ],
+    },
+  });
+
+  it("should key scores by user rather than seat", () => {
+    let state = seatUsers(rootReducer(undefined, { type: "@@INIT" }), ["P1", "P2"]);
+    state = rootReducer(state, endGame(["P2"], "flow"));
+
+    expect(state.ui.sessionScores).toEqual({ "google:bob": 1 });
+  });
+
+  it("should keep the series through the reset on the way into a rematch", () => {
+    let state = seatUsers(rootReducer(undefined, { type: "@@INIT" }), ["P1", "P2"]);
+    state = rootReducer(state, endGame(["P1"], "flow"));
+
+    // The multiplayer client resets the game before the rematch's players are seated again
+    state = rootReducer(state, resetGame(true));
+    expect(state.ui.sessionScores).toEqual({ "google:alice": 1 });
+
+    state = seatUsers(state, ["P4", "P3"]);
+    state = rootReducer(state, endGame(["P4"], "flow"));
+    expect(state.ui.sessionScores).toEqual({ "google:alice": 2 });
+  });
+});
@@ -124,6 +124,37 @@ describe('Rematch Functionality', () => {
     expect(new Set(playerIds).size).toBe(3); // All unique
   });
 
+  it('should reproduce a seeded game and its rematch exactly', () => {
+    const playSeededGame = (seed: number) => {
+      resetPlayerIdCounter();
+      let state = gameReducer(undefined, { type: '@@INIT' } as any);
+      state = gameReducer(state, addPlayer('#0173B2', 0));
+      state = gameReducer(state, addPlayer('#DE8F05', 1));
+      state = gameReducer(state, addPlayer('#029E73', 2));
+      state = gameReducer(state, startGame({ seed }));
+
+      const seatingOrder = state.seatingPhase.seatingOrder;
+      state = gameReducer(state, selectEdge(seatingOrder[0], 0));
+      state = gameReducer(state, selectEdge(seatingOrder[1], 2));
+      state = gameReducer(state, selectEdge(seatingOrder[2], 4));
+      const game = state;
+
+      return { game, rematch: gameReducer(game, rematchGame()) };
+    };
+
+    const first = playSeededGame(2024);
+    const second = playSeededGame(2024);
+
+    expect(second.game.seatingPhase.seatingOrder).toEqual(first.game.seatingPhase.seatingOrder);
+    expect(second.game.availableTiles).toEqual(first.game.availableTiles);
+    expect(second.rematch.players.map((p) => p.id)).toEqual(first.rematch.players.map((p) => p.id));
+    expect(second.rematch.availableTiles).toEqual(first.rematch.availableTiles);
+    expect(second.rematch.currentTile).toBe(first.rematch.currentTile);
+
+    // The rematch draws from its own seed rather than dealing the same bag again
+    expect(first.rematch.seed).toBe(2025);
+  });
+
   it('should clear game state but preserve player configuration on rematch', () => {
     // Start with initial state
     let state = gameReducer(undefined, { type: '@@INIT' } as any);
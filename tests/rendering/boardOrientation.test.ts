@@ -0,0 +1,99 @@
+// Unit tests for board orientation
+import { describe, it, expect } from 'vitest';
+import {
+  getOrientationPlayerId,
+  getBoardRotationAngle,
+  isBoardRotationActive,
+} from '../../src/rendering/boardOrientation';
+import { RootState } from '../../src/redux/types';
+import { initialState as initialGameState } from '../../src/redux/gameReducer';
+import { initialUIState, uiReducer } from '../../src/redux/uiReducer';
+import { setGameMode, setLocalPlayerId, setOrientationOverride, updateSettings } from '../../src/redux/actions';
+import { formatMoveHistory } from '../../src/game/notation';
+import { TileType } from '../../src/game/types';
+
+describe('boardOrientation', () => {
+  const players = [
+    { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+    { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: false },
+  ];
+
+  const createState = (ui = initialUIState): RootState => ({
+    game: {
+      ...initialGameState,
+      players,
+      moveHistory: [
+        { playerId: 'p1', tile: { type: TileType.NoSharps, rotation: 0, position: { row: 0, col: 0 } }, timestamp: 0 },
+        { playerId: 'p2', tile: { type: TileType.OneSharp, rotation: 1, position: { row: 1, col: 0 } }, timestamp: 1 },
+      ],
+    },
+    ui,
+    animation: {} as any,
+  });
+
+  it('should not rotate a tabletop game by default', () => {
+    const state = createState();
+
+    expect(getOrientationPlayerId(state)).toBeNull();
+    expect(isBoardRotationActive(state)).toBe(false);
+    expect(getBoardRotationAngle(state)).toBe(0);
+  });
+
+  it('should orient a multiplayer game to the local player', () => {
+    let ui = uiReducer(initialUIState, setGameMode('multiplayer'));
+    ui = uiReducer(ui, setLocalPlayerId('p1'));
+    const state = createState(ui);
+
+    expect(getOrientationPlayerId(state)).toBe('p1');
+    expect(getBoardRotationAngle(state)).toBe(180);
+  });
+
+  it('should change the rotation without changing the viewer or visible moves', () => {
+    let ui = uiReducer(initialUIState, setGameMode('multiplayer'));
+    ui = uiReducer(ui, setLocalPlayerId('p1'));
+    const before = createState(ui);
+    const after = createState(uiReducer(ui, setOrientationOverride('p2')));
+
+    expect(getBoardRotationAngle(before)).toBe(180);
+    expect(getBoardRotationAngle(after)).toBe(0);
+    expect(isBoardRotationActive(after)).toBe(true);
+
+    // The viewer and everything it can see are unchanged
+    expect(after.ui.localPlayerId).toBe('p1');
+    expect(after.game).toEqual(before.game);
+    expect(formatMoveHistory(after.game.moveHistory, after.game.players, after.game.boardRadius))
+      .toEqual(formatMoveHistory(before.game.moveHistory, before.game.players, before.game.boardRadius));
+  });
+
+  it('should apply the override in tabletop mode too', () => {
+    const state = createState(uiReducer(initialUIState, setOrientationOverride('p2')));
+
+    expect(isBoardRotationActive(state)).toBe(true);
+    expect(getBoardRotationAngle(state)).toBe(0);
+  });
+
+  it('should turn a tabletop game to the player to move when enabled', () => {
+    const ui = uiReducer(initialUIState, updateSettings({ rotateBoardToCurrentPlayer: true }));
+    const first = createState(ui);
+    const second = { ...first, game: { ...first.game, currentPlayerIndex: 1 } };
+
+    expect(getOrientationPlayerId(first)).toBe('p1');
+    expect(getBoardRotationAngle(first)).toBe(180);
+    expect(getOrientationPlayerId(second)).toBe('p2');
+    expect(getBoardRotationAngle(second)).toBe(0);
+  });
+
+  it('should let the analysis override win over following the player to move', () => {
+    let ui = uiReducer(initialUIState, updateSettings({ rotateBoardToCurrentPlayer: true }));
+    ui = uiReducer(ui, setOrientationOverride('p2'));
+
+    expect(getOrientationPlayerId(createState(ui))).toBe('p2');
+  });
+
+  it('should ignore an override for an unknown player', () => {
+    const state = createState(uiReducer(initialUIState, setOrientationOverride('p9')));
+
+    expect(isBoardRotationActive(state)).toBe(false);
+    expect(getBoardRotationAngle(state)).toBe(0);
+  });
+});
@@ -0,0 +1,56 @@
+// Unit tests for board SVG export
+import { describe, it, expect } from 'vitest';
+import { generateBoardSvg } from '../../src/rendering/boardSvg';
+import { calculateFlows } from '../../src/game/flows';
+import { getEdgePositions, positionToKey } from '../../src/game/board';
+import { TileType, PlacedTile, Player } from '../../src/game/types';
+
+describe('Board SVG', () => {
+  const players: Player[] = [
+    { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+    { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: false },
+  ];
+
+  // One tile on player 1's edge, so at least one flow is filled, and one in the center
+  const createGame = () => {
+    const board = new Map<string, PlacedTile>();
+    const tiles: PlacedTile[] = [
+      { type: TileType.NoSharps, rotation: 0, position: getEdgePositions(0, 3)[0] },
+      { type: TileType.ThreeSharps, rotation: 1, position: { row: 0, col: 0 } },
+    ];
+    tiles.forEach((tile) => board.set(positionToKey(tile.position), tile));
+    const { flowEdges } = calculateFlows(board, players, 3);
+    return { board, players, flowEdges, boardRadius: 3 };
+  };
+
+  it('should produce a well-formed SVG document', () => {
+    const svg = generateBoardSvg(createGame());
+
+    expect(svg.startsWith('<svg xmlns="http://www.w3.org/2000/svg"')).toBe(true);
+    expect(svg.trimEnd().endsWith('</svg>')).toBe(true);
+    expect(svg.match(/<g[ >]/g)?.length).toBe(svg.match(/<\/g>/g)?.length);
+  });
+
+  it('should draw three flows per placed tile and an edge per player', () => {
+    const svg = generateBoardSvg(createGame());
+
+    expect(svg.match(/class="tile"/g)).toHaveLength(2);
+    expect(svg.match(/class="flow"/g)).toHaveLength(6);
+    expect(svg.match(/class="player-edge"/g)).toHaveLength(2);
+  });
+
+  it('should colour filled flows with the player colour', () => {
+    const svg = generateBoardSvg(createGame());
+    const flows = svg.split('\n').filter((line) => line.includes('class="flow"'));
+
+    expect(flows.some((line) => line.includes('stroke="#0173B2"'))).toBe(true);
+  });
+
+  it('should draw players in the chosen palette and apply the rotation', () => {
+    const svg = generateBoardSvg(createGame(), { palette: 'colorblind', rotation: 180 });
+
+    expect(svg).toContain('stroke="#4477AA"');
+    expect(svg).not.toContain('stroke="#0173B2"');
+    expect(svg).toContain('rotate(180 ');
+  });
+});
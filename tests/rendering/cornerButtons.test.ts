@@ -0,0 +1,33 @@
+// Unit tests for the corner button layout shared by rendering and hit-testing
+import { describe, it, expect } from 'vitest';
+import {
+  CORNER_BUTTON_SIZE,
+  getCornerButtonPositions,
+} from '../../src/rendering/cornerButtons';
+
+describe('getCornerButtonPositions', () => {
+  const layout = { canvasWidth: 1000, canvasHeight: 800 };
+  const step = CORNER_BUTTON_SIZE * 1.15;
+
+  it('should place slot 0 in each corner, inset by the margin', () => {
+    expect(getCornerButtonPositions(0, layout)).toEqual([
+      { x: 35, y: 765, edge: 0 },
+      { x: 965, y: 765, edge: 1 },
+      { x: 965, y: 35, edge: 2 },
+      { x: 35, y: 35, edge: 3 },
+    ]);
+  });
+
+  it('should move each later slot one button further along its edge', () => {
+    const [bottom, right, top, left] = getCornerButtonPositions(4, layout);
+
+    expect(bottom.x).toBeCloseTo(35 + 4 * step);
+    expect(bottom.y).toBe(765);
+    expect(right.x).toBe(965);
+    expect(right.y).toBeCloseTo(765 - 4 * step);
+    expect(top.x).toBeCloseTo(965 - 4 * step);
+    expect(top.y).toBe(35);
+    expect(left.x).toBe(35);
+    expect(left.y).toBeCloseTo(35 + 4 * step);
+  });
+});
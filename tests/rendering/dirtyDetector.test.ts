@@ -27,6 +27,7 @@ function createMockState(overrides: any = {}): RootState {
       supermove: false,
       singleSupermove: false,
       supermoveAnyPlayer: false,
+      oppositeSidesVictory: false,
       supermoveInProgress: false,
       flowEdges: new Map(),
       phase: 'playing' as const,
@@ -43,6 +44,7 @@ function createMockState(overrides: any = {}): RootState {
       hoveredElement: null,
       currentRotation: 0,
       showLegalMoves: false,
+      legalMovesForHeldRotation: false,
       showFlowMarkers: false,
       animationSpeed: 1,
       zoom: 1,
@@ -53,7 +55,10 @@ function createMockState(overrides: any = {}): RootState {
       showMoveList: false,
       moveListCorner: null,
       moveListIndex: -1,
+      replayPlaying: false,
+      replaySpeed: 1,
       savedGameState: null,
+      sessionScores: {},
       disconnectedPlayers: new Set(),
       userIdToPlayerId: new Map(),
       isSpectator: false,
@@ -63,6 +68,14 @@ function createMockState(overrides: any = {}): RootState {
         supermove: false,
         singleSupermove: false,
         supermoveAnyPlayer: false,
+        oppositeSidesVictory: false,
+        noSelfStalemate: false,
+        longestFlowVictory: false,
+        autoRotateToLegal: false,
+        highlightCriticalPaths: false,
+        showCoordinates: false,
+        rotateBoardToCurrentPlayer: false,
+        soundEffects: true,
         debugShowEdgeLabels: false,
         debugShowVictoryEdges: false,
         debugLegalityTest: false,
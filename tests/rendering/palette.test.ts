@@ -0,0 +1,37 @@
+// Unit tests for player colour palettes
+import { describe, it, expect } from 'vitest';
+import { getPaletteColor, getPlayerMarker, getNextPalette, PALETTES } from '../../src/rendering/palette';
+import { PLAYER_COLORS } from '../../src/redux/types';
+
+describe('palette', () => {
+  it('should draw player colours unchanged in the default palette', () => {
+    for (const color of PLAYER_COLORS) {
+      expect(getPaletteColor(color, 'default')).toBe(color);
+    }
+  });
+
+  it('should give every player a distinct colour in each palette', () => {
+    for (const palette of PALETTES) {
+      const colors = new Set(PLAYER_COLORS.map((color) => getPaletteColor(color, palette)));
+      expect(colors.size).toBe(PLAYER_COLORS.length);
+    }
+  });
+
+  it('should leave colours outside the player colours unchanged', () => {
+    expect(getPaletteColor('#123456', 'colorblind')).toBe('#123456');
+    expect(getPlayerMarker('#123456', 'colorblind')).toBeNull();
+  });
+
+  it('should only mark edges in the colorblind palette', () => {
+    expect(getPlayerMarker(PLAYER_COLORS[0], 'default')).toBeNull();
+    expect(getPlayerMarker(PLAYER_COLORS[0], 'high-contrast')).toBeNull();
+    expect(getPlayerMarker(PLAYER_COLORS[0], 'colorblind')).toBe('A');
+    expect(getPlayerMarker(PLAYER_COLORS[5], 'colorblind')).toBe('F');
+  });
+
+  it('should cycle through the palettes', () => {
+    expect(getNextPalette('default')).toBe('colorblind');
+    expect(getNextPalette('colorblind')).toBe('high-contrast');
+    expect(getNextPalette('high-contrast')).toBe('default');
+  });
+});
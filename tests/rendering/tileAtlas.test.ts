@@ -0,0 +1,72 @@
+// Unit tests for tile atlas SVG generation
+import { describe, it, expect } from 'vitest';
+import {
+  generateTileAtlasSvg,
+  generateTileSvg,
+  getTileAtlasCells,
+  getUniqueRotations,
+  getTileSvgName,
+} from '../../src/rendering/tileAtlas';
+import { TileType } from '../../src/game/types';
+
+describe('Tile Atlas', () => {
+  describe('getUniqueRotations', () => {
+    it('should skip rotations that repeat a symmetric tile', () => {
+      expect(getUniqueRotations(TileType.NoSharps)).toEqual([0, 1, 2]);
+      expect(getUniqueRotations(TileType.OneSharp)).toEqual([0, 1, 2, 3, 4, 5]);
+      expect(getUniqueRotations(TileType.TwoSharps)).toEqual([0, 1, 2]);
+      expect(getUniqueRotations(TileType.ThreeSharps)).toEqual([0, 1]);
+    });
+  });
+
+  describe('generateTileAtlasSvg', () => {
+    it('should contain one cell per tile type and unique rotation', () => {
+      const expectedCells = [
+        TileType.NoSharps,
+        TileType.OneSharp,
+        TileType.TwoSharps,
+        TileType.ThreeSharps,
+      ].reduce((sum, type) => sum + getUniqueRotations(type).length, 0);
+
+      const svg = generateTileAtlasSvg();
+      const cells = svg.match(/class="tile-cell"/g) ?? [];
+
+      expect(cells).toHaveLength(expectedCells);
+      expect(getTileAtlasCells().flat()).toHaveLength(expectedCells);
+    });
+
+    it('should draw three flows and a label for each cell', () => {
+      const svg = generateTileAtlasSvg();
+      const cellCount = getTileAtlasCells().flat().length;
+
+      expect(svg.match(/<path /g)).toHaveLength(cellCount * 3);
+      expect(svg.match(/<text /g)).toHaveLength(cellCount);
+      expect(svg).toContain('>ThreeSharps r1<');
+    });
+  });
+
+  describe('generateTileSvg', () => {
+    it('should render a single tile', () => {
+      const svg = generateTileSvg(TileType.OneSharp, 0);
+      expect(svg.match(/class="tile-cell"/g)).toHaveLength(1);
+      expect(svg).toContain('data-rotation="0"');
+    });
+
+    it('should draw the flows of the requested rotation', () => {
+      const unrotated = generateTileSvg(TileType.OneSharp, 0);
+      const rotated = generateTileSvg(TileType.OneSharp, 2);
+
+      expect(rotated).toContain('data-rotation="2"');
+      expect(rotated.match(/<path /g)).toHaveLength(3);
+      expect(rotated.match(/d="[^"]*"/g)).not.toEqual(unrotated.match(/d="[^"]*"/g));
+    });
+  });
+
+  describe('getTileSvgName', () => {
+    it('should name files by tile type and orientation', () => {
+      expect(getTileSvgName(TileType.NoSharps, 0)).toBe('T0_N');
+      expect(getTileSvgName(TileType.TwoSharps, 2)).toBe('T2_SE');
+      expect(getTileSvgName(TileType.ThreeSharps, 5)).toBe('T3_NW');
+    });
+  });
+});
@@ -0,0 +1,60 @@
+// Tests for the move list replay timing
+
+import { describe, it, expect } from 'vitest';
+import {
+  advanceReplayClock,
+  nextReplaySpeed,
+  REPLAY_STEP_MS,
+  REPLAY_SPEEDS,
+} from '../src/animation/replay';
+
+describe('Replay Playback', () => {
+  describe('advanceReplayClock', () => {
+    it('should not step before the interval has elapsed', () => {
+      const clock = advanceReplayClock(0, REPLAY_STEP_MS - 1, 1);
+
+      expect(clock.steps).toBe(0);
+      expect(clock.elapsedMs).toBe(REPLAY_STEP_MS - 1);
+    });
+
+    it('should step once per interval and carry over the remainder', () => {
+      let clock = advanceReplayClock(0, 600, 1);
+      clock = advanceReplayClock(clock.elapsedMs, 600, 1);
+
+      expect(clock.steps).toBe(1);
+      expect(clock.elapsedMs).toBe(200);
+    });
+
+    it('should step several moves after a long frame', () => {
+      const clock = advanceReplayClock(0, REPLAY_STEP_MS * 3 + 10, 1);
+
+      expect(clock.steps).toBe(3);
+      expect(clock.elapsedMs).toBe(10);
+    });
+
+    it('should scale the interval by the playback speed', () => {
+      expect(advanceReplayClock(0, REPLAY_STEP_MS, 2).steps).toBe(2);
+      expect(advanceReplayClock(0, REPLAY_STEP_MS, 0.5).steps).toBe(0);
+      expect(advanceReplayClock(0, REPLAY_STEP_MS * 2, 0.5).steps).toBe(1);
+    });
+
+    it('should ignore time running backwards', () => {
+      const clock = advanceReplayClock(300, -50, 1);
+
+      expect(clock.steps).toBe(0);
+      expect(clock.elapsedMs).toBe(300);
+    });
+  });
+
+  describe('nextReplaySpeed', () => {
+    it('should cycle through the speeds and wrap around', () => {
+      let speed = REPLAY_SPEEDS[0];
+      for (let i = 1; i < REPLAY_SPEEDS.length; i++) {
+        speed = nextReplaySpeed(speed);
+        expect(speed).toBe(REPLAY_SPEEDS[i]);
+      }
+
+      expect(nextReplaySpeed(speed)).toBe(REPLAY_SPEEDS[0]);
+    });
+  });
+});
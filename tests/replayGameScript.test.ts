@@ -0,0 +1,75 @@
+// Tests for the headless replay script's report and error checks
+
+import { describe, it, expect, beforeEach } from 'vitest';
+import { replayGame } from '../scripts/replay-game';
+import { GameAction, placeTile } from '../src/redux/actions';
+import { gameReducer, initialState, resetPlayerIdCounter } from '../src/redux/gameReducer';
+import { toSaveString } from '../src/redux/saveFile';
+import { getAllBoardPositions, positionToKey } from '../src/game/board';
+import { isLegalMove } from '../src/game/legality';
+import { Rotation } from '../src/game/types';
+import { generateRandomGame } from './utils/gameGenerator';
+
+// A random game cut short by a placement that closes a player's last path
+// The reducer accepts such a placement, so only the legality check can catch it
+function withBlockingMove(seed: number): { actions: GameAction[]; moveIndex: number } | null {
+  const actions = generateRandomGame(seed);
+  resetPlayerIdCounter();
+
+  let state = initialState;
+  for (let i = 0; i < actions.length; i++) {
+    if (actions[i].type === 'PLACE_TILE' && state.currentTile !== null) {
+      for (const position of getAllBoardPositions(state.boardRadius)) {
+        if (state.board.has(positionToKey(position))) continue;
+        for (let rotation = 0; rotation < 6; rotation++) {
+          const tile = { type: state.currentTile, rotation: rotation as Rotation, position };
+          if (!isLegalMove(state.board, tile, state.players, state.teams, state.boardRadius, state.supermove)) {
+            return {
+              actions: [...actions.slice(0, i), placeTile(position, rotation as Rotation)],
+              moveIndex: state.moveHistory.length,
+            };
+          }
+        }
+      }
+    }
+    state = gameReducer(state, actions[i]);
+  }
+  return null;
+}
+
+describe('replay-game script', () => {
+  beforeEach(() => {
+    resetPlayerIdCounter();
+  });
+
+  it('should report the board and outcome of a legal game', () => {
+    const actions = generateRandomGame(42, 5);
+
+    const { report, errors } = replayGame(toSaveString(actions));
+
+    expect(errors).toEqual([]);
+    expect(report.some((line) => line.startsWith(`Actions: ${actions.length},`))).toBe(true);
+    expect(report.some((line) => line.startsWith('Outcome: '))).toBe(true);
+  });
+
+  it('should fail on input that cannot be loaded', () => {
+    const { errors } = replayGame('not a save');
+
+    expect(errors).toEqual(['Error: Save is not valid JSON']);
+  });
+
+  it('should fail on a placement that closes a player\'s last path', () => {
+    let blocked: { actions: GameAction[]; moveIndex: number } | null = null;
+    for (let seed = 1; blocked === null && seed <= 20; seed++) {
+      blocked = withBlockingMove(seed);
+    }
+    expect(blocked).not.toBeNull();
+
+    resetPlayerIdCounter();
+    const { errors } = replayGame(blocked!.actions.map((action) => JSON.stringify(action)).join('\n'));
+
+    expect(errors).toHaveLength(1);
+    expect(errors[0]).toContain(`Illegal move: move ${blocked!.moveIndex + 1} `);
+    expect(errors[0]).toContain('blocks a player\'s last path');
+  });
+});
@@ -0,0 +1,162 @@
+// Tests for the held tile and board rotation animations and their angle interpolation
+
+import { describe, it, expect, beforeEach, vi } from 'vitest';
+import {
+  lerpRadians,
+  rotationToRadians,
+  animateHeldTileRotation,
+  heldTileRotationState,
+  animateBoardRotation,
+  updateBoardRotation,
+  boardRotationState,
+} from '../src/animation/rotationAnimations';
+import { getAnimationFunction } from '../src/animation/registry';
+
+describe('Rotation Animations', () => {
+  const STEP = Math.PI / 3;
+
+  // Compare angles modulo a full turn
+  const expectSameAngle = (actual: number, expected: number) => {
+    const fullTurn = 2 * Math.PI;
+    const diff = ((actual - expected) % fullTurn + fullTurn) % fullTurn;
+    expect(Math.min(diff, fullTurn - diff)).toBeCloseTo(0);
+  };
+
+  beforeEach(() => {
+    heldTileRotationState.offset = 0;
+    boardRotationState.offset = 0;
+    updateBoardRotation(null);
+    (global as any).window = {
+      __REDUX_STORE__: {
+        dispatch: vi.fn(),
+        getState: vi.fn(() => ({
+          animation: { frameCounter: 0, paused: false, animations: [] },
+        })),
+      },
+    };
+  });
+
+  describe('lerpRadians', () => {
+    it('should interpolate linearly away from the wraparound', () => {
+      expect(lerpRadians(STEP, 3 * STEP, 0)).toBeCloseTo(STEP);
+      expect(lerpRadians(STEP, 3 * STEP, 0.5)).toBeCloseTo(2 * STEP);
+      expect(lerpRadians(STEP, 3 * STEP, 1)).toBeCloseTo(3 * STEP);
+    });
+
+    it('should turn forward across the wraparound from 5 to 0', () => {
+      const start = rotationToRadians(5);
+      const end = rotationToRadians(0);
+      expect(lerpRadians(start, end, 0.5)).toBeCloseTo(5.5 * STEP);
+      expectSameAngle(lerpRadians(start, end, 1), end);
+    });
+
+    it('should turn backward across the wraparound from 0 to 5', () => {
+      const start = rotationToRadians(0);
+      const end = rotationToRadians(5);
+      expect(lerpRadians(start, end, 0.5)).toBeCloseTo(-0.5 * STEP);
+      expectSameAngle(lerpRadians(start, end, 1), end);
+    });
+
+    it('should never turn more than half a circle', () => {
+      for (let from = 0; from < 6; from++) {
+        for (let to = 0; to < 6; to++) {
+          const start = from * STEP;
+          const turn = lerpRadians(start, to * STEP, 1) - start;
+          expect(Math.abs(turn)).toBeLessThanOrEqual(Math.PI + 1e-9);
+          expectSameAngle(start + turn, to * STEP);
+        }
+      }
+    });
+
+    it('should accept angles outside a single turn', () => {
+      expect(lerpRadians(-STEP, 13 * STEP, 0.5)).toBeCloseTo(0);
+    });
+  });
+
+  describe('animateHeldTileRotation', () => {
+    it('should start drawn at the old rotation and settle at the new one', () => {
+      animateHeldTileRotation(5, 0);
+      const animFn = getAnimationFunction('held-tile-rotation')!;
+      expectSameAngle(heldTileRotationState.offset, -STEP);
+
+      animFn(0.5);
+      expect(heldTileRotationState.offset).toBeGreaterThan(-STEP);
+      expect(heldTileRotationState.offset).toBeLessThan(0);
+
+      animFn(1);
+      expect(heldTileRotationState.offset).toBe(0);
+    });
+
+    it('should turn the short way when rotating counter-clockwise across 0', () => {
+      animateHeldTileRotation(0, 5);
+      const animFn = getAnimationFunction('held-tile-rotation')!;
+
+      animFn(0.5);
+      expect(heldTileRotationState.offset).toBeGreaterThan(0);
+      expect(heldTileRotationState.offset).toBeLessThan(STEP);
+    });
+
+    it('should register the animation, replacing any turn in progress', () => {
+      animateHeldTileRotation(1, 2);
+      const store = (window as any).__REDUX_STORE__;
+      const types = store.dispatch.mock.calls.map((call: any[]) => call[0].type);
+      expect(types).toEqual(['CANCEL_ANIMATIONS_BY_NAME', 'REGISTER_ANIMATION']);
+    });
+
+    it('should not leave the tile turned without a store to run the animation', () => {
+      (global as any).window = {};
+      animateHeldTileRotation(1, 2);
+      expect(heldTileRotationState.offset).toBe(0);
+    });
+  });
+
+  describe('animateBoardRotation', () => {
+    it('should swing the board the short way across 0°', () => {
+      animateBoardRotation(300, 0);
+      const animFn = getAnimationFunction('board-rotation')!;
+      expectSameAngle(boardRotationState.offset, -STEP);
+
+      animFn(0.5);
+      expect(boardRotationState.offset).toBeCloseTo(-STEP / 2);
+
+      animFn(1);
+      expect(boardRotationState.offset).toBe(0);
+    });
+
+    it('should swing half a turn when the opposite player takes over', () => {
+      animateBoardRotation(180, 0);
+      expect(Math.abs(boardRotationState.offset)).toBeCloseTo(Math.PI);
+    });
+  });
+
+  describe('updateBoardRotation', () => {
+    const registeredNames = () =>
+      (window as any).__REDUX_STORE__.dispatch.mock.calls
+        .map((call: any[]) => call[0])
+        .filter((action: any) => action.type === 'REGISTER_ANIMATION')
+        .map((action: any) => action.payload.animationName);
+
+    it('should not animate the first orientation seen on entering gameplay', () => {
+      updateBoardRotation(180);
+      expect(registeredNames()).toEqual([]);
+      expect(boardRotationState.offset).toBe(0);
+    });
+
+    it('should animate when the orientation changes during play', () => {
+      updateBoardRotation(180);
+      updateBoardRotation(180);
+      expect(registeredNames()).toEqual([]);
+
+      updateBoardRotation(120);
+      expect(registeredNames()).toEqual(['board-rotation']);
+      expectSameAngle(boardRotationState.offset, STEP);
+    });
+
+    it('should not animate across a return to gameplay', () => {
+      updateBoardRotation(180);
+      updateBoardRotation(null);
+      updateBoardRotation(0);
+      expect(registeredNames()).toEqual([]);
+    });
+  });
+});
@@ -0,0 +1,110 @@
+// Tests for saving and loading games as versioned action logs
+
+import { describe, it, expect, beforeEach } from 'vitest';
+import { toSaveString, fromSaveString, SAVE_FORMAT, SAVE_FORMAT_VERSION } from '../src/redux/saveFile';
+import { gameReducer, initialState, resetPlayerIdCounter } from '../src/redux/gameReducer';
+import { generateRandomGame } from './utils/gameGenerator';
+import { assertGamesEqual } from './utils/boardAssertions';
+
+describe('save files', () => {
+  beforeEach(() => {
+    resetPlayerIdCounter();
+  });
+
+  it('should write a versioned header with the action log', () => {
+    const actions = generateRandomGame(42, 5);
+
+    const save = JSON.parse(toSaveString(actions));
+
+    expect(save.format).toBe(SAVE_FORMAT);
+    expect(save.version).toBe(SAVE_FORMAT_VERSION);
+    expect(save.actions).toEqual(actions);
+  });
+
+  it('should reconstruct the board, current player, and outcome', () => {
+    const actions = generateRandomGame(888);
+    resetPlayerIdCounter();
+    const original = actions.reduce(gameReducer, initialState);
+
+    const result = fromSaveString(toSaveString(actions));
+
+    expect(result.valid).toBe(true);
+    if (result.valid) {
+      expect(result.actions).toEqual(actions);
+      expect(result.state.board).toEqual(original.board);
+      expect(result.state.currentPlayerIndex).toBe(original.currentPlayerIndex);
+      expect(result.state.players).toEqual(original.players);
+      expect(result.state.phase).toBe(original.phase);
+      expect(result.state.winners).toEqual(original.winners);
+      expect(result.state.winType).toBe(original.winType);
+      assertGamesEqual(result.state, original);
+      expect(result.rejectedMoves).toEqual([]);
+    }
+  });
+
+  it('should list moves the reducer rejected', () => {
+    const actions = generateRandomGame(7, 4);
+    const placement = actions.findIndex((action) => action.type === 'PLACE_TILE');
+    // Placing the same tile again right away lands on an occupied hex with no tile in hand
+    const withRepeat = [...actions.slice(0, placement + 1), actions[placement], ...actions.slice(placement + 1)];
+
+    const result = fromSaveString(toSaveString(withRepeat));
+
+    expect(result.valid).toBe(true);
+    if (result.valid) {
+      expect(result.rejectedMoves).toEqual([placement + 1]);
+    }
+  });
+
+  it('should reconstruct an in-progress game', () => {
+    const actions = generateRandomGame(7, 4);
+    resetPlayerIdCounter();
+    const original = actions.reduce(gameReducer, initialState);
+
+    const result = fromSaveString(toSaveString(actions));
+
+    expect(result.valid).toBe(true);
+    if (result.valid) {
+      expect(result.state.phase).toBe('playing');
+      expect(result.state.board).toEqual(original.board);
+      expect(result.state.currentTile).toBe(original.currentTile);
+      assertGamesEqual(result.state, original);
+    }
+  });
+
+  it('should reject text that is not JSON', () => {
+    expect(fromSaveString('not a save')).toEqual({ valid: false, reason: 'Save is not valid JSON' });
+  });
+
+  it('should reject JSON without the save header', () => {
+    expect(fromSaveString('null')).toEqual({ valid: false, reason: 'Not a Quortex save file' });
+    expect(fromSaveString('{"actions":[]}')).toEqual({ valid: false, reason: 'Not a Quortex save file' });
+  });
+
+  it('should reject unsupported versions', () => {
+    const save = JSON.stringify({ format: SAVE_FORMAT, version: 99, actions: [] });
+    expect(fromSaveString(save)).toEqual({ valid: false, reason: 'Unsupported save version 99' });
+  });
+
+  it('should reject saves without actions', () => {
+    expect(fromSaveString(toSaveString([]))).toEqual({ valid: false, reason: 'Save contains no actions' });
+  });
+
+  it('should reject saves that do not start by setting up the game', () => {
+    const result = fromSaveString(toSaveString([{ type: 'DRAW_TILE' }]));
+
+    expect(result).toEqual({
+      valid: false,
+      reason: 'Save must start with ADD_PLAYER or SETUP_GAME, found DRAW_TILE',
+    });
+  });
+
+  it('should report actions that cannot be replayed instead of throwing', () => {
+    const result = fromSaveString(toSaveString([{ type: 'SETUP_GAME' } as any]));
+
+    expect(result.valid).toBe(false);
+    if (!result.valid) {
+      expect(result.reason).toContain('could not be replayed');
+    }
+  });
+});
@@ -1,7 +1,7 @@
 // Unit tests for seating phase functionality
 
 import { describe, it, expect } from 'vitest';
-import { gameReducer, initialState } from '../src/redux/gameReducer';
+import { gameReducer, initialState, validateSeatingOrder } from '../src/redux/gameReducer';
 import { addPlayer, startGame, selectEdge, startSeatingPhase, completeSeatingPhase, drawTile } from '../src/redux/actions';
 import { PLAYER_COLORS } from '../src/redux/types';
 
@@ -428,7 +428,10 @@ describe('Seating Phase', () => {
 
     it('should handle START_SEATING_PHASE reducer action', () => {
       let state = initialState;
-      const seatingOrder = ['p1', 'p2', 'p3'];
+      state = gameReducer(state, addPlayer(PLAYER_COLORS[0], 0, 'p1'));
+      state = gameReducer(state, addPlayer(PLAYER_COLORS[1], 1, 'p2'));
+      state = gameReducer(state, addPlayer(PLAYER_COLORS[2], 2, 'p3'));
+      const seatingOrder = ['p2', 'p3', 'p1'];
       
       state = gameReducer(state, startSeatingPhase(seatingOrder));
       
@@ -441,6 +444,51 @@ describe('Seating Phase', () => {
     });
   });
 
+  describe('validateSeatingOrder', () => {
+    const createState = () => {
+      let state = initialState;
+      state = gameReducer(state, addPlayer(PLAYER_COLORS[0], 0, 'p1'));
+      state = gameReducer(state, addPlayer(PLAYER_COLORS[1], 1, 'p2'));
+      return state;
+    };
+
+    it('should accept every player exactly once', () => {
+      expect(validateSeatingOrder(createState(), ['p2', 'p1'])).toEqual({ valid: true });
+    });
+
+    it('should reject an order of the wrong length', () => {
+      const result = validateSeatingOrder(createState(), ['p1']);
+      expect(result.valid).toBe(false);
+      expect(!result.valid && result.reason).toContain("length doesn't match");
+    });
+
+    it('should reject repeated or unknown players', () => {
+      for (const order of [['p1', 'p1'], ['p1', 'p9']]) {
+        const result = validateSeatingOrder(createState(), order);
+        expect(!result.valid && result.reason).toContain('each player exactly once');
+      }
+    });
+
+    it('should reject a second randomization', () => {
+      const state = gameReducer(createState(), startSeatingPhase(['p2', 'p1']));
+      const result = validateSeatingOrder(state, ['p1', 'p2']);
+      expect(!result.valid && result.reason).toContain('already randomized');
+
+      // The reducer keeps the first order
+      expect(gameReducer(state, startSeatingPhase(['p1', 'p2'])).seatingPhase.seatingOrder).toEqual(['p2', 'p1']);
+    });
+
+    it('should let the first player in the order choose an edge first', () => {
+      let state = gameReducer(createState(), startSeatingPhase(['p2', 'p1']));
+
+      state = gameReducer(state, selectEdge('p1', 0));
+      expect(state.seatingPhase.seatingIndex).toBe(0);
+
+      state = gameReducer(state, selectEdge('p2', 0));
+      expect(state.seatingPhase.seatingIndex).toBe(1);
+    });
+  });
+
   describe('Reducer edge cases', () => {
     it('should handle COMPLETE_SEATING_PHASE action', () => {
       let state = initialState;
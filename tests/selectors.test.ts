@@ -4,14 +4,26 @@ import { describe, it, expect } from 'vitest';
 import {
   selectCurrentPlayer,
   selectLegalPositions,
+  selectLegalMovesForCurrentPlayer,
   selectFlowsForRendering,
   selectIsPositionHovered,
   selectGameStatus,
   selectRemainingTileCounts,
+  selectNextTileOdds,
+  willSomeoneMissDraw,
+  selectBagRunningOut,
+  selectTurnNumber,
+  selectPlacedTileCount,
+  selectResigningPlayer,
+  selectHintMove,
   selectBlockedPlayers,
+  selectIsReviewingHistory,
+  selectLegalTargets,
 } from '../src/redux/selectors';
 import { RootState } from '../src/redux/types';
-import { TileType } from '../src/game/types';
+import { TileType, PlacedTile, Rotation } from '../src/game/types';
+import { isLegalMove, findAllLegalMoves, findLegalRotations } from '../src/game/legality';
+import { getAllBoardPositions, positionToKey, keyToPosition } from '../src/game/board';
 import { initialState as initialGameState } from '../src/redux/gameReducer';
 import { initialUIState } from '../src/redux/uiReducer';
 
@@ -97,6 +109,93 @@ describe('Redux Selectors', () => {
     });
   });
 
+  describe('selectLegalMovesForCurrentPlayer', () => {
+    it('should list the legal placements of the tile in hand', () => {
+      const players = [
+        { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+        { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: false },
+      ];
+      const noTile = createMockState({ game: { ...initialGameState, players } });
+      const withTile = createMockState({
+        game: { ...initialGameState, players, currentTile: TileType.TwoSharps },
+      });
+
+      expect(selectLegalMovesForCurrentPlayer(noTile)).toEqual([]);
+      expect(selectLegalMovesForCurrentPlayer(withTile)).toEqual(
+        findAllLegalMoves(new Map(), TileType.TwoSharps, players, [], 3, initialGameState.supermove),
+      );
+    });
+  });
+
+  describe('selectLegalTargets', () => {
+    const players = [
+      { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+      { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: false },
+    ];
+
+    // A wall of sharp tiles with a one-hex gap, so some placements would block a player
+    const createWalledBoard = (): Map<string, PlacedTile> => {
+      const board = new Map<string, PlacedTile>();
+      for (let col = -3; col <= 3; col++) {
+        const position = { row: 0, col };
+        if (col !== 0) {
+          board.set(positionToKey(position), { type: TileType.ThreeSharps, rotation: 0, position });
+        }
+      }
+      return board;
+    };
+
+    it('should be empty without a tile in hand', () => {
+      expect(selectLegalTargets(createMockState()).size).toBe(0);
+    });
+
+    it('should mark each empty hex by whether any rotation is legal there', () => {
+      for (const noSelfStalemate of [false, true]) {
+        const board = createWalledBoard();
+        const game = {
+          ...initialGameState,
+          players,
+          board,
+          currentTile: TileType.ThreeSharps,
+          availableTiles: [TileType.ThreeSharps, TileType.NoSharps],
+          supermove: false,
+          noSelfStalemate,
+        };
+
+        const targets = selectLegalTargets(createMockState({ game }));
+
+        for (const position of getAllBoardPositions(3)) {
+          const key = positionToKey(position);
+          if (board.has(key)) {
+            expect(targets.has(key)).toBe(false);
+            continue;
+          }
+          const rotations = findLegalRotations(
+            board, TileType.ThreeSharps, position, players, [], 3, false,
+            noSelfStalemate ? game.availableTiles : undefined,
+          );
+          expect(targets.get(key)).toBe(rotations.length > 0);
+        }
+      }
+    });
+
+    it('should judge only the held rotation when asked', () => {
+      const board = createWalledBoard();
+      const game = { ...initialGameState, players, board, currentTile: TileType.OneSharp, supermove: false };
+
+      for (let rotation = 0; rotation < 6; rotation++) {
+        const targets = selectLegalTargets(createMockState({ game }), rotation as Rotation);
+
+        for (const [key, isLegal] of targets) {
+          const position = keyToPosition(key);
+          expect(isLegal).toBe(
+            isLegalMove(board, { type: TileType.OneSharp, rotation: rotation as Rotation, position }, players, [], 3, false),
+          );
+        }
+      }
+    });
+  });
+
   describe('selectFlowsForRendering', () => {
     it('should return empty array when no players', () => {
       const state = createMockState();
@@ -230,6 +329,175 @@ describe('Redux Selectors', () => {
     });
   });
 
+  describe('selectNextTileOdds', () => {
+    it('should be zero for every type when the bag is empty', () => {
+      const odds = selectNextTileOdds(createMockState());
+
+      expect(odds).toEqual({ noSharps: 0, oneSharp: 0, twoSharps: 0, threeSharps: 0 });
+    });
+
+    it('should give each type its share of the bag', () => {
+      const availableTiles = [
+        TileType.NoSharps,
+        TileType.OneSharp,
+        TileType.OneSharp,
+        TileType.ThreeSharps,
+      ];
+
+      const state = createMockState({
+        game: { ...initialGameState, availableTiles },
+      });
+
+      expect(selectNextTileOdds(state)).toEqual({
+        noSharps: 0.25,
+        oneSharp: 0.5,
+        twoSharps: 0,
+        threeSharps: 0.25,
+      });
+    });
+  });
+
+  describe('willSomeoneMissDraw', () => {
+    it('should warn only when fewer tiles remain than players', () => {
+      expect(willSomeoneMissDraw(5, 2)).toBe(false);
+      expect(willSomeoneMissDraw(2, 2)).toBe(false);
+      expect(willSomeoneMissDraw(1, 2)).toBe(true);
+      expect(willSomeoneMissDraw(0, 2)).toBe(true);
+      expect(willSomeoneMissDraw(5, 6)).toBe(true);
+    });
+  });
+
+  describe('selectBagRunningOut', () => {
+    const players = [
+      { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+      { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: false },
+    ];
+
+    it('should be true while playing with fewer tiles than players', () => {
+      const state = createMockState({
+        game: { ...initialGameState, phase: 'playing', players, availableTiles: [TileType.NoSharps] },
+      });
+
+      expect(selectBagRunningOut(state)).toBe(true);
+    });
+
+    it('should be false outside of play', () => {
+      const state = createMockState({
+        game: { ...initialGameState, phase: 'finished', players, availableTiles: [] },
+      });
+
+      expect(selectBagRunningOut(state)).toBe(false);
+    });
+  });
+
+  describe('selectTurnNumber / selectPlacedTileCount', () => {
+    it('should count turns from 1 and tiles on the board', () => {
+      const tile = { type: TileType.NoSharps, rotation: 0 as const, position: { row: 0, col: 0 } };
+      const fresh = createMockState();
+      const played = createMockState({
+        game: {
+          ...initialGameState,
+          board: new Map([['0,0', tile]]),
+          moveHistory: [{ playerId: 'p1', tile, timestamp: 0 }],
+        },
+      });
+
+      expect(selectTurnNumber(fresh)).toBe(1);
+      expect(selectPlacedTileCount(fresh)).toBe(0);
+      expect(selectTurnNumber(played)).toBe(2);
+      expect(selectPlacedTileCount(played)).toBe(1);
+    });
+  });
+
+  describe('selectResigningPlayer', () => {
+    const players = [
+      { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+      { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: true },
+    ];
+
+    it('should return the current human player during play', () => {
+      const state = createMockState({
+        game: { ...initialGameState, phase: 'playing', players, currentPlayerIndex: 0 },
+      });
+
+      expect(selectResigningPlayer(state)?.id).toBe('p1');
+    });
+
+    it('should return null outside of play or on an AI turn', () => {
+      const finished = createMockState({
+        game: { ...initialGameState, phase: 'finished', players, currentPlayerIndex: 0 },
+      });
+      const aiTurn = createMockState({
+        game: { ...initialGameState, phase: 'playing', players, currentPlayerIndex: 1 },
+      });
+
+      expect(selectResigningPlayer(finished)).toBeNull();
+      expect(selectResigningPlayer(aiTurn)).toBeNull();
+    });
+
+    it('should only offer resignation to the local player in multiplayer', () => {
+      const game = { ...initialGameState, phase: 'playing' as const, players, currentPlayerIndex: 0 };
+      const local = createMockState({
+        game,
+        ui: { ...initialUIState, gameMode: 'multiplayer', localPlayerId: 'p1' },
+      });
+      const remote = createMockState({
+        game,
+        ui: { ...initialUIState, gameMode: 'multiplayer', localPlayerId: 'p2' },
+      });
+      const spectator = createMockState({
+        game,
+        ui: { ...initialUIState, gameMode: 'multiplayer', localPlayerId: 'p1', isSpectator: true },
+      });
+
+      expect(selectResigningPlayer(local)?.id).toBe('p1');
+      expect(selectResigningPlayer(remote)).toBeNull();
+      expect(selectResigningPlayer(spectator)).toBeNull();
+    });
+  });
+
+  describe('selectHintMove', () => {
+    const players = [
+      { id: 'p1', color: '#0173B2', edgePosition: 0, isAI: false },
+      { id: 'p2', color: '#DE8F05', edgePosition: 3, isAI: true },
+    ];
+
+    it('should suggest a legal placement of the tile in hand', () => {
+      const state = createMockState({
+        game: {
+          ...initialGameState,
+          phase: 'playing',
+          players,
+          currentPlayerIndex: 0,
+          currentTile: TileType.OneSharp,
+        },
+      });
+
+      const hint = selectHintMove(state);
+      expect(hint).not.toBeNull();
+      expect(isLegalMove(
+        state.game.board,
+        { type: TileType.OneSharp, rotation: hint!.rotation, position: hint!.position },
+        players,
+        [],
+        state.game.boardRadius,
+        false,
+      )).toBe(true);
+    });
+
+    it('should return null without a tile or on an AI turn', () => {
+      const noTile = createMockState({
+        game: { ...initialGameState, phase: 'playing', players, currentPlayerIndex: 0, currentTile: null },
+      });
+      const aiTurn = createMockState({
+        game: { ...initialGameState, phase: 'playing', players, currentPlayerIndex: 1, currentTile: TileType.OneSharp },
+      });
+
+      expect(selectHintMove(noTile)).toBeNull();
+      expect(selectHintMove(aiTurn)).toBeNull();
+    });
+  });
+
   describe('selectBlockedPlayers', () => {
     it('should return empty array when no selected position', () => {
       const players = [
@@ -298,4 +566,20 @@ describe('Redux Selectors', () => {
       expect(Array.isArray(blocked)).toBe(true);
     });
   });
+
+  describe('selectIsReviewingHistory', () => {
+    it('should not be reviewing at the current position', () => {
+      const state = createMockState();
+
+      expect(selectIsReviewingHistory(state)).toBe(false);
+    });
+
+    it('should be reviewing when an earlier move is selected', () => {
+      const state = createMockState({
+        ui: { ...initialUIState, showMoveList: true, moveListIndex: 0 },
+      });
+
+      expect(selectIsReviewingHistory(state)).toBe(true);
+    });
+  });
 });
@@ -0,0 +1,80 @@
+// Tests for the sound effects middleware
+
+import { describe, it, expect, vi } from 'vitest';
+import { createStore, applyMiddleware } from 'redux';
+import { rootReducer } from '../src/redux/reducer';
+import { createSoundMiddleware, getSoundEffects } from '../src/redux/soundMiddleware';
+import { initialState as initialGameState } from '../src/redux/gameReducer';
+import { initialUIState } from '../src/redux/uiReducer';
+import { placeTile, setRotation, setSelectedPosition, updateSettings } from '../src/redux/actions';
+import { TileType } from '../src/game/types';
+import type { RootState } from '../src/redux/types';
+
+describe('sound middleware', () => {
+  const createTestStore = () => {
+    const play = vi.fn();
+    const store = createStore(rootReducer, applyMiddleware(createSoundMiddleware(play)));
+    return { store, play };
+  };
+
+  it('should tick when the tile is rotated', () => {
+    const { store, play } = createTestStore();
+
+    store.dispatch(setRotation(1));
+    store.dispatch(setRotation(1));
+
+    expect(play.mock.calls).toEqual([['rotate']]);
+  });
+
+  it('should snap when a new hex is selected', () => {
+    const { store, play } = createTestStore();
+
+    store.dispatch(setSelectedPosition({ row: 0, col: 0 }));
+    store.dispatch(setSelectedPosition({ row: 0, col: 0 }));
+    store.dispatch(setSelectedPosition(null));
+
+    expect(play.mock.calls).toEqual([['snap']]);
+  });
+
+  it('should stay silent when sound effects are turned off', () => {
+    const { store, play } = createTestStore();
+
+    store.dispatch(updateSettings({ soundEffects: false }));
+    store.dispatch(setRotation(2));
+    store.dispatch(setSelectedPosition({ row: 1, col: 0 }));
+
+    expect(play).not.toHaveBeenCalled();
+  });
+
+  describe('getSoundEffects', () => {
+    const before: RootState = { game: initialGameState, ui: initialUIState };
+    const move = {
+      playerId: 'p1',
+      tile: { type: TileType.NoSharps, rotation: 0 as const, position: { row: 0, col: 0 } },
+      timestamp: 1,
+    };
+
+    it('should click when a tile is placed', () => {
+      const after: RootState = { ...before, game: { ...initialGameState, moveHistory: [move] } };
+
+      expect(getSoundEffects(placeTile({ row: 0, col: 0 }, 0), before, after)).toEqual(['place']);
+    });
+
+    it('should play a fanfare when the game is won', () => {
+      const after: RootState = {
+        ...before,
+        game: { ...initialGameState, moveHistory: [move], winners: ['p1'], winType: 'flow' },
+      };
+
+      expect(getSoundEffects(placeTile({ row: 0, col: 0 }, 0), before, after)).toEqual(['place', 'victory']);
+    });
+
+    it('should not play anything for a muted victory', () => {
+      const muted: RootState = { ...before, ui: { ...initialUIState, settings: { ...initialUIState.settings, soundEffects: false } } };
+      const after: RootState = { ...muted, game: { ...initialGameState, winners: ['p1'], winType: 'flow' } };
+
+      expect(getSoundEffects(placeTile({ row: 0, col: 0 }, 0), muted, after)).toEqual([]);
+    });
+  });
+});
+
@@ -2,6 +2,7 @@
 
 import { describe, it, expect } from 'vitest';
 import { calculateHexCount, calculateTileDistribution, calculateTileCountsFromRatio } from '../src/redux/gameReducer';
+import { TILE_DISTRIBUTION_SKEWED } from '../src/redux/types';
 
 describe('Tile Distribution', () => {
   describe('calculateHexCount', () => {
@@ -127,6 +128,13 @@ describe('Tile Distribution', () => {
       expect(result.distribution).toEqual([16, 8, 8, 8]);
     });
 
+    it('should calculate correct distribution for the skewed preset [3,2,2,1]', () => {
+      const result = calculateTileCountsFromRatio(3, TILE_DISTRIBUTION_SKEWED);
+      expect(result.totalTiles).toBe(40);
+      expect(result.numGroups).toBe(5);
+      expect(result.distribution).toEqual([15, 10, 10, 5]);
+    });
+
     it('should calculate correct distribution for all ThreeSharps [0,0,0,1]', () => {
       const result = calculateTileCountsFromRatio(3, [0, 0, 0, 1]);
       expect(result.totalTiles).toBe(37);
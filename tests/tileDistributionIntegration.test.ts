@@ -5,6 +5,8 @@ import { store } from '../src/redux/store';
 import { addPlayer, startGame, selectEdge, updateSettings, resetGame } from '../src/redux/actions';
 import { TileType } from '../src/game/types';
 import { resetPlayerIdCounter } from '../src/redux/gameReducer';
+import { selectNextTileOdds } from '../src/redux/selectors';
+import { TILE_DISTRIBUTION_SKEWED } from '../src/redux/types';
 
 describe('Tile Distribution Integration', () => {
   it('should use custom tile distribution when starting a game', () => {
@@ -234,4 +236,36 @@ describe('Tile Distribution Integration', () => {
     expect(counts[TileType.TwoSharps]).toBe(5);
     expect(counts[TileType.ThreeSharps]).toBe(5);
   });
+
+  it('should skew the draw odds with the skewed preset', () => {
+    resetPlayerIdCounter();
+    store.dispatch(resetGame());
+
+    store.dispatch(updateSettings({
+      tileDistribution: TILE_DISTRIBUTION_SKEWED
+    }));
+
+    store.dispatch(addPlayer('#0173B2', 0));
+    store.dispatch(addPlayer('#DE8F05', 1));
+    store.dispatch(startGame(3));
+
+    const state1 = store.getState();
+    const seatingOrder = state1.game.seatingPhase.seatingOrder;
+    store.dispatch(selectEdge(seatingOrder[0], 0));
+    store.dispatch(selectEdge(seatingOrder[1], 3));
+
+    // Put the drawn tile back so the odds describe the full bag
+    const state = store.getState();
+    const fullBag = [...state.game.availableTiles];
+    if (state.game.currentTile !== null) {
+      fullBag.push(state.game.currentTile);
+    }
+    const odds = selectNextTileOdds({ ...state, game: { ...state.game, availableTiles: fullBag } });
+
+    // [3,2,2,1] on radius 3: 15/10/10/5 of 40 tiles
+    expect(odds.noSharps).toBeCloseTo(0.375);
+    expect(odds.oneSharp).toBeCloseTo(0.25);
+    expect(odds.twoSharps).toBeCloseTo(0.25);
+    expect(odds.threeSharps).toBeCloseTo(0.125);
+  });
 });
@@ -10,6 +10,7 @@ import {
   setSelectedPosition,
   setRotation,
   toggleLegalMoves,
+  toggleLegalMovesRotation,
   toggleSettings,
   updateSettings,
   showHelp,
@@ -17,11 +18,22 @@ import {
   showMoveList,
   hideMoveList,
   navigateMoveList,
+  jumpToMove,
+  toggleReplay,
+  stepReplay,
+  setReplaySpeed,
   setPlayerConnected,
   setPlayerDisconnected,
   setUserIdMapping,
   setSpectatorMode,
   setSpectatorCount,
+  setRematchVotes,
+  setServerNotice,
+  setOrientationOverride,
+  showTutorial,
+  hideTutorial,
+  showHint,
+  hideHint,
 } from '../src/redux/actions';
 
 describe('uiReducer', () => {
@@ -175,6 +187,17 @@ describe('uiReducer', () => {
     });
   });
 
+  describe('TOGGLE_LEGAL_MOVES_ROTATION', () => {
+    it('should switch between any rotation and the held rotation', () => {
+      let state = uiReducer(initialUIState, toggleLegalMovesRotation());
+      expect(state.legalMovesForHeldRotation).toBe(true);
+      expect(state.showLegalMoves).toBe(false);
+
+      state = uiReducer(state, toggleLegalMovesRotation());
+      expect(state.legalMovesForHeldRotation).toBe(false);
+    });
+  });
+
   describe('Initial State', () => {
     it('should have correct initial values', () => {
       expect(initialUIState.selectedPosition).toBeNull();
@@ -182,6 +205,7 @@ describe('uiReducer', () => {
       expect(initialUIState.hoveredElement).toBeNull();
       expect(initialUIState.currentRotation).toBe(0);
       expect(initialUIState.showLegalMoves).toBe(false);
+      expect(initialUIState.legalMovesForHeldRotation).toBe(false);
       expect(initialUIState.showFlowMarkers).toBe(true);
       expect(initialUIState.animationSpeed).toBe(1.0);
       expect(initialUIState.zoom).toBe(1.0);
@@ -362,6 +386,110 @@ describe('uiReducer', () => {
     });
   });
 
+  describe('JUMP_TO_MOVE', () => {
+    it('should jump directly to a move in the history', () => {
+      let state = uiReducer(initialUIState, showMoveList(0));
+      state = uiReducer(state, jumpToMove(7, 20));
+
+      expect(state.moveListIndex).toBe(7);
+    });
+
+    it('should clamp negative indices to the start of the game', () => {
+      let state = uiReducer(initialUIState, showMoveList(0));
+      state = uiReducer(state, jumpToMove(-3, 20));
+
+      expect(state.moveListIndex).toBe(0);
+    });
+
+    it('should return to the current state at or beyond the last move', () => {
+      let state = uiReducer(initialUIState, showMoveList(0));
+      state = uiReducer(state, jumpToMove(4, 20));
+      state = uiReducer(state, jumpToMove(20, 20));
+      expect(state.moveListIndex).toBe(-1);
+
+      state = uiReducer(state, jumpToMove(4, 20));
+      state = uiReducer(state, jumpToMove(25, 20));
+      expect(state.moveListIndex).toBe(-1);
+    });
+
+    it('should step back from the current state', () => {
+      let state = uiReducer(initialUIState, showMoveList(0));
+      state = uiReducer(state, jumpToMove(20 - 1, 20));
+
+      expect(state.moveListIndex).toBe(19);
+    });
+  });
+
+  describe('Replay playback', () => {
+    it('should start from the empty board when played from the current state', () => {
+      let state = uiReducer(initialUIState, showMoveList(0));
+      state = uiReducer(state, toggleReplay(5));
+
+      expect(state.replayPlaying).toBe(true);
+      expect(state.moveListIndex).toBe(0);
+    });
+
+    it('should resume from the position being reviewed', () => {
+      let state = uiReducer(initialUIState, showMoveList(0));
+      state = uiReducer(state, jumpToMove(3, 5));
+      state = uiReducer(state, toggleReplay(5));
+
+      expect(state.moveListIndex).toBe(3);
+    });
+
+    it('should step one move at a time and stop at the current state', () => {
+      let state = uiReducer(initialUIState, showMoveList(0));
+      state = uiReducer(state, toggleReplay(3));
+
+      state = uiReducer(state, stepReplay(3));
+      expect(state.moveListIndex).toBe(1);
+      state = uiReducer(state, stepReplay(3));
+      expect(state.moveListIndex).toBe(2);
+
+      state = uiReducer(state, stepReplay(3));
+      expect(state.moveListIndex).toBe(-1);
+      expect(state.replayPlaying).toBe(false);
+
+      // Further steps do nothing once stopped
+      state = uiReducer(state, stepReplay(3));
+      expect(state.moveListIndex).toBe(-1);
+    });
+
+    it('should pause and ignore steps while paused', () => {
+      let state = uiReducer(initialUIState, showMoveList(0));
+      state = uiReducer(state, toggleReplay(5));
+      state = uiReducer(state, stepReplay(5));
+      state = uiReducer(state, toggleReplay(5));
+
+      expect(state.replayPlaying).toBe(false);
+      state = uiReducer(state, stepReplay(5));
+      expect(state.moveListIndex).toBe(1);
+    });
+
+    it('should not play a game without moves', () => {
+      const state = uiReducer(initialUIState, toggleReplay(0));
+
+      expect(state.replayPlaying).toBe(false);
+    });
+
+    it('should pause when navigating manually or closing the move list', () => {
+      let state = uiReducer(initialUIState, showMoveList(0));
+      state = uiReducer(state, toggleReplay(5));
+      state = uiReducer(state, jumpToMove(2, 5));
+      expect(state.replayPlaying).toBe(false);
+
+      state = uiReducer(state, toggleReplay(5));
+      state = uiReducer(state, hideMoveList());
+      expect(state.replayPlaying).toBe(false);
+    });
+
+    it('should set the replay speed', () => {
+      const state = uiReducer(initialUIState, setReplaySpeed(2));
+
+      expect(state.replaySpeed).toBe(2);
+    });
+  });
+
   describe('Unknown Action', () => {
     it('should return current state for unknown action', () => {
       const state = uiReducer(initialUIState, { type: 'UNKNOWN_ACTION' } as any);
@@ -443,4 +571,72 @@ describe('uiReducer', () => {
       expect(state.spectatorCount).toBe(0);
     });
   });
+
+  describe('SET_SERVER_NOTICE', () => {
+    it('should show and clear a server notice', () => {
+      let state = uiReducer(initialUIState, setServerNotice('Only seated players can make moves in this game'));
+      expect(state.serverNotice).toBe('Only seated players can make moves in this game');
+
+      state = uiReducer(state, setServerNotice(null));
+      expect(state.serverNotice).toBeNull();
+    });
+  });
+
+  describe('SET_REMATCH_VOTES', () => {
+    it('should start with no pending rematch votes', () => {
+      expect(initialUIState.rematchVotes).toBeNull();
+    });
+
+    it('should record rematch vote progress', () => {
+      const state = uiReducer(initialUIState, setRematchVotes({ ready: 1, total: 3 }));
+
+      expect(state.rematchVotes).toEqual({ ready: 1, total: 3 });
+    });
+
+    it('should clear rematch votes', () => {
+      let state = uiReducer(initialUIState, setRematchVotes({ ready: 2, total: 3 }));
+      state = uiReducer(state, setRematchVotes(null));
+
+      expect(state.rematchVotes).toBeNull();
+    });
+  });
+
+  describe('SET_ORIENTATION_OVERRIDE', () => {
+    it('should set and clear the orientation override', () => {
+      let state = uiReducer(initialUIState, setOrientationOverride('p2'));
+      expect(state.orientationPlayerId).toBe('p2');
+
+      state = uiReducer(state, setOrientationOverride(null));
+      expect(state.orientationPlayerId).toBeNull();
+    });
+  });
+
+  describe('SHOW_TUTORIAL / HIDE_TUTORIAL', () => {
+    it('should show and hide the tutorial', () => {
+      let state = uiReducer(initialUIState, showTutorial());
+      expect(state.showTutorial).toBe(true);
+
+      state = uiReducer(state, hideTutorial());
+      expect(state.showTutorial).toBe(false);
+    });
+  });
+
+  describe('SHOW_HINT / HIDE_HINT', () => {
+    it('should show and hide the hint', () => {
+      let state = uiReducer(initialUIState, showHint({ row: 1, col: 0 }, 2));
+      expect(state.hint).toEqual({ position: { row: 1, col: 0 }, rotation: 2 });
+
+      state = uiReducer(state, hideHint());
+      expect(state.hint).toBeNull();
+    });
+
+    it('should dismiss the hint when a hex is selected', () => {
+      let state = uiReducer(initialUIState, showHint({ row: 1, col: 0 }, 2));
+      state = uiReducer(state, setSelectedPosition(null));
+      expect(state.hint).not.toBeNull();
+
+      state = uiReducer(state, setSelectedPosition({ row: 0, col: 0 }));
+      expect(state.hint).toBeNull();
+    });
+  });
 });
@@ -0,0 +1,48 @@
+/**
+ * Tests for board assertion helpers
+ */
+
+import { describe, it, expect, beforeEach } from 'vitest';
+import { assertGamesEqual } from './boardAssertions';
+import { generateRandomGame } from './gameGenerator';
+import { gameReducer, initialState, resetPlayerIdCounter } from '../../src/redux/gameReducer';
+import { GameAction } from '../../src/redux/actions';
+import { GameState } from '../../src/redux/types';
+
+// Replay a generated game's actions from scratch
+function replay(actions: GameAction[]): GameState {
+  resetPlayerIdCounter();
+  return actions.reduce(gameReducer, initialState);
+}
+
+describe('Board Assertions', () => {
+  beforeEach(() => {
+    resetPlayerIdCounter();
+  });
+
+  it('should accept a game replayed from the same actions', () => {
+    const actions = generateRandomGame(42, 6);
+
+    expect(() => assertGamesEqual(replay(actions), replay(actions))).not.toThrow();
+  });
+
+  it('should report the differing cell and draw both boards', () => {
+    const game = replay(generateRandomGame(42, 6));
+    const [key, tile] = [...game.board.entries()][0];
+    const board = new Map(game.board);
+    board.set(key, { ...tile, rotation: ((tile.rotation + 1) % 6) as typeof tile.rotation });
+
+    expect(() => assertGamesEqual({ ...game, board }, game)).toThrow(
+      `${key}: expected type ${tile.type} rotation ${tile.rotation}, got type ${tile.type} rotation ${(tile.rotation + 1) % 6}`,
+    );
+    expect(() => assertGamesEqual({ ...game, board }, game)).toThrow('Expected:');
+  });
+
+  it('should say when only the turn, bag or outcome differ', () => {
+    const game = replay(generateRandomGame(42, 6));
+
+    expect(() => assertGamesEqual({ ...game, availableTiles: [] }, game)).toThrow(
+      'Boards match; turn, bag or outcome differ',
+    );
+  });
+});
@@ -0,0 +1,53 @@
+/**
+ * Board Assertions
+ *
+ * Compares games rebuilt from saves or action logs against the original, and on a
+ * mismatch fails with both boards drawn as text and the cells that differ.
+ */
+
+import { GameState } from '../../src/redux/types';
+import { gamesEqual } from '../../src/redux/positionKey';
+import { boardToText } from '../../src/game/boardText';
+
+/**
+ * Describe each board cell whose tile differs between the two games
+ */
+function describeBoardDifferences(actual: GameState, expected: GameState): string[] {
+  const keys = new Set([...actual.board.keys(), ...expected.board.keys()]);
+  const describe = (state: GameState, key: string) => {
+    const tile = state.board.get(key);
+    return tile ? `type ${tile.type} rotation ${tile.rotation}` : 'empty';
+  };
+
+  return [...keys]
+    .sort()
+    .filter((key) => describe(actual, key) !== describe(expected, key))
+    .map((key) => `  ${key}: expected ${describe(expected, key)}, got ${describe(actual, key)}`);
+}
+
+/**
+ * Throw unless the two games are equal (see gamesEqual)
+ */
+export function assertGamesEqual(actual: GameState, expected: GameState): void {
+  if (gamesEqual(actual, expected)) {
+    return;
+  }
+
+  const draw = (state: GameState) =>
+    boardToText(state.board, state.players, state.flowEdges, state.boardRadius);
+  const differences = describeBoardDifferences(actual, expected);
+
+  throw new Error(
+    [
+      'Games differ',
+      differences.length > 0 ? 'Board cells:' : 'Boards match; turn, bag or outcome differ',
+      ...differences,
+      '',
+      'Expected:',
+      draw(expected),
+      '',
+      'Actual:',
+      draw(actual),
+    ].join('\n'),
+  );
+}
@@ -0,0 +1,61 @@
+/**
+ * Tests for the scripted backend
+ */
+
+import { describe, it, expect, beforeEach } from 'vitest';
+import { ScriptedBackend } from './scriptedBackend';
+import { generateRandomGameWithState } from './gameGenerator';
+import { resetPlayerIdCounter } from '../../src/redux/gameReducer';
+import { drawTile } from '../../src/redux/actions';
+
+describe('ScriptedBackend', () => {
+  beforeEach(() => {
+    resetPlayerIdCounter();
+  });
+
+  it('should reproduce the scripted board exactly', () => {
+    const { actions, finalState } = generateRandomGameWithState(999, 10);
+    const backend = new ScriptedBackend(actions);
+
+    resetPlayerIdCounter();
+    const state = backend.replay();
+
+    expect(state.board).toEqual(finalState.board);
+    expect(state.moveHistory.map(m => m.tile)).toEqual(finalState.moveHistory.map(m => m.tile));
+    expect(state.currentTile).toBe(finalState.currentTile);
+  });
+
+  it('should resume from an index to the same state', () => {
+    const { actions } = generateRandomGameWithState(888, 10);
+    const backend = new ScriptedBackend(actions);
+
+    resetPlayerIdCounter();
+    const full = backend.replay();
+
+    resetPlayerIdCounter();
+    const midpoint = Math.floor(backend.length / 2);
+    const partial = new ScriptedBackend(actions.slice(0, midpoint)).replay();
+    const resumed = backend.replay(partial, midpoint);
+
+    expect(resumed.board).toEqual(full.board);
+  });
+
+  it('should serve actions from an index', () => {
+    const { actions } = generateRandomGameWithState(999, 5);
+    const backend = new ScriptedBackend(actions);
+
+    expect(backend.actionsFromIndex(0)).toEqual(actions);
+    expect(backend.actionsFromIndex(3)).toEqual(actions.slice(3));
+    expect(backend.actionsFromIndex(backend.length)).toEqual([]);
+  });
+
+  it('should record posted actions without changing the script', () => {
+    const { actions } = generateRandomGameWithState(999, 5);
+    const backend = new ScriptedBackend(actions);
+
+    backend.postAction('game-1', drawTile());
+
+    expect(backend.getSubmittedActions()).toEqual([drawTile()]);
+    expect(backend.length).toBe(actions.length);
+  });
+});
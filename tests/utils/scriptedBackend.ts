@@ -0,0 +1,56 @@
+/**
+ * Scripted backend for deterministic tests
+ *
+ * Stands in for the multiplayer server: serves a fixed action log instead of a
+ * live one and records actions posted by the client instead of sending them.
+ * Nothing is randomized, so any view built from it is fully reproducible.
+ */
+
+import { GameAction } from '../../src/redux/actions';
+import { GameState } from '../../src/redux/types';
+import { gameReducer, initialState } from '../../src/redux/gameReducer';
+
+export class ScriptedBackend {
+  private readonly script: GameAction[];
+  private readonly submitted: Array<{ gameId: string; action: GameAction }> = [];
+
+  constructor(script: GameAction[]) {
+    this.script = [...script];
+  }
+
+  /**
+   * Number of actions in the script
+   */
+  get length(): number {
+    return this.script.length;
+  }
+
+  /**
+   * Get the scripted actions starting at an index (like a reconnecting client catching up)
+   */
+  actionsFromIndex(index: number): GameAction[] {
+    return this.script.slice(index);
+  }
+
+  /**
+   * Record an action posted by the client; the script itself is never modified
+   * Matches socket.postAction so it can be used in its place
+   */
+  postAction(gameId: string, action: GameAction): void {
+    this.submitted.push({ gameId, action });
+  }
+
+  /**
+   * Get all actions posted by the client, in order
+   */
+  getSubmittedActions(): GameAction[] {
+    return this.submitted.map(({ action }) => action);
+  }
+
+  /**
+   * Replay the script from an index onto a game state
+   */
+  replay(state: GameState = initialState, fromIndex = 0): GameState {
+    return this.actionsFromIndex(fromIndex).reduce(gameReducer, state);
+  }
+}
@@ -0,0 +1,141 @@
+/**
+ * AI-vs-AI self-play for balance testing
+ *
+ * Plays a complete two-player game with no human: each seat is driven by its own
+ * move strategy, and the game runs through the real reducer until it finishes.
+ * Games are seeded, so a strategy pairing can be compared over many games in a loop.
+ */
+
+import { GameAction } from '../../src/redux/actions';
+import { gameReducer, initialState } from '../../src/redux/gameReducer';
+import { GameState, WinType } from '../../src/redux/types';
+import { HexPosition, Player, Rotation } from '../../src/game/types';
+import { findLegalMoves } from '../../src/game/legality';
+import { selectAIMove, EvaluationStrategy, pathLengthEvaluator, MAX_AI_DIFFICULTY } from '../../src/game/ai';
+
+export interface StrategyMove {
+  position: HexPosition;
+  rotation: Rotation;
+}
+
+/**
+ * Choose a placement for the tile in hand, or null if there is none
+ */
+export type MoveStrategy = (state: GameState, player: Player) => StrategyMove | null;
+
+export interface SelfPlayResult {
+  winType: WinType | null;
+  winnerSeats: number[];     // Indexes into the strategies of the seats that won
+  moveCount: number;         // Tiles placed
+  actions: GameAction[];     // Full action log, replayable with gameReducer
+}
+
+/**
+ * The game's own AI at full strength, scoring positions with the given evaluator
+ */
+export function createAIStrategy(evaluator: EvaluationStrategy): MoveStrategy {
+  return (state, player) =>
+    selectAIMove(
+      state.board,
+      state.currentTile!,
+      player,
+      state.players,
+      state.teams,
+      false,
+      state.boardRadius,
+      MAX_AI_DIFFICULTY,
+      0,
+      undefined,
+      evaluator,
+      state.oppositeSidesVictory,
+    );
+}
+
+/**
+ * The game's own AI
+ */
+export const aiStrategy: MoveStrategy = createAIStrategy(pathLengthEvaluator);
+
+/**
+ * A baseline that plays a uniformly random legal placement
+ */
+export function createRandomStrategy(seed: number): MoveStrategy {
+  let value = seed;
+  const next = () => {
+    value = (value * 1664525 + 1013904223) % 4294967296;
+    return value / 4294967296;
+  };
+
+  return (state) => {
+    const moves: StrategyMove[] = [];
+    for (let r = 0; r < 6; r++) {
+      const rotation = r as Rotation;
+      findLegalMoves(
+        state.board,
+        state.currentTile!,
+        rotation,
+        state.players,
+        state.teams,
+        state.boardRadius,
+        false,
+      ).forEach((position) => moves.push({ position, rotation }));
+    }
+    return moves.length > 0 ? moves[Math.floor(next() * moves.length)] : null;
+  };
+}
+
+/**
+ * Play one seeded game between two strategies
+ * Seats face each other on opposite edges; supermoves are off
+ */
+export function playSelfPlayGame(
+  strategies: [MoveStrategy, MoveStrategy],
+  seed: number,
+  maxMoves = 100,
+): SelfPlayResult {
+  const actions: GameAction[] = [];
+  const apply = (current: GameState, action: GameAction): GameState => {
+    actions.push(action);
+    return gameReducer(current, action);
+  };
+
+  let state = initialState;
+  state = apply(state, { type: 'ADD_PLAYER', payload: { color: '#0173B2', edge: 0, isAI: true } });
+  state = apply(state, { type: 'ADD_PLAYER', payload: { color: '#DE8F05', edge: 1, isAI: true } });
+  state = apply(state, { type: 'START_GAME', payload: { seed, supermove: false } });
+  state = apply(state, { type: 'SHUFFLE_TILES', payload: { seed } });
+
+  // Seat ids in strategy order, then take opposite edges in seating order
+  const seatIds = state.configPlayers.map((p) => p.id);
+  for (const [index, playerId] of state.seatingPhase.seatingOrder.entries()) {
+    state = apply(state, { type: 'SELECT_EDGE', payload: { playerId, edgeNumber: index * 3 } });
+  }
+
+  let moveCount = 0;
+  while (state.phase === 'playing' && state.currentTile !== null && moveCount < maxMoves) {
+    const player = state.players[state.currentPlayerIndex];
+    const strategy = strategies[seatIds.indexOf(player.id)];
+    const move = strategy(state, player);
+    if (!move) {
+      break;
+    }
+
+    state = apply(state, { type: 'PLACE_TILE', payload: { position: move.position, rotation: move.rotation } });
+    moveCount++;
+    if (state.phase !== 'playing') {
+      break;
+    }
+
+    state = apply(state, { type: 'NEXT_PLAYER' });
+    state = apply(state, { type: 'DRAW_TILE' });
+  }
+
+  return {
+    winType: state.winType,
+    winnerSeats: state.winners
+      .map((id) => seatIds.indexOf(id))
+      .filter((seat) => seat >= 0),
+    moveCount,
+    actions,
+  };
+}